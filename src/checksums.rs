@@ -0,0 +1,213 @@
+//! Check characters of the ISO 7064 family.
+//!
+//! - mod 11-2: one check character (0-9 or X) over digits, used by ORCID
+//!   and ISNI
+//! - mod 97-10: two check digits over digits, used by ROR IDs and the base32
+//!   identifiers in [`crate::crockford`]
+//! - mod 11-10: one check digit over digits
+//! - mod 37-36: one check character (0-9 or A-Z) over alphanumerics
+//!
+//! and the mod 10 check digits outside ISO 7064:
+//!
+//! - Luhn: one check digit over digits, used by payment card numbers and
+//!   some national researcher and person IDs
+//! - weighted mod 10: one check digit over digits with repeating weights,
+//!   used by ISBN-13, ISMN and EAN/GTIN barcodes with weights 3 and 1
+//!
+//! `compute_*` take the string without its check characters, `verify_*` the
+//! string ending with them. Characters outside the system's alphabet, such
+//! as hyphens and spaces, are skipped, and letters are case-insensitive.
+
+const ALPHANUMERIC: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn digits(input: &str) -> impl Iterator<Item = u32> + '_ {
+    input.chars().filter_map(|c| c.to_digit(10))
+}
+
+/// Splits off the last `n` characters of the alphabet `keep` accepts, after
+/// dropping all other characters. `None` if there are no more than `n`.
+fn split_check(input: &str, n: usize, keep: impl Fn(char) -> bool) -> Option<(String, String)> {
+    let chars: String = input.chars().filter(|c| keep(*c)).collect();
+    if chars.len() <= n {
+        return None;
+    }
+    let (body, check) = chars.split_at(chars.len() - n);
+    Some((body.to_string(), check.to_string()))
+}
+
+/// The ISO 7064 mod 11-2 check character (0-9 or X) of the digits in
+/// `input`, e.g. `7` for the first 15 digits of the ORCID
+/// `0000-0002-1825-0097`.
+pub fn compute_mod11_2(input: &str) -> char {
+    let m = digits(input).fold(0, |m, d| ((m + d) * 2) % 11);
+    match (12 - m) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10).unwrap_or('0'),
+    }
+}
+
+/// Whether the last character of `input` is its mod 11-2 check character.
+pub fn verify_mod11_2(input: &str) -> bool {
+    split_check(input, 1, |c| c.is_ascii_digit() || c.eq_ignore_ascii_case(&'x'))
+        .filter(|(body, _)| body.chars().all(|c| c.is_ascii_digit()))
+        .is_some_and(|(body, check)| {
+            check.eq_ignore_ascii_case(&compute_mod11_2(&body).to_string())
+        })
+}
+
+/// The ISO 7064 mod 97-10 check digits (2-98) of the digits in `input`.
+pub fn compute_mod97_10(input: &str) -> u8 {
+    let rest = digits(input).fold(0, |r, d| (r * 10 + d) % 97);
+    (98 - (rest * 100) % 97) as u8
+}
+
+/// Whether the last two digits of `input` are its mod 97-10 check digits.
+pub fn verify_mod97_10(input: &str) -> bool {
+    split_check(input, 2, |c| c.is_ascii_digit())
+        .is_some_and(|(body, check)| check.parse() == Ok(compute_mod97_10(&body)))
+}
+
+/// The ISO 7064 mod 11-10 check digit of the digits in `input`.
+pub fn compute_mod11_10(input: &str) -> char {
+    let p = digits(input).fold(10, |p, d| {
+        let s = match (p + d) % 10 {
+            0 => 10,
+            s => s,
+        };
+        (2 * s) % 11
+    });
+    char::from_digit((11 - p) % 10, 10).unwrap_or('0')
+}
+
+/// Whether the last digit of `input` is its mod 11-10 check digit.
+pub fn verify_mod11_10(input: &str) -> bool {
+    split_check(input, 1, |c| c.is_ascii_digit())
+        .is_some_and(|(body, check)| check == compute_mod11_10(&body).to_string())
+}
+
+/// The value of an alphanumeric character, 0-9 for digits and 10-35 for
+/// the letters A-Z.
+fn alphanumeric_value(c: char) -> Option<u32> {
+    c.to_digit(36)
+}
+
+/// The ISO 7064 mod 37-36 check character (0-9 or A-Z) of the
+/// alphanumerics in `input`.
+pub fn compute_mod37_36(input: &str) -> char {
+    let p = input.chars().filter_map(alphanumeric_value).fold(36, |p, v| {
+        let s = match (p + v) % 36 {
+            0 => 36,
+            s => s,
+        };
+        (2 * s) % 37
+    });
+    ALPHANUMERIC[((37 - p) % 36) as usize] as char
+}
+
+/// Whether the last alphanumeric character of `input` is its mod 37-36
+/// check character.
+pub fn verify_mod37_36(input: &str) -> bool {
+    split_check(input, 1, |c| c.is_ascii_alphanumeric())
+        .is_some_and(|(body, check)| {
+            check.eq_ignore_ascii_case(&compute_mod37_36(&body).to_string())
+        })
+}
+
+/// The Luhn check digit of the digits in `input`: every second digit from
+/// the right, starting with the last, is doubled (less 9 if above 9).
+pub fn compute_luhn(input: &str) -> char {
+    let digits: Vec<u32> = digits(input).collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .zip([2, 1].into_iter().cycle())
+        .map(|(d, weight)| match d * weight {
+            n if n > 9 => n - 9,
+            n => n,
+        })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10).unwrap_or('0')
+}
+
+/// Whether the last digit of `input` is its Luhn check digit.
+pub fn verify_luhn(input: &str) -> bool {
+    split_check(input, 1, |c| c.is_ascii_digit())
+        .is_some_and(|(body, check)| check == compute_luhn(&body).to_string())
+}
+
+/// The mod 10 check digit of the digits in `input`, weighted with `weights`
+/// repeating from the right, starting with the last digit, e.g. `[3, 1]` for
+/// ISBN-13 and EAN-13. Unlike [`compute_luhn`], products are not reduced to
+/// one digit. Only the last digit of each weight counts, so any weights
+/// and inputs of any length are fine.
+pub fn compute_mod10_weighted(input: &str, weights: &[u32]) -> char {
+    let digits: Vec<u32> = digits(input).collect();
+    let sum = digits
+        .iter()
+        .rev()
+        .zip(weights.iter().cycle())
+        .fold(0, |sum, (d, w)| (sum + d * (w % 10)) % 10);
+    char::from_digit((10 - sum) % 10, 10).unwrap_or('0')
+}
+
+/// Whether the last digit of `input` is its weighted mod 10 check digit.
+pub fn verify_mod10_weighted(input: &str, weights: &[u32]) -> bool {
+    split_check(input, 1, |c| c.is_ascii_digit())
+        .is_some_and(|(body, check)| check == compute_mod10_weighted(&body, weights).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod11_2() {
+        assert_eq!(compute_mod11_2("000000021825009"), '7');
+        assert_eq!(compute_mod11_2("0000-0002-1694-233"), 'X');
+        assert!(verify_mod11_2("0000-0002-1825-0097"));
+        assert!(verify_mod11_2("0000-0002-1694-233x"));
+        assert!(!verify_mod11_2("0000-0002-1825-0098"));
+        assert!(!verify_mod11_2("X"));
+    }
+
+    #[test]
+    fn test_mod97_10() {
+        assert_eq!(compute_mod97_10("794"), 44);
+        assert!(verify_mod97_10("79444"));
+        assert!(!verify_mod97_10("79445"));
+        assert!(!verify_mod97_10("44"));
+    }
+
+    #[test]
+    fn test_mod11_10() {
+        assert_eq!(compute_mod11_10("79462"), '3');
+        assert!(verify_mod11_10("794623"));
+        assert!(!verify_mod11_10("794624"));
+    }
+
+    #[test]
+    fn test_mod37_36() {
+        assert_eq!(compute_mod37_36("A12425GABC1234002"), 'M');
+        assert_eq!(compute_mod37_36("a12425gabc1234002"), 'M');
+        assert!(verify_mod37_36("A12425G-ABC1234002-M"));
+        assert!(!verify_mod37_36("A12425GABC1234002N"));
+    }
+
+    #[test]
+    fn test_luhn() {
+        assert_eq!(compute_luhn("7992739871"), '3');
+        assert!(verify_luhn("79927398713"));
+        assert!(verify_luhn("4539 1488 0343 6467"));
+        assert!(!verify_luhn("79927398710"));
+        assert!(!verify_luhn("7"));
+    }
+
+    #[test]
+    fn test_mod10_weighted() {
+        assert_eq!(compute_mod10_weighted("978-3-16-148410", &[3, 1]), '0');
+        assert!(verify_mod10_weighted("978-0-306-40615-7", &[3, 1]));
+        assert!(!verify_mod10_weighted("978-0-306-40615-6", &[3, 1]));
+        assert_eq!(compute_mod10_weighted("978-3-16-148410", &[13, 21]), '0');
+        assert_eq!(compute_mod10_weighted("1", &[u32::MAX]), '5');
+    }
+}
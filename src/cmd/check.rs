@@ -0,0 +1,165 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::sync::Arc;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use commonmeta::doi_utils::{doi_resolution_status, validate_doi};
+
+use crate::cmd::{Failure, read_input};
+use crate::cmd::list::identifier_entries;
+
+/// Number of DOIs resolved at the same time.
+const CONCURRENCY: usize = 10;
+
+/// Build the check subcommand
+pub fn command() -> Command {
+    Command::new("check")
+        .about("Check whether DOIs are registered")
+        .long_about(
+            "Check whether DOIs are registered by resolving them via the DOI handle \
+            servers, and report the HTTP status of the resolution (a redirect for a \
+            registered DOI, 404 otherwise). Exits with status 1 if any DOI is \
+            invalid or not registered, and 3 if a DOI could not be resolved.\n\n\
+            Examples:\n\n\
+            commonmeta check 10.53731/abc12-de345\n\
+            commonmeta check 10.5555/12345678 https://doi.org/10.5555/87654321\n\
+            commonmeta check --file dois.txt\n\
+            (--file reads a plain-text or CSV file with one DOI per row, or a JSON array)",
+        )
+        .arg(
+            Arg::new("dois")
+                .help("DOIs to check")
+                .num_args(0..)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .help("Read the DOIs to check from this file (- for stdin)"),
+        )
+}
+
+/// Execute the check command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let mut dois: Vec<String> = matches
+        .get_many::<String>("dois")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+    if let Some(path) = matches.get_one::<String>("file") {
+        let content = read_input(path)?;
+        dois.extend(identifier_entries(&content)?);
+    }
+    if dois.is_empty() {
+        return Err(Failure::InvalidInput(
+            "check: give one or more DOIs or --file".to_string(),
+        ));
+    }
+
+    let statuses = resolve_all(&dois)?;
+    let mut failed = 0;
+    let mut unresolved = 0;
+    for (doi, status) in dois.iter().zip(&statuses) {
+        let (registered, line) = status_line(doi, *status);
+        if !registered {
+            failed += 1;
+            if status.is_none() && validate_doi(doi).is_some() {
+                unresolved += 1;
+            }
+        }
+        println!("{}", line);
+    }
+
+    let message = format!("{} of {} DOI(s) not registered", failed, dois.len());
+    if unresolved > 0 {
+        return Err(Failure::Network(message));
+    }
+    if failed > 0 {
+        return Err(Failure::Partial(message));
+    }
+    Ok(())
+}
+
+/// Resolve every valid DOI in `dois`, keeping their order. Invalid DOIs are
+/// not sent to the handle servers and get `None`.
+fn resolve_all(dois: &[String]) -> Result<Vec<Option<u16>>, String> {
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("failed to start async runtime: {}", e))?;
+    Ok(runtime.block_on(async {
+        let semaphore = Arc::new(Semaphore::new(CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for (idx, doi) in dois.iter().enumerate() {
+            if validate_doi(doi).is_none() {
+                continue;
+            }
+            let doi = doi.clone();
+            let semaphore = Arc::clone(&semaphore);
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                (idx, doi_resolution_status(&doi).await)
+            });
+        }
+        let mut statuses = vec![None; dois.len()];
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((idx, status)) = joined {
+                statuses[idx] = status;
+            }
+        }
+        statuses
+    }))
+}
+
+/// Whether `doi` counts as registered, and the line reported for it.
+fn status_line(doi: &str, status: Option<u16>) -> (bool, String) {
+    if validate_doi(doi).is_none() {
+        return (false, format!("{}: invalid DOI", doi));
+    }
+    match status {
+        Some(code) if code <= 308 => (true, format!("{}: registered (HTTP {})", doi, code)),
+        Some(code) => (false, format!("{}: unregistered (HTTP {})", doi, code)),
+        None => (false, format!("{}: unresolved (request failed)", doi)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_line() {
+        assert_eq!(
+            status_line("10.5555/12345678", Some(302)),
+            (true, "10.5555/12345678: registered (HTTP 302)".to_string())
+        );
+        assert_eq!(
+            status_line("10.5555/12345678", Some(404)),
+            (false, "10.5555/12345678: unregistered (HTTP 404)".to_string())
+        );
+        assert_eq!(
+            status_line("10.5555/12345678", None),
+            (false, "10.5555/12345678: unresolved (request failed)".to_string())
+        );
+        assert_eq!(
+            status_line("not-a-doi", None),
+            (false, "not-a-doi: invalid DOI".to_string())
+        );
+    }
+
+    #[test]
+    fn test_requires_dois() {
+        let m = command().get_matches_from(["check"]);
+        let err = execute(&m).unwrap_err();
+        assert_eq!(err.exit_code(), 2);
+        assert!(err.to_string().contains("one or more DOIs"));
+    }
+
+    #[test]
+    fn test_invalid_doi_is_not_resolved() {
+        let statuses = resolve_all(&["not-a-doi".to_string()]).unwrap();
+        assert_eq!(statuses, vec![None]);
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgMatches, Command};
+use clap_complete::Shell;
+
+/// Build the completions subcommand
+pub fn command() -> Command {
+    Command::new("completions")
+        .about("Generate shell completions")
+        .long_about(
+            "Print a completion script for the given shell, covering every \
+            subcommand, flag and flag value.\n\n\
+            Examples:\n\n\
+            commonmeta completions bash > /etc/bash_completion.d/commonmeta\n\
+            commonmeta completions zsh > \"${fpath[1]}/_commonmeta\"\n\
+            commonmeta completions fish > ~/.config/fish/completions/commonmeta.fish\n\
+            commonmeta completions powershell >> $PROFILE",
+        )
+        .arg(
+            Arg::new("shell")
+                .help("Shell to generate completions for")
+                .required(true)
+                .value_parser(clap::value_parser!(Shell))
+                .index(1),
+        )
+}
+
+/// Execute the completions command for the command-line interface `cli`
+pub fn execute(matches: &ArgMatches, cli: Command) -> Result<(), String> {
+    let shell = *matches.get_one::<Shell>("shell").expect("required");
+    print!("{}", generate(shell, cli));
+    Ok(())
+}
+
+/// Completion script for `shell`, generated from the subcommands and flags
+/// of `cli`.
+fn generate(shell: Shell, mut cli: Command) -> String {
+    let bin = cli.get_name().to_string();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut cli, bin, &mut script);
+    String::from_utf8_lossy(&script).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_shell_covers_subcommands_and_flags() {
+        let mut cli = crate::cli();
+        cli.build();
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let script = generate(shell, crate::cli());
+            for sub in cli.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+                let name = sub.get_name();
+                assert!(script.contains(name), "{shell} script is missing {name}");
+                let longs = sub
+                    .get_arguments()
+                    .filter(|arg| !arg.is_hide_set())
+                    .filter_map(Arg::get_long);
+                for long in longs {
+                    // fish names long flags without their dashes.
+                    let flag = match shell {
+                        Shell::Fish => format!("-l {long}"),
+                        _ => format!("--{long}"),
+                    };
+                    assert!(script.contains(&flag), "{shell} script is missing {flag}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bash_completes_flag_values() {
+        let script = generate(Shell::Bash, crate::cli());
+        assert!(script.contains("complete -F _commonmeta"));
+        assert!(script.contains("4.5 4.6 4.7"));
+    }
+}
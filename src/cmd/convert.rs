@@ -5,7 +5,10 @@
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::path::Path;
 
-use crate::cmd::resolve_db_path;
+use crate::cmd::{
+    Failure, deposit_args, deposit_head, read_input, resolve_db_path, write_output_file, xml_args,
+    xml_options,
+};
 
 pub fn command() -> Command {
     Command::new("convert")
@@ -15,23 +18,38 @@ pub fn command() -> Command {
             The input is a file path, a DOI/URL, or a ROR organization ID. \
             When --from is omitted the format is auto-detected: DOIs are \
             resolved via the DOI RA API; ROR URLs are detected by pattern; \
-            JSON files are inspected for schema markers.\n\n\
+            files are recognised by extension (.bib, .ris, .cff, .csv, .mrc) \
+            and otherwise by content (JSON schema markers, XML root elements \
+            and namespaces, BibTeX and RIS syntax). JSON that fits several \
+            formats is not guessed at: convert lists the candidates and asks \
+            for --from.\n\n\
             For ROR input, a local 'commonmeta.sqlite3' in the current \
             directory (produced by 'commonmeta list --to ror --file \
             commonmeta.sqlite3') is queried first — faster and offline. \
             The ROR API is used as a fallback when no local database exists.\n\n\
-            Supported input formats:  crossref, commonmeta, ror\n\
-            Supported output formats: commonmeta, csl, ror, inveniordm\n\n\
+            Supported input formats: commonmeta, crossref, crossref_xml, datacite,\n\
+            datacite_xml, csl, schemaorg, codemeta, cff, bibtex, ris, csv, jats, dublincore,\n\
+            endnote, marc21, inveniordm, jsonfeed, openaire, openalex, ror, vraix\n\n\
+            Supported output formats: commonmeta, yaml, jsonl, crossref, crossref_xml,\n\
+            datacite, datacite_xml, csl, schemaorg, inveniordm, bibtex, ris, csv, jats,\n\
+            dublincore, openaire, citation, markdown, html, ror\n\n\
             Examples:\n\n\
             commonmeta convert 10.5555/12345678\n\
             commonmeta convert https://doi.org/10.59350/gj8re-sca95 --to csl\n\
+            commonmeta convert https://doi.org/10.59350/gj8re-sca95 --to yaml\n\
             commonmeta convert https://ror.org/02nr0ka47\n\
             commonmeta convert https://ror.org/02nr0ka47 --to inveniordm\n\
-            commonmeta convert record.json --from commonmeta --to csl --file out.json",
+            commonmeta convert record.json --from commonmeta --to csl --output out.json\n\
+            commonmeta convert references.bib --to ris\n\
+            curl -s https://api.crossref.org/works/10.5555/12345678 | commonmeta convert - --from crossref --to bibtex\n\
+            commonmeta convert record.json --to crossref_xml --depositor Example --email info@example.org\n\
+            commonmeta convert record.json --to datacite_xml --schema-version 4.5\n\
+            commonmeta convert 10.5555/12345678 --match-affiliations\n\
+            commonmeta convert record.json --to datacite_xml --compact --xml-declaration none",
         )
         .arg(
             Arg::new("input")
-                .help("File path, DOI, URL, or ROR ID")
+                .help("File path (- for stdin), DOI, URL, or ROR ID")
                 .required(true)
                 .index(1),
         )
@@ -39,13 +57,13 @@ pub fn command() -> Command {
             Arg::new("from")
                 .long("from")
                 .short('f')
-                .help("Input format (crossref, commonmeta, ror); auto-detected if omitted"),
+                .help("Input format (see list above); auto-detected if omitted"),
         )
         .arg(
             Arg::new("to")
                 .long("to")
                 .short('t')
-                .help("Output format (commonmeta, csl, ror, inveniordm)")
+                .help("Output format (see list above)")
                 .default_value("commonmeta"),
         )
         .arg(
@@ -63,7 +81,18 @@ pub fn command() -> Command {
         .arg(
             Arg::new("file")
                 .long("file")
-                .help("Write output to this file instead of stdout"),
+                .short('o')
+                .visible_alias("output")
+                .help("Write output to this file instead of stdout (.zip, .gz or .zst to compress)"),
+        )
+        .args(deposit_args())
+        .args(xml_args())
+        .arg(
+            Arg::new("schema-version")
+                .long("schema-version")
+                .help("DataCite Metadata Schema version for datacite_xml output")
+                .value_parser(["4.5", "4.6", "4.7"])
+                .default_value("4.7"),
         )
         .arg(
             Arg::new("no-network")
@@ -71,6 +100,12 @@ pub fn command() -> Command {
                 .help("Disable all outbound network requests; fails if the operation would require network access")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("match-affiliations")
+                .long("match-affiliations")
+                .help("Add ROR IDs to affiliations that only have a name (local ROR database, else the ROR API)")
+                .action(ArgAction::SetTrue),
+        )
 }
 
 // ─── Format detection ─────────────────────────────────────────────────────────
@@ -90,6 +125,7 @@ fn doi_prefix(s: &str) -> Option<String> {
 
 fn ra_for_prefix(prefix: &str) -> Option<String> {
     let url = format!("https://doi.org/ra/{prefix}");
+    tracing::debug!("GET {}", url);
     let resp = reqwest::blocking::get(&url).ok()?;
     let json: serde_json::Value = resp.json().ok()?;
     json.as_array()?
@@ -99,6 +135,46 @@ fn ra_for_prefix(prefix: &str) -> Option<String> {
         .map(|s| s.to_lowercase())
 }
 
+/// Input format implied by a file name, for extensions that belong to a
+/// single format. `.json` and `.xml` are shared by several formats and are
+/// left to [`detect_format`].
+pub(crate) fn format_from_extension(path: &str) -> Option<&'static str> {
+    let path = Path::new(path);
+    if path.file_name().and_then(|n| n.to_str()) == Some("CITATION.cff") {
+        return Some("cff");
+    }
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "bib" | "bibtex" => Some("bibtex"),
+        "ris" => Some("ris"),
+        "cff" => Some("cff"),
+        "csv" | "tsv" => Some("csv"),
+        "mrc" | "marc" => Some("marc21"),
+        _ => None,
+    }
+}
+
+/// XML input format, from the root element or namespace of `input`.
+fn detect_xml_format(input: &str) -> &'static str {
+    if input.contains("<doi_batch") || input.contains("<crossref_result") || input.contains("<doi_records") {
+        "crossref_xml"
+    } else if input.contains("datacite.org/schema/kernel") {
+        "datacite_xml"
+    } else if input.contains("<oai_dc:dc") || input.contains("<OAI-PMH") {
+        "dublincore"
+    } else if input.contains("www.loc.gov/MARC21/slim") {
+        "marc21"
+    } else if input.contains("<records>") && input.contains("<ref-type") {
+        "endnote"
+    } else if input.contains("<article") || input.contains("<element-citation") || input.contains("<mixed-citation") {
+        "jats"
+    } else {
+        // Anything else is treated as an HTML landing page with embedded
+        // schema.org metadata.
+        "schemaorg"
+    }
+}
+
 pub(crate) fn detect_format(input: &str) -> String {
     // ROR URL or bare ROR ID
     if commonmeta::utils::validate_ror(input).is_some() {
@@ -108,35 +184,125 @@ pub(crate) fn detect_format(input: &str) -> String {
     if let Some(prefix) = doi_prefix(input) {
         return ra_for_prefix(&prefix).unwrap_or_else(|| "crossref".to_string());
     }
+    // Text formats → recognise them by their leading syntax
+    let trimmed = input.trim_start();
+    if trimmed.starts_with('<') {
+        return detect_xml_format(trimmed).to_string();
+    }
+    if trimmed.starts_with('@') {
+        return "bibtex".to_string();
+    }
+    if trimmed.starts_with("TY  -") {
+        return "ris".to_string();
+    }
+    if input.contains("cff-version:") {
+        return "cff".to_string();
+    }
     // JSON content → inspect schema markers
+    if let Ok(v) = serde_json::from_str::<serde_json::Value>(input)
+        && let Some(format) = json_format_candidates(&v).first()
+    {
+        return format.to_string();
+    }
+    "commonmeta".to_string()
+}
+
+/// Input formats a JSON document could be in. Documents that declare their
+/// format (a commonmeta `schema_version`, a Crossref `message-type`, a JSON-LD
+/// `@context`, ...) get exactly one; otherwise every format whose typical
+/// keys are present is listed, most likely first.
+fn json_format_candidates(v: &serde_json::Value) -> Vec<&'static str> {
+    let str_field = |key: &str| v.get(key).and_then(|s| s.as_str()).unwrap_or("");
+    if str_field("schema_version").starts_with("https://commonmeta.org") {
+        return vec!["commonmeta"];
+    }
+    // Crossref API envelope, or a bare work as found in list responses
+    if v.get("message-type").is_some() || (v.get("DOI").is_some() && v.get("member").is_some()) {
+        return vec!["crossref"];
+    }
+    // ROR record
+    if str_field("id").starts_with("https://ror.org/") && v.get("names").is_some() {
+        return vec!["ror"];
+    }
+    // JSON-LD: CodeMeta before schema.org, as CodeMeta documents also
+    // reference the schema.org vocabulary
+    let context = v.get("@context").map(|c| c.to_string()).unwrap_or_default();
+    if context.contains("codemeta") {
+        return vec!["codemeta"];
+    }
+    if context.contains("schema.org") {
+        return vec!["schemaorg"];
+    }
+    // DataCite REST API response
+    if v.pointer("/data/attributes/doi").is_some() {
+        return vec!["datacite"];
+    }
+    if str_field("version").starts_with("https://jsonfeed.org") {
+        return vec!["jsonfeed"];
+    }
+    if str_field("id").starts_with("https://openalex.org/") {
+        return vec!["openalex"];
+    }
+
+    let mut candidates = Vec::new();
+    // Bare DataCite attributes
+    if v.pointer("/types/resourceTypeGeneral").is_some() {
+        candidates.push("datacite");
+    }
+    // InvenioRDM record
+    if v.get("pids").is_some() && v.get("metadata").is_some() {
+        candidates.push("inveniordm");
+    }
+    // CSL-JSON item
+    if v.get("type").is_some_and(|t| t.is_string())
+        && (v.get("issued").is_some() || v.get("container-title").is_some())
+    {
+        candidates.push("csl");
+    }
+    candidates
+}
+
+/// Input format of `input` for convert without `--from`: as
+/// [`detect_format`], but JSON that fits several formats equally well is an
+/// error naming them, rather than a guess.
+pub(crate) fn sniff_format(input: &str) -> Result<String, String> {
     if let Ok(v) = serde_json::from_str::<serde_json::Value>(input) {
-        if v.get("schema_version")
-            .and_then(|s| s.as_str())
-            .map(|s| s.starts_with("https://commonmeta.org"))
-            .unwrap_or(false)
-        {
-            return "commonmeta".to_string();
-        }
-        // Crossref API envelope
-        if v.get("message-type").is_some() {
-            return "crossref".to_string();
+        let candidates = json_format_candidates(&v);
+        if candidates.len() > 1 {
+            return Err(format!(
+                "cannot tell the input format: it could be any of {}; pass --from with the right one",
+                candidates.join(", ")
+            ));
         }
-        // ROR record
-        if v.get("id")
-            .and_then(|s| s.as_str())
-            .map(|s| s.starts_with("https://ror.org/"))
-            .unwrap_or(false)
-            && v.get("names").is_some()
-        {
-            return "ror".to_string();
+    }
+    Ok(detect_format(input))
+}
+
+/// Reader for a DOI, URL or ROR ID: the registration agency for a DOI, `ror`
+/// for a ROR ID and `schemaorg` for any other URL.
+pub(crate) fn identifier_format(id: &str) -> Result<String, String> {
+    match detect_format(id).as_str() {
+        "commonmeta" if id.starts_with("http://") || id.starts_with("https://") => {
+            Ok("schemaorg".to_string())
         }
+        "commonmeta" => Err(format!("'{}' is not a DOI, URL or ROR ID", id)),
+        ra if doi_prefix(id).is_some() && !matches!(ra, "crossref" | "datacite") => Err(format!(
+            "'{}' is registered with {}; only Crossref and DataCite DOIs are supported",
+            id, ra
+        )),
+        other => Ok(other.to_string()),
     }
-    "commonmeta".to_string()
+}
+
+/// Fetch the metadata for a DOI, URL or ROR ID from the API that serves it.
+pub(crate) fn read_identifier(id: &str) -> Result<commonmeta::Data, Failure> {
+    let from = identifier_format(id)?;
+    Ok(commonmeta::read(&from, id)?)
 }
 
 // ─── Execute ─────────────────────────────────────────────────────────────────
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
     let input_arg = matches.get_one::<String>("input").expect("required");
     let out_file = matches.get_one::<String>("file");
     let no_network = matches.get_flag("no-network");
@@ -144,7 +310,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     let locale = matches.get_one::<String>("locale").map(String::as_str);
     let to_arg = matches.get_one::<String>("to").expect("has default");
 
-    let is_local_file = Path::new(input_arg).exists();
+    let is_local_file = input_arg == "-" || Path::new(input_arg).exists();
 
     // When --no-network is set and the input is a DOI/URL, look it up in the
     // local commonmeta database instead of fetching from the API.
@@ -162,10 +328,9 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 "local database not found at '{}'; \
                 run 'commonmeta import {}' or remove --no-network",
                 db_path_str, input_arg
-            ));
+            ).into());
         }
-        let data = commonmeta::read_sqlite_by_id(&doi_url, db_path)
-            .map_err(|e| e.to_string())?
+        let data = commonmeta::read_sqlite_by_id(&doi_url, db_path)?
             .ok_or_else(|| format!(
                 "'{}' not found in local database '{}'; \
                 run 'commonmeta import {}' or remove --no-network",
@@ -174,25 +339,27 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         let json = serde_json::to_string(&data).map_err(|e| e.to_string())?;
         let to_arg = to_arg.as_str();
         let output = if to_arg == "citation" {
-            commonmeta::convert_citation("commonmeta", &json, style, locale)
-                .map_err(|e| e.to_string())?
+            commonmeta::convert_citation("commonmeta", &json, style, locale)?
         } else {
-            commonmeta::convert("commonmeta", to_arg, &json).map_err(|e| e.to_string())?
+            commonmeta::convert("commonmeta", to_arg, &json)?
         };
-        return write_output(&output, to_arg, out_file);
+        return Ok(write_output(&output, to_arg, out_file)?);
     }
 
     let input = if is_local_file {
-        std::fs::read_to_string(input_arg)
-            .map_err(|e| format!("failed to read '{}': {}", input_arg, e))?
+        read_input(input_arg)?
     } else {
         input_arg.clone()
     };
 
     let from = match matches.get_one::<String>("from") {
         Some(f) => f.clone(),
-        None => detect_format(&input),
+        None => match is_local_file.then(|| format_from_extension(input_arg)).flatten() {
+            Some(format) => format.to_string(),
+            None => sniff_format(&input)?,
+        },
     };
+    tracing::debug!("reading input as {}", from);
 
     // For ROR input the natural default output is "ror", not "commonmeta".
     let to_arg = to_arg.as_str();
@@ -207,7 +374,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         // Normalize the input to a full ROR URL for the SQLite lookup.
         let ror_id = commonmeta::utils::normalize_ror(&input);
         if ror_id.is_empty() {
-            return Err(format!("'{}' is not a valid ROR identifier", input));
+            return Err(format!("'{}' is not a valid ROR identifier", input).into());
         }
 
         // Prefer the local SQLite database (COMMONMETA_DB > platform default);
@@ -215,36 +382,87 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         let db_path_str = resolve_db_path(None);
         let db_path = Path::new(&db_path_str);
         let data = if db_path.exists() {
-            commonmeta::fetch_ror_sqlite(&ror_id, db_path).map_err(|e| e.to_string())?
+            commonmeta::fetch_ror_sqlite(&ror_id, db_path)?
         } else if no_network {
             return Err(format!(
                 "ROR lookup requires network access (local database not found at '{}'); \
                 run 'commonmeta import --from ror' or remove --no-network",
                 db_path_str
-            ));
+            ).into());
         } else {
-            commonmeta::fetch_ror(&ror_id).map_err(|e| e.to_string())?
+            commonmeta::fetch_ror(&ror_id)?
         };
 
         let output = match to {
-            "inveniordm" => commonmeta::write("ror", &data).map_err(|e| e.to_string())?,
-            _ => commonmeta::write_ror_json(&data).map_err(|e| e.to_string())?,
+            "inveniordm" => commonmeta::write("ror", &data)?,
+            _ => commonmeta::write_ror_json(&data)?,
         };
 
-        return write_output(&output, to, out_file);
+        return Ok(write_output(&output, to, out_file)?);
     }
 
     // ── Scholarly-work input path ─────────────────────────────────────────────
-    let output = if to == "citation" {
-        commonmeta::convert_citation(&from, &input, style, locale).map_err(|e| e.to_string())?
+    // Multi-record inputs (a .bib file, a Crossref deposit, ...) convert every
+    // record, into one document where the output format allows it.
+    let mut list = commonmeta::read_list(&from, &input)?;
+
+    // Legacy GRID affiliations (common in older Crossref deposits) are
+    // upgraded to ROR when a local ROR database is available.
+    let db_path_str = resolve_db_path(None);
+    let db_path = Path::new(&db_path_str);
+    if db_path.exists() {
+        match commonmeta::upgrade_grid_ids(&mut list, db_path) {
+            Ok(0) => {}
+            Ok(n) => tracing::debug!("upgraded {} GRID IDs to ROR", n),
+            Err(e) => tracing::warn!("GRID to ROR upgrade skipped: {}", e),
+        }
+    }
+    if matches.get_flag("match-affiliations") {
+        let db_path = if db_path.exists() {
+            Some(db_path)
+        } else if no_network {
+            return Err(format!(
+                "affiliation matching requires network access (local database not found at '{}'); \
+                run 'commonmeta import --from ror' or remove --no-network",
+                db_path_str
+            ).into());
+        } else {
+            None
+        };
+        match commonmeta::match_ror_affiliations(&mut list, db_path) {
+            Ok(n) => tracing::debug!("matched {} affiliations to ROR", n),
+            Err(e) => tracing::warn!("affiliation matching skipped: {}", e),
+        }
+    }
+    let output = if to == "crossref_xml" {
+        commonmeta::write_crossref_xml(&list, &deposit_head(matches), &xml_options(matches))?
+    } else if to == "datacite_xml" {
+        let version = matches.get_one::<String>("schema-version").unwrap();
+        commonmeta::write_datacite_xml(&list, version, &xml_options(matches))?
+    } else if matches!(to, "dublincore" | "openaire") {
+        commonmeta::write_xml(to, &list, &xml_options(matches))?
+    } else if let [data] = list.as_slice() {
+        commonmeta::write_with_style(to, data, style, locale)?
     } else {
-        commonmeta::convert(&from, to, &input).map_err(|e| e.to_string())?
+        commonmeta::write_list_citation(&list, to, style, locale)?
     };
 
-    write_output(&output, to, out_file)
+    Ok(write_output(&output, to, out_file)?)
 }
 
-fn write_output(output: &[u8], to: &str, out_file: Option<&String>) -> Result<(), String> {
+/// `list` written in format `to` with default options: one document for the
+/// XML formats and for more than one record, as convert does.
+pub(crate) fn write_records(list: &[commonmeta::Data], to: &str) -> commonmeta::Result<Vec<u8>> {
+    match list {
+        _ if matches!(to, "crossref_xml" | "datacite_xml" | "dublincore" | "openaire") => {
+            commonmeta::write_xml(to, list, &commonmeta::XmlOptions::default())
+        }
+        [data] => commonmeta::write(to, data),
+        _ => commonmeta::write_list(list, to),
+    }
+}
+
+pub(crate) fn write_output(output: &[u8], to: &str, out_file: Option<&String>) -> Result<(), String> {
     // JSON formats get pretty-printed; XML/YAML stay as-is.
     let formatted: Vec<u8> = if matches!(to, "inveniordm") {
         output.to_vec()
@@ -256,8 +474,7 @@ fn write_output(output: &[u8], to: &str, out_file: Option<&String>) -> Result<()
     };
 
     match out_file {
-        Some(path) => std::fs::write(path, &formatted)
-            .map_err(|e| format!("failed to write '{}': {}", path, e)),
+        Some(path) => write_output_file(path, &formatted),
         None => {
             println!("{}", String::from_utf8_lossy(&formatted));
             Ok(())
@@ -280,7 +497,7 @@ mod tests {
         // (Ok), or the DB/record is absent and a "not found" error is returned.
         // In neither case should the error be an API-fetch refusal.
         let m = parse_args(&["convert", "--no-network", "10.7554/elife.01567"]);
-        match execute(&m) {
+        match execute(&m).map_err(|e| e.to_string()) {
             Ok(()) => {}
             Err(e) => assert!(
                 e.contains("not found") || e.contains("--no-network"),
@@ -292,7 +509,7 @@ mod tests {
     #[test]
     fn test_no_network_with_doi_url_uses_local_db() {
         let m = parse_args(&["convert", "--no-network", "https://doi.org/10.7554/elife.01567"]);
-        match execute(&m) {
+        match execute(&m).map_err(|e| e.to_string()) {
             Ok(()) => {}
             Err(e) => assert!(
                 e.contains("not found") || e.contains("--no-network"),
@@ -307,10 +524,139 @@ mod tests {
         // Fails at parse time because the JSON is not valid commonmeta — but NOT
         // with a --no-network error.
         let m = parse_args(&["convert", "--no-network", r#"{"type":"JournalArticle"}"#]);
-        let err = execute(&m).unwrap_err();
+        let err = execute(&m).unwrap_err().to_string();
         assert!(
             !err.contains("--no-network"),
             "should not fail at network guard for inline JSON, got: {err}"
         );
     }
+
+    #[test]
+    fn test_deposit_head_from_args() {
+        let m = parse_args(&[
+            "convert",
+            "record.json",
+            "--to",
+            "crossref_xml",
+            "--depositor",
+            "Example Blog",
+            "--email",
+            "info@example.org",
+        ]);
+        let head = deposit_head(&m);
+        assert_eq!(head.depositor, "Example Blog");
+        assert_eq!(head.email, "info@example.org");
+        assert!(head.registrant.is_empty());
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(format_from_extension("refs.bib"), Some("bibtex"));
+        assert_eq!(format_from_extension("export.RIS"), Some("ris"));
+        assert_eq!(format_from_extension("path/to/CITATION.cff"), Some("cff"));
+        assert_eq!(format_from_extension("works.tsv"), Some("csv"));
+        assert_eq!(format_from_extension("record.json"), None);
+        assert_eq!(format_from_extension("record.xml"), None);
+    }
+
+    #[test]
+    fn test_detect_format_from_content() {
+        assert_eq!(detect_format("@article{key,\n  title = {A}\n}"), "bibtex");
+        assert_eq!(detect_format("TY  - JOUR\nTI  - A\nER  - "), "ris");
+        assert_eq!(detect_format("cff-version: 1.2.0\ntitle: A"), "cff");
+        assert_eq!(
+            detect_format(r#"<?xml version="1.0"?><doi_batch version="5.3.1"></doi_batch>"#),
+            "crossref_xml"
+        );
+        assert_eq!(
+            detect_format(r#"<resource xmlns="http://datacite.org/schema/kernel-4"></resource>"#),
+            "datacite_xml"
+        );
+        assert_eq!(
+            detect_format(r#"{"@context":"https://w3id.org/codemeta/3.0","name":"x"}"#),
+            "codemeta"
+        );
+        assert_eq!(
+            detect_format(r#"{"@context":"http://schema.org","@type":"Dataset"}"#),
+            "schemaorg"
+        );
+        assert_eq!(
+            detect_format(r#"{"type":"article-journal","issued":{"date-parts":[[2020]]}}"#),
+            "csl"
+        );
+        assert_eq!(detect_format(r#"{"type":"JournalArticle"}"#), "commonmeta");
+    }
+
+    #[test]
+    fn test_sniff_format_reports_ambiguous_json() {
+        let input = r#"{"types":{"resourceTypeGeneral":"Dataset"},"type":"dataset","issued":{"date-parts":[[2020]]}}"#;
+        let err = sniff_format(input).unwrap_err();
+        assert!(err.contains("any of datacite, csl"), "got: {err}");
+        assert_eq!(detect_format(input), "datacite");
+        // A Crossref work also carries CSL keys, but identifies itself.
+        let work = r#"{"DOI":"10.5555/1","member":"7822","type":"journal-article","issued":{}}"#;
+        assert_eq!(sniff_format(work).unwrap(), "crossref");
+    }
+
+    #[test]
+    fn test_convert_bibtex_file_to_ris() {
+        let dir = std::env::temp_dir().join("commonmeta_convert_bibtex_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("refs.bib");
+        let output = dir.join("refs.ris");
+        std::fs::write(
+            &input,
+            "@article{one,\n  title = {First},\n  year = {2020}\n}\n\n\
+             @article{two,\n  title = {Second},\n  year = {2021}\n}\n",
+        )
+        .unwrap();
+        let m = parse_args(&[
+            "convert",
+            input.to_str().unwrap(),
+            "--to",
+            "ris",
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+        execute(&m).unwrap();
+        let ris = std::fs::read_to_string(&output).unwrap();
+        assert!(ris.contains("First"));
+        assert!(ris.contains("Second"));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_xml_options_from_args() {
+        let m = parse_args(&["convert", "record.json", "--to", "datacite_xml"]);
+        assert_eq!(xml_options(&m), commonmeta::XmlOptions::default());
+
+        let m = parse_args(&[
+            "convert",
+            "record.json",
+            "--compact",
+            "--indent",
+            "4",
+            "--xml-declaration",
+            "version",
+        ]);
+        let options = xml_options(&m);
+        assert!(!options.pretty);
+        assert_eq!(options.indent_size, 4);
+        assert!(options.declaration);
+        assert_eq!(options.encoding, None);
+
+        let m = parse_args(&["convert", "record.json", "--xml-declaration", "none"]);
+        assert!(!xml_options(&m).declaration);
+    }
+
+    #[test]
+    fn test_schema_version_arg() {
+        let m = parse_args(&["convert", "record.json", "--to", "datacite_xml"]);
+        assert_eq!(m.get_one::<String>("schema-version").unwrap(), "4.7");
+        let m = parse_args(&["convert", "record.json", "--schema-version", "4.5"]);
+        assert_eq!(m.get_one::<String>("schema-version").unwrap(), "4.5");
+        assert!(command()
+            .try_get_matches_from(["convert", "record.json", "--schema-version", "3.1"])
+            .is_err());
+    }
 }
@@ -4,7 +4,10 @@
 
 use clap::{ArgMatches, Command};
 
-use crate::utils::decode_id;
+use serde_json::{Value, json};
+
+use crate::cmd::{Failure, output_arg, read_input, write_lines};
+use crate::utils::{decode_id, validate_id};
 
 /// Build the decode subcommand
 pub fn command() -> Command {
@@ -14,29 +17,222 @@ pub fn command() -> Command {
             "Decode a DOI, ROR or ORCID identifier. For DOIs only Crockford \
             base32-encoding is supported, used by Rogue Scholar and some DataCite \
             members.\n\n\
+            Pass --file, or - as the identifier to read from stdin, to decode many \
+            identifiers (one per line) in one go; each is printed with its number \
+            or error, followed by a summary of failures.\n\n\
             Example usage:\n\n\
-            commonmeta decode 10.54900/d3ck1-skq19",
+            commonmeta decode 10.54900/d3ck1-skq19\n\
+            commonmeta decode 10.54900/00000-c1s --no-checksum\n\
+            commonmeta decode --file ids.txt\n\
+            cat ids.txt | commonmeta decode -\n\
+            commonmeta decode --file ids.txt --json --output decoded.jsonl",
         )
         .arg(
             clap::Arg::new("identifier")
-                .help("Identifier to decode")
-                .required(true)
+                .help("Identifier to decode, or - to read identifiers from stdin")
+                .required_unless_present("file")
                 .index(1),
         )
+        .arg(
+            clap::Arg::new("no-checksum")
+                .long("no-checksum")
+                .help("Decode identifiers minted without the ISO 7064 checksum suffix")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("json")
+                .long("json")
+                .help("Print a JSON object per identifier instead of the bare number")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("file")
+                .long("file")
+                .help("Read identifiers to decode from this file, one per line (- for stdin)")
+                .conflicts_with("identifier"),
+        )
+        .arg(output_arg())
 }
 
 /// Execute the decode command
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let checksum = !matches.get_flag("no-checksum");
+    let as_json = matches.get_flag("json");
+    let output = matches.get_one::<String>("output");
+    if let Some(path) = matches.get_one::<String>("file") {
+        let content = read_input(path)?;
+        return decode_batch(&content, checksum, as_json, output);
+    }
     let input = matches.get_one::<String>("identifier").expect("required");
+    if input == "-" {
+        return decode_batch(&read_input(input)?, checksum, as_json, output);
+    }
 
-    match decode_id(input) {
-        Ok(number) => {
-            println!("{}", number);
-            Ok(())
-        }
+    if as_json {
+        let result = decode_json(input, checksum);
+        write_lines(&[result.to_string()], output)?;
+        return match result.get("error").and_then(Value::as_str) {
+            Some(e) => Err(Failure::InvalidInput(e.to_string())),
+            None => Ok(()),
+        };
+    }
+
+    match decode_id(input, checksum) {
+        Ok(number) => Ok(write_lines(&[number.to_string()], output)?),
         Err(e) => {
-            println!("{}", e);
-            Err(e)
+            let e = if checksum && e.contains("checksum") {
+                format!("{} (use --no-checksum for identifiers minted without one)", e)
+            } else {
+                e
+            };
+            write_lines(std::slice::from_ref(&e), output)?;
+            Err(Failure::InvalidInput(e))
         }
     }
 }
+
+/// Decode every non-empty line of `content`, writing `identifier<TAB>number`
+/// or `identifier<TAB>error: ...` (with `as_json`, one JSON object) per line.
+/// Fails with the number of failures if any identifier can't be decoded.
+fn decode_batch(
+    content: &str,
+    checksum: bool,
+    as_json: bool,
+    output: Option<&String>,
+) -> Result<(), Failure> {
+    let (lines, failed) = if as_json {
+        let results: Vec<Value> = content
+            .lines()
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| decode_json(id, checksum))
+            .collect();
+        let failed = results.iter().filter(|r| r.get("error").is_some()).count();
+        (results.iter().map(Value::to_string).collect(), failed)
+    } else {
+        decode_lines(content, checksum)
+    };
+    write_lines(&lines, output)?;
+    if failed > 0 {
+        return Err(Failure::Partial(format!(
+            "decode: {} of {} identifiers failed to decode",
+            failed,
+            lines.len()
+        )));
+    }
+    Ok(())
+}
+
+/// `--json` result for one identifier. `checksum_valid` is `null` when the
+/// checksum wasn't checked, and `error` is only present on failure.
+pub(crate) fn decode_json(id: &str, checksum: bool) -> Value {
+    let (_, id_type) = validate_id(id);
+    let id_type = (!id_type.is_empty()).then_some(id_type);
+    match decode_id(id, checksum) {
+        Ok(number) => json!({
+            "identifier": id,
+            "type": id_type,
+            "value": json_number(number),
+            "checksum_valid": checksum.then_some(true),
+        }),
+        Err(e) => json!({
+            "identifier": id,
+            "type": id_type,
+            "value": null,
+            "checksum_valid": (checksum && e.contains("checksum")).then_some(false),
+            "error": e,
+        }),
+    }
+}
+
+/// A decoded number as JSON: a number where it fits into a `u64`, which
+/// JSON parsers generally read correctly, and a string beyond.
+pub(crate) fn json_number(number: u128) -> Value {
+    match u64::try_from(number) {
+        Ok(number) => json!(number),
+        Err(_) => json!(number.to_string()),
+    }
+}
+
+/// Output lines for a batch of identifiers and the number that failed.
+fn decode_lines(content: &str, checksum: bool) -> (Vec<String>, usize) {
+    let mut failed = 0;
+    let lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| match decode_id(id, checksum) {
+            Ok(number) => format!("{}\t{}", id, number),
+            Err(e) => {
+                failed += 1;
+                format!("{}\terror: {}", id, e)
+            }
+        })
+        .collect();
+    (lines, failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_lines_reports_failures() {
+        let (lines, failed) = decode_lines("10.54900/00000-c1s20\n\n  not-an-id  \n", true);
+        assert_eq!(failed, 1);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "10.54900/00000-c1s20\t12345");
+        assert!(lines[1].starts_with("not-an-id\terror: "));
+    }
+
+    #[test]
+    fn test_decode_lines_without_checksum() {
+        // 00000-c1s is 12345 without its checksum digits
+        let (lines, failed) = decode_lines("10.54900/00000-c1s", false);
+        assert_eq!(failed, 0);
+        assert_eq!(lines, vec!["10.54900/00000-c1s\t12345"]);
+        let (_, failed) = decode_lines("10.54900/00000-c1s", true);
+        assert_eq!(failed, 1);
+    }
+
+    #[test]
+    fn test_decode_json() {
+        assert_eq!(
+            decode_json("10.54900/00000-c1s20", true),
+            json!({
+                "identifier": "10.54900/00000-c1s20",
+                "type": "DOI",
+                "value": 12345,
+                "checksum_valid": true,
+            })
+        );
+        let result = decode_json("10.54900/00000-c1s21", true);
+        assert_eq!(result["checksum_valid"], json!(false));
+        assert!(result["value"].is_null());
+        assert!(result.get("error").is_some());
+        assert!(decode_json("10.54900/00000-c1s", false)["checksum_valid"].is_null());
+    }
+
+    #[test]
+    fn test_identifier_or_file_required() {
+        assert!(command().try_get_matches_from(["decode"]).is_err());
+        assert!(command().try_get_matches_from(["decode", "--file", "ids.txt"]).is_ok());
+    }
+
+    #[test]
+    fn test_output_to_zip_file() {
+        let dir = std::env::temp_dir().join("commonmeta_decode_output_test");
+        let path = dir.join("decoded.txt.zip");
+        std::fs::create_dir_all(&dir).unwrap();
+        let m = command().get_matches_from([
+            "decode",
+            "10.54900/00000-c1s20",
+            "--output",
+            path.to_str().unwrap(),
+        ]);
+        execute(&m).unwrap();
+        let content = commonmeta::file_utils::read_zip_file(&path, "decoded.txt").unwrap();
+        assert_eq!(content, b"12345\n");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::path::Path;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::{Map, Value, json};
+
+use crate::cmd::convert::{read_identifier, sniff_format};
+use crate::cmd::{Failure, read_input};
+use crate::diagnostic::{Stream, Style, paint};
+
+/// Build the diff subcommand
+pub fn command() -> Command {
+    Command::new("diff")
+        .about("Compare two metadata records field by field")
+        .long_about(
+            "Convert two records to commonmeta and print the fields that were \
+            added (+), removed (-) or changed (~) in the second one, with the \
+            JSON pointer of each value. Each input is a file (- for stdin) or a \
+            DOI, URL or ROR ID to fetch, e.g. to audit what a registration agency \
+            changed compared to your deposit. Lists are compared position by \
+            position.\n\n\
+            Examples:\n\n\
+            commonmeta diff deposit.xml 10.5555/12345678 --from-a crossref_xml\n\
+            commonmeta diff old.json new.json --ignore date_updated\n\
+            commonmeta diff a.json b.json --json --exit-code",
+        )
+        .arg(
+            Arg::new("a")
+                .help("First record: file, - for stdin, or identifier")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("b")
+                .help("Second record: file, - for stdin, or identifier")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new("from-a")
+                .long("from-a")
+                .help("Format of the first record; auto-detected if omitted"),
+        )
+        .arg(
+            Arg::new("from-b")
+                .long("from-b")
+                .help("Format of the second record; auto-detected if omitted"),
+        )
+        .arg(
+            Arg::new("ignore")
+                .long("ignore")
+                .help("Leave out this field, or JSON pointer, from the comparison (repeatable)")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Print the changes as a JSON array")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("exit-code")
+                .long("exit-code")
+                .help("Exit with status 1 if the records differ")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Execute the diff command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let a = read_record(
+        matches.get_one::<String>("a").expect("required"),
+        matches.get_one::<String>("from-a"),
+    )?;
+    let b = read_record(
+        matches.get_one::<String>("b").expect("required"),
+        matches.get_one::<String>("from-b"),
+    )?;
+    let ignore: Vec<String> = matches
+        .get_many::<String>("ignore")
+        .map(|values| {
+            values
+                .map(|v| if v.starts_with('/') { v.clone() } else { format!("/{}", v) })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut changes = Vec::new();
+    diff_values(&a, &b, "", &mut changes);
+    changes.retain(|change| {
+        let pointer = change.pointer();
+        !ignore
+            .iter()
+            .any(|i| pointer == i || pointer.starts_with(&format!("{}/", i)))
+    });
+
+    if matches.get_flag("json") {
+        let changes: Vec<Value> = changes.iter().map(Change::to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&changes).map_err(|e| e.to_string())?);
+    } else {
+        for change in &changes {
+            println!("{}", change.format(Stream::Stdout));
+        }
+    }
+
+    if matches.get_flag("exit-code") && !changes.is_empty() {
+        return Err(Failure::Partial(format!(
+            "diff: the records differ in {} place(s)",
+            changes.len()
+        )));
+    }
+    Ok(())
+}
+
+/// `input` as a commonmeta JSON value.
+fn read_record(input: &str, from: Option<&String>) -> Result<Value, Failure> {
+    let data = if input == "-" || Path::new(input).exists() {
+        let content = read_input(input)?;
+        let from = match from {
+            Some(from) => from.clone(),
+            None => sniff_format(&content)?,
+        };
+        commonmeta::read(&from, &content).map_err(|e| format!("{}: {}", input, e))?
+    } else {
+        match from {
+            Some(from) => commonmeta::read(from, input)?,
+            None => read_identifier(input)?,
+        }
+    };
+    let bytes = commonmeta::write("commonmeta", &data)?;
+    Ok(serde_json::from_slice(&bytes).map_err(|e| e.to_string())?)
+}
+
+/// A difference between two records at a JSON pointer.
+#[derive(Debug, PartialEq)]
+enum Change {
+    Added { pointer: String, value: Value },
+    Removed { pointer: String, value: Value },
+    Changed { pointer: String, old: Value, new: Value },
+}
+
+impl Change {
+    fn pointer(&self) -> &str {
+        match self {
+            Change::Added { pointer, .. }
+            | Change::Removed { pointer, .. }
+            | Change::Changed { pointer, .. } => pointer,
+        }
+    }
+
+    fn format(&self, stream: Stream) -> String {
+        match self {
+            Change::Added { pointer, value } => {
+                paint(&format!("+ {}: {}", pointer, value), Style::Added, stream)
+            }
+            Change::Removed { pointer, value } => {
+                paint(&format!("- {}: {}", pointer, value), Style::Removed, stream)
+            }
+            Change::Changed { pointer, old, new } => format!(
+                "~ {}: {} -> {}",
+                pointer,
+                paint(&old.to_string(), Style::Removed, stream),
+                paint(&new.to_string(), Style::Added, stream)
+            ),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Change::Added { pointer, value } => {
+                json!({ "op": "added", "pointer": pointer, "value": value })
+            }
+            Change::Removed { pointer, value } => {
+                json!({ "op": "removed", "pointer": pointer, "value": value })
+            }
+            Change::Changed { pointer, old, new } => {
+                json!({ "op": "changed", "pointer": pointer, "old": old, "new": new })
+            }
+        }
+    }
+}
+
+/// Append the changes from `a` to `b`, both at `pointer`, to `changes`.
+/// Objects are compared key by key and arrays position by position; other
+/// values are changed as a whole.
+fn diff_values(a: &Value, b: &Value, pointer: &str, changes: &mut Vec<Change>) {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => diff_objects(a, b, pointer, changes),
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                let child = format!("{}/{}", pointer, i);
+                match (a.get(i), b.get(i)) {
+                    (Some(a), Some(b)) => diff_values(a, b, &child, changes),
+                    (Some(a), None) => changes.push(Change::Removed {
+                        pointer: child,
+                        value: a.clone(),
+                    }),
+                    (None, Some(b)) => changes.push(Change::Added {
+                        pointer: child,
+                        value: b.clone(),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ if a != b => changes.push(Change::Changed {
+            pointer: if pointer.is_empty() { "/".to_string() } else { pointer.to_string() },
+            old: a.clone(),
+            new: b.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_objects(
+    a: &Map<String, Value>,
+    b: &Map<String, Value>,
+    pointer: &str,
+    changes: &mut Vec<Change>,
+) {
+    let child = |key: &str| format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"));
+    for (key, a_value) in a {
+        match b.get(key) {
+            Some(b_value) => diff_values(a_value, b_value, &child(key), changes),
+            None => changes.push(Change::Removed {
+                pointer: child(key),
+                value: a_value.clone(),
+            }),
+        }
+    }
+    for (key, b_value) in b {
+        if !a.contains_key(key) {
+            changes.push(Change::Added {
+                pointer: child(key),
+                value: b_value.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(a: Value, b: Value) -> Vec<Change> {
+        let mut changes = Vec::new();
+        diff_values(&a, &b, "", &mut changes);
+        changes
+    }
+
+    #[test]
+    fn test_diff_values() {
+        let changes = diff(
+            json!({"title": "Old", "license": {"id": "CC-BY-4.0"}, "a/b": [1, 2]}),
+            json!({"title": "New", "license": {}, "a/b": [1, 2, 3], "language": "en"}),
+        );
+        assert_eq!(
+            changes,
+            vec![
+                Change::Changed {
+                    pointer: "/title".to_string(),
+                    old: json!("Old"),
+                    new: json!("New"),
+                },
+                Change::Removed {
+                    pointer: "/license/id".to_string(),
+                    value: json!("CC-BY-4.0"),
+                },
+                Change::Added {
+                    pointer: "/a~1b/2".to_string(),
+                    value: json!(3),
+                },
+                Change::Added {
+                    pointer: "/language".to_string(),
+                    value: json!("en"),
+                },
+            ]
+        );
+        assert!(diff(json!({"a": [1]}), json!({"a": [1]})).is_empty());
+    }
+
+    #[test]
+    fn test_format_without_color() {
+        crate::diagnostic::disable_color();
+        let change = Change::Changed {
+            pointer: "/title".to_string(),
+            old: json!("Old"),
+            new: json!("New"),
+        };
+        assert_eq!(change.format(Stream::Stdout), "~ /title: \"Old\" -> \"New\"");
+        assert_eq!(change.to_json()["op"], "changed");
+        assert_eq!(change.pointer(), "/title");
+    }
+
+    #[test]
+    fn test_execute_with_ignore() {
+        let dir = std::env::temp_dir().join("commonmeta_diff_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let a = dir.join("a.bib");
+        let b = dir.join("b.bib");
+        std::fs::write(&a, "@article{a, title={A Title}, year={2024}, doi={10.5555/1}}").unwrap();
+        std::fs::write(&b, "@article{a, title={B Title}, year={2024}, doi={10.5555/1}}").unwrap();
+
+        let args = |extra: &[&str]| {
+            let mut args = vec!["diff", a.to_str().unwrap(), b.to_str().unwrap(), "--exit-code"];
+            args.extend_from_slice(extra);
+            command().get_matches_from(args)
+        };
+        assert_eq!(execute(&args(&[])).unwrap_err().exit_code(), 1);
+        assert!(execute(&args(&["--ignore", "title"])).is_ok());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
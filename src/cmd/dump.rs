@@ -77,9 +77,9 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
 
     write_output(&bytes, &out_path)?;
 
-    eprintln!("wrote {} rows → {}", parquet_row_count(&bytes), out_path);
+    tracing::info!("wrote {} rows → {}", parquet_row_count(&bytes), out_path);
     if timer {
-        eprintln!("package timer: {:?}", started.elapsed());
+        tracing::info!("package timer: {:?}", started.elapsed());
     }
 
     Ok(())
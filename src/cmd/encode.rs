@@ -4,8 +4,13 @@
 
 use clap::{ArgMatches, Command};
 
-use crate::doi_utils::encode_doi;
-use crate::doi_utils::validate_prefix;
+use crate::cmd::decode::decode_json;
+use crate::cmd::{output_arg, read_input, write_lines};
+use crate::crockford::{BLOCKLIST, parse_number};
+use crate::doi_utils::{
+    MAX_SUFFIX_LENGTH, SuffixShape, encode_doi_number_with_shape, encode_dois_with_shape,
+    validate_prefix,
+};
 
 /// Build the encode subcommand
 pub fn command() -> Command {
@@ -13,7 +18,14 @@ pub fn command() -> Command {
         .about("Generate a random DOI string given a prefix")
         .long_about(
             "Generate a random DOI string given a prefix. Example usage:\n\n\
-            commonmeta encode 10.5555",
+            commonmeta encode 10.5555\n\
+            commonmeta encode 10.5555 --count 100\n\
+            commonmeta encode 10.5555 --number 12345\n\
+            commonmeta encode 10.5555 --count 10 --json\n\
+            commonmeta encode 10.5555 --length 10 --split-every 4\n\
+            commonmeta encode 10.5555 --uppercase\n\
+            commonmeta encode 10.5555 --count 1000 --filter\n\
+            commonmeta encode 10.5555 --count 1000 --output dois.txt.zip",
         )
         .arg(
             clap::Arg::new("prefix")
@@ -21,6 +33,70 @@ pub fn command() -> Command {
                 .required(true)
                 .index(1),
         )
+        .arg(
+            clap::Arg::new("count")
+                .long("count")
+                .short('c')
+                .help("Number of DOIs to generate, all distinct")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .default_value("1"),
+        )
+        .arg(
+            clap::Arg::new("number")
+                .long("number")
+                .short('n')
+                .help("Encode this number (e.g. a database key) instead of a random one")
+                .value_parser(|number: &str| parse_number(number).map_err(|e| e.to_string()))
+                .allow_hyphen_values(true)
+                .conflicts_with("count"),
+        )
+        .arg(
+            clap::Arg::new("length")
+                .long("length")
+                .help("Length of the suffix, including the two checksum characters")
+                .value_parser(clap::value_parser!(u64).range(3..=MAX_SUFFIX_LENGTH as u64))
+                .default_value("10"),
+        )
+        .arg(
+            clap::Arg::new("split-every")
+                .long("split-every")
+                .help("Split the suffix every n characters (0 for no split)")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("5"),
+        )
+        .arg(
+            clap::Arg::new("separator")
+                .long("separator")
+                .help("Character to split the suffix with")
+                .value_parser(["-", "_", "."])
+                .default_value("-"),
+        )
+        .arg(
+            clap::Arg::new("uppercase")
+                .long("uppercase")
+                .help("Write the suffix in uppercase, which decodes the same")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("filter")
+                .long("filter")
+                .help("Regenerate suffixes containing offensive words, also with digits read as letters")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("number"),
+        )
+        .arg(
+            clap::Arg::new("blocklist")
+                .long("blocklist")
+                .help("Also regenerate suffixes containing a word from this file, one per line (implies --filter)")
+                .conflicts_with("number"),
+        )
+        .arg(
+            clap::Arg::new("json")
+                .long("json")
+                .help("Print a JSON object per DOI with the number it encodes")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(output_arg())
 }
 
 /// Execute the encode command
@@ -32,8 +108,77 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         None => return Err("Invalid prefix".to_string()),
     };
 
-    let doi = encode_doi(&prefix);
-    println!("{}", doi);
+    let mut blocklist = Vec::new();
+    if matches.get_flag("filter") || matches.contains_id("blocklist") {
+        blocklist.extend(BLOCKLIST.iter().map(|word| word.to_string()));
+    }
+    if let Some(path) = matches.get_one::<String>("blocklist") {
+        let content = read_input(path)?;
+        blocklist.extend(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        );
+    }
+
+    let shape = SuffixShape {
+        length: *matches.get_one::<u64>("length").expect("has default") as usize,
+        split_every: *matches.get_one::<u64>("split-every").expect("has default") as usize,
+        separator: matches
+            .get_one::<String>("separator")
+            .and_then(|s| s.chars().next())
+            .expect("has default"),
+        blocklist,
+        uppercase: matches.get_flag("uppercase"),
+    };
+
+    let dois = match matches.get_one::<u128>("number") {
+        Some(number) => vec![encode_doi_number_with_shape(&prefix, *number, &shape)],
+        None => {
+            // Fails for more DOIs than the length allows, or than are left
+            // after filtering.
+            let count = *matches.get_one::<u64>("count").expect("has default");
+            encode_dois_with_shape(&prefix, count as usize, &shape)
+                .map_err(|e| format!("encode: {}", e))?
+        }
+    };
+
+    // The JSON lines are what decode --json reports for the new DOIs, so
+    // the value and checksum are checked rather than assumed.
+    let lines: Vec<String> = if matches.get_flag("json") {
+        dois.iter().map(|doi| decode_json(doi, true).to_string()).collect()
+    } else {
+        dois
+    };
+    write_lines(&lines, matches.get_one::<String>("output"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_checks_the_checksum() {
+        let path = std::env::temp_dir().join(format!("cm-encode-{}.jsonl", std::process::id()));
+        let m = command().get_matches_from([
+            "encode",
+            "10.5555",
+            "--number",
+            "12345",
+            "--json",
+            "--output",
+            path.to_str().unwrap(),
+        ]);
+        execute(&m).unwrap();
+        let line = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
 
-    Ok(())
+        let value: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(value["identifier"], "https://doi.org/10.5555/00000-c1s20");
+        assert_eq!(value["type"], "DOI");
+        assert_eq!(value["value"], 12345);
+        assert_eq!(value["checksum_valid"], true);
+    }
 }
@@ -0,0 +1,151 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use commonmeta::Data;
+
+use crate::cmd::convert::{identifier_format, write_output};
+use crate::doi_utils::{normalize_doi, validate_doi};
+
+/// Build the enrich subcommand
+pub fn command() -> Command {
+    Command::new("enrich")
+        .about("Merge the metadata of a DOI from several sources")
+        .long_about(
+            "Fetch the metadata for a DOI from several sources and merge them into \
+            one record. --sources lists the sources in order of precedence: a field \
+            is taken from the first source that has it, so e.g. a sparse Crossref \
+            record for a blog post gets its abstract from OpenAlex and ORCID IDs \
+            and affiliations from the landing page. Contributors are matched by \
+            name; identifiers and relations are combined from all sources.\n\n\
+            Sources: ra (the registration agency of the DOI, Crossref or \
+            DataCite), crossref, datacite, openalex and schemaorg (the landing \
+            page). A source that fails or doesn't know the DOI is skipped with a \
+            warning.\n\n\
+            Examples:\n\n\
+            commonmeta enrich 10.59350/k2s7x-8s448\n\
+            commonmeta enrich 10.5555/12345678 --sources openalex,crossref --to bibtex\n\
+            commonmeta enrich 10.5281/zenodo.5244404 --sources ra,schemaorg --file record.json",
+        )
+        .arg(
+            Arg::new("doi")
+                .help("DOI to enrich")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("sources")
+                .long("sources")
+                .help("Sources to merge, highest precedence first")
+                .value_delimiter(',')
+                .value_parser(["ra", "crossref", "datacite", "openalex", "schemaorg"])
+                .action(ArgAction::Append)
+                .default_value("ra,openalex,schemaorg"),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .help("Output format (any format supported by convert)")
+                .default_value("commonmeta"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .short('o')
+                .visible_alias("output")
+                .help("Write output to this file instead of stdout"),
+        )
+}
+
+/// Execute the enrich command
+pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+    let doi = matches.get_one::<String>("doi").expect("required");
+    let to = matches.get_one::<String>("to").expect("has default");
+    let out_file = matches.get_one::<String>("file");
+    let sources: Vec<&String> =
+        matches.get_many::<String>("sources").expect("has default").collect();
+
+    if validate_doi(doi).is_none() {
+        return Err(format!("enrich: '{}' is not a DOI", doi));
+    }
+
+    let mut records = Vec::with_capacity(sources.len());
+    for source in sources {
+        match fetch_source(source, doi, &records) {
+            Ok(data) => {
+                tracing::info!("{}: found {}", source, doi);
+                records.push(data);
+            }
+            Err(e) => tracing::warn!("enrich: skipping {}: {}", source, e),
+        }
+    }
+    if records.is_empty() {
+        return Err(format!("enrich: no source returned metadata for {}", doi));
+    }
+
+    let data = commonmeta::merge::merge(&records);
+    let output = commonmeta::write(to, &data).map_err(|e| e.to_string())?;
+    write_output(&output, to, out_file)
+}
+
+/// Fetch `doi` from `source`.
+fn fetch_source(source: &str, doi: &str, fetched: &[Data]) -> Result<Data, String> {
+    let (from, input) = match source {
+        "ra" => (identifier_format(doi)?, doi.to_string()),
+        "schemaorg" => ("schemaorg".to_string(), landing_page(doi, fetched)),
+        other => (other.to_string(), doi.to_string()),
+    };
+    commonmeta::read(&from, &input).map_err(|e| e.to_string())
+}
+
+/// URL of the landing page of `doi`: the first URL of the records fetched
+/// so far, or else wherever the DOI resolves to.
+fn landing_page(doi: &str, fetched: &[Data]) -> String {
+    fetched
+        .iter()
+        .map(|data| data.url.as_str())
+        .find(|url| !url.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| normalize_doi(doi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sources_default_and_order() {
+        let m = command().get_matches_from(["enrich", "10.5555/1"]);
+        let sources: Vec<&String> = m.get_many::<String>("sources").unwrap().collect();
+        assert_eq!(sources, ["ra", "openalex", "schemaorg"]);
+
+        let m =
+            command().get_matches_from(["enrich", "10.5555/1", "--sources", "openalex,crossref"]);
+        let sources: Vec<&String> = m.get_many::<String>("sources").unwrap().collect();
+        assert_eq!(sources, ["openalex", "crossref"]);
+
+        let result =
+            command().try_get_matches_from(["enrich", "10.5555/1", "--sources", "crossref,medra"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_doi() {
+        let m = command().get_matches_from(["enrich", "https://example.org/post"]);
+        assert!(execute(&m).unwrap_err().contains("is not a DOI"));
+    }
+
+    #[test]
+    fn test_landing_page() {
+        let mut fetched = vec![Data::default()];
+        assert_eq!(landing_page("10.5555/ABC", &fetched), "https://doi.org/10.5555/abc");
+        fetched.push(Data {
+            url: "https://blog.example.org/posts/abc".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(landing_page("10.5555/ABC", &fetched), "https://blog.example.org/posts/abc");
+    }
+}
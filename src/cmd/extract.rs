@@ -0,0 +1,151 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::collections::HashSet;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::json;
+
+use commonmeta::utils::{IdentifierMatch, extract_identifiers};
+
+use crate::cmd::{output_arg, read_input, write_lines};
+use crate::diagnostic::locate_span;
+
+const TYPES: [&str; 9] = [
+    "DOI", "ORCID", "ROR", "arXiv", "PMID", "PMCID", "ISSN", "Wikidata", "OpenAlex",
+];
+
+/// Build the extract subcommand
+pub fn command() -> Command {
+    Command::new("extract")
+        .about("Find the identifiers in free text")
+        .long_about(
+            "Scan a text file, such as a manuscript or its reference list, for \
+            DOIs, ORCID IDs, ROR IDs, arXiv IDs, PMIDs, PMCIDs, ISSNs and Wikidata \
+            and OpenAlex URLs, and print each one as line:column, type and \
+            normalized identifier. PMIDs and ISSNs are only recognized with their \
+            label (PMID: 12345678), ROR, Wikidata and OpenAlex IDs only as URLs.\n\n\
+            Examples:\n\n\
+            commonmeta extract manuscript.txt\n\
+            commonmeta extract references.txt --type DOI --unique --output dois.txt\n\
+            pdftotext paper.pdf - | commonmeta extract - --json",
+        )
+        .arg(
+            Arg::new("input")
+                .help("Text file to scan (- for stdin)")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .help("Only print identifiers of this type (repeatable)")
+                .value_parser(TYPES)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("unique")
+                .long("unique")
+                .help("Print each identifier only at its first occurrence")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Print the identifiers as JSON Lines")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(output_arg())
+}
+
+/// Execute the extract command
+pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+    let input = read_input(matches.get_one::<String>("input").expect("required"))?;
+    let types: Vec<&String> = matches.get_many::<String>("type").unwrap_or_default().collect();
+
+    let mut seen = HashSet::new();
+    let found: Vec<IdentifierMatch> = extract_identifiers(&input)
+        .into_iter()
+        .filter(|m| types.is_empty() || types.iter().any(|t| t.as_str() == m.identifier_type))
+        .filter(|m| !matches.get_flag("unique") || seen.insert(m.identifier.clone()))
+        .collect();
+    tracing::info!("found {} identifiers", found.len());
+
+    let lines: Vec<String> = found
+        .iter()
+        .map(|m| format_match(&input, m, matches.get_flag("json")))
+        .collect();
+    write_lines(&lines, matches.get_one::<String>("output"))
+}
+
+/// `m` as a `line:column<TAB>type<TAB>identifier` line, or a JSON object.
+fn format_match(input: &str, m: &IdentifierMatch, json: bool) -> String {
+    let location = locate_span(input, m.start, m.end);
+    if json {
+        json!({
+            "identifier": m.identifier,
+            "type": m.identifier_type,
+            "line": location.line,
+            "column": location.column,
+            "start": m.start,
+            "end": m.end,
+            "text": m.text,
+        })
+        .to_string()
+    } else {
+        format!(
+            "{}:{}\t{}\t{}",
+            location.line, location.column, m.identifier_type, m.identifier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_match() {
+        let input = "References\n1. Doe J. doi:10.5555/12345678\n";
+        let found = extract_identifiers(input);
+        assert_eq!(
+            format_match(input, &found[0], false),
+            "2:11\tDOI\thttps://doi.org/10.5555/12345678"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&format_match(input, &found[0], true)).unwrap();
+        assert_eq!(json["text"], "doi:10.5555/12345678");
+        assert_eq!(json["line"], 2);
+    }
+
+    #[test]
+    fn test_execute_type_and_unique() {
+        let dir = std::env::temp_dir().join("commonmeta_extract_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("references.txt");
+        let output = dir.join("dois.txt");
+        std::fs::write(
+            &input,
+            "10.5555/1, https://orcid.org/0000-0002-1825-0097 and again https://doi.org/10.5555/1",
+        )
+        .unwrap();
+
+        let m = command().get_matches_from([
+            "extract",
+            input.to_str().unwrap(),
+            "--type",
+            "DOI",
+            "--unique",
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+        execute(&m).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&output).unwrap(),
+            "1:1\tDOI\thttps://doi.org/10.5555/1\n"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
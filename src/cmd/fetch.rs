@@ -0,0 +1,98 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgMatches, Command};
+
+use crate::cmd::convert::{identifier_format, write_output};
+use crate::cmd::Failure;
+
+/// Build the fetch subcommand
+pub fn command() -> Command {
+    Command::new("fetch")
+        .about("Retrieve the metadata for an identifier")
+        .long_about(
+            "Retrieve the metadata for a DOI, URL or ROR ID and convert it.\n\n\
+            The registration agency of a DOI is looked up via the DOI RA API and the \
+            record is fetched from the Crossref or DataCite API accordingly. ROR IDs \
+            are fetched from the ROR API, any other URL is read as a landing page \
+            with embedded schema.org metadata.\n\n\
+            Examples:\n\n\
+            commonmeta fetch 10.5555/12345678 --to bibtex\n\
+            commonmeta fetch https://doi.org/10.5281/zenodo.5244404 --to csl\n\
+            commonmeta fetch https://ror.org/02nr0ka47\n\
+            commonmeta fetch 10.5555/12345678 --to citation --style apa --file citation.txt",
+        )
+        .arg(
+            Arg::new("identifier")
+                .help("DOI, URL, or ROR ID")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .help("Output format (any format supported by convert)")
+                .default_value("commonmeta"),
+        )
+        .arg(
+            Arg::new("style")
+                .long("style")
+                .short('s')
+                .help("CSL style name for citation output (default: apa)"),
+        )
+        .arg(
+            Arg::new("locale")
+                .long("locale")
+                .short('l')
+                .help("BCP 47 locale for citation output (e.g. de-DE)"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .short('o')
+                .visible_alias("output")
+                .help("Write output to this file instead of stdout"),
+        )
+}
+
+/// Execute the fetch command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let identifier = matches.get_one::<String>("identifier").expect("required");
+    let to = matches.get_one::<String>("to").expect("has default").as_str();
+    let style = matches.get_one::<String>("style").map(String::as_str);
+    let locale = matches.get_one::<String>("locale").map(String::as_str);
+    let out_file = matches.get_one::<String>("file");
+
+    let from = identifier_format(identifier)?;
+    let data = commonmeta::read(&from, identifier)?;
+
+    // As in convert, a ROR record is written as ROR JSON unless another
+    // format is asked for.
+    let output = if from == "ror" && to == "commonmeta" {
+        commonmeta::write_ror_json(&data)
+    } else {
+        commonmeta::write_with_style(to, &data, style, locale)
+    }?;
+    Ok(write_output(&output, to, out_file)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_non_identifier() {
+        let m = command().get_matches_from(["fetch", "not-an-identifier"]);
+        let err = execute(&m).unwrap_err().to_string();
+        assert!(err.contains("is not a DOI, URL or ROR ID"), "got: {err}");
+    }
+
+    #[test]
+    fn test_output_alias() {
+        let m = command().get_matches_from(["fetch", "10.5555/12345678", "--output", "out.bib"]);
+        assert_eq!(m.get_one::<String>("file").unwrap(), "out.bib");
+        assert_eq!(m.get_one::<String>("to").unwrap(), "commonmeta");
+    }
+}
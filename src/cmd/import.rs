@@ -9,7 +9,7 @@ use clap::{Arg, ArgAction, ArgMatches, Command};
 
 use commonmeta::{self, file_utils};
 
-use crate::cmd::{resolve_db_path, PIDBOX_CACHE_KEY, PIDBOX_URL, VRAIX_CACHE_TTL};
+use crate::cmd::{Failure, resolve_db_path, PIDBOX_CACHE_KEY, PIDBOX_URL, VRAIX_CACHE_TTL};
 use crate::cmd::convert::detect_format;
 use crate::cmd::list::{fetch_list_from_api, fmt_wrote_sqlite};
 
@@ -169,7 +169,7 @@ pub fn command() -> Command {
         )
 }
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
     let input_path = matches.get_one::<String>("input").map(String::as_str);
     let date = matches.get_one::<String>("date").map(String::as_str);
 
@@ -221,7 +221,8 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         return Err(format!(
             "import: unsupported --from value '{}' (supported: crossref, datacite, openalex, pidbox, ror)",
             from
-        ));
+        )
+        .into());
     }
 
     // When --no-network is set, only a local VRAIX .sqlite3 file is accepted.
@@ -232,7 +233,8 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         return Err(
             "--no-network requires a local .sqlite3 input file; \
             provide a VRAIX dump path or remove --no-network"
-                .to_string(),
+                .to_string()
+                .into(),
         );
     }
 
@@ -245,7 +247,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     // pidbox is a full VRAIX dump installed directly into commonmeta.sqlite3.
     if from == "pidbox" {
         let out_path = resolve_db_path(None);
-        return install_pidbox(&out_path);
+        return Ok(install_pidbox(&out_path)?);
     }
 
     let out_path = resolve_db_path(None);
@@ -255,7 +257,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     // Fast path: stream VRAIX SQLite → commonmeta SQLite without loading all
     // records into RAM. Always imports every row (limit=0). Always upserts.
     if is_vraix_sqlite || is_date_download {
-        return import_vraix_fast(from, input_path, date, &out_path);
+        return Ok(import_vraix_fast(from, input_path, date, &out_path)?);
     }
 
     // Single-record path: DOI, URL, or any identifier that isn't a file path.
@@ -274,12 +276,14 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     // API fetch path: fetch records, then upsert into commonmeta SQLite.
     if from == "commonmeta" {
         return Err(
-            "import: --from commonmeta requires an input .sqlite3 file path".to_string()
+            "import: --from commonmeta requires an input .sqlite3 file path"
+                .to_string()
+                .into(),
         );
     }
     let fetch_start = Instant::now();
     let data = fetch_list_from_api(matches, from)?;
-    eprintln!(
+    tracing::info!(
         "import: fetch took {:.2?} ({} records)",
         fetch_start.elapsed(),
         data.len()
@@ -289,7 +293,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     let write_start = Instant::now();
     commonmeta::upsert_sqlite(&data, out_sqlite).map_err(|e| e.to_string())?;
     let total = commonmeta::count_sqlite_works(out_sqlite).ok();
-    eprintln!(
+    tracing::info!(
         "import: upsert took {:.2?} ({} records)",
         write_start.elapsed(),
         data.len()
@@ -299,17 +303,17 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
 }
 
 /// Fetch a single record by DOI, URL, or other identifier and upsert it.
-fn import_single(identifier: &str, from: &str, out_path: &str) -> Result<(), String> {
+fn import_single(identifier: &str, from: &str, out_path: &str) -> Result<(), Failure> {
     let fetch_start = Instant::now();
-    let data = commonmeta::read(from, identifier).map_err(|e| e.to_string())?;
-    eprintln!("import: fetch took {:.2?}", fetch_start.elapsed());
+    let data = commonmeta::read(from, identifier)?;
+    tracing::info!("import: fetch took {:.2?}", fetch_start.elapsed());
 
     let out_sqlite = Path::new(out_path);
     let write_start = Instant::now();
     commonmeta::upsert_sqlite(std::slice::from_ref(&data), out_sqlite)
         .map_err(|e| e.to_string())?;
     let total = commonmeta::count_sqlite_works(out_sqlite).ok();
-    eprintln!("import: upsert took {:.2?}", write_start.elapsed());
+    tracing::info!("import: upsert took {:.2?}", write_start.elapsed());
     println!("{}", fmt_wrote_sqlite(out_path, 1, total));
     Ok(())
 }
@@ -336,7 +340,7 @@ fn import_vraix_fast(
             file_utils::ensure_cached_path(&url, "vraix", &cache_key, VRAIX_CACHE_TTL)
                 .map_err(|e| format!("failed to download '{}': {}", url, e))?;
         let size = cache_path.metadata().map(|m| m.len()).unwrap_or(0);
-        eprintln!(
+        tracing::info!(
             "import: download took {:.2?} ({} bytes{})",
             dl_start.elapsed(),
             size,
@@ -346,7 +350,7 @@ fn import_vraix_fast(
         let tmp = out_sqlite.with_extension(format!("sqlite3.vraix-{}.tmp", std::process::id()));
         let dc_bytes = file_utils::decompress_zst_file(&cache_path, &tmp)
             .map_err(|e| format!("failed to decompress '{}': {}", url, e))?;
-        eprintln!(
+        tracing::info!(
             "import: decompress took {:.2?} ({} bytes)",
             dc_start.elapsed(),
             dc_bytes
@@ -364,26 +368,26 @@ fn import_vraix_fast(
     }
     let n = result?;
     let total = commonmeta::count_sqlite_works(&out_sqlite).ok();
-    eprintln!(
+    tracing::info!(
         "import: convert+write took {:.2?} ({} records)",
         convert_start.elapsed(),
         n
     );
-    eprintln!("import: total took {:.2?}", total_start.elapsed());
+    tracing::info!("import: total took {:.2?}", total_start.elapsed());
     println!("{}", fmt_wrote_sqlite(out_path, n, total));
     Ok(())
 }
 
-pub(crate) fn install_ror(out_path: &str) -> Result<(), String> {
+pub(crate) fn install_ror(out_path: &str) -> Result<(), Failure> {
     let total = Instant::now();
 
-    eprintln!("Fetching latest ROR release metadata from Zenodo...");
+    tracing::info!("Fetching latest ROR release metadata from Zenodo...");
     let t = Instant::now();
-    let release = commonmeta::fetch_latest_ror_release().map_err(|e| e.to_string())?;
-    eprintln!("  metadata fetched in {:.2}s", t.elapsed().as_secs_f64());
+    let release = commonmeta::fetch_latest_ror_release()?;
+    tracing::info!("  metadata fetched in {:.2}s", t.elapsed().as_secs_f64());
 
     let db_path = Path::new(out_path);
-    match commonmeta::fetch_installed_ror_version(db_path).map_err(|e| e.to_string())? {
+    match commonmeta::fetch_installed_ror_version(db_path)? {
         Some(ref installed) if installed == &release.version => {
             println!(
                 "ROR {} ({}) is already installed at {}",
@@ -392,27 +396,25 @@ pub(crate) fn install_ror(out_path: &str) -> Result<(), String> {
             return Ok(());
         }
         Some(ref installed) => {
-            eprintln!("Upgrading ROR {} → {}...", installed, release.version);
+            tracing::info!("Upgrading ROR {} → {}...", installed, release.version);
         }
         None => {}
     }
 
     let t = Instant::now();
-    let (list, from_cache) =
-        commonmeta::download_ror_release(&release).map_err(|e| e.to_string())?;
-    eprintln!(
+    let (list, from_cache) = commonmeta::download_ror_release(&release)?;
+    tracing::info!(
         "  {} and parsed {} organizations in {:.2}s",
         if from_cache { "loaded" } else { "downloaded" },
         list.len(),
         t.elapsed().as_secs_f64()
     );
 
-    eprintln!("Writing to {}...", out_path);
+    tracing::info!("Writing to {}...", out_path);
     let t = Instant::now();
-    commonmeta::write_ror_sqlite(&list, db_path, Some(&release.version), Some(&release.date))
-        .map_err(|e| e.to_string())?;
-    eprintln!("  SQLite written in {:.2}s", t.elapsed().as_secs_f64());
-    eprintln!("  total: {:.2}s", total.elapsed().as_secs_f64());
+    commonmeta::write_ror_sqlite(&list, db_path, Some(&release.version), Some(&release.date))?;
+    tracing::info!("  SQLite written in {:.2}s", t.elapsed().as_secs_f64());
+    tracing::info!("  total: {:.2}s", total.elapsed().as_secs_f64());
 
     println!(
         "Installed ROR {} ({}) → {} ({} organizations)",
@@ -427,15 +429,15 @@ pub(crate) fn install_ror(out_path: &str) -> Result<(), String> {
 pub(crate) fn install_pidbox(out_path: &str) -> Result<(), String> {
     let total = Instant::now();
 
-    eprintln!("Downloading pidbox from {}...", PIDBOX_URL);
+    tracing::info!("Downloading pidbox from {}...", PIDBOX_URL);
     let t = Instant::now();
     let (cache_path, from_cache) =
         file_utils::ensure_cached_path(PIDBOX_URL, "vraix", PIDBOX_CACHE_KEY, VRAIX_CACHE_TTL)
             .map_err(|e| format!("failed to download pidbox: {}", e))?;
     if from_cache {
-        eprintln!("  pidbox download skipped (cached at {})", cache_path.display());
+        tracing::info!("  pidbox download skipped (cached at {})", cache_path.display());
     } else {
-        eprintln!("  downloaded in {:.2}s", t.elapsed().as_secs_f64());
+        tracing::info!("  downloaded in {:.2}s", t.elapsed().as_secs_f64());
     }
 
     // The pidbox SQLite database is not VACUUM'd, so overflow pages for large
@@ -444,12 +446,12 @@ pub(crate) fn install_pidbox(out_path: &str) -> Result<(), String> {
     // resolve backward chain links without extra full-file scans.
     // Tune with COMMONMETA_SCAN_WINDOW_GIB and COMMONMETA_SCAN_DISK_GIB.
     let out = Path::new(out_path);
-    eprintln!("Converting (streaming decompress + convert) → {}…", out_path);
+    tracing::info!("Converting (streaming decompress + convert) → {}…", out_path);
     let t = Instant::now();
     let n = commonmeta::stream_zst_pidbox_to_sqlite(&cache_path, out, 0)
         .map_err(|e| format!("failed to convert pidbox: {}", e))?;
-    eprintln!("  converted and wrote {} records in {:.0}s", n, t.elapsed().as_secs_f64());
-    eprintln!("  total: {:.0}s", total.elapsed().as_secs_f64());
+    tracing::info!("  converted and wrote {} records in {:.0}s", n, t.elapsed().as_secs_f64());
+    tracing::info!("  total: {:.0}s", total.elapsed().as_secs_f64());
 
     let date = commonmeta::fetch_installed_vraix_date(out)
         .ok()
@@ -471,7 +473,7 @@ mod tests {
     #[test]
     fn test_no_network_with_doi_errors() {
         let m = parse_args(&["import", "--no-network", "10.7554/elife.01567"]);
-        let err = execute(&m).unwrap_err();
+        let err = execute(&m).unwrap_err().to_string();
         assert!(
             err.contains("--no-network"),
             "expected --no-network in error, got: {err}"
@@ -481,7 +483,7 @@ mod tests {
     #[test]
     fn test_no_network_with_api_fetch_errors() {
         let m = parse_args(&["import", "--no-network", "--from", "crossref", "--ror", "00pd74e08"]);
-        let err = execute(&m).unwrap_err();
+        let err = execute(&m).unwrap_err().to_string();
         assert!(
             err.contains("--no-network"),
             "expected --no-network in error, got: {err}"
@@ -494,7 +496,7 @@ mod tests {
         // so the guard passes and the command fails at the "from commonmeta requires
         // a .sqlite3 path" check rather than entering the slow streaming path.
         let m = parse_args(&["import", "--no-network", "local.sqlite3"]);
-        let err = execute(&m).unwrap_err();
+        let err = execute(&m).unwrap_err().to_string();
         assert!(
             !err.contains("--no-network"),
             "should not fail at network guard for local sqlite, got: {err}"
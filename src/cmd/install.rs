@@ -7,7 +7,7 @@
 
 use clap::{Arg, ArgMatches, Command};
 
-use crate::cmd::resolve_db_path;
+use crate::cmd::{Failure, resolve_db_path};
 use crate::cmd::import;
 
 pub fn command() -> Command {
@@ -42,7 +42,7 @@ pub fn command() -> Command {
         )
 }
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
     let vocabulary = matches.get_one::<String>("vocabulary").expect("required");
     let out_path = resolve_db_path(matches.get_one::<String>("file"));
 
@@ -51,6 +51,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         other => Err(format!(
             "unsupported vocabulary '{}'. Use 'commonmeta import --from {}' instead.",
             other, other
-        )),
+        )
+        .into()),
     }
 }
@@ -8,6 +8,8 @@ use url::Url;
 use commonmeta::{self, Data};
 use commonmeta::file_utils;
 
+use crate::cmd::convert::read_identifier;
+
 /// Maximum number of records per output batch, used both for Parquet batch
 /// files and for entries within a `.zip`/`.tgz` archive.
 const BATCH_SIZE: usize = 100_000;
@@ -34,7 +36,7 @@ pub(crate) fn fmt_wrote_sqlite(path: &str, written: usize, total: Option<usize>)
     }
 }
 
-use crate::cmd::{resolve_db_path, VRAIX_CACHE_TTL};
+use crate::cmd::{Failure, read_input, resolve_db_path, write_output_file, VRAIX_CACHE_TTL};
 
 pub fn command() -> Command {
     Command::new("list")
@@ -46,10 +48,29 @@ pub fn command() -> Command {
             commonmeta list --number 10 --client cern.zenodo --type dataset --from datacite\n\
             commonmeta list --number 10 --from openalex --type journal-article\n\
             commonmeta list --from crossref --file out.json\n\
+            commonmeta list works.csv --from csv --map title=Title,doi=DOI\n\
+            (--from csv reads a CSV/TSV spreadsheet with one work per row; --map assigns\n\
+            commonmeta fields to column headers, other columns are matched by name)\n\
+            commonmeta list dois.csv --ids --to bibtex --file references.bib\n\
+            (--ids reads a JSON array, JSON Lines or CSV/plain-text file of DOIs, URLs or\n\
+            ROR IDs; each is fetched with its format auto-detected, as in convert. Entries\n\
+            that fail are reported on stderr and skipped instead of aborting the run)\n\
+            commonmeta list records.jsonl --from csl --to commonmeta --file out/\n\
+            (any --from format convert reads can be listed from a JSON array or JSON Lines\n\
+            file of records; a directory --file writes one file per record, named after\n\
+            its identifier)\n\
             commonmeta list --from crossref --to citation --style chicago-author-date\n\
             (--to accepts every format --to accepts in convert: commonmeta, csl, datacite,\n\
-            inveniordm, schemaorg, ror, bibtex, ris, crossref_xml, citation; --style/--locale\n\
-            only affect --to citation, same as convert)\n\
+            inveniordm, schemaorg, ror, bibtex, ris, crossref_xml, citation, markdown, html,\n\
+            yaml; --style/--locale only affect --to citation, markdown and html, same as\n\
+            convert)\n\
+            commonmeta list --from crossref --to markdown --file references.md\n\
+            (--to markdown and --to html render a reference list ordered by author and year)\n\
+            commonmeta list --from crossref --to csv --map doi=DOI,title=Title,authors=Authors\n\
+            (--to csv writes one work per row; --map selects and names the columns)\n\
+            commonmeta list --from crossref --number 1000 --file out.jsonl.gz\n\
+            (a .jsonl --file extension streams one commonmeta record per line, optionally\n\
+            gzip- or zstd-compressed; --to jsonl prints the same to stdout)\n\
             commonmeta list --from crossref --number 1000 --file out.parquet\n\
             (a .parquet --file extension selects Parquet output and is only supported for\n\
             --to commonmeta, the default; output is always zstd-compressed, with records\n\
@@ -78,7 +99,7 @@ pub fn command() -> Command {
         )
         .arg(
             Arg::new("input")
-                .help("Optional input file path (JSON/JSONL, Parquet, or SQLite with --date)")
+                .help("Optional input file path (JSON/JSONL, CSV/TSV, Parquet, SQLite with --date, or an identifier list with --ids; - for stdin)")
                 .required(false)
                 .index(1),
         )
@@ -208,10 +229,21 @@ pub fn command() -> Command {
                 .default_value("true")
                 .value_parser(clap::value_parser!(bool)),
         )
+        .arg(
+            Arg::new("map")
+                .long("map")
+                .help("Column mapping for --from csv and --to csv, e.g. title=Title,doi=DOI"),
+        )
+        .arg(
+            Arg::new("ids")
+                .long("ids")
+                .help("The input file lists identifiers (DOIs, URLs, ROR IDs) to fetch and convert")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("file")
                 .long("file")
-                .help("Write output to file instead of stdout"),
+                .help("Write output to file instead of stdout (- for stdout); an existing directory or a path ending in / gets one file per record"),
         )
         .arg(Arg::new("date").long("date").help(
             "Date (YYYY-MM-DD) of a VRAIX daily dump, used with --from crossref or \
@@ -242,7 +274,21 @@ pub fn command() -> Command {
         )
 }
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let mut failed = 0;
+    run(matches, &mut failed)?;
+    if failed > 0 {
+        return Err(Failure::Partial(format!(
+            "list: {} entries could not be converted",
+            failed
+        )));
+    }
+    Ok(())
+}
+
+/// Run the list command, adding the number of batch entries that could not
+/// be converted (but didn't stop the others) to `failed`.
+fn run(matches: &ArgMatches, failed: &mut usize) -> Result<(), Failure> {
     let from_explicit = matches
         .get_one::<String>("from")
         .map(String::as_str)
@@ -271,14 +317,22 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     let style = matches.get_one::<String>("style").map(String::as_str);
     let locale = matches.get_one::<String>("locale").map(String::as_str);
 
-    if !matches!(from, "crossref" | "datacite" | "openalex" | "commonmeta") {
+    let ids = matches.get_flag("ids");
+    let has_input = matches.get_one::<String>("input").is_some();
+    if ids && !has_input {
+        return Err(Failure::InvalidInput(
+            "list: --ids requires an input file of identifiers".to_string(),
+        ));
+    }
+    if !has_input && !matches!(from, "crossref" | "datacite" | "openalex" | "commonmeta" | "csv") {
         return Err(format!(
-            "list: --from {} is not implemented yet (supported: crossref, datacite, openalex, commonmeta)",
+            "list: --from {} requires an input file (API sources: crossref, datacite, openalex)",
             from
-        ));
+        )
+        .into());
     }
     if !is_supported_output_format(to) {
-        return Err(format!("list: unsupported --to format: {}", to));
+        return Err(format!("list: unsupported --to format: {}", to).into());
     }
 
     let no_network = matches.get_flag("no-network");
@@ -294,7 +348,8 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 "local database not found at '{}'; \
                 import records first with 'commonmeta import' or remove --no-network",
                 path
-            ));
+            )
+            .into());
         }
         Some(path)
     } else {
@@ -383,13 +438,13 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 if timers {
                     let size = cache_path.metadata().map(|m| m.len()).unwrap_or(0);
                     if from_cache {
-                        eprintln!(
+                        tracing::info!(
                             "list: download took {:.2?} ({} bytes, from local cache)",
                             download_start.elapsed(),
                             size
                         );
                     } else {
-                        eprintln!(
+                        tracing::info!(
                             "list: download took {:.2?} ({} bytes)",
                             download_start.elapsed(),
                             size
@@ -404,7 +459,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 let decompressed_bytes = file_utils::decompress_zst_file(&cache_path, &tmp_path)
                     .map_err(|e| format!("failed to decompress '{}': {}", url, e))?;
                 if timers {
-                    eprintln!(
+                    tracing::info!(
                         "list: decompress + write temp took {:.2?} ({} bytes)",
                         decompress_start.elapsed(),
                         decompressed_bytes
@@ -423,7 +478,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
             }
             let n = result?;
             if timers {
-                eprintln!(
+                tracing::info!(
                     "list: stream convert+write took {:.2?} ({} records)",
                     convert_start.elapsed(),
                     n
@@ -456,12 +511,12 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 }
                 std::fs::remove_file(&sqlite_out).ok();
                 if timers {
-                    eprintln!("list: zstd compression took {:.2?}", compress_start.elapsed());
+                    tracing::info!("list: zstd compression took {:.2?}", compress_start.elapsed());
                 }
             }
 
             if timers {
-                eprintln!(
+                tracing::info!(
                     "list: total took {:.2?} ({} records)",
                     write_start.elapsed(),
                     n
@@ -476,7 +531,8 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         if !matches!(from, "crossref" | "datacite") {
             return Err(
                 "list: reading a VRAIX SQLite dump requires --from crossref or --from datacite"
-                    .to_string(),
+                    .to_string()
+                    .into(),
             );
         }
         load_vraix_list_for_date(date.unwrap_or(""), input_path, from, matches, timers)?
@@ -485,9 +541,24 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         let page = *matches.get_one::<usize>("page").unwrap_or(&1);
         let file_limit = if number == 0 { None } else { Some(number) };
         let file_offset = page.saturating_sub(1).saturating_mul(number);
-        let d = load_list_from_file(input_path, from, file_limit, file_offset)?;
+        let d = if ids || is_batch_input(input_path, from) {
+            if ids && no_network {
+                return Err(Failure::InvalidInput(
+                    "list: --ids fetches every identifier and cannot be combined with --no-network".to_string(),
+                ));
+            }
+            let (d, errors) = load_batch_from_file(input_path, from, ids)?;
+            report_batch_errors(&errors, d.len())?;
+            *failed += errors.len();
+            d
+        } else if from == "csv" {
+            let map = matches.get_one::<String>("map").map(String::as_str);
+            load_csv_list_from_file(input_path, map.unwrap_or(""))?
+        } else {
+            load_list_from_file(input_path, from, file_limit, file_offset)?
+        };
         if timers {
-            eprintln!(
+            tracing::info!(
                 "list: read from file took {:.2?} ({} records)",
                 read_start.elapsed(),
                 d.len()
@@ -496,7 +567,14 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         d
     } else {
         if from == "commonmeta" {
-            return Err("list: --from commonmeta requires an input .parquet or .sqlite3 file path".to_string());
+            return Err(Failure::InvalidInput(
+                "list: --from commonmeta requires an input .parquet or .sqlite3 file path".to_string(),
+            ));
+        }
+        if from == "csv" {
+            return Err(Failure::InvalidInput(
+                "list: --from csv requires an input .csv or .tsv file path".to_string(),
+            ));
         }
         fetch_list_from_api(matches, from)?
     };
@@ -506,13 +584,18 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     // supported for the native commonmeta schema until other flattened
     // tabular representations (e.g. for datacite, csl) are added.
     if let Some(path) = out_file {
+        if path.ends_with('/') || Path::new(path).is_dir() {
+            *failed += write_directory_output(&data, to, path, style, locale)?;
+            return Ok(());
+        }
         let (_base, extension, compress) = file_utils::get_extension(path, ".json");
         if extension == ".parquet" {
             if to != "commonmeta" {
                 return Err(format!(
                     "list: --file *.parquet output is only supported for --to commonmeta (got --to {}), until other flattened formats are added",
                     to
-                ));
+                )
+                .into());
             }
             let write_start = Instant::now();
             // e.g. --file out.parquet.zip packs the zstd-compressed batch
@@ -524,32 +607,54 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
                 write_parquet_batches(&data, path)
             };
             if timers {
-                eprintln!(
+                tracing::info!(
                     "list: write {} took {:.2?} ({} records)",
                     to,
                     write_start.elapsed(),
                     data.len()
                 );
             }
-            return result;
+            return Ok(result?);
+        }
+        // JSON Lines is streamed record by record (optionally through a
+        // gzip/zstd encoder) rather than rendered into one buffer first.
+        if extension == ".jsonl" && matches!(compress.as_str(), "" | "gz" | "zst") {
+            if to != "commonmeta" {
+                return Err(format!(
+                    "list: --file *.jsonl output is only supported for --to commonmeta (got --to {})",
+                    to
+                )
+                .into());
+            }
+            let write_start = Instant::now();
+            let result = write_jsonl_output(&data, path, &compress);
+            if timers {
+                tracing::info!(
+                    "list: write jsonl took {:.2?} ({} records)",
+                    write_start.elapsed(),
+                    data.len()
+                );
+            }
+            return Ok(result?);
         }
         if extension == ".sqlite3" {
             if to != "commonmeta" {
                 return Err(format!(
                     "list: --file *.sqlite3 output is only supported for --to commonmeta (got --to {})",
                     to
-                ));
+                )
+                .into());
             }
             let write_start = Instant::now();
             let result = write_sqlite_output(&data, path, &compress, update);
             if timers {
-                eprintln!(
+                tracing::info!(
                     "list: write sqlite3 took {:.2?} ({} records)",
                     write_start.elapsed(),
                     data.len()
                 );
             }
-            return result;
+            return Ok(result?);
         }
         // A .zip/.tgz --file extension archives the records as multiple
         // batched entries (e.g. for a large --number 0 VRAIX dump) instead
@@ -558,21 +663,26 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
             let write_start = Instant::now();
             let result = write_archive_batches(&data, to, path, &compress, style, locale);
             if timers {
-                eprintln!(
+                tracing::info!(
                     "list: write {} took {:.2?} ({} records)",
                     to,
                     write_start.elapsed(),
                     data.len()
                 );
             }
-            return result;
+            return Ok(result?);
         }
     }
 
     let write_start = Instant::now();
-    let output = write_output(&data, to, style, locale)?;
+    let output = if to == "csv" {
+        let map = matches.get_one::<String>("map").map(String::as_str);
+        commonmeta::write_csv(&data, map.unwrap_or("")).map_err(|e| e.to_string())?
+    } else {
+        write_output(&data, to, style, locale)?
+    };
     if timers {
-        eprintln!(
+        tracing::info!(
             "list: write {} took {:.2?} ({} records)",
             to,
             write_start.elapsed(),
@@ -581,17 +691,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     }
 
     match out_file {
-        Some(path) => {
-            let (file, _extension, compress) = file_utils::get_extension(path, ".json");
-            match compress.as_str() {
-                "gz" => file_utils::write_gz_file(&file, &output)
-                    .map_err(|e| format!("failed to write gzip '{}': {}", path, e)),
-                "zst" => file_utils::write_zst_file(&file, &output)
-                    .map_err(|e| format!("failed to write zst '{}': {}", path, e)),
-                _ => file_utils::write_file(&file, &output)
-                    .map_err(|e| format!("failed to write '{}': {}", path, e)),
-            }
-        }
+        Some(path) => Ok(write_output_file(path, &output)?),
         None => {
             println!("{}", String::from_utf8_lossy(&output));
             Ok(())
@@ -616,6 +716,11 @@ fn is_supported_output_format(to: &str) -> bool {
             | "ris"
             | "crossref_xml"
             | "citation"
+            | "markdown"
+            | "html"
+            | "csv"
+            | "jsonl"
+            | "yaml"
     )
 }
 
@@ -684,6 +789,41 @@ fn write_parquet_archive(data: &[Data], out_path: &str, compress: &str) -> Resul
     Ok(())
 }
 
+/// Stream `data` as JSON Lines to `out_path`, through a gzip or zstd
+/// encoder when `compress` is `"gz"` or `"zst"`.
+fn write_jsonl_output(data: &[Data], out_path: &str, compress: &str) -> Result<(), String> {
+    fn write_all<W: std::io::Write>(data: &[Data], inner: W) -> Result<W, String> {
+        let mut writer = commonmeta::JsonLinesWriter::new(inner);
+        for item in data {
+            writer.write(item).map_err(|e| e.to_string())?;
+        }
+        writer.finish().map_err(|e| e.to_string())
+    }
+
+    let file = std::fs::File::create(out_path)
+        .map_err(|e| format!("failed to create '{}': {}", out_path, e))?;
+    match compress {
+        "gz" => {
+            let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            write_all(data, encoder)?
+                .finish()
+                .map_err(|e| format!("failed to write gzip '{}': {}", out_path, e))?;
+        }
+        "zst" => {
+            let encoder = zstd::Encoder::new(file, 0)
+                .map_err(|e| format!("failed to create zstd encoder: {}", e))?;
+            write_all(data, encoder)?
+                .finish()
+                .map_err(|e| format!("failed to write zst '{}': {}", out_path, e))?;
+        }
+        _ => {
+            write_all(data, file)?;
+        }
+    }
+    println!("wrote {} ({} records)", out_path, data.len());
+    Ok(())
+}
+
 /// Write `data` as a SQLite3 file at `out_path`. When `compress` is non-empty
 /// the database is first written to a temp file, read into memory, then
 /// zstd-compressed or archived (zip/tgz) to the final `out_path`.
@@ -827,7 +967,7 @@ fn write_output(
     commonmeta::write_list_citation(data, to, style, locale).map_err(|e| e.to_string())
 }
 
-pub(crate) fn fetch_list_from_api(matches: &ArgMatches, from: &str) -> Result<Vec<Data>, String> {
+pub(crate) fn fetch_list_from_api(matches: &ArgMatches, from: &str) -> Result<Vec<Data>, Failure> {
     let number = *matches.get_one::<usize>("number").unwrap_or(&10);
     let page = *matches.get_one::<usize>("page").unwrap_or(&1);
 
@@ -839,7 +979,7 @@ pub(crate) fn fetch_list_from_api(matches: &ArgMatches, from: &str) -> Result<Ve
         "crossref" => fetch_crossref_page(matches, number, page),
         "datacite" => fetch_datacite_list(matches, number, page),
         "openalex" => fetch_openalex_list(matches, number, page),
-        _ => Err(format!("unsupported source: {from}")),
+        _ => Err(format!("unsupported source: {from}").into()),
     }
 }
 
@@ -860,7 +1000,11 @@ const API_RATE_DELAY_MS: u64 = 500;
 /// Uses cursor-based pagination for every source to avoid the 10,000-record
 /// offset limit imposed by Crossref, DataCite, and OpenAlex (all backed by
 /// Elasticsearch/OpenSearch).
-fn fetch_all_pages(matches: &ArgMatches, from: &str, _start_page: usize) -> Result<Vec<Data>, String> {
+fn fetch_all_pages(
+    matches: &ArgMatches,
+    from: &str,
+    _start_page: usize,
+) -> Result<Vec<Data>, Failure> {
     let batch = api_batch_size(from);
     let delay = std::time::Duration::from_millis(API_RATE_DELAY_MS);
     let mut all: Vec<Data> = Vec::new();
@@ -873,7 +1017,7 @@ fn fetch_all_pages(matches: &ArgMatches, from: &str, _start_page: usize) -> Resu
                 let (got, next) = fetch_crossref_page_with_cursor(matches, batch, &cursor)?;
                 let n = got.len();
                 all.extend(got);
-                eprintln!("fetched {} records from crossref (page {}, {} total)", n, page, all.len());
+                tracing::info!("fetched {} records from crossref (page {}, {} total)", n, page, all.len());
                 if n < batch || next.is_none() {
                     break;
                 }
@@ -889,7 +1033,7 @@ fn fetch_all_pages(matches: &ArgMatches, from: &str, _start_page: usize) -> Resu
                 let (got, next) = fetch_datacite_page_with_cursor(matches, batch, cursor.as_deref())?;
                 let n = got.len();
                 all.extend(got);
-                eprintln!("fetched {} records from datacite (page {}, {} total)", n, page, all.len());
+                tracing::info!("fetched {} records from datacite (page {}, {} total)", n, page, all.len());
                 if n < batch || next.is_none() {
                     break;
                 }
@@ -905,7 +1049,7 @@ fn fetch_all_pages(matches: &ArgMatches, from: &str, _start_page: usize) -> Resu
                 let (got, next) = fetch_openalex_page_with_cursor(matches, batch, &cursor)?;
                 let n = got.len();
                 all.extend(got);
-                eprintln!("fetched {} records from openalex (page {}, {} total)", n, page, all.len());
+                tracing::info!("fetched {} records from openalex (page {}, {} total)", n, page, all.len());
                 if n < batch || next.is_none() {
                     break;
                 }
@@ -914,7 +1058,7 @@ fn fetch_all_pages(matches: &ArgMatches, from: &str, _start_page: usize) -> Resu
                 std::thread::sleep(delay);
             }
         }
-        _ => return Err(format!("unsupported source: {from}")),
+        _ => return Err(format!("unsupported source: {from}").into()),
     }
     Ok(all)
 }
@@ -923,7 +1067,7 @@ fn fetch_crossref_page_with_cursor(
     matches: &ArgMatches,
     number: usize,
     cursor: &str,
-) -> Result<(Vec<Data>, Option<String>), String> {
+) -> Result<(Vec<Data>, Option<String>), Failure> {
     commonmeta::crossref_fetch_page_with_cursor(
         cursor,
         number,
@@ -942,7 +1086,7 @@ fn fetch_crossref_page_with_cursor(
         matches.get_flag("has-archive"),
         *matches.get_one::<bool>("match").unwrap_or(&true),
     )
-    .map_err(|e| e.to_string())
+    .map_err(Failure::from)
 }
 
 /// Fetch one page of DataCite records using cursor-based pagination.
@@ -953,7 +1097,7 @@ fn fetch_datacite_page_with_cursor(
     matches: &ArgMatches,
     number: usize,
     cursor: Option<&str>,
-) -> Result<(Vec<Data>, Option<String>), String> {
+) -> Result<(Vec<Data>, Option<String>), Failure> {
     let mut url =
         Url::parse("https://api.datacite.org/dois").map_err(|e| format!("invalid URL: {}", e))?;
     {
@@ -1014,11 +1158,11 @@ fn fetch_datacite_page_with_cursor(
     let text = client
         .get(url.as_str())
         .send()
-        .map_err(|e| format!("http request failed: {}", e))?
+        .map_err(|e| Failure::Network(format!("http request failed: {}", e)))?
         .error_for_status()
-        .map_err(|e| format!("http status error: {}", e))?
+        .map_err(|e| Failure::Network(format!("http status error: {}", e)))?
         .text()
-        .map_err(|e| format!("failed to read response: {}", e))?;
+        .map_err(|e| Failure::Network(format!("failed to read response: {}", e)))?;
 
     let value: serde_json::Value =
         serde_json::from_str(&text).map_err(|e| format!("invalid DataCite response: {}", e))?;
@@ -1046,7 +1190,7 @@ fn fetch_openalex_page_with_cursor(
     matches: &ArgMatches,
     number: usize,
     cursor: &str,
-) -> Result<(Vec<Data>, Option<String>), String> {
+) -> Result<(Vec<Data>, Option<String>), Failure> {
     let mut url =
         Url::parse("https://api.openalex.org/works").map_err(|e| format!("invalid URL: {}", e))?;
     {
@@ -1103,11 +1247,11 @@ fn fetch_openalex_page_with_cursor(
     let text = client
         .get(url.as_str())
         .send()
-        .map_err(|e| format!("http request failed: {}", e))?
+        .map_err(|e| Failure::Network(format!("http request failed: {}", e)))?
         .error_for_status()
-        .map_err(|e| format!("http status error: {}", e))?
+        .map_err(|e| Failure::Network(format!("http status error: {}", e)))?
         .text()
-        .map_err(|e| format!("failed to read response: {}", e))?;
+        .map_err(|e| Failure::Network(format!("failed to read response: {}", e)))?;
 
     let value: serde_json::Value =
         serde_json::from_str(&text).map_err(|e| format!("invalid OpenAlex response: {}", e))?;
@@ -1128,7 +1272,11 @@ fn fetch_openalex_page_with_cursor(
     Ok((out, next_cursor))
 }
 
-fn fetch_crossref_page(matches: &ArgMatches, number: usize, page: usize) -> Result<Vec<Data>, String> {
+fn fetch_crossref_page(
+    matches: &ArgMatches,
+    number: usize,
+    page: usize,
+) -> Result<Vec<Data>, Failure> {
     commonmeta::crossref::fetch_all(
         number,
         page,
@@ -1148,14 +1296,14 @@ fn fetch_crossref_page(matches: &ArgMatches, number: usize, page: usize) -> Resu
         matches.get_flag("has-archive"),
         *matches.get_one::<bool>("match").unwrap_or(&true),
     )
-    .map_err(|e| e.to_string())
+    .map_err(Failure::from)
 }
 
 fn fetch_datacite_list(
     matches: &ArgMatches,
     number: usize,
     page: usize,
-) -> Result<Vec<Data>, String> {
+) -> Result<Vec<Data>, Failure> {
     let mut url =
         Url::parse("https://api.datacite.org/dois").map_err(|e| format!("invalid URL: {}", e))?;
     {
@@ -1204,6 +1352,11 @@ fn fetch_datacite_list(
         }
     }
 
+    fetch_datacite_url(&url)
+}
+
+/// Fetch a DataCite REST API `/dois` listing and convert every work in it.
+pub(crate) fn fetch_datacite_url(url: &Url) -> Result<Vec<Data>, Failure> {
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!(
             "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
@@ -1215,11 +1368,11 @@ fn fetch_datacite_list(
     let text = client
         .get(url.as_str())
         .send()
-        .map_err(|e| format!("http request failed: {}", e))?
+        .map_err(|e| Failure::Network(format!("http request failed: {}", e)))?
         .error_for_status()
-        .map_err(|e| format!("http status error: {}", e))?
+        .map_err(|e| Failure::Network(format!("http status error: {}", e)))?
         .text()
-        .map_err(|e| format!("failed to read response: {}", e))?;
+        .map_err(|e| Failure::Network(format!("failed to read response: {}", e)))?;
 
     let value: serde_json::Value =
         serde_json::from_str(&text).map_err(|e| format!("invalid DataCite response: {}", e))?;
@@ -1239,7 +1392,7 @@ fn fetch_openalex_list(
     matches: &ArgMatches,
     number: usize,
     page: usize,
-) -> Result<Vec<Data>, String> {
+) -> Result<Vec<Data>, Failure> {
     let mut url =
         Url::parse("https://api.openalex.org/works").map_err(|e| format!("invalid URL: {}", e))?;
     {
@@ -1296,11 +1449,11 @@ fn fetch_openalex_list(
     let text = client
         .get(url.as_str())
         .send()
-        .map_err(|e| format!("http request failed: {}", e))?
+        .map_err(|e| Failure::Network(format!("http request failed: {}", e)))?
         .error_for_status()
-        .map_err(|e| format!("http status error: {}", e))?
+        .map_err(|e| Failure::Network(format!("http status error: {}", e)))?
         .text()
-        .map_err(|e| format!("failed to read response: {}", e))?;
+        .map_err(|e| Failure::Network(format!("failed to read response: {}", e)))?;
 
     let value: serde_json::Value =
         serde_json::from_str(&text).map_err(|e| format!("invalid OpenAlex response: {}", e))?;
@@ -1343,6 +1496,213 @@ pub(crate) fn load_list_from_file(
     }
 }
 
+/// Whether `path` is a JSON array or JSON Lines file of `from` records that
+/// none of the source-specific loaders handle, and is therefore read one
+/// entry at a time by [`load_batch_from_file`].
+fn is_batch_input(path: &str, from: &str) -> bool {
+    let (_base, extension, _compress) = file_utils::get_extension(path, ".json");
+    if matches!(extension.as_str(), ".parquet" | ".sqlite3") {
+        return false;
+    }
+    match from {
+        "crossref" | "datacite" | "openalex" | "csv" => false,
+        "commonmeta" => path == "-" || matches!(extension.as_str(), ".json" | ".jsonl" | ".ndjson"),
+        _ => true,
+    }
+}
+
+/// Read the entries of a batch input file and convert each one on its own,
+/// so that a bad entry is reported instead of aborting the run. With `ids`,
+/// every entry is a DOI, URL or ROR ID that is fetched with its format
+/// auto-detected; otherwise every entry is a record in `from` format.
+/// Returns the converted records and one message per failed entry.
+fn load_batch_from_file(path: &str, from: &str, ids: bool) -> Result<(Vec<Data>, Vec<String>), String> {
+    let content = read_input(path)?;
+    let entries = if ids {
+        identifier_entries(&content)?
+    } else {
+        record_entries(&content)?
+    };
+
+    let bar = commonmeta::progress::count_bar("converting", entries.len() as u64);
+    let mut data = Vec::with_capacity(entries.len());
+    let mut errors = Vec::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        let result = if ids {
+            read_identifier(entry).map_err(|e| e.to_string())
+        } else {
+            commonmeta::read(from, entry).map_err(|e| e.to_string())
+        };
+        match result {
+            Ok(d) => data.push(d),
+            Err(e) if ids => errors.push(format!("entry {} ({}): {}", idx + 1, entry, e)),
+            Err(e) => errors.push(format!("entry {}: {}", idx + 1, e)),
+        }
+        bar.inc(1);
+    }
+    bar.finish_and_clear();
+    Ok((data, errors))
+}
+
+/// Split a JSON array or JSON Lines file into one JSON document per record.
+fn record_entries(content: &str) -> Result<Vec<String>, String> {
+    if content.trim_start().starts_with('[') {
+        let items: Vec<serde_json::Value> = serde_json::from_str(content)
+            .map_err(|e| format!("list: invalid JSON array: {}", e))?;
+        return Ok(items.iter().map(|item| item.to_string()).collect());
+    }
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Identifiers listed in a JSON array or JSON Lines file of strings, or in a
+/// CSV/TSV or plain-text file. For CSV, an `id`, `doi`, `identifier`, `url`
+/// or `ror` header column is used when present, the first column otherwise.
+pub(crate) fn identifier_entries(content: &str) -> Result<Vec<String>, String> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') {
+        let items: Vec<serde_json::Value> = serde_json::from_str(trimmed)
+            .map_err(|e| format!("list: invalid JSON array: {}", e))?;
+        return Ok(items
+            .into_iter()
+            .map(|item| match item {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            })
+            .collect());
+    }
+    if trimmed.starts_with('"') {
+        return Ok(trimmed
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_str::<String>(line).unwrap_or_else(|_| line.to_string()))
+            .collect());
+    }
+
+    let delimiter = match trimmed.lines().next() {
+        Some(first) if first.contains('\t') => b'\t',
+        _ => b',',
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .from_reader(trimmed.as_bytes());
+    let mut rows = reader.records();
+    let mut column = 0;
+    let mut out = Vec::new();
+    if let Some(first) = rows.next() {
+        let first = first.map_err(|e| format!("list: invalid CSV: {}", e))?;
+        let header = first.iter().position(|cell| {
+            matches!(
+                cell.trim().to_lowercase().as_str(),
+                "id" | "doi" | "identifier" | "url" | "ror"
+            )
+        });
+        match header {
+            Some(idx) => column = idx,
+            None => out.extend(first.get(0).map(|cell| cell.trim().to_string())),
+        }
+    }
+    for row in rows {
+        let row = row.map_err(|e| format!("list: invalid CSV: {}", e))?;
+        out.extend(row.get(column).map(|cell| cell.trim().to_string()));
+    }
+    out.retain(|id| !id.is_empty());
+    Ok(out)
+}
+
+/// Log one warning per failed batch entry and a summary. Fails only when no
+/// entry could be converted.
+fn report_batch_errors(errors: &[String], converted: usize) -> Result<(), String> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    for error in errors {
+        tracing::warn!("list: {}", error);
+    }
+    tracing::info!(
+        "list: converted {} of {} entries ({} failed)",
+        converted,
+        converted + errors.len(),
+        errors.len()
+    );
+    if converted == 0 {
+        return Err("list: no entries could be converted".to_string());
+    }
+    Ok(())
+}
+
+/// File extension for one record written in `to` format.
+pub(crate) fn output_extension(to: &str) -> &'static str {
+    match to {
+        "yaml" => "yaml",
+        "crossref_xml" | "datacite_xml" | "dublincore" | "jats" | "openaire" => "xml",
+        "bibtex" => "bib",
+        "ris" => "ris",
+        "citation" => "txt",
+        "markdown" => "md",
+        "html" => "html",
+        "csv" => "csv",
+        "jsonl" => "jsonl",
+        _ => "json",
+    }
+}
+
+/// File name stem for a record, derived from its identifier.
+fn record_file_stem(data: &Data, idx: usize) -> String {
+    let id = data
+        .id
+        .trim_start_matches("https://doi.org/")
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let stem: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') { c } else { '_' })
+        .collect();
+    if stem.is_empty() {
+        format!("record-{}", idx + 1)
+    } else {
+        stem
+    }
+}
+
+/// Write every record to its own file in directory `dir`, reporting records
+/// that fail to render instead of aborting. Returns the number of failures.
+fn write_directory_output(
+    data: &[Data],
+    to: &str,
+    dir: &str,
+    style: Option<&str>,
+    locale: Option<&str>,
+) -> Result<usize, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("failed to create '{}': {}", dir, e))?;
+    let extension = output_extension(to);
+    let mut written = 0;
+    let mut errors = Vec::new();
+    for (idx, item) in data.iter().enumerate() {
+        let path = Path::new(dir).join(format!("{}.{}", record_file_stem(item, idx), extension));
+        let result = commonmeta::write_with_style(to, item, style, locale)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| {
+                file_utils::write_file(&path, &bytes)
+                    .map_err(|e| format!("failed to write '{}': {}", path.display(), e))
+            });
+        match result {
+            Ok(()) => written += 1,
+            Err(e) => errors.push(format!("record {} ({}): {}", idx + 1, item.id, e)),
+        }
+    }
+    report_batch_errors(&errors, written)?;
+    println!("wrote {} ({} records)", dir, written);
+    Ok(errors.len())
+}
+
 /// Read a commonmeta SQLite database (optionally zstd-compressed) written by
 /// `--file *.sqlite3` back into a list of records.
 fn load_commonmeta_list_from_sqlite(
@@ -1438,9 +1798,15 @@ fn load_commonmeta_list_from_parquet(path: &str) -> Result<Vec<Data>, String> {
     Ok(out)
 }
 
+/// Read a CSV/TSV spreadsheet of works, one record per row, assigning
+/// columns to commonmeta fields via the `--map` specification.
+fn load_csv_list_from_file(path: &str, map: &str) -> Result<Vec<Data>, String> {
+    let content = read_input(path)?;
+    commonmeta::read_csv(&content, map).map_err(|e| format!("invalid spreadsheet '{}': {}", path, e))
+}
+
 fn load_crossref_list_from_file(path: &str) -> Result<Vec<Data>, String> {
-    let content =
-        std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {}", path, e))?;
+    let content = read_input(path)?;
 
     if path.ends_with(".jsonl") || path.ends_with(".jsonlines") {
         return parse_crossref_jsonlines(&content);
@@ -1503,8 +1869,7 @@ fn convert_crossref_item(item: &serde_json::Value) -> Result<Data, String> {
 }
 
 fn load_datacite_list_from_file(path: &str) -> Result<Vec<Data>, String> {
-    let content =
-        std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {}", path, e))?;
+    let content = read_input(path)?;
 
     if path.ends_with(".jsonl") || path.ends_with(".jsonlines") {
         let mut out: Vec<Data> = Vec::new();
@@ -1557,8 +1922,7 @@ fn convert_datacite_item(item: &serde_json::Value) -> Result<Data, String> {
 }
 
 fn load_openalex_list_from_file(path: &str) -> Result<Vec<Data>, String> {
-    let content =
-        std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {}", path, e))?;
+    let content = read_input(path)?;
 
     if path.ends_with(".jsonl") || path.ends_with(".jsonlines") {
         let mut out: Vec<Data> = Vec::new();
@@ -1644,7 +2008,7 @@ fn load_vraix_list_for_date(
             commonmeta::read_vraix_sqlite(path, from, limit, offset).map_err(|e| e.to_string())?
         };
         if timers {
-            eprintln!(
+            tracing::info!(
                 "list: read to commonmeta took {:.2?} ({} records)",
                 convert_start.elapsed(),
                 data.len()
@@ -1662,7 +2026,7 @@ fn load_vraix_list_for_date(
             .map_err(|e| format!("failed to download '{}': {}", url, e))?;
     if timers {
         let label = if from_cache { ", from local cache" } else { "" };
-        eprintln!(
+        tracing::info!(
             "list: download took {:.2?}{}",
             download_start.elapsed(),
             label,
@@ -1683,7 +2047,7 @@ fn load_vraix_list_for_date(
     std::fs::remove_file(&tmp_path).ok();
     let data = result.map_err(|e| e.to_string())?;
     if timers {
-        eprintln!(
+        tracing::info!(
             "list: read to commonmeta took {:.2?} ({} records)",
             convert_start.elapsed(),
             data.len()
@@ -1710,7 +2074,7 @@ mod tests {
         match execute(&m) {
             Ok(()) => {}
             Err(e) => assert!(
-                !e.contains("--no-network requires"),
+                !e.to_string().contains("--no-network requires"),
                 "should fall back to local DB rather than refusing; got: {e}"
             ),
         }
@@ -1723,7 +2087,7 @@ mod tests {
         let m = parse_args(&["list", "--no-network", "nonexistent.sqlite3"]);
         let err = execute(&m).unwrap_err();
         assert!(
-            !err.contains("--no-network requires"),
+            !err.to_string().contains("--no-network requires"),
             "should not fail at network guard when input file is given, got: {err}"
         );
     }
@@ -1748,6 +2112,30 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/out.zst"));
     }
 
+    #[test]
+    fn test_write_jsonl_output_gz() {
+        use std::io::Read;
+        let dir = temp_dir().join("commonmeta_list_jsonl_gz");
+        std::fs::create_dir_all(&dir).unwrap();
+        let out_path = dir.join("out.jsonl.gz");
+
+        let data = vec![
+            sample_data("https://doi.org/10.1/a"),
+            sample_data("https://doi.org/10.1/b"),
+        ];
+        write_jsonl_output(&data, out_path.to_str().unwrap(), "gz").unwrap();
+
+        let mut text = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(&out_path).unwrap())
+            .read_to_string(&mut text)
+            .unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("https://doi.org/10.1/b"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_write_archive_batches_zip() {
         let dir = temp_dir().join("commonmeta_list_archive_zip");
@@ -2093,12 +2481,43 @@ mod tests {
         let matches =
             command().get_matches_from(vec!["list", "--from", "openalex", "--date", "2026-06-14"]);
         let err = execute(&matches).unwrap_err();
-        assert!(err.contains("requires --from crossref or --from datacite"));
+        assert!(err.to_string().contains("requires --from crossref or --from datacite"));
+    }
+
+    #[test]
+    fn test_load_csv_list_from_file_applies_column_map() {
+        let dir = temp_dir().join("commonmeta_list_csv");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("works.csv");
+        std::fs::write(
+            &path,
+            "Name,DOI,Year\nAlpine soils,10.5555/abc,2021\nValley soils,10.5555/def,2022\n",
+        )
+        .unwrap();
+
+        let data = load_csv_list_from_file(path.to_str().unwrap(), "title=Name").unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].title, "Alpine soils");
+        assert_eq!(data[1].id, "https://doi.org/10.5555/def");
+
+        let err = load_csv_list_from_file(path.to_str().unwrap(), "title=Heading").unwrap_err();
+        assert!(err.to_string().contains("column 'Heading' not found"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_execute_csv_requires_input_path() {
+        let matches = command().get_matches_from(vec!["list", "--from", "csv"]);
+        let err = execute(&matches).unwrap_err();
+        assert!(err.to_string().contains("--from csv requires an input"));
     }
 
     #[test]
     fn test_is_supported_output_format_includes_citation() {
         assert!(is_supported_output_format("citation"));
+        assert!(is_supported_output_format("markdown"));
+        assert!(is_supported_output_format("html"));
     }
 
     #[test]
@@ -2201,4 +2620,78 @@ mod tests {
 
         std::fs::remove_dir_all(&dir).ok();
     }
+
+    #[test]
+    fn test_identifier_entries_formats() {
+        assert_eq!(
+            identifier_entries(r#"["10.5555/1", "https://ror.org/02nr0ka47"]"#).unwrap(),
+            vec!["10.5555/1", "https://ror.org/02nr0ka47"]
+        );
+        assert_eq!(
+            identifier_entries("\"10.5555/1\"\n\"10.5555/2\"\n").unwrap(),
+            vec!["10.5555/1", "10.5555/2"]
+        );
+        assert_eq!(
+            identifier_entries("title,DOI\nFirst,10.5555/1\nSecond,10.5555/2\n").unwrap(),
+            vec!["10.5555/1", "10.5555/2"]
+        );
+        assert_eq!(
+            identifier_entries("10.5555/1\n\n10.5555/2\n").unwrap(),
+            vec!["10.5555/1", "10.5555/2"]
+        );
+    }
+
+    #[test]
+    fn test_is_batch_input() {
+        assert!(is_batch_input("records.jsonl", "csl"));
+        assert!(is_batch_input("records.json", "commonmeta"));
+        assert!(is_batch_input("-", "commonmeta"));
+        assert!(is_batch_input("-", "csl"));
+        assert!(!is_batch_input("-", "crossref"));
+        assert!(!is_batch_input("records.json", "crossref"));
+        assert!(!is_batch_input("works.csv", "csv"));
+        assert!(!is_batch_input("out.parquet", "commonmeta"));
+    }
+
+    #[test]
+    fn test_execute_batch_reports_bad_entries_and_writes_directory() {
+        let dir = temp_dir().join(format!("commonmeta_list_batch_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("records.jsonl");
+        std::fs::write(
+            &input,
+            "{\"DOI\":\"10.5555/1\",\"type\":\"article-journal\",\"title\":\"First\"}\n\
+             not json\n\
+             {\"DOI\":\"10.5555/2\",\"type\":\"article-journal\",\"title\":\"Second\"}\n",
+        )
+        .unwrap();
+        let out = dir.join("out");
+        let out_arg = format!("{}/", out.display());
+        let matches = command().get_matches_from(vec![
+            "list",
+            input.to_str().unwrap(),
+            "--from",
+            "csl",
+            "--to",
+            "bibtex",
+            "--file",
+            &out_arg,
+        ]);
+        // The bad entry makes this a partial failure, but the good ones are
+        // still written.
+        let err = execute(&matches).unwrap_err();
+        assert_eq!(err.exit_code(), 1);
+
+        let first = std::fs::read_to_string(out.join("10.5555_1.bib")).unwrap();
+        assert!(first.contains("First"));
+        assert!(out.join("10.5555_2.bib").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_execute_ids_requires_input() {
+        let matches = command().get_matches_from(vec!["list", "--ids"]);
+        assert!(execute(&matches).unwrap_err().to_string().contains("--ids requires an input"));
+    }
 }
@@ -0,0 +1,152 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::path::Path;
+
+use clap::{Arg, ArgMatches, Command};
+use clap_mangen::Man;
+
+/// Build the (hidden) man subcommand
+pub fn command() -> Command {
+    Command::new("man")
+        .about("Generate roff man pages")
+        .long_about(
+            "Print the man page for commonmeta, or for one of its subcommands, in \
+            roff format. With --dir, write commonmeta.1 and one \
+            commonmeta-<subcommand>.1 page per subcommand into that directory \
+            instead, as needed for distro packaging.\n\n\
+            Examples:\n\n\
+            commonmeta man | man -l -\n\
+            commonmeta man convert > commonmeta-convert.1\n\
+            commonmeta man --dir target/man",
+        )
+        .hide(true)
+        .arg(
+            Arg::new("subcommand")
+                .help("Subcommand to print the man page for (default: commonmeta itself)")
+                .index(1)
+                .conflicts_with("dir"),
+        )
+        .arg(
+            Arg::new("dir")
+                .long("dir")
+                .help("Write all man pages into this directory"),
+        )
+}
+
+/// Execute the man command for the command-line interface `cli`
+pub fn execute(matches: &ArgMatches, mut cli: Command) -> Result<(), String> {
+    cli.build();
+    if let Some(dir) = matches.get_one::<String>("dir") {
+        return write_pages(&cli, Path::new(dir));
+    }
+    let page = match matches.get_one::<String>("subcommand") {
+        Some(name) => {
+            let sub = cli
+                .find_subcommand(name)
+                .ok_or_else(|| format!("unknown subcommand '{}'", name))?;
+            render(&cli, sub.clone())?
+        }
+        None => render(&cli, cli.clone())?,
+    };
+    print!("{}", page);
+    Ok(())
+}
+
+/// Write the page for `cli` and one page per visible subcommand into `dir`.
+fn write_pages(cli: &Command, dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("failed to create '{}': {}", dir.display(), e))?;
+    let subcommands = cli.get_subcommands().filter(|sub| !sub.is_hide_set());
+    let pages: Vec<Command> = std::iter::once(cli).chain(subcommands).cloned().collect();
+    for cmd in &pages {
+        let path = man(cli, cmd.clone())
+            .generate_to(dir)
+            .map_err(|e| format!("failed to write man pages to '{}': {}", dir.display(), e))?;
+        tracing::debug!("Wrote {}", path.display());
+    }
+    tracing::info!("Wrote {} man pages to {}", pages.len(), dir.display());
+    Ok(())
+}
+
+/// The man page of `cmd`, the built `cli` or one of its subcommands.
+fn render(cli: &Command, cmd: Command) -> Result<String, String> {
+    let mut page = Vec::new();
+    man(cli, cmd)
+        .render(&mut page)
+        .map_err(|e| format!("failed to render the man page: {}", e))?;
+    Ok(String::from_utf8_lossy(&page).to_string())
+}
+
+/// Subcommands don't carry the version, so every page names the version
+/// of `cli` as its source.
+fn man(cli: &Command, cmd: Command) -> Man {
+    let source = format!("{} {}", cli.get_name(), cli.get_version().unwrap_or_default());
+    Man::new(cmd).source(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn built_cli() -> Command {
+        let mut cli = crate::cli();
+        cli.build();
+        cli
+    }
+
+    /// `--to` as clap_mangen writes it in roff.
+    fn roff_flag(long: &str) -> String {
+        format!("\\-\\-{}", long.replace('-', "\\-"))
+    }
+
+    #[test]
+    fn test_main_page_lists_subcommands() {
+        let cli = built_cli();
+        let page = render(&cli, cli.clone()).unwrap();
+        assert!(page.contains(".TH commonmeta 1"));
+        for sub in cli.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+            let name = sub.get_name();
+            assert!(page.contains(&format!("commonmeta\\-{}", name)), "missing {name}");
+        }
+        assert!(!page.contains("commonmeta\\-man("));
+    }
+
+    #[test]
+    fn test_subcommand_pages_list_every_argument() {
+        let cli = built_cli();
+        for sub in cli.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+            let page = render(&cli, sub.clone()).unwrap();
+            let name = sub.get_name();
+            assert!(page.contains(&format!(".TH commonmeta-{} 1", name)), "{name}");
+            assert!(page.contains(env!("CARGO_PKG_VERSION")), "{name}");
+            for arg in sub.get_arguments().filter(|arg| !arg.is_hide_set()) {
+                let label = match arg.get_long() {
+                    Some(long) => roff_flag(long),
+                    None => format!("\\fI{}\\fR", arg.get_id()),
+                };
+                assert!(page.contains(&label), "{name} page is missing {label}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_subcommand_page_has_values() {
+        let cli = built_cli();
+        let page = render(&cli, cli.find_subcommand("convert").unwrap().clone()).unwrap();
+        assert!(page.contains("4.5"));
+        assert!(page.contains("4.7"));
+    }
+
+    #[test]
+    fn test_write_pages() {
+        let dir =
+            std::env::temp_dir().join(format!("commonmeta_man_test_{}", std::process::id()));
+        write_pages(&built_cli(), &dir).unwrap();
+        assert!(dir.join("commonmeta.1").exists());
+        assert!(dir.join("commonmeta-validate.1").exists());
+        assert!(!dir.join("commonmeta-man.1").exists());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,7 +1,7 @@
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::path::Path;
 
-use crate::cmd::resolve_db_path;
+use crate::cmd::{Failure, resolve_db_path};
 
 pub fn command() -> Command {
     Command::new("match")
@@ -16,6 +16,7 @@ pub fn command() -> Command {
             Example usage:\n\n\
             commonmeta match \"Leibniz Universität Hannover\"\n\
             commonmeta match \"MIT\" --no-network\n\
+            commonmeta match \"Dept. of Physics, MIT, Cambridge\" --all\n\
             commonmeta match \"MIT\" --file /data/ror.sqlite3",
         )
         .arg(
@@ -53,9 +54,15 @@ pub fn command() -> Command {
                 .help("Use only the local ROR database; error if it does not exist instead of falling back to the ROR API")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .help("List every candidate as score, ROR ID and name instead of the chosen organization")
+                .action(ArgAction::SetTrue),
+        )
 }
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
     let input = matches.get_one::<String>("input").expect("required");
     let from = matches
         .get_one::<String>("from")
@@ -81,17 +88,23 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     let db_path = Path::new(&db_path_str);
 
     let candidates = if db_path.exists() {
-        commonmeta::match_ror_affiliation_sqlite(input, db_path)
-            .map_err(|e| e.to_string())?
+        commonmeta::match_ror_affiliation_sqlite(input, db_path)?
     } else if no_network {
         return Err(format!(
             "local ROR database not found at '{}'; \
             run 'commonmeta import --from ror' or remove --no-network",
             db_path_str
-        ));
+        )
+        .into());
     } else {
-        commonmeta::match_ror_affiliation(input).map_err(|e| e.to_string())?
+        commonmeta::match_ror_affiliation(input)?
     };
+    if matches.get_flag("all") {
+        for m in &candidates {
+            println!("{}", format_candidate(m));
+        }
+        return Ok(());
+    }
     let chosen = candidates.into_iter().find(|m| m.chosen);
 
     let organization = match chosen {
@@ -103,15 +116,26 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     };
 
     let output = match to {
-        "inveniordm" => commonmeta::write("ror", &organization).map_err(|e| e.to_string())?,
-        "ror" => commonmeta::write_ror_json(&organization).map_err(|e| e.to_string())?,
-        other => return Err(format!("match: unsupported --to format: {}", other)),
+        "inveniordm" => commonmeta::write("ror", &organization)?,
+        "ror" => commonmeta::write_ror_json(&organization)?,
+        other => return Err(format!("match: unsupported --to format: {}", other).into()),
     };
 
     println!("{}", String::from_utf8_lossy(&output));
     Ok(())
 }
 
+/// `score<TAB>ROR ID<TAB>name`, with `*` after the score of the chosen match.
+fn format_candidate(m: &commonmeta::AffiliationMatch) -> String {
+    format!(
+        "{:.2}{}\t{}\t{}",
+        m.score,
+        if m.chosen { "*" } else { "" },
+        m.organization.id,
+        m.organization.title
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,7 +178,7 @@ mod tests {
             "--file",
             "/tmp/nonexistent-ror-test.sqlite3",
         ]);
-        let err = execute(&matches).unwrap_err();
+        let err = execute(&matches).unwrap_err().to_string();
         assert!(
             err.contains("--no-network") || err.contains("not found"),
             "expected network-guard error, got: {err}"
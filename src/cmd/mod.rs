@@ -1,13 +1,35 @@
+pub mod check;
+pub mod completions;
 pub mod convert;
 pub mod decode;
+pub mod diff;
 pub mod dump;
+pub mod enrich;
+pub mod extract;
 pub mod encode;
+pub mod fetch;
 pub mod import;
 pub mod install;
 pub mod list;
+pub mod man;
+pub mod orcid;
+pub mod prefix;
 pub mod r#match;
 pub mod push;
 pub mod put;
+pub mod ra;
+pub mod register;
+pub mod ror;
+pub mod serve;
+pub mod sample;
+pub mod validate;
+pub mod watch;
+
+use std::io::{Read, Write};
+
+use clap::{Arg, ArgAction, ArgMatches};
+
+use commonmeta::file_utils;
 
 pub const PIDBOX_URL: &str = "https://metadata.vraix.org/pidbox.sqlite3.zst";
 pub const PIDBOX_CACHE_KEY: &str = "pidbox.sqlite3.zst";
@@ -52,3 +74,230 @@ fn platform_default_db_path() -> String {
     }
 }
 
+/// Why a command failed, which decides the exit code of the process.
+#[derive(Debug)]
+pub enum Failure {
+    /// Some items of a batch failed, the others were processed (exit code 1).
+    Partial(String),
+    /// Bad arguments or an unreadable or unparseable input (exit code 2).
+    InvalidInput(String),
+    /// A request to an API or the DOI resolver failed (exit code 3).
+    Network(String),
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Failure::Partial(_) => 1,
+            Failure::InvalidInput(_) => 2,
+            Failure::Network(_) => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Partial(message)
+            | Failure::InvalidInput(message)
+            | Failure::Network(message) => f.write_str(message),
+        }
+    }
+}
+
+/// Library errors of commands that fail as a whole: HTTP errors count as
+/// network errors, anything else as invalid input.
+impl From<commonmeta::Error> for Failure {
+    fn from(error: commonmeta::Error) -> Self {
+        match error {
+            commonmeta::Error::Http(_) => Failure::Network(error.to_string()),
+            _ => Failure::InvalidInput(error.to_string()),
+        }
+    }
+}
+
+/// Other errors of commands that fail as a whole count as invalid input.
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure::InvalidInput(message)
+    }
+}
+
+/// `--output`, for commands that print their results to stdout by default.
+pub fn output_arg() -> Arg {
+    Arg::new("output")
+        .long("output")
+        .short('o')
+        .help("Write results to this file instead of stdout (.zip, .gz or .zst to compress, - for stdout)")
+}
+
+/// Read the text file at `path`, or stdin when `path` is `-`.
+pub fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| format!("failed to read stdin: {}", e))?;
+        return Ok(content);
+    }
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {}", path, e))
+}
+
+/// Write `output` to `path`, compressed when the file name ends in `.zip`,
+/// `.gz` or `.zst`, or to stdout when `path` is `-`.
+pub fn write_output_file(path: &str, output: &[u8]) -> Result<(), String> {
+    if path == "-" {
+        return std::io::stdout()
+            .write_all(output)
+            .map_err(|e| format!("failed to write stdout: {}", e));
+    }
+    let (file, _extension, compress) = file_utils::get_extension(path, "");
+    match compress.as_str() {
+        "zip" => file_utils::write_zip_file(&file, output)
+            .map_err(|e| format!("failed to write zip '{}': {}", path, e)),
+        "gz" => file_utils::write_gz_file(&file, output)
+            .map_err(|e| format!("failed to write gzip '{}': {}", path, e)),
+        "zst" => file_utils::write_zst_file(&file, output)
+            .map_err(|e| format!("failed to write zst '{}': {}", path, e)),
+        _ => file_utils::write_file(&file, output)
+            .map_err(|e| format!("failed to write '{}': {}", path, e)),
+    }
+}
+
+/// Print `lines` to stdout, or write them to `output` when `--output` was
+/// given.
+pub fn write_lines(lines: &[String], output: Option<&String>) -> Result<(), String> {
+    match output {
+        Some(path) => {
+            let mut content = lines.join("\n");
+            content.push('\n');
+            write_output_file(path, content.as_bytes())
+        }
+        None => {
+            for line in lines {
+                println!("{}", line);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// `--depositor`, `--email` and `--registrant`, for commands that can write
+/// a Crossref deposit with `--to crossref_xml`.
+pub fn deposit_args() -> [Arg; 3] {
+    [
+        Arg::new("depositor")
+            .long("depositor")
+            .help("Depositor name for --to crossref_xml deposits"),
+        Arg::new("email")
+            .long("email")
+            .help("Depositor email address for --to crossref_xml deposits"),
+        Arg::new("registrant")
+            .long("registrant")
+            .help("Registrant for --to crossref_xml deposits (default: the publisher)"),
+    ]
+}
+
+/// `--compact`, `--indent` and `--xml-declaration`, for commands that write
+/// the XML document formats.
+pub fn xml_args() -> [Arg; 3] {
+    [
+        Arg::new("compact")
+            .long("compact")
+            .help("Write XML without whitespace between elements")
+            .action(ArgAction::SetTrue),
+        Arg::new("indent")
+            .long("indent")
+            .help("Spaces per nesting level in XML output")
+            .value_parser(clap::value_parser!(usize))
+            .default_value("2"),
+        Arg::new("xml-declaration")
+            .long("xml-declaration")
+            .help("XML declaration: full (with encoding), version (without encoding), or none")
+            .value_parser(["full", "version", "none"])
+            .default_value("full"),
+    ]
+}
+
+/// Collect `--compact`, `--indent` and `--xml-declaration` into XML layout
+/// options.
+pub fn xml_options(matches: &ArgMatches) -> commonmeta::XmlOptions {
+    let declaration = matches
+        .get_one::<String>("xml-declaration")
+        .map(String::as_str)
+        .unwrap_or("full");
+    let defaults = commonmeta::XmlOptions::default();
+    commonmeta::XmlOptions {
+        pretty: !matches.get_flag("compact"),
+        indent_size: matches.get_one::<usize>("indent").copied().unwrap_or(2),
+        declaration: declaration != "none",
+        encoding: if declaration == "full" { defaults.encoding.clone() } else { None },
+        ..defaults
+    }
+}
+
+/// Collect `--depositor`, `--email` and `--registrant` into a Crossref
+/// deposit head.
+pub fn deposit_head(matches: &ArgMatches) -> commonmeta::DepositHead {
+    let value = |name: &str| {
+        matches
+            .get_one::<String>(name)
+            .cloned()
+            .unwrap_or_default()
+    };
+    commonmeta::DepositHead {
+        depositor: value("depositor"),
+        email: value("email"),
+        registrant: value("registrant"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_exit_codes() {
+        assert_eq!(Failure::Partial("1 of 2 failed".to_string()).exit_code(), 1);
+        assert_eq!(Failure::InvalidInput("bad".to_string()).exit_code(), 2);
+        assert_eq!(Failure::Network("timeout".to_string()).exit_code(), 3);
+    }
+
+    #[test]
+    fn test_failure_from_library_error() {
+        let failure = Failure::from(commonmeta::Error::Http("404 Not Found".to_string()));
+        assert!(matches!(failure, Failure::Network(_)));
+        assert_eq!(failure.to_string(), "http error: 404 Not Found");
+
+        let failure = Failure::from(commonmeta::Error::Parse("expected value".to_string()));
+        assert!(matches!(failure, Failure::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_failure_from_message_is_invalid_input() {
+        // Messages are not inspected, even if they mention HTTP.
+        let failure = Failure::from("fetch: http error: 404".to_string());
+        assert!(matches!(failure, Failure::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_write_output_file_dash_is_stdout() {
+        write_output_file("-", b"").unwrap();
+        assert!(!std::path::Path::new("-").exists());
+    }
+
+    #[test]
+    fn test_command_failures() {
+        let m = fetch::command().get_matches_from(["fetch", "not-an-identifier"]);
+        assert_eq!(fetch::execute(&m).unwrap_err().exit_code(), 2);
+
+        let dir = std::env::temp_dir().join(format!("cm-failure-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ids.txt");
+        std::fs::write(&path, "10.53731/r79z0kh-97aq74v\nnot-an-id\n").unwrap();
+        let m = decode::command().get_matches_from(["decode", "--file", path.to_str().unwrap()]);
+        let failure = decode::execute(&m).unwrap_err();
+        std::fs::remove_dir_all(&dir).ok();
+        assert!(matches!(failure, Failure::Partial(_)), "{}", failure);
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgMatches, Command};
+
+use commonmeta::utils::validate_orcid;
+
+use crate::cmd::Failure;
+use crate::cmd::convert::{write_output, write_records};
+
+/// Build the orcid subcommand
+pub fn command() -> Command {
+    Command::new("orcid")
+        .about("Retrieve the works of an ORCID record")
+        .long_about(
+            "Retrieve the public works list of an ORCID record from the ORCID API \
+            and convert it. Each work summary becomes one record with its title, \
+            type, publication date, journal title and identifiers, and the ORCID \
+            holder as author; fetch a work by its DOI for its full metadata.\n\n\
+            Examples:\n\n\
+            commonmeta orcid 0000-0003-1419-2405 --to bibtex\n\
+            commonmeta orcid https://orcid.org/0000-0003-1419-2405 --to jsonl --file works.jsonl",
+        )
+        .arg(
+            Arg::new("orcid")
+                .help("ORCID ID or URL")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .help("Output format (any format supported by convert)")
+                .default_value("commonmeta"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .short('o')
+                .visible_alias("output")
+                .help("Write output to this file instead of stdout"),
+        )
+}
+
+/// Execute the orcid command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let orcid = matches.get_one::<String>("orcid").expect("required");
+    let to = matches.get_one::<String>("to").expect("has default");
+    let out_file = matches.get_one::<String>("file");
+
+    if validate_orcid(orcid).is_none() {
+        return Err(format!("orcid: '{}' is not a valid ORCID ID", orcid).into());
+    }
+    let list = commonmeta::read_list("orcid", orcid)?;
+    tracing::info!("found {} works", list.len());
+    let output = write_records(&list, to)?;
+    Ok(write_output(&output, to, out_file)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_invalid_orcid() {
+        for orcid in ["0000-0000-0000-0000", "10.5555/12345678"] {
+            let m = command().get_matches_from(["orcid", orcid]);
+            assert!(execute(&m).unwrap_err().to_string().contains("not a valid ORCID ID"));
+        }
+    }
+}
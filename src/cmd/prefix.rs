@@ -0,0 +1,136 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgMatches, Command};
+
+use commonmeta::doi_utils::{get_doi_ra_sync, validate_doi, validate_prefix};
+
+use crate::cmd::{Failure, output_arg, write_lines};
+
+/// Largest page both the Crossref and the DataCite API hand out.
+const MAX_ROWS: usize = 1000;
+
+/// Build the prefix subcommand
+pub fn command() -> Command {
+    Command::new("prefix")
+        .about("List the DOIs registered under a prefix")
+        .long_about(
+            "List every DOI registered under a DOI prefix, with its commonmeta type \
+            and registration date, as JSON Lines. The registration agency of the \
+            prefix is looked up via https://doi.org/ra/{prefix} unless given with \
+            --ra, and its API is paged through with a cursor until all DOIs (or \
+            --limit) have been listed. Only Crossref and DataCite prefixes are \
+            supported.\n\n\
+            Examples:\n\n\
+            commonmeta prefix 10.53731 --limit 1000\n\
+            commonmeta prefix 10.5281 --ra datacite --limit 100\n\
+            commonmeta prefix 10.59350 --output dois.jsonl.gz",
+        )
+        .arg(
+            Arg::new("prefix")
+                .help("DOI prefix, e.g. 10.53731")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("ra")
+                .long("ra")
+                .help("Registration agency of the prefix; looked up if omitted")
+                .value_parser(["crossref", "datacite"]),
+        )
+        .arg(
+            Arg::new("limit")
+                .long("limit")
+                .help("List at most this many DOIs (0 for all)")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("0"),
+        )
+        .arg(output_arg())
+}
+
+/// Execute the prefix command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let arg = matches.get_one::<String>("prefix").expect("required");
+    let prefix = validate_prefix(arg)
+        .filter(|_| validate_doi(arg).is_none())
+        .ok_or_else(|| format!("prefix: '{}' is not a DOI prefix", arg))?;
+    let limit = *matches.get_one::<usize>("limit").expect("has default");
+    let output = matches.get_one::<String>("output");
+
+    let agency = match matches.get_one::<String>("ra") {
+        Some(ra) => ra.clone(),
+        None => {
+            let ra = get_doi_ra_sync(&prefix).ok_or_else(|| {
+                Failure::Network(format!(
+                    "prefix: failed to look up the registration agency of {}",
+                    prefix
+                ))
+            })?;
+            match ra.as_str() {
+                "Crossref" | "DataCite" => ra.to_lowercase(),
+                _ => {
+                    return Err(format!(
+                        "prefix: {} is registered with {}; only Crossref and DataCite \
+                        prefixes are supported",
+                        prefix, ra
+                    )
+                    .into());
+                }
+            }
+        }
+    };
+    tracing::info!("Listing DOIs under {} from {}", prefix, agency);
+
+    let mut lines = Vec::new();
+    let mut count = 0;
+    let mut cursor: Option<String> = None;
+    loop {
+        let rows = match limit {
+            0 => MAX_ROWS,
+            limit => (limit - count).min(MAX_ROWS),
+        };
+        let (dois, next_cursor) =
+            commonmeta::fetch_prefix_dois(&agency, &prefix, cursor.as_deref(), rows)?;
+        for doi in dois.iter().take(rows) {
+            let line = serde_json::to_string(doi).map_err(|e| e.to_string())?;
+            // Without --output, DOIs are printed page by page as they arrive.
+            match output {
+                Some(_) => lines.push(line),
+                None => println!("{}", line),
+            }
+            count += 1;
+        }
+        tracing::debug!("{} DOIs so far", count);
+        cursor = next_cursor;
+        if cursor.is_none() || dois.is_empty() || (limit > 0 && count >= limit) {
+            break;
+        }
+    }
+    tracing::info!("Listed {} DOIs", count);
+
+    if output.is_some() {
+        write_lines(&lines, output)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_dois_and_garbage() {
+        for arg in ["10.53731/abc", "53731", "10.1"] {
+            let m = command().get_matches_from(["prefix", arg, "--ra", "crossref"]);
+            let err = execute(&m).unwrap_err().to_string();
+            assert!(err.contains("is not a DOI prefix"), "{}", arg);
+        }
+    }
+
+    #[test]
+    fn test_rejects_unknown_agency() {
+        let result = command().try_get_matches_from(["prefix", "10.53731", "--ra", "medra"]);
+        assert!(result.is_err());
+    }
+}
@@ -4,6 +4,7 @@ use std::time::Instant;
 use commonmeta::Data;
 
 use crate::cmd::list::load_list_from_file;
+use crate::cmd::read_input;
 
 pub fn command() -> Command {
     Command::new("push")
@@ -19,7 +20,7 @@ pub fn command() -> Command {
         )
         .arg(
             Arg::new("input")
-                .help("Input file path (JSON/JSONL array, or Parquet for --from commonmeta)")
+                .help("Input file path (JSON/JSONL array, or Parquet for --from commonmeta; - for stdin)")
                 .required(false)
                 .index(1),
         )
@@ -119,7 +120,11 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
 
     let result = match to {
         "inveniordm" => push_to_inveniordm(&data, matches),
-        "crossref_xml" | "datacite" => Err(format!(
+        "crossref_xml" => Err(
+            "push: --to crossref_xml is not supported; register with Crossref using `commonmeta register crossref`"
+                .to_string(),
+        ),
+        "datacite" => Err(format!(
             "push: --to {} is not yet implemented (registration is currently only supported with --to inveniordm)",
             to
         )),
@@ -127,7 +132,7 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
     };
 
     if show_timer {
-        eprintln!("Runtime: {:.2} seconds", timer.elapsed().as_secs_f64());
+        tracing::info!("Runtime: {:.2} seconds", timer.elapsed().as_secs_f64());
     }
 
     result
@@ -158,8 +163,7 @@ fn load_commonmeta_file(path: &str) -> Result<Vec<Data>, String> {
         return load_list_from_file(path, "commonmeta", None, 0);
     }
 
-    let content =
-        std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {}", path, e))?;
+    let content = read_input(path)?;
     let value: serde_json::Value =
         serde_json::from_str(&content).map_err(|e| format!("invalid JSON in '{}': {}", path, e))?;
 
@@ -3,6 +3,7 @@ use clap::{Arg, ArgAction, ArgMatches, Command};
 use commonmeta::Data;
 
 use crate::cmd::convert::detect_format;
+use crate::cmd::{Failure, read_input};
 
 pub fn command() -> Command {
     Command::new("put")
@@ -21,7 +22,7 @@ pub fn command() -> Command {
         )
         .arg(
             Arg::new("input")
-                .help("File path, DOI, or URL")
+                .help("File path (- for stdin), DOI, or URL")
                 .required(true)
                 .index(1),
         )
@@ -90,16 +91,15 @@ pub fn command() -> Command {
         )
 }
 
-pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
     let input_arg = matches.get_one::<String>("input").expect("required");
     let to = matches
         .get_one::<String>("to")
         .map(String::as_str)
         .unwrap_or("inveniordm");
 
-    let input = if std::path::Path::new(input_arg).exists() {
-        std::fs::read_to_string(input_arg)
-            .map_err(|e| format!("failed to read '{}': {}", input_arg, e))?
+    let input = if input_arg == "-" || std::path::Path::new(input_arg).exists() {
+        read_input(input_arg)?
     } else {
         input_arg.clone()
     };
@@ -109,16 +109,21 @@ pub fn execute(matches: &ArgMatches) -> Result<(), String> {
         None => detect_format(&input),
     };
 
-    let data = commonmeta::read(&via, &input).map_err(|e| e.to_string())?;
+    let data = commonmeta::read(&via, &input)?;
 
-    match to {
+    let result = match to {
         "inveniordm" => put_to_inveniordm(&data, matches),
-        "crossref_xml" | "datacite" => Err(format!(
+        "crossref_xml" => Err(
+            "put: --to crossref_xml is not supported; register with Crossref using `commonmeta register crossref`"
+                .to_string(),
+        ),
+        "datacite" => Err(format!(
             "put: --to {} is not yet implemented (registration is currently only supported with --to inveniordm)",
             to
         )),
         other => Err(format!("put: unsupported --to target: {}", other)),
-    }
+    };
+    Ok(result?)
 }
 
 fn put_to_inveniordm(data: &Data, matches: &ArgMatches) -> Result<(), String> {
@@ -156,7 +161,7 @@ mod tests {
 
         let matches =
             command().get_matches_from(vec!["put", path.to_str().unwrap(), "--from", "commonmeta"]);
-        let err = execute(&matches).unwrap_err();
+        let err = execute(&matches).unwrap_err().to_string();
         assert!(err.contains("requires --host"));
 
         std::fs::remove_dir_all(&dir).ok();
@@ -181,8 +186,8 @@ mod tests {
             "--to",
             "crossref_xml",
         ]);
-        let err = execute(&matches).unwrap_err();
-        assert!(err.contains("not yet implemented"));
+        let err = execute(&matches).unwrap_err().to_string();
+        assert!(err.contains("commonmeta register crossref"));
 
         std::fs::remove_dir_all(&dir).ok();
     }
@@ -206,7 +211,7 @@ mod tests {
             "--host",
             "example.invenio.host",
         ]);
-        let err = execute(&matches).unwrap_err();
+        let err = execute(&matches).unwrap_err().to_string();
         assert!(err.contains("requires --token"));
 
         std::fs::remove_dir_all(&dir).ok();
@@ -225,7 +230,7 @@ mod tests {
         // commonmeta::read with via="commonmeta" will fail to parse the
         // literal string as JSON, surfacing a parse error rather than a
         // missing-host error — confirms we got past the file-read branch.
-        let err = execute(&matches).unwrap_err();
+        let err = execute(&matches).unwrap_err().to_string();
         assert!(!err.contains("failed to read"));
     }
 }
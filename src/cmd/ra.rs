@@ -0,0 +1,130 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::collections::{BTreeMap, HashMap};
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use commonmeta::doi_utils::{get_doi_ra_sync, validate_prefix};
+
+use crate::cmd::list::identifier_entries;
+use crate::cmd::{Failure, read_input};
+
+/// Build the ra subcommand
+pub fn command() -> Command {
+    Command::new("ra")
+        .about("Look up the registration agency of DOIs")
+        .long_about(
+            "Look up the registration agency (Crossref, DataCite, mEDRA, JaLC, ...) \
+            of one or more DOIs via https://doi.org/ra/{prefix}. Each prefix is only \
+            looked up once, so long lists of DOIs sharing a few prefixes are fast.\n\n\
+            Examples:\n\n\
+            commonmeta ra 10.1038/nature12373\n\
+            commonmeta ra 10.1038/nature12373 10.5281/zenodo.5244404\n\
+            commonmeta ra --file dois.csv --summary\n\
+            (--file reads a plain-text or CSV file with one DOI per row, or a JSON array)",
+        )
+        .arg(
+            Arg::new("dois")
+                .help("DOIs or DOI prefixes to look up")
+                .num_args(0..)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .help("Read the DOIs to look up from this file (- for stdin)"),
+        )
+        .arg(
+            Arg::new("summary")
+                .long("summary")
+                .help("Print the number of DOIs per registration agency after the list")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Execute the ra command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let mut dois: Vec<String> = matches
+        .get_many::<String>("dois")
+        .map(|values| values.cloned().collect())
+        .unwrap_or_default();
+    if let Some(path) = matches.get_one::<String>("file") {
+        let content = read_input(path)?;
+        dois.extend(identifier_entries(&content)?);
+    }
+    if dois.is_empty() {
+        return Err(Failure::InvalidInput(
+            "ra: give one or more DOIs or --file".to_string(),
+        ));
+    }
+
+    let mut by_prefix: HashMap<String, Option<String>> = HashMap::new();
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut invalid = 0;
+    let mut unresolved = 0;
+    for doi in &dois {
+        let agency = match validate_prefix(doi) {
+            Some(prefix) => {
+                let agency = by_prefix
+                    .entry(prefix.clone())
+                    .or_insert_with(|| get_doi_ra_sync(&prefix))
+                    .clone();
+                agency.unwrap_or_else(|| {
+                    unresolved += 1;
+                    "unknown".to_string()
+                })
+            }
+            None => {
+                invalid += 1;
+                "invalid DOI".to_string()
+            }
+        };
+        println!("{}\t{}", doi, agency);
+        *counts.entry(agency).or_default() += 1;
+    }
+
+    if matches.get_flag("summary") {
+        println!();
+        for (agency, count) in &counts {
+            println!("{}\t{}", agency, count);
+        }
+    }
+
+    let message = format!(
+        "{} of {} DOI(s) invalid, {} without a registration agency",
+        invalid,
+        dois.len(),
+        unresolved
+    );
+    if invalid == dois.len() {
+        return Err(Failure::InvalidInput(message));
+    }
+    if invalid == 0 && unresolved == dois.len() {
+        return Err(Failure::Network(message));
+    }
+    if invalid > 0 || unresolved > 0 {
+        return Err(Failure::Partial(message));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_dois() {
+        let m = command().get_matches_from(["ra"]);
+        assert!(execute(&m).unwrap_err().to_string().contains("one or more DOIs"));
+    }
+
+    #[test]
+    fn test_invalid_doi_needs_no_lookup() {
+        let m = command().get_matches_from(["ra", "not-a-doi", "--summary"]);
+        let failure = execute(&m).unwrap_err();
+        assert!(matches!(failure, Failure::InvalidInput(_)));
+        assert_eq!(failure.to_string(), "1 of 1 DOI(s) invalid, 0 without a registration agency");
+    }
+}
@@ -0,0 +1,239 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::time::Duration;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use commonmeta::{Data, DataciteLogin, DepositLogin};
+
+use crate::cmd::convert::sniff_format;
+use crate::cmd::{Failure, deposit_args, deposit_head, read_input};
+
+/// Build the register subcommand
+pub fn command() -> Command {
+    Command::new("register")
+        .about("Register DOIs with a registration agency")
+        .long_about(
+            "Register the DOIs of scholarly metadata with Crossref or DataCite.\n\n\
+            crossref: convert the input to a Crossref deposit, submit it to the \
+            Crossref deposit endpoint and wait for the submission log to report \
+            which DOIs were registered. --test deposits with test.crossref.org.\n\n\
+            datacite: create or update each DOI with the DataCite REST API, as a \
+            draft, registered or findable DOI depending on --event. --username is \
+            the repository ID; --sandbox uses api.test.datacite.org.\n\n\
+            The results are printed as JSON; the exit status is 1 if any DOI \
+            failed (or Crossref hadn't finished before --timeout). This performs \
+            real, network-visible writes unless --test or --sandbox is given. The \
+            password can also be set with the CROSSREF_PASSWORD or \
+            DATACITE_PASSWORD environment variable.\n\n\
+            Examples:\n\n\
+            commonmeta register crossref record.json --username USER --password PASS \
+            --depositor \"Example Blog\" --email info@example.org\n\
+            commonmeta register crossref refs.bib --from bibtex --username USER --test \
+            --depositor \"Example Blog\" --email info@example.org\n\
+            commonmeta register datacite dataset.json --username DATACITE.EXAMPLE --event publish --sandbox",
+        )
+        .arg(
+            Arg::new("agency")
+                .help("Registration agency")
+                .required(true)
+                .value_parser(["crossref", "datacite"])
+                .index(1),
+        )
+        .arg(
+            Arg::new("input")
+                .help("File with the metadata to register (- for stdin)")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .short('f')
+                .help("Input format; auto-detected if omitted"),
+        )
+        .arg(
+            Arg::new("username")
+                .long("username")
+                .help("Crossref username, or DataCite repository ID")
+                .required(true),
+        )
+        .arg(
+            Arg::new("password")
+                .long("password")
+                .help("Password (default: $CROSSREF_PASSWORD or $DATACITE_PASSWORD)"),
+        )
+        .args(deposit_args())
+        .arg(
+            Arg::new("test")
+                .long("test")
+                .help("Deposit with the Crossref test system, without registering DOIs")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("event")
+                .long("event")
+                .help("DataCite state to move DOIs to")
+                .value_parser(["draft", "register", "publish"])
+                .default_value("draft"),
+        )
+        .arg(
+            Arg::new("sandbox")
+                .long("sandbox")
+                .help("Register with the DataCite test system at api.test.datacite.org")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("Seconds to wait for Crossref to process the deposit")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("300"),
+        )
+}
+
+/// Execute the register command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let agency = matches.get_one::<String>("agency").expect("required");
+    let username = matches.get_one::<String>("username").expect("required").clone();
+    let password_var = match agency.as_str() {
+        "crossref" => "CROSSREF_PASSWORD",
+        _ => "DATACITE_PASSWORD",
+    };
+    let password = match matches.get_one::<String>("password") {
+        Some(password) => password.clone(),
+        None => std::env::var(password_var).map_err(|_| {
+            Failure::InvalidInput(format!("register: give --password or set {}", password_var))
+        })?,
+    };
+
+    match agency.as_str() {
+        "crossref" => {
+            let login = DepositLogin {
+                username,
+                password,
+                test: matches.get_flag("test"),
+            };
+            register_crossref(matches, &login)
+        }
+        _ => {
+            let login = DataciteLogin {
+                repository_id: username,
+                password,
+                sandbox: matches.get_flag("sandbox"),
+            };
+            register_datacite(matches, &login)
+        }
+    }
+}
+
+/// Every record in the input file.
+fn read_records(matches: &ArgMatches) -> Result<Vec<Data>, String> {
+    let input_arg = matches.get_one::<String>("input").expect("required");
+    let input = read_input(input_arg)?;
+    let from = match matches.get_one::<String>("from") {
+        Some(from) => from.clone(),
+        None => sniff_format(&input)?,
+    };
+    tracing::debug!("reading input as {}", from);
+    commonmeta::read_list(&from, &input).map_err(|e| e.to_string())
+}
+
+fn register_crossref(matches: &ArgMatches, login: &DepositLogin) -> Result<(), Failure> {
+    let head = deposit_head(matches);
+    if head.depositor.is_empty() || head.email.is_empty() {
+        return Err(Failure::InvalidInput(
+            "register: Crossref deposits need --depositor and --email".to_string(),
+        ));
+    }
+    let list = read_records(matches)?;
+
+    let timeout = Duration::from_secs(*matches.get_one::<u64>("timeout").expect("has default"));
+    let result = commonmeta::register_crossref(&list, &head, login, timeout)?;
+    let output = serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?;
+    println!("{}", output);
+
+    if result.status != "completed" {
+        return Err(Failure::Partial(format!(
+            "register: Crossref is still processing {} ({}); check its submission log later",
+            result.file_name, result.status
+        )));
+    }
+    if result.failure_count > 0 {
+        return Err(Failure::Partial(format!(
+            "register: {} of {} DOIs failed to register",
+            result.failure_count, result.record_count
+        )));
+    }
+    Ok(())
+}
+
+fn register_datacite(matches: &ArgMatches, login: &DataciteLogin) -> Result<(), Failure> {
+    let event = matches.get_one::<String>("event").expect("has default");
+    let list = read_records(matches)?;
+
+    if let [data] = list.as_slice() {
+        let result = commonmeta::register_datacite(data, login, event)?;
+        let output = serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    // A batch keeps going past failed records and reports them at the end.
+    let mut results = Vec::with_capacity(list.len());
+    let mut failed = 0;
+    for data in &list {
+        match commonmeta::register_datacite(data, login, event) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                tracing::warn!("register: {}: {}", data.id, e);
+                failed += 1;
+            }
+        }
+    }
+    let output = serde_json::to_string_pretty(&results).map_err(|e| e.to_string())?;
+    println!("{}", output);
+    if failed > 0 {
+        return Err(Failure::Partial(format!(
+            "register: {} of {} DOIs failed to register",
+            failed,
+            list.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_depositor_and_email() {
+        let m = command().get_matches_from([
+            "register",
+            "crossref",
+            "record.json",
+            "--username",
+            "user",
+            "--password",
+            "secret",
+        ]);
+        let err = execute(&m).unwrap_err();
+        assert_eq!(err.exit_code(), 2);
+        assert!(err.to_string().contains("--depositor and --email"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_agency() {
+        let result = command().try_get_matches_from([
+            "register",
+            "medra",
+            "record.json",
+            "--username",
+            "user",
+        ]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use commonmeta::utils::{RorIdError, parse_ror};
+
+use crate::cmd::Failure;
+
+/// Build the ror subcommand
+pub fn command() -> Command {
+    Command::new("ror")
+        .about("Look up an organization in ROR")
+        .long_about(
+            "Look up an organization by its ROR ID with the ROR API and print its \
+            name, aliases and acronyms, country, types, website and relationships \
+            to other organizations as JSON. With --search, the argument is an \
+            organization name instead, and the best matches are printed with the \
+            score (0-1) the ROR affiliation API gave them.\n\n\
+            Examples:\n\n\
+            commonmeta ror 0342dzm54\n\
+            commonmeta ror https://ror.org/02nr0ka47\n\
+            commonmeta ror --search \"Leibniz Universität Hannover\" --limit 3",
+        )
+        .arg(
+            Arg::new("query")
+                .help("ROR ID, or organization name with --search")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("search")
+                .long("search")
+                .help("Search organizations by name instead of looking up an ID")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("limit")
+                .long("limit")
+                .help("Number of search results to print")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("5"),
+        )
+}
+
+/// Execute the ror command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let query = matches.get_one::<String>("query").expect("required");
+
+    let output = if matches.get_flag("search") {
+        let limit = *matches.get_one::<usize>("limit").expect("has default");
+        let mut results = commonmeta::search_ror_organizations(query)?;
+        results.truncate(limit);
+        tracing::info!("found {} organizations", results.len());
+        serde_json::to_string_pretty(&results)
+    } else {
+        match parse_ror(query) {
+            Ok(_) => {}
+            Err(RorIdError::Malformed(_)) => {
+                return Err(format!(
+                    "ror: '{}' is not a ROR ID; use --search to search by name",
+                    query
+                )
+                .into());
+            }
+            Err(e) => return Err(format!("ror: {}", e).into()),
+        }
+        let summary = commonmeta::fetch_ror_organization(query)?;
+        serde_json::to_string_pretty(&summary)
+    }
+    .map_err(|e| e.to_string())?;
+    println!("{}", output);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_needs_search() {
+        let m = command().get_matches_from(["ror", "Leibniz Universität Hannover"]);
+        assert!(execute(&m).unwrap_err().to_string().contains("use --search"));
+    }
+
+    #[test]
+    fn test_wrong_checksum() {
+        let m = command().get_matches_from(["ror", "https://ror.org/0342dzm55"]);
+        assert!(execute(&m).unwrap_err().to_string().contains("wrong checksum"));
+    }
+
+    #[test]
+    fn test_search_of_blank_name_is_empty() {
+        let m = command().get_matches_from(["ror", "  ", "--search"]);
+        assert!(execute(&m).is_ok());
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgMatches, Command};
+use url::Url;
+
+use commonmeta::{self, Data};
+
+use crate::cmd::list::fetch_datacite_url;
+use crate::cmd::{Failure, write_output_file};
+
+/// Crossref returns at most 100 works per `sample` request.
+const CROSSREF_MAX_SAMPLE: usize = 100;
+
+/// DataCite returns at most 1000 works per page, random or not.
+const DATACITE_MAX_SAMPLE: usize = 1000;
+
+/// Build the sample subcommand
+pub fn command() -> Command {
+    Command::new("sample")
+        .about("Fetch random records from Crossref or DataCite")
+        .long_about(
+            "Fetch a random sample of works from the Crossref or DataCite REST API and \
+            convert them, e.g. to test mappings against real-world metadata.\n\n\
+            Crossref samples at most 100 and DataCite at most 1000 works per call.\n\n\
+            Examples:\n\n\
+            commonmeta sample --provider crossref --number 10 --type journal-article\n\
+            commonmeta sample --provider crossref --member 340 --to csl\n\
+            commonmeta sample --provider datacite --type Dataset --client cern.zenodo\n\
+            commonmeta sample --provider datacite --number 100 --file sample.json",
+        )
+        .arg(
+            Arg::new("provider")
+                .long("provider")
+                .short('p')
+                .help("API to sample from")
+                .value_parser(["crossref", "datacite"])
+                .default_value("crossref"),
+        )
+        .arg(
+            Arg::new("number")
+                .long("number")
+                .short('n')
+                .help("Number of records to fetch")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("10"),
+        )
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .help("Crossref work type (e.g. journal-article) or DataCite resourceTypeGeneral (e.g. Dataset)"),
+        )
+        .arg(Arg::new("member").long("member").help("Crossref member ID"))
+        .arg(Arg::new("client").long("client").help("DataCite client ID"))
+        .arg(Arg::new("year").long("year").help("Publication year"))
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .help("Output format (any format supported by convert)")
+                .default_value("commonmeta"),
+        )
+        .arg(
+            Arg::new("style")
+                .long("style")
+                .help("CSL style name for --to citation output (default: apa)"),
+        )
+        .arg(
+            Arg::new("locale")
+                .long("locale")
+                .help("BCP 47 locale for --to citation output (e.g. de-DE)"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .help("Write output to file instead of stdout"),
+        )
+}
+
+/// Execute the sample command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let provider = matches.get_one::<String>("provider").expect("has default");
+    let number = *matches.get_one::<usize>("number").expect("has default");
+    let to = matches.get_one::<String>("to").expect("has default");
+    let style = matches.get_one::<String>("style").map(String::as_str);
+    let locale = matches.get_one::<String>("locale").map(String::as_str);
+    let value = |name: &str| {
+        matches
+            .get_one::<String>(name)
+            .map(String::as_str)
+            .unwrap_or("")
+    };
+
+    let data: Vec<Data> = match provider.as_str() {
+        "crossref" => commonmeta::crossref::fetch_all(
+            number.clamp(1, CROSSREF_MAX_SAMPLE),
+            1,
+            value("member"),
+            value("type"),
+            true,
+            value("year"),
+            "",
+            "",
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true,
+        )?,
+        _ => fetch_datacite_url(&datacite_sample_url(
+            number,
+            value("client"),
+            value("type"),
+            value("year"),
+        ))?,
+    };
+
+    let output = commonmeta::write_list_citation(&data, to, style, locale)?;
+    match matches.get_one::<String>("file") {
+        Some(path) => Ok(write_output_file(path, &output)?),
+        None => {
+            println!("{}", String::from_utf8_lossy(&output));
+            Ok(())
+        }
+    }
+}
+
+/// DataCite `/dois` query for `number` random works, optionally restricted
+/// to a client, resourceTypeGeneral and publication year.
+fn datacite_sample_url(number: usize, client: &str, type_: &str, year: &str) -> Url {
+    let mut url = Url::parse("https://api.datacite.org/dois").expect("hardcoded DataCite URL should parse");
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("random", "true");
+        query.append_pair("page[size]", &number.clamp(1, DATACITE_MAX_SAMPLE).to_string());
+        query.append_pair("affiliation", "true");
+        if !client.is_empty() {
+            query.append_pair("client-id", client);
+        }
+        let mut search_terms: Vec<String> = Vec::new();
+        if !type_.is_empty() {
+            search_terms.push(format!("types.resourceTypeGeneral:{}", type_));
+        }
+        if !year.is_empty() {
+            search_terms.push(format!("publicationYear:{}", year));
+        }
+        if !search_terms.is_empty() {
+            query.append_pair("query", &search_terms.join(" "));
+        }
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let m = command().get_matches_from(["sample"]);
+        assert_eq!(m.get_one::<String>("provider").unwrap(), "crossref");
+        assert_eq!(*m.get_one::<usize>("number").unwrap(), 10);
+        assert_eq!(m.get_one::<String>("to").unwrap(), "commonmeta");
+        assert!(command().try_get_matches_from(["sample", "--provider", "openalex"]).is_err());
+    }
+
+    #[test]
+    fn test_datacite_sample_url() {
+        let url = datacite_sample_url(5000, "cern.zenodo", "Dataset", "2024");
+        let query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(query.contains(&("random".to_string(), "true".to_string())));
+        assert!(query.contains(&("page[size]".to_string(), "1000".to_string())));
+        assert!(query.contains(&("client-id".to_string(), "cern.zenodo".to_string())));
+        assert!(query.contains(&(
+            "query".to_string(),
+            "types.resourceTypeGeneral:Dataset publicationYear:2024".to_string()
+        )));
+    }
+}
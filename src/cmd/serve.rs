@@ -0,0 +1,526 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{Receiver, TrySendError, sync_channel};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use clap::{Arg, ArgMatches, Command};
+use serde_json::{Value, json};
+
+use commonmeta::schema_utils;
+
+use crate::cmd::convert::{sniff_format, write_records};
+use crate::cmd::decode::decode_json;
+use crate::crockford::parse_number;
+use crate::doi_utils::{
+    MAX_SUFFIX_LENGTH, SuffixShape, encode_doi_number_with_shape, encode_dois_with_shape,
+    validate_prefix,
+};
+
+/// Largest request body accepted, e.g. a big Crossref deposit to convert.
+const MAX_BODY: usize = 50 * 1024 * 1024;
+
+/// Most DOIs a single encode request may ask for.
+const MAX_COUNT: usize = 10_000;
+
+/// Longest request line or header line accepted, in bytes.
+const MAX_LINE: usize = 8 * 1024;
+
+/// Most headers a request may send.
+const MAX_HEADERS: usize = 100;
+
+/// Connections handled at the same time.
+const WORKERS: usize = 16;
+
+/// Connections waiting for a worker; beyond that they are turned away with
+/// 503 Service Unavailable.
+const MAX_QUEUED: usize = 64;
+
+/// Build the serve subcommand
+pub fn command() -> Command {
+    Command::new("serve")
+        .about("Serve encode, decode, validate and convert over HTTP")
+        .long_about(
+            "Run an HTTP server exposing commonmeta to other services, so they \
+            don't need to shell out. Every endpoint answers with JSON, except \
+            convert, which returns the converted document; errors are JSON \
+            objects with an \"error\" field.\n\n\
+            GET  /                                   version\n\
+            GET  /encode?prefix=10.5555[&count=N][&number=N][&length=N][&split_every=N][&uppercase=true]\n\
+            GET  /decode?id=10.5555/nwbyp-29t86[&checksum=false]\n\
+            POST /validate[?schema=commonmeta]      document in the body\n\
+            POST /convert?to=csl[&from=crossref]    document in the body\n\
+            GET  /convert?id=10.5555/12345678&to=csl\n\n\
+            Examples:\n\n\
+            commonmeta serve --port 8080\n\
+            curl 'localhost:8080/encode?prefix=10.5555&count=3'\n\
+            curl --data-binary @record.json 'localhost:8080/convert?to=bibtex'",
+        )
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .short('p')
+                .help("Port to listen on")
+                .value_parser(clap::value_parser!(u16))
+                .default_value("8080"),
+        )
+        .arg(
+            Arg::new("host")
+                .long("host")
+                .help("Address to listen on (0.0.0.0 for all interfaces)")
+                .default_value("127.0.0.1"),
+        )
+}
+
+/// Execute the serve command
+pub fn execute(matches: &ArgMatches) -> Result<(), String> {
+    let host = matches.get_one::<String>("host").expect("has default");
+    let port = *matches.get_one::<u16>("port").expect("has default");
+    let listener = TcpListener::bind((host.as_str(), port))
+        .map_err(|e| format!("serve: cannot listen on {}:{}: {}", host, port, e))?;
+    tracing::info!("Listening on http://{}:{}", host, port);
+
+    let (sender, receiver) = sync_channel::<TcpStream>(MAX_QUEUED);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..WORKERS {
+        let receiver = Arc::clone(&receiver);
+        std::thread::spawn(move || worker(&receiver));
+    }
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => match sender.try_send(stream) {
+                Ok(()) => {}
+                Err(TrySendError::Full(mut stream)) => {
+                    let response = Response::error(503, "too many connections, try again later");
+                    write_response(&mut stream, &response).ok();
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    return Err("serve: all workers stopped".to_string());
+                }
+            },
+            Err(e) => tracing::warn!("serve: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Handle queued connections one at a time until the queue is closed.
+fn worker(receiver: &Mutex<Receiver<TcpStream>>) {
+    loop {
+        let stream = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        match stream {
+            Ok(stream) => handle_connection(stream),
+            Err(_) => return,
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn json(status: u16, value: Value) -> Self {
+        Response {
+            status,
+            content_type: "application/json",
+            body: value.to_string().into_bytes(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response::json(status, json!({ "error": message.into() }))
+    }
+}
+
+fn handle_connection(mut stream: TcpStream) {
+    stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => {
+            let response = route(&request);
+            tracing::info!("{} {} {}", request.method, request.path, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    if let Err(e) = write_response(&mut stream, &response) {
+        tracing::debug!("serve: {}", e);
+    }
+}
+
+/// Read one line of at most `MAX_LINE` bytes, answering `status` if it is
+/// longer.
+fn read_line(reader: &mut impl BufRead, status: u16) -> Result<String, Response> {
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_LINE as u64 + 1)
+        .read_line(&mut line)
+        .map_err(|e| Response::error(400, e.to_string()))?;
+    if line.len() > MAX_LINE {
+        return Err(Response::error(status, format!("lines are limited to {} bytes", MAX_LINE)));
+    }
+    Ok(line)
+}
+
+/// Parse an HTTP/1.1 request: request line, headers, and a body of
+/// `Content-Length` bytes. Chunked bodies aren't supported.
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let line = read_line(reader, 414)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    let mut content_length = 0;
+    let mut headers = 0;
+    loop {
+        let header = read_line(reader, 431)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Err(Response::error(431, format!("at most {} headers", MAX_HEADERS)));
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(Response::error(400, format!("malformed header: {}", header)));
+        };
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .trim()
+                .parse()
+                .map_err(|_| Response::error(400, "invalid Content-Length"))?;
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(411, "send the body with a Content-Length"));
+        }
+    }
+    if content_length > MAX_BODY {
+        return Err(Response::error(413, "request body too large"));
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| Response::error(400, e.to_string()))?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query,
+        body,
+    })
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> std::io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason,
+        response.content_type,
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+fn route(request: &Request) -> Response {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/") => Response::json(
+            200,
+            json!({ "name": "commonmeta", "version": env!("CARGO_PKG_VERSION") }),
+        ),
+        ("GET", "/encode") => encode(&request.query),
+        ("GET", "/decode") => decode(&request.query),
+        ("POST", "/validate") => validate(&request.query, &request.body),
+        ("GET" | "POST", "/convert") => convert(&request.query, &request.body),
+        (_, "/" | "/encode" | "/decode" | "/validate" | "/convert") => {
+            Response::error(405, format!("{} is not allowed on {}", request.method, request.path))
+        }
+        _ => Response::error(404, format!("no endpoint {}", request.path)),
+    }
+}
+
+/// A numeric query parameter, or its default when absent.
+fn number_param<T: std::str::FromStr>(
+    query: &HashMap<String, String>,
+    name: &str,
+    default: T,
+) -> Result<T, Response> {
+    match query.get(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| Response::error(400, format!("invalid {}: {}", name, value))),
+        None => Ok(default),
+    }
+}
+
+fn encode(query: &HashMap<String, String>) -> Response {
+    let Some(prefix) = query.get("prefix").and_then(|p| validate_prefix(p)) else {
+        return Response::error(400, "give a valid DOI prefix as ?prefix=");
+    };
+    let shape = SuffixShape::default();
+    let shape = match (
+        number_param(query, "length", shape.length),
+        number_param(query, "split_every", shape.split_every),
+    ) {
+        (Ok(length), Ok(split_every)) if (3..=MAX_SUFFIX_LENGTH).contains(&length) => SuffixShape {
+            length,
+            split_every,
+            uppercase: query.get("uppercase").is_some_and(|u| u == "true"),
+            ..shape
+        },
+        (Ok(_), Ok(_)) => {
+            let message = format!("length must be between 3 and {}", MAX_SUFFIX_LENGTH);
+            return Response::error(400, message);
+        }
+        (Err(response), _) | (_, Err(response)) => return response,
+    };
+
+    let dois = if query.contains_key("number") {
+        match parse_number(&query["number"]) {
+            Ok(number) => vec![encode_doi_number_with_shape(&prefix, number, &shape)],
+            Err(e) => return Response::error(400, e.to_string()),
+        }
+    } else {
+        let count = match number_param::<usize>(query, "count", 1) {
+            Ok(count) => count,
+            Err(response) => return response,
+        };
+        if count == 0 || count > MAX_COUNT {
+            return Response::error(400, format!("count must be between 1 and {}", MAX_COUNT));
+        }
+        if count as u64 > shape.capacity() {
+            let message = format!(
+                "count {} exceeds the {} distinct suffixes of length {}",
+                count,
+                shape.capacity(),
+                shape.length
+            );
+            return Response::error(400, message);
+        }
+        match encode_dois_with_shape(&prefix, count, &shape) {
+            Ok(dois) => dois,
+            Err(e) => return Response::error(400, e.to_string()),
+        }
+    };
+    Response::json(200, json!({ "dois": dois }))
+}
+
+fn decode(query: &HashMap<String, String>) -> Response {
+    let Some(id) = query.get("id") else {
+        return Response::error(400, "give the identifier to decode as ?id=");
+    };
+    let checksum = query.get("checksum").is_none_or(|c| c != "false");
+    let result = decode_json(id, checksum);
+    let status = if result.get("error").is_some() { 400 } else { 200 };
+    Response::json(status, result)
+}
+
+fn validate(query: &HashMap<String, String>, body: &[u8]) -> Response {
+    let schema = query
+        .get("schema")
+        .map(String::as_str)
+        .unwrap_or(schema_utils::DEFAULT_SCHEMA);
+    match schema_utils::json_schema_violations(body, Some(schema)) {
+        Ok(violations) => {
+            let errors: Vec<Value> = violations
+                .iter()
+                .map(|v| json!({ "pointer": v.pointer, "message": v.message }))
+                .collect();
+            Response::json(200, json!({ "valid": errors.is_empty(), "errors": errors }))
+        }
+        Err(e) => Response::error(400, e.to_string()),
+    }
+}
+
+fn convert(query: &HashMap<String, String>, body: &[u8]) -> Response {
+    let Some(to) = query.get("to") else {
+        return Response::error(400, "give the output format as ?to=");
+    };
+    let input = match query.get("id") {
+        Some(id) => id.clone(),
+        None => match String::from_utf8(body.to_vec()) {
+            Ok(input) if !input.trim().is_empty() => input,
+            Ok(_) => return Response::error(400, "send the document in the body, or ?id="),
+            Err(_) => return Response::error(400, "the body is not UTF-8"),
+        },
+    };
+    let from = match query.get("from") {
+        Some(from) => from.clone(),
+        None => match sniff_format(&input) {
+            Ok(from) => from,
+            Err(e) => return Response::error(400, e),
+        },
+    };
+
+    let output = commonmeta::read_list(&from, &input).and_then(|list| write_records(&list, to));
+    match output {
+        Ok(body) => Response {
+            status: 200,
+            content_type: content_type(to),
+            body,
+        },
+        Err(e @ commonmeta::Error::Http(_)) => Response::error(502, e.to_string()),
+        Err(e) => Response::error(400, e.to_string()),
+    }
+}
+
+/// Media type of documents written in format `to`.
+fn content_type(to: &str) -> &'static str {
+    match to {
+        "crossref_xml" | "datacite_xml" | "dublincore" | "openaire" | "jats" | "endnote" => {
+            "application/xml"
+        }
+        "bibtex" | "ris" | "citation" | "markdown" | "csv" => "text/plain; charset=utf-8",
+        "html" => "text/html; charset=utf-8",
+        _ => "application/json",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, target: &str, body: &str) -> Response {
+        let raw = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            target,
+            body.len(),
+            body
+        );
+        match read_request(&mut raw.as_bytes()) {
+            Ok(request) => route(&request),
+            Err(response) => response,
+        }
+    }
+
+    fn body(response: &Response) -> Value {
+        serde_json::from_slice(&response.body).unwrap()
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /convert?to=csl&from=bibtex%20x HTTP/1.1\r\nContent-Length: 4\r\n\r\nbody";
+        let request = read_request(&mut raw.as_bytes()).ok().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/convert");
+        assert_eq!(request.query["from"], "bibtex x");
+        assert_eq!(request.body, b"body");
+    }
+
+    #[test]
+    fn test_read_request_limits() {
+        let long_target = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE));
+        let response = read_request(&mut long_target.as_bytes()).err().unwrap();
+        assert_eq!(response.status, 414);
+
+        let long_header = format!("GET / HTTP/1.1\r\nX-Long: {}\r\n\r\n", "a".repeat(MAX_LINE));
+        let response = read_request(&mut long_header.as_bytes()).err().unwrap();
+        assert_eq!(response.status, 431);
+
+        let headers = "X-A: b\r\n".repeat(MAX_HEADERS + 1);
+        let many_headers = format!("GET / HTTP/1.1\r\n{}\r\n", headers);
+        let response = read_request(&mut many_headers.as_bytes()).err().unwrap();
+        assert_eq!(response.status, 431);
+        assert!(body(&response)["error"].as_str().unwrap().contains("headers"));
+    }
+
+    #[test]
+    fn test_encode_and_decode() {
+        let response = request("GET", "/encode?prefix=10.5555&count=3&split_every=4", "");
+        assert_eq!(response.status, 200);
+        let dois = body(&response)["dois"].as_array().unwrap().clone();
+        assert_eq!(dois.len(), 3);
+
+        let response = request("GET", "/encode?prefix=10.5555&number=12345&uppercase=true", "");
+        let doi = body(&response)["dois"][0].as_str().unwrap().to_string();
+        assert_eq!(doi, "https://doi.org/10.5555/00000-C1S20");
+        let response = request("GET", &format!("/decode?id={}", doi), "");
+        assert_eq!(response.status, 200);
+        assert_eq!(body(&response)["value"], 12345);
+
+        assert_eq!(request("GET", "/encode?prefix=nope", "").status, 400);
+        assert_eq!(request("GET", "/encode?prefix=10.5555&count=0", "").status, 400);
+        let response = request("GET", "/encode?prefix=10.5555&count=2000&length=4", "");
+        assert_eq!(response.status, 400);
+        assert_eq!(
+            body(&response)["error"],
+            "count 2000 exceeds the 1024 distinct suffixes of length 4"
+        );
+        let response = request("GET", "/encode?prefix=10.5555&number=-5", "");
+        assert_eq!(response.status, 400);
+        assert!(body(&response)["error"].as_str().unwrap().contains("out of range"));
+        assert_eq!(request("GET", "/decode?id=10.5555/zzzzz-zzzz0", "").status, 400);
+    }
+
+    #[test]
+    fn test_validate() {
+        let response = request("POST", "/validate?schema=csl", r#"[{"id":"a","type":"article"}]"#);
+        assert_eq!(response.status, 200);
+        assert_eq!(body(&response)["valid"], true);
+
+        let response = request("POST", "/validate", r#"{"type": 42}"#);
+        assert_eq!(body(&response)["valid"], false);
+        assert!(!body(&response)["errors"].as_array().unwrap().is_empty());
+
+        assert_eq!(request("POST", "/validate", "{").status, 400);
+    }
+
+    #[test]
+    fn test_convert() {
+        let bibtex =
+            "@article{a, title={A Title}, author={Doe, Jane}, year={2024}, doi={10.5555/12345678}}";
+        let response = request("POST", "/convert?from=bibtex&to=csl", bibtex);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.content_type, "application/json");
+        assert_eq!(body(&response)["title"], "A Title");
+
+        assert_eq!(request("POST", "/convert?from=bibtex", bibtex).status, 400);
+        assert_eq!(request("POST", "/convert?to=csl", "").status, 400);
+    }
+
+    #[test]
+    fn test_unknown_routes() {
+        assert_eq!(request("GET", "/nope", "").status, 404);
+        assert_eq!(request("DELETE", "/encode", "").status, 405);
+        assert_eq!(request("GET", "/", "").status, 200);
+    }
+}
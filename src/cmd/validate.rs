@@ -0,0 +1,260 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use commonmeta::schema_utils::{self, SchemaViolation};
+use commonmeta::utils::{ValidationReport, validate_batch};
+
+use crate::cmd::{Failure, read_input};
+use crate::cmd::list::identifier_entries;
+use crate::diagnostic::{self, Location, Stream};
+
+/// Build the validate subcommand
+pub fn command() -> Command {
+    Command::new("validate")
+        .about("Validate documents against a bundled JSON Schema")
+        .long_about(
+            "Validate one or more JSON documents against a bundled JSON Schema \
+            (commonmeta by default) and print every violation with the JSON \
+            pointer of the offending value, its line and column, and the source \
+            line with the value underlined. Exits with status 1 if any document \
+            is invalid, so it can gate deposits in CI pipelines.\n\n\
+            With --ids, validate a list of identifiers instead (one per line, \
+            the id column of a CSV file, or a JSON array): print how many are \
+            valid per type, every invalid entry with the reason (e.g. a wrong \
+            check digit), and the identifiers listed more than once in any \
+            spelling. Exits with status 1 if any entry is invalid.\n\n\
+            Examples:\n\n\
+            commonmeta validate record.json\n\
+            commonmeta validate records/*.json\n\
+            commonmeta validate csl.json --schema csl\n\
+            commonmeta validate --ids identifiers.txt\n\
+            commonmeta validate --ids works.csv --json",
+        )
+        .arg(
+            Arg::new("files")
+                .help("JSON documents to validate (- for stdin)")
+                .required_unless_present("ids")
+                .num_args(1..)
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("schema")
+                .long("schema")
+                .help("Schema to validate against")
+                .value_parser(schema_utils::known_schemata().to_vec())
+                .default_value(schema_utils::DEFAULT_SCHEMA),
+        )
+        .arg(
+            Arg::new("ids")
+                .long("ids")
+                .help("Validate the identifiers listed in this file instead (- for stdin)")
+                .conflicts_with("files"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Print the --ids report as JSON")
+                .requires("ids")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Execute the validate command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    if let Some(path) = matches.get_one::<String>("ids") {
+        return validate_ids(path, matches.get_flag("json"));
+    }
+    let schema = matches.get_one::<String>("schema").expect("has default");
+    let files: Vec<&String> = matches.get_many::<String>("files").expect("required").collect();
+
+    let mut invalid = 0;
+    for file in &files {
+        let source = read_input(file)?;
+        let violations = match schema_utils::json_schema_violations(source.as_bytes(), Some(schema)) {
+            Ok(violations) => violations,
+            Err(e) => {
+                // Unparseable input counts as invalid rather than stopping
+                // the run, so every file still gets checked.
+                let message = e.to_string();
+                match diagnostic::locate_parse_error(&source, &message) {
+                    Some(location) => {
+                        println!("{}:{}:{}: {}", file, location.line, location.column, message);
+                        println!("{}", diagnostic::snippet(&source, &location, Stream::Stdout));
+                    }
+                    None => println!("{}: {}", file, message),
+                }
+                invalid += 1;
+                continue;
+            }
+        };
+        if violations.is_empty() {
+            println!("{}: valid", file);
+        } else {
+            invalid += 1;
+            for violation in &violations {
+                let location = diagnostic::locate_pointer(&source, &violation.pointer);
+                println!("{}", format_violation(file, violation, location.as_ref()));
+                if let Some(location) = &location {
+                    println!("{}", diagnostic::snippet(&source, location, Stream::Stdout));
+                }
+            }
+        }
+    }
+
+    if invalid > 0 {
+        return Err(Failure::Partial(format!(
+            "{} of {} document(s) failed {} schema validation",
+            invalid,
+            files.len(),
+            schema
+        )));
+    }
+    Ok(())
+}
+
+/// Validate the identifiers listed in `path` and print the report.
+fn validate_ids(path: &str, json: bool) -> Result<(), Failure> {
+    let content = read_input(path)?;
+    let report = validate_batch(identifier_entries(&content)?.into_iter());
+    if json {
+        let output = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        println!("{}", output);
+    } else {
+        for line in format_report(&report) {
+            println!("{}", line);
+        }
+    }
+
+    if !report.invalid.is_empty() {
+        return Err(Failure::Partial(format!(
+            "{} of {} identifier(s) invalid",
+            report.invalid.len(),
+            report.total
+        )));
+    }
+    Ok(())
+}
+
+/// A summary line, the count per type, then one line per invalid entry and
+/// per duplicated identifier.
+fn format_report(report: &ValidationReport) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{} identifier(s): {} valid, {} invalid, {} duplicated",
+        report.total,
+        report.valid,
+        report.invalid.len(),
+        report.duplicates.len()
+    )];
+    lines.extend(report.counts.iter().map(|(type_, count)| format!("  {}: {}", type_, count)));
+    lines.extend(
+        report
+            .invalid
+            .iter()
+            .map(|e| format!("entry {}: {:?}: {}", e.position, e.entry, e.reason)),
+    );
+    lines.extend(report.duplicates.iter().map(|d| {
+        let positions: Vec<String> = d.positions.iter().map(|p| p.to_string()).collect();
+        format!("duplicate: {} (entries {})", d.identifier, positions.join(", "))
+    }));
+    lines
+}
+
+/// `file:line:column: /pointer: message`, or `file: /pointer: message` when
+/// the value can't be located, with `/` standing for the document root.
+fn format_violation(file: &str, violation: &SchemaViolation, location: Option<&Location>) -> String {
+    let pointer = if violation.pointer.is_empty() {
+        "/"
+    } else {
+        &violation.pointer
+    };
+    match location {
+        Some(location) => format!(
+            "{}:{}:{}: {}: {}",
+            file, location.line, location.column, pointer, violation.message
+        ),
+        None => format!("{}: {}: {}", file, pointer, violation.message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_document() {
+        let m = command().get_matches_from([
+            "validate",
+            "tests/fixtures/commonmeta/journal_article.json",
+        ]);
+        assert!(execute(&m).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_document_fails() {
+        let path = std::env::temp_dir().join(format!("commonmeta_validate_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"id":"https://doi.org/10.5555/1","type":42}"#).unwrap();
+        let m = command().get_matches_from(["validate", path.to_str().unwrap()]);
+        let err = execute(&m).unwrap_err();
+        assert_eq!(err.exit_code(), 1);
+        assert!(err.to_string().contains("1 of 1 document(s) failed"), "got: {err}");
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_format_violation_root_pointer() {
+        let violation = SchemaViolation {
+            pointer: String::new(),
+            message: "\"title\" is a required property".to_string(),
+        };
+        assert_eq!(
+            format_violation("a.json", &violation, None),
+            "a.json: /: \"title\" is a required property"
+        );
+    }
+
+    #[test]
+    fn test_format_violation_with_location() {
+        let violation = SchemaViolation {
+            pointer: "/type".to_string(),
+            message: "42 is not of type \"string\"".to_string(),
+        };
+        let location = Location { line: 3, column: 11, width: 2 };
+        assert_eq!(
+            format_violation("a.json", &violation, Some(&location)),
+            "a.json:3:11: /type: 42 is not of type \"string\""
+        );
+    }
+
+    #[test]
+    fn test_format_report() {
+        let ids = ["10.5555/12345678", "not an identifier", "https://doi.org/10.5555/12345678"];
+        let report = validate_batch(ids.iter().map(|id| id.to_string()));
+        assert_eq!(
+            format_report(&report),
+            vec![
+                "3 identifier(s): 2 valid, 1 invalid, 1 duplicated",
+                "  DOI: 2",
+                "entry 2: \"not an identifier\": identifier not recognized",
+                "duplicate: https://doi.org/10.5555/12345678 (entries 1, 3)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ids_and_files_conflict() {
+        assert!(command()
+            .try_get_matches_from(["validate", "a.json", "--ids", "ids.txt"])
+            .is_err());
+        assert!(command().try_get_matches_from(["validate", "--json"]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unknown_schema() {
+        assert!(command()
+            .try_get_matches_from(["validate", "a.json", "--schema", "nope"])
+            .is_err());
+    }
+}
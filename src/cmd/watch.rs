@@ -0,0 +1,313 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::cmd::Failure;
+use crate::cmd::convert::{sniff_format, write_output, write_records};
+use crate::cmd::list::output_extension;
+
+/// Build the watch subcommand
+pub fn command() -> Command {
+    Command::new("watch")
+        .about("Convert files dropped into a directory")
+        .long_about(
+            "Watch a directory and convert every new or changed file into the \
+            output directory, as <name>.<extension of --to>. A file is converted \
+            once it has stopped changing for one --interval, so files still being \
+            written are left alone. Files already converted (whose output is newer \
+            than the input) are skipped at startup; hidden files and files ending \
+            in .tmp, .part or ~ are ignored.\n\n\
+            Examples:\n\n\
+            commonmeta watch incoming --from crossref --to commonmeta --output converted\n\
+            commonmeta watch incoming --to crossref_xml --output deposits --interval 10\n\
+            commonmeta watch incoming --to csl --output converted --once",
+        )
+        .arg(
+            Arg::new("dir")
+                .help("Directory to watch")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .short('f')
+                .help("Input format; auto-detected per file if omitted"),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .short('t')
+                .help("Output format")
+                .default_value("commonmeta"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .help("Directory to write converted files to")
+                .required(true),
+        )
+        .arg(
+            Arg::new("interval")
+                .long("interval")
+                .help("Seconds between checks for new or changed files")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .default_value("2"),
+        )
+        .arg(
+            Arg::new("once")
+                .long("once")
+                .help("Convert what's there and exit instead of watching")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+/// Execute the watch command
+pub fn execute(matches: &ArgMatches) -> Result<(), Failure> {
+    let dir = Path::new(matches.get_one::<String>("dir").expect("required"));
+    let output = Path::new(matches.get_one::<String>("output").expect("required"));
+    let from = matches.get_one::<String>("from").map(String::as_str);
+    let to = matches.get_one::<String>("to").expect("has default");
+    let interval = Duration::from_secs(*matches.get_one::<u64>("interval").expect("has default"));
+
+    if !dir.is_dir() {
+        return Err(Failure::InvalidInput(format!(
+            "watch: '{}' is not a directory",
+            dir.display()
+        )));
+    }
+    std::fs::create_dir_all(output)
+        .map_err(|e| format!("watch: failed to create '{}': {}", output.display(), e))?;
+
+    let mut watcher = Watcher::default();
+    if matches.get_flag("once") {
+        let mut paths: Vec<PathBuf> = scan(dir)?.into_keys().collect();
+        paths.sort();
+        let mut failed = 0;
+        for path in &paths {
+            if let Err(e) = convert_file(path, output, from, to) {
+                eprintln!("watch: {}: {}", path.display(), e);
+                failed += 1;
+            }
+        }
+        if failed > 0 {
+            return Err(Failure::Partial(format!(
+                "watch: {} of {} file(s) failed to convert",
+                failed,
+                paths.len()
+            )));
+        }
+        return Ok(());
+    }
+
+    tracing::info!("Watching {} for files to convert to {}", dir.display(), to);
+    loop {
+        for path in watcher.ready(scan(dir)?) {
+            // One bad file doesn't stop the watch.
+            if let Err(e) = convert_file(&path, output, from, to) {
+                tracing::warn!("watch: {}: {}", path.display(), e);
+            }
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// Modification time and size of a file, to tell when it changed.
+type FileState = (SystemTime, u64);
+
+/// Tracks the files of a directory between checks. A file is ready once
+/// two consecutive checks see the same new state, i.e. it has stopped
+/// changing.
+#[derive(Default)]
+struct Watcher {
+    converted: HashMap<PathBuf, FileState>,
+    pending: HashMap<PathBuf, FileState>,
+}
+
+impl Watcher {
+    /// Files of `files` that are new or changed and have settled since the
+    /// last check.
+    fn ready(&mut self, files: HashMap<PathBuf, FileState>) -> Vec<PathBuf> {
+        let mut ready = Vec::new();
+        for (path, state) in files {
+            if self.converted.get(&path) == Some(&state) {
+                continue;
+            }
+            if self.pending.get(&path) == Some(&state) {
+                self.pending.remove(&path);
+                self.converted.insert(path.clone(), state);
+                ready.push(path);
+            } else {
+                self.pending.insert(path, state);
+            }
+        }
+        ready.sort();
+        ready
+    }
+}
+
+/// The files directly in `dir` that may be converted, with their state.
+fn scan(dir: &Path) -> Result<HashMap<PathBuf, FileState>, String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("watch: failed to read '{}': {}", dir.display(), e))?;
+    let mut files = HashMap::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if name.starts_with('.')
+            || name.ends_with('~')
+            || name.ends_with(".tmp")
+            || name.ends_with(".part")
+        {
+            continue;
+        }
+        if let Ok(metadata) = entry.metadata()
+            && metadata.is_file()
+        {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.insert(path, (modified, metadata.len()));
+        }
+    }
+    Ok(files)
+}
+
+/// Path of the converted `input` in `output`.
+fn output_path(input: &Path, output: &Path, to: &str) -> PathBuf {
+    let stem = input
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    output.join(format!("{}.{}", stem, output_extension(to)))
+}
+
+/// Convert `input` into `output`, unless it already was.
+fn convert_file(input: &Path, output: &Path, from: Option<&str>, to: &str) -> Result<(), String> {
+    let target = output_path(input, output, to);
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    if let (Some(input_time), Some(target_time)) = (modified(input), modified(&target))
+        && target_time >= input_time
+    {
+        tracing::debug!("{} is up to date", target.display());
+        return Ok(());
+    }
+
+    std::fs::read_to_string(input)
+        .map_err(|e| e.to_string())
+        .and_then(|content| {
+            let from = match from {
+                Some(from) => from.to_string(),
+                None => sniff_format(&content)?,
+            };
+            let list = commonmeta::read_list(&from, &content).map_err(|e| e.to_string())?;
+            write_records(&list, to).map_err(|e| e.to_string())
+        })
+        .and_then(|bytes| write_output(&bytes, to, Some(&target.to_string_lossy().to_string())))?;
+    tracing::info!("{} -> {}", input.display(), target.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_are_ready_once_settled() {
+        let mut watcher = Watcher::default();
+        let path = PathBuf::from("a.json");
+        let state = |size| HashMap::from([(path.clone(), (SystemTime::UNIX_EPOCH, size))]);
+
+        assert!(watcher.ready(state(1)).is_empty());
+        // Still being written.
+        assert!(watcher.ready(state(2)).is_empty());
+        assert_eq!(watcher.ready(state(2)), vec![path.clone()]);
+        assert!(watcher.ready(state(2)).is_empty());
+        // Changed again.
+        assert!(watcher.ready(state(3)).is_empty());
+        assert_eq!(watcher.ready(state(3)), vec![path.clone()]);
+    }
+
+    #[test]
+    fn test_output_path() {
+        let target = output_path(Path::new("in/record.bib"), Path::new("out"), "crossref_xml");
+        assert_eq!(target, PathBuf::from("out/record.xml"));
+    }
+
+    #[test]
+    fn test_once_converts_and_skips_up_to_date() {
+        let dir =
+            std::env::temp_dir().join(format!("commonmeta_watch_test_{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let input = dir.join("in");
+        let output = dir.join("out");
+        std::fs::create_dir_all(&input).unwrap();
+        std::fs::write(
+            input.join("a.bib"),
+            "@article{a, title={A Title}, author={Doe, Jane}, year={2024}, doi={10.5555/1}}",
+        )
+        .unwrap();
+        std::fs::write(input.join(".hidden.bib"), "ignored").unwrap();
+
+        let m = command().get_matches_from([
+            "watch",
+            input.to_str().unwrap(),
+            "--to",
+            "csl",
+            "--output",
+            output.to_str().unwrap(),
+            "--once",
+        ]);
+        execute(&m).unwrap();
+        let converted = std::fs::read_to_string(output.join("a.json")).unwrap();
+        assert!(converted.contains("A Title"));
+        assert!(!output.join(".hidden.json").exists());
+
+        std::fs::write(output.join("a.json"), "kept").unwrap();
+        execute(&m).unwrap();
+        assert_eq!(std::fs::read_to_string(output.join("a.json")).unwrap(), "kept");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_once_reports_failed_files() {
+        let dir =
+            std::env::temp_dir().join(format!("commonmeta_watch_fail_{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        let input = dir.join("in");
+        let output = dir.join("out");
+        std::fs::create_dir_all(&input).unwrap();
+        std::fs::write(
+            input.join("a.bib"),
+            "@article{a, title={A Title}, author={Doe, Jane}, year={2024}, doi={10.5555/1}}",
+        )
+        .unwrap();
+        std::fs::write(input.join("b.json"), "{ not json").unwrap();
+
+        let m = command().get_matches_from([
+            "watch",
+            input.to_str().unwrap(),
+            "--from",
+            "bibtex",
+            "--to",
+            "csl",
+            "--output",
+            output.to_str().unwrap(),
+            "--once",
+        ]);
+        let failure = execute(&m).unwrap_err();
+        assert!(matches!(failure, Failure::Partial(_)));
+        assert_eq!(failure.to_string(), "watch: 1 of 2 file(s) failed to convert");
+        assert!(output.join("a.json").exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -409,6 +409,101 @@ pub fn cm_to_ris(cm: &str) -> &'static str {
     }
 }
 
+/// Dublin Core `dc:type` → Commonmeta work type. Accepts the DCMI Type
+/// Vocabulary and the `info:eu-repo/semantics/` publication types used by
+/// OAI-PMH repositories (without that prefix).
+pub fn dcmi_to_cm(dcmi: &str) -> &'static str {
+    match dcmi {
+        "article" | "contributionToPeriodical" => "JournalArticle",
+        "bachelorThesis" | "doctoralThesis" | "masterThesis" => "Dissertation",
+        "book" => "Book",
+        "bookPart" => "BookChapter",
+        "conferenceObject" | "conferencePaper" => "ProceedingsArticle",
+        "conferenceProceedings" => "Proceedings",
+        "lecture" | "conferencePoster" | "conferenceContribution" => "Presentation",
+        "patent" => "Patent",
+        "preprint" | "submittedVersion" => "Article",
+        "report" | "technicalDocumentation" | "workingPaper" => "Report",
+        "review" | "bookReview" => "Review",
+        "annotation" | "other" => "Other",
+        "Collection" => "Collection",
+        "Dataset" => "Dataset",
+        "Event" => "Event",
+        "Image" | "StillImage" => "Image",
+        "InteractiveResource" => "InteractiveResource",
+        "MovingImage" => "Audiovisual",
+        "PhysicalObject" => "PhysicalObject",
+        "Service" => "Service",
+        "Software" => "Software",
+        "Sound" => "Sound",
+        "Text" => "Document",
+        _ => "",
+    }
+}
+
+/// Commonmeta work type → DCMI Type Vocabulary term.
+pub fn cm_to_dcmi(cm: &str) -> &'static str {
+    match cm {
+        "Audiovisual" => "MovingImage",
+        "Collection" => "Collection",
+        "Dataset" => "Dataset",
+        "Event" | "Presentation" => "Event",
+        "Figure" | "Image" | "Map" => "StillImage",
+        "InteractiveResource" => "InteractiveResource",
+        "PhysicalObject" => "PhysicalObject",
+        "Service" => "Service",
+        "ComputationalNotebook" | "Software" => "Software",
+        "Sound" => "Sound",
+        _ => "Text",
+    }
+}
+
+/// Commonmeta work type → `info:eu-repo/semantics/` publication type
+/// (without that prefix), or `""` when the vocabulary has no match.
+pub fn cm_to_eurepo(cm: &str) -> &'static str {
+    match cm {
+        "Article" => "preprint",
+        "Book" => "book",
+        "BookChapter" => "bookPart",
+        "Dissertation" => "doctoralThesis",
+        "JournalArticle" => "article",
+        "Patent" => "patent",
+        "Presentation" => "lecture",
+        "Proceedings" => "conferenceProceedings",
+        "ProceedingsArticle" => "conferenceObject",
+        "Report" => "report",
+        "Review" => "review",
+        _ => "",
+    }
+}
+
+/// EndNote XML `ref-type` name → Commonmeta work type.
+pub fn endnote_to_cm(endnote: &str) -> &'static str {
+    match endnote {
+        "Journal Article" => "JournalArticle",
+        "Electronic Article" | "Magazine Article" | "Newspaper Article" => "Article",
+        "Book" | "Edited Book" | "Electronic Book" => "Book",
+        "Book Section" | "Electronic Book Section" => "BookChapter",
+        "Conference Paper" | "Conference Proceedings" => "ProceedingsArticle",
+        "Thesis" => "Dissertation",
+        "Report" | "Government Document" => "Report",
+        "Dataset" => "Dataset",
+        "Computer Program" => "Software",
+        "Web Page" => "WebPage",
+        "Blog" => "BlogPost",
+        "Patent" => "Patent",
+        "Manuscript" | "Unpublished Work" => "Manuscript",
+        "Standard" => "Standard",
+        "Figure" => "Figure",
+        "Map" => "Map",
+        "Audiovisual Material" | "Film or Broadcast" => "Audiovisual",
+        "Dictionary" | "Encyclopedia" => "Entry",
+        "Personal Communication" => "PersonalCommunication",
+        "Serial" => "Journal",
+        _ => "Other",
+    }
+}
+
 /// Schema.org `@type` → Commonmeta work type.
 pub fn so_to_cm(so: &str) -> &'static str {
     match so {
@@ -461,6 +556,32 @@ pub fn cm_to_so(cm: &str) -> &'static str {
     }
 }
 
+/// ORCID work type → Commonmeta work type.
+pub fn orcid_to_cm(orcid: &str) -> &'static str {
+    match orcid {
+        "artistic-performance" => "Event",
+        "book" | "edited-book" | "manual" => "Book",
+        "book-chapter" => "BookChapter",
+        "book-review" | "magazine-article" | "newsletter-article" | "newspaper-article"
+        | "preprint" | "review" | "working-paper" => "Article",
+        "conference-abstract" | "conference-paper" => "ProceedingsArticle",
+        "conference-poster" => "Poster",
+        "data-management-plan" => "Document",
+        "data-set" => "Dataset",
+        "dictionary-entry" | "encyclopedia-entry" => "Entry",
+        "dissertation-thesis" => "Dissertation",
+        "journal-article" => "JournalArticle",
+        "journal-issue" => "JournalIssue",
+        "lecture-speech" => "Presentation",
+        "online-resource" | "website" => "WebPage",
+        "physical-object" => "PhysicalObject",
+        "report" => "Report",
+        "research-tool" | "software" => "Software",
+        "standards-and-policy" | "technical-standard" => "Standard",
+        _ => "Other",
+    }
+}
+
 /// OpenAlex work type → Commonmeta work type.
 pub fn oa_to_cm(oa: &str) -> &'static str {
     match oa {
@@ -2,12 +2,18 @@
 //! This encoder/decoder:
 //! - uses Douglas Crockford Base32 encoding: https://www.crockford.com/base32.html
 //! - is based on: https://github.com/front-matter/base32-url
-//! - allows for ISO 7064 checksum
+//! - allows for ISO 7064 checksums or a Crockford check symbol, see
+//!   [`Checksum`]
 //! - encodes the checksum using only characters in the base32 set
 //! - produces string that are URI-friendly (no '=' or '/' for instance)
+//! - encodes with other alphabets as well (RFC 4648 base32, base36, base58),
+//!   with the same padding, splitting and checksum, see [`Alphabet`]
 
-use rand::RngExt;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngExt};
+use std::collections::HashSet;
 use std::fmt;
+use std::num::IntErrorKind;
 
 // NO i, l, o or u
 const ENCODING_CHARS: &str = "0123456789abcdefghjkmnpqrstvwxyz";
@@ -16,7 +22,25 @@ const ENCODING_CHARS: &str = "0123456789abcdefghjkmnpqrstvwxyz";
 pub enum CrockfordError {
     InvalidCharacter(char),
     InvalidChecksum(String, u8),
+    /// A single check character that doesn't match, see [`Checksum`].
+    InvalidCheckSymbol(String, char),
     InvalidChecksumFormat(String),
+    /// The string encodes a number larger than `u128::MAX`.
+    Overflow(String),
+    /// A length to generate that leaves no room for a digit next to the
+    /// check characters: the length, and the minimum.
+    InvalidLength(usize, usize),
+    /// More distinct strings asked of [`generate_batch`] than there are of
+    /// the length: the number asked for, and the number there are.
+    CapacityExceeded(usize, u128),
+    /// Every string [`generate_filtered`] drew contained a blocked word:
+    /// the number of draws.
+    AllBlocked(usize),
+    /// A number to encode that is negative or larger than `u128::MAX`, see
+    /// [`parse_number`].
+    NumberOutOfRange(String),
+    /// A number to encode that isn't a decimal integer.
+    InvalidNumber(String),
 }
 
 impl fmt::Display for CrockfordError {
@@ -26,141 +50,778 @@ impl fmt::Display for CrockfordError {
             CrockfordError::InvalidChecksum(s, cs) => {
                 write!(f, "wrong checksum {:02} for identifier {}", cs, s)
             }
+            CrockfordError::InvalidCheckSymbol(s, c) => {
+                write!(f, "wrong check character {} for identifier {}", c, s)
+            }
             CrockfordError::InvalidChecksumFormat(s) => write!(f, "invalid checksum: {}", s),
+            CrockfordError::Overflow(s) => write!(f, "identifier {} is too long to decode", s),
+            CrockfordError::InvalidLength(length, min) => {
+                write!(f, "invalid length {}: must be at least {}", length, min)
+            }
+            CrockfordError::CapacityExceeded(asked, capacity) => write!(
+                f,
+                "cannot generate {} distinct identifiers, only {} of this length",
+                asked, capacity
+            ),
+            CrockfordError::AllBlocked(draws) => {
+                write!(f, "no identifier without a blocked word in {} tries", draws)
+            }
+            CrockfordError::NumberOutOfRange(s) => {
+                write!(f, "number {} out of range: must be between 0 and {}", s, u128::MAX)
+            }
+            CrockfordError::InvalidNumber(s) => write!(f, "invalid number: {}", s),
         }
     }
 }
 
 impl std::error::Error for CrockfordError {}
 
-/// Encode a number to a URI-friendly Douglas Crockford base32 string.
-/// optionally split with '-' every n characters, pad with zeros to a minimum length,
-/// and append a checksum using modulo 97-10 (ISO 7064).
-pub fn encode(number: i64, split_every: usize, mut length: usize, checksum: bool) -> String {
-    let original_number = number;
-    let mut encoded = if number == 0 {
-        "0".to_string()
-    } else {
-        let mut num = number;
-        let mut result = String::new();
-        while num > 0 {
-            let remainder = (num % 32) as usize;
-            num /= 32;
-            result.insert(0, ENCODING_CHARS.chars().nth(remainder).unwrap());
-        }
-        result
-    };
+// ── Alphabets ─────────────────────────────────────────────────────────────────
+
+/// The digits numbers are encoded with, e.g. [`Crockford`] base32. The
+/// base is the number of digits; the first one stands for zero and pads
+/// encoded strings to a minimum length.
+pub trait Alphabet {
+    /// The digits, from zero up.
+    fn digits(&self) -> &'static str;
 
-    if checksum && length > 2 {
-        length -= 2;
+    /// Brings an encoded string into the spelling of [`Alphabet::digits`]
+    /// before decoding, e.g. folds its case. The default only removes the
+    /// separators '-', '_' and '.'.
+    fn normalize(&self, encoded: &str) -> String {
+        encoded.replace(['-', '_', '.'], "")
     }
+}
+
+/// Douglas Crockford base32: lowercase, without i, l, o and u, and reading
+/// I and L as 1 and O as 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Crockford;
 
-    if length > 0 && encoded.len() < length {
-        encoded = "0".repeat(length - encoded.len()) + &encoded;
+impl Alphabet for Crockford {
+    fn digits(&self) -> &'static str {
+        ENCODING_CHARS
     }
 
-    if checksum {
-        let computed_checksum = generate_checksum(original_number);
-        encoded.push_str(&format!("{:02}", computed_checksum));
+    fn normalize(&self, encoded: &str) -> String {
+        normalize(encoded)
     }
+}
 
-    if split_every > 0 {
-        let mut result = String::new();
-        let mut i = 0;
+/// Douglas Crockford base32 in uppercase, as many registries display it.
+/// Decodes both cases, like [`Crockford`], which also reads uppercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CrockfordUpper;
 
-        while i < encoded.len() {
-            let end = std::cmp::min(i + split_every, encoded.len());
-            if !result.is_empty() {
-                result.push('-');
-            }
-            result.push_str(&encoded[i..end]);
-            i = end;
-        }
+impl Alphabet for CrockfordUpper {
+    fn digits(&self) -> &'static str {
+        "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+    }
+
+    fn normalize(&self, encoded: &str) -> String {
+        normalize(encoded).to_uppercase()
+    }
+}
 
-        encoded = result;
+/// RFC 4648 base32: A-Z and 2-7, uppercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Base32;
+
+impl Alphabet for Base32 {
+    fn digits(&self) -> &'static str {
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567"
     }
 
-    encoded
+    fn normalize(&self, encoded: &str) -> String {
+        encoded.to_uppercase().replace(['-', '_', '.'], "")
+    }
 }
 
-/// Generate a random Crockford base32 string.
-/// optionally split with '-' every n characters, pad with zeros to a minimum length,
-/// and append a checksum using modulo 97-10 (ISO 7064).
-pub fn generate(mut length: usize, split_every: usize, checksum: bool) -> String {
-    if checksum && length < 3 {
-        panic!("Invalid 'length'. Must be >= 3 if checksum enabled.");
+/// Base36: 0-9 and a-z, lowercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Base36;
+
+impl Alphabet for Base36 {
+    fn digits(&self) -> &'static str {
+        "0123456789abcdefghijklmnopqrstuvwxyz"
     }
 
-    // fixes number size, otherwise decoding checksum check will fail
-    if checksum {
-        length -= 2;
+    fn normalize(&self, encoded: &str) -> String {
+        encoded.to_lowercase().replace(['-', '_', '.'], "")
     }
+}
 
-    // generate a random number between 0 and 32^length
-    let n = (32_f64).powi(length as i32);
-    let number = rand::rng().random_range(0..n.min(i64::MAX as f64) as i64);
+/// Base58 as used by Bitcoin: case-sensitive, without 0, O, I and l.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Base58;
 
-    encode(number, split_every, length, checksum)
+impl Alphabet for Base58 {
+    fn digits(&self) -> &'static str {
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+    }
 }
 
-/// Decode a URI-friendly Douglas Crockford base32 string to a number.
-pub fn decode(str: &str, checksum: bool) -> Result<i64, CrockfordError> {
-    let normalized = normalize(str);
+// ── Crockford base32 ──────────────────────────────────────────────────────────
 
-    let (encoded, cs) = if checksum {
-        if normalized.len() < 2 {
-            return Err(CrockfordError::InvalidChecksumFormat(normalized.clone()));
-        }
+/// Encode a number to a URI-friendly Douglas Crockford base32 string.
+/// optionally split with '-' every n characters (or into the groups and
+/// with the separator of a [`Grouping`]), pad with zeros to a minimum length,
+/// and append a checksum using modulo 97-10 (ISO 7064), or the check
+/// characters of another [`Checksum`].
+/// Any `u128` fits into 26 characters, UUID-scale numbers included; there
+/// is no negative or out-of-range input, callers parse into a `u128` and
+/// report what doesn't fit (see [`parse_number`]).
+#[deprecated(note = "use `EncodeOptions` and `EncodeOptions::encode`")]
+pub fn encode(
+    number: u128,
+    grouping: impl Into<Grouping>,
+    length: usize,
+    checksum: impl Into<Checksum>,
+) -> String {
+    EncodeOptions::new().grouping(grouping).length(length).checksum(checksum).encode(number)
+}
 
-        // checksum is the last two characters
-        let cs_str = &normalized[normalized.len() - 2..];
-        match cs_str.parse::<u8>() {
-            Ok(cs) => (&normalized[..normalized.len() - 2], Some(cs)),
-            Err(_) => return Err(CrockfordError::InvalidChecksumFormat(cs_str.to_string())),
+/// Parse a decimal number to encode, failing with
+/// [`CrockfordError::NumberOutOfRange`] for negative numbers and numbers
+/// above `u128::MAX` rather than the bare "invalid digit" of `str::parse`.
+/// A negative zero such as `-0` is 0.
+pub fn parse_number(str: &str) -> Result<u128, CrockfordError> {
+    let trimmed = str.trim();
+    let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+    let is_integer = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+    if is_integer && digits.chars().all(|c| c == '0') {
+        return Ok(0);
+    }
+    trimmed.parse::<u128>().map_err(|e| {
+        if *e.kind() == IntErrorKind::PosOverflow || (is_integer && trimmed.starts_with('-')) {
+            CrockfordError::NumberOutOfRange(trimmed.to_string())
+        } else {
+            CrockfordError::InvalidNumber(trimmed.to_string())
         }
-    } else {
-        (&normalized[..], None)
-    };
+    })
+}
 
-    let mut number: i64 = 0;
-    for c in encoded.chars() {
-        number *= 32;
-        match ENCODING_CHARS.find(c) {
-            Some(pos) => number += pos as i64,
-            None => return Err(CrockfordError::InvalidCharacter(c)),
+/// Split an encoded string with `separator` every n characters, or leave it
+/// as is if n is 0. `normalize` removes the separators '-', '_' and '.'
+/// again before decoding.
+pub fn split(encoded: &str, split_every: usize, separator: char) -> String {
+    Grouping::every(split_every).with_separator(separator).apply(encoded)
+}
+
+/// How an encoded string is split into groups: the sizes of the groups from
+/// the start, the last size repeating for the rest, and the separator
+/// between them. A size of 0 takes the rest of the string. A `usize`
+/// converts to a split every n characters with '-', what the `split_every`
+/// arguments of this module always did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grouping {
+    pub sizes: Vec<usize>,
+    pub separator: char,
+}
+
+impl Grouping {
+    /// No splitting.
+    pub fn none() -> Self {
+        Grouping { sizes: vec![], separator: '-' }
+    }
+
+    /// Groups of `n` characters, or no splitting if `n` is 0.
+    pub fn every(n: usize) -> Self {
+        Grouping { sizes: vec![n], separator: '-' }
+    }
+
+    /// Groups of the given sizes, e.g. `[4, 4, 2]` for `abcd-efgh-12`.
+    pub fn pattern(sizes: &[usize]) -> Self {
+        Grouping { sizes: sizes.to_vec(), separator: '-' }
+    }
+
+    /// The same groups with another separator, e.g. ' ' or '/'.
+    pub fn with_separator(self, separator: char) -> Self {
+        Grouping { separator, ..self }
+    }
+
+    /// Split `encoded` into the groups.
+    pub fn apply(&self, encoded: &str) -> String {
+        let chars: Vec<char> = encoded.chars().collect();
+        let last = self.sizes.last().copied().unwrap_or(0);
+        let mut sizes = self.sizes.iter().copied().chain(std::iter::repeat(last));
+        let mut groups: Vec<String> = Vec::new();
+        let mut start = 0;
+        while start < chars.len() {
+            let end = match sizes.next().unwrap_or(0) {
+                0 => chars.len(),
+                size => (start + size).min(chars.len()),
+            };
+            groups.push(chars[start..end].iter().collect());
+            start = end;
         }
+        groups.join(&self.separator.to_string())
     }
 
-    if let Some(cs) = cs
-        && !validate(number, cs as i64)
-    {
-        return Err(CrockfordError::InvalidChecksum(str.to_string(), cs));
+    /// Remove the separator from `encoded` again. [`decode`] and
+    /// [`normalize`] only know the separators '-', '_' and '.', see
+    /// [`normalize_with`] for others.
+    pub fn strip(&self, encoded: &str) -> String {
+        encoded.replace(self.separator, "")
     }
+}
 
-    Ok(number)
+impl Default for Grouping {
+    fn default() -> Self {
+        Grouping::none()
+    }
+}
+
+impl From<usize> for Grouping {
+    fn from(split_every: usize) -> Self {
+        Grouping::every(split_every)
+    }
+}
+
+/// Generate a random Crockford base32 string.
+/// optionally split with '-' every n characters, pad with zeros to a minimum length,
+/// and append a checksum using modulo 97-10 (ISO 7064), or the check
+/// characters of another [`Checksum`].
+///
+/// `length` counts the check characters but not the separators, and must
+/// leave room for at least one digit: at least 3 with the default mod 97-10
+/// checksum, 2 with a single check character and 1 without. Shorter lengths
+/// fail with [`CrockfordError::InvalidLength`]. A `grouping` of 0 leaves
+/// the string unsplit.
+pub fn generate(
+    length: usize,
+    grouping: impl Into<Grouping>,
+    checksum: impl Into<Checksum>,
+) -> Result<String, CrockfordError> {
+    generate_with(&Crockford, length, grouping, checksum)
+}
+
+/// Words that should not show up in generated identifiers, which end up in
+/// public DOIs and citations. Crockford base32 already leaves out 'u' to
+/// avoid most accidental obscenities; see `contains_blocked` for digits
+/// read as letters.
+pub const BLOCKLIST: &[&str] = &[
+    "anal", "anus", "arse", "ass", "bitch", "boob", "cock", "crap", "cum", "damn", "dick",
+    "dyke", "fag", "jizz", "kkk", "nazi", "nigg", "penis", "piss", "porn", "rape", "sex",
+    "shit", "slag", "slut", "tit", "twat", "wank", "whore",
+];
+
+/// Generate a random Crockford base32 string as `generate` does, but
+/// generate a new one as long as it contains a word from `blocklist`.
+/// Fails with [`CrockfordError::AllBlocked`] after 1000 blocked strings.
+pub fn generate_filtered(
+    length: usize,
+    grouping: impl Into<Grouping>,
+    checksum: impl Into<Checksum>,
+    blocklist: &[&str],
+) -> Result<String, CrockfordError> {
+    generate_filtered_with(&Crockford, length, grouping, checksum, blocklist)
+}
+
+/// Generate a random string in the digits of `alphabet` as
+/// [`generate_filtered`] does with Crockford base32.
+pub fn generate_filtered_with(
+    alphabet: &dyn Alphabet,
+    length: usize,
+    grouping: impl Into<Grouping>,
+    checksum: impl Into<Checksum>,
+    blocklist: &[&str],
+) -> Result<String, CrockfordError> {
+    let (grouping, checksum) = (grouping.into(), checksum.into());
+    for _ in 0..MIN_DRAWS {
+        let generated = generate_with(alphabet, length, grouping.clone(), checksum)?;
+        if !contains_blocked(&generated, blocklist) {
+            return Ok(generated);
+        }
+    }
+    Err(CrockfordError::AllBlocked(MIN_DRAWS))
+}
+
+/// Whether `encoded` contains a word from `blocklist`, ignoring case and
+/// separators, and also when digits are read as the letters they resemble
+/// (0 as o, 1 as i or l, 3 as e, 4 as a, 5 as s, 7 as t).
+pub fn contains_blocked(encoded: &str, blocklist: &[&str]) -> bool {
+    let normalized = normalize(encoded);
+    let as_letters = |one: char| -> String {
+        normalized
+            .chars()
+            .map(|c| match c {
+                '0' => 'o',
+                '1' => one,
+                '3' => 'e',
+                '4' => 'a',
+                '5' => 's',
+                '7' => 't',
+                c => c,
+            })
+            .collect()
+    };
+    let readings = [normalized.clone(), as_letters('i'), as_letters('l')];
+    blocklist.iter().any(|word| {
+        let word = word.to_lowercase();
+        !word.is_empty() && readings.iter().any(|reading| reading.contains(&word))
+    })
+}
+
+/// Decode a URI-friendly Douglas Crockford base32 string to a number.
+/// Fails rather than wrapping around for strings encoding more than a
+/// `u128` holds.
+pub fn decode(str: &str, checksum: impl Into<Checksum>) -> Result<u128, CrockfordError> {
+    decode_with(&Crockford, str, checksum)
+}
+
+/// Decode a Crockford base32 string that may or may not end with a mod
+/// 97-10 checksum: with the checksum if it verifies, otherwise as a plain
+/// number. Returns the number and the checksum that matched,
+/// [`Checksum::None`] for the plain reading. One in 97 plain strings ends
+/// in digits that happen to verify and is read with a checksum.
+pub fn decode_detect(str: &str) -> Result<(u128, Checksum), CrockfordError> {
+    decode_detect_with(&Crockford, str, &[Checksum::Mod97_10])
 }
 
 /// Normalize returns a normalized encoded string for base32 encoding.
 pub fn normalize(str: &str) -> String {
     str.to_string()
         .to_lowercase()
-        .replace("-", "")
+        .replace(['-', '_', '.'], "")
         .replace("i", "1")
         .replace("l", "1")
         .replace("o", "0")
 }
 
+/// Normalize an encoded string split by `grouping`, removing its separator
+/// on top of the ones [`normalize`] removes, e.g. the spaces of
+/// `0000 0C1S 20`. The result can be passed to [`decode`].
+pub fn normalize_with(str: &str, grouping: &Grouping) -> String {
+    normalize(&grouping.strip(str))
+}
+
 /// Validate returns true if the encoded string is a valid base32 string with checksum.
-pub fn validate(number: i64, checksum: i64) -> bool {
+pub fn validate(number: u128, checksum: u8) -> bool {
     checksum == generate_checksum(number)
 }
 
 /// GenerateChecksum returns the checksum for a number using ISO 7064 (mod 97-10).
-pub fn generate_checksum(number: i64) -> i64 {
-    97 - ((100 * number) % 97) + 1
+pub fn generate_checksum(number: u128) -> u8 {
+    crate::checksums::compute_mod97_10(&number.to_string())
+}
+
+// ── Checksums ─────────────────────────────────────────────────────────────────
+
+/// Crockford's check symbols: the 32 digits and `*~$=u` for the remainders
+/// 32 to 36 of the number divided by 37.
+const CHECK_SYMBOLS: &str = "0123456789abcdefghjkmnpqrstvwxyz*~$=u";
+
+/// The check characters appended to an encoded number. `true` and `false`
+/// convert to `Mod97_10` and `None`, the checksums this module always had.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Checksum {
+    /// No check characters.
+    None,
+    /// Two decimal digits, the ISO 7064 mod 97-10 checksum of the number.
+    #[default]
+    Mod97_10,
+    /// One of Crockford's check symbols (0-9, a-z without i, l, o, and
+    /// `*~$=u`): the number modulo 37, uppercase after uppercase digits.
+    CrockfordSymbol,
+    /// One character (0-9 or a-z), the ISO 7064 mod 37-36 check character of
+    /// the encoded digits.
+    Mod37_36,
+}
+
+impl From<bool> for Checksum {
+    fn from(checksum: bool) -> Self {
+        if checksum { Checksum::Mod97_10 } else { Checksum::None }
+    }
+}
+
+impl Checksum {
+    /// The number of check characters.
+    pub fn width(&self) -> usize {
+        match self {
+            Checksum::None => 0,
+            Checksum::Mod97_10 => 2,
+            Checksum::CrockfordSymbol | Checksum::Mod37_36 => 1,
+        }
+    }
+
+    /// The check characters of `number`, which is `encoded` in some alphabet
+    /// without separators.
+    fn compute(&self, number: u128, encoded: &str) -> String {
+        match self {
+            Checksum::None => String::new(),
+            Checksum::Mod97_10 => format!("{:02}", generate_checksum(number)),
+            Checksum::CrockfordSymbol => {
+                let symbol = CHECK_SYMBOLS.as_bytes()[(number % 37) as usize] as char;
+                if encoded.chars().any(|c| c.is_ascii_uppercase()) {
+                    symbol.to_ascii_uppercase().to_string()
+                } else {
+                    symbol.to_string()
+                }
+            }
+            Checksum::Mod37_36 => {
+                let check = crate::checksums::compute_mod37_36(encoded);
+                if encoded.chars().any(|c| c.is_ascii_uppercase()) {
+                    check.to_string()
+                } else {
+                    check.to_ascii_lowercase().to_string()
+                }
+            }
+        }
+    }
+
+    /// Verifies the check characters `check` of `number`, `encoded` in
+    /// `alphabet`.
+    fn verify(
+        &self,
+        alphabet: &dyn Alphabet,
+        number: u128,
+        encoded: &str,
+        check: &str,
+        original: &str,
+    ) -> Result<(), CrockfordError> {
+        match self {
+            Checksum::None => Ok(()),
+            Checksum::Mod97_10 => {
+                let check = alphabet.normalize(check);
+                let cs = check
+                    .parse::<u8>()
+                    .map_err(|_| CrockfordError::InvalidChecksumFormat(check.clone()))?;
+                if !validate(number, cs) {
+                    return Err(CrockfordError::InvalidChecksum(original.to_string(), cs));
+                }
+                Ok(())
+            }
+            Checksum::CrockfordSymbol | Checksum::Mod37_36 => {
+                // Crockford's decoding rules hold for the check symbol, too:
+                // i and l read as 1, o as 0.
+                let found = match self {
+                    Checksum::CrockfordSymbol => normalize(check),
+                    _ => check.to_lowercase(),
+                };
+                if found != self.compute(number, encoded).to_lowercase() {
+                    let symbol = check.chars().next().unwrap_or_default();
+                    return Err(CrockfordError::InvalidCheckSymbol(original.to_string(), symbol));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// ── Any alphabet ──────────────────────────────────────────────────────────────
+
+/// How [`EncodeOptions::encode`] encodes a number, set with named setters
+/// instead of the positional arguments of [`encode`]: the digits of an
+/// [`Alphabet`], the [`Grouping`], the minimum length (including the check
+/// characters, padded with the zero digit) and the [`Checksum`]. The
+/// default is Crockford base32, unsplit and unpadded, with the mod 97-10
+/// checksum; `EncodeOptions::new().length(10).split_every(5).encode(12345)`
+/// gives `00000-c1s20`.
+#[derive(Clone)]
+pub struct EncodeOptions<'a> {
+    alphabet: &'a dyn Alphabet,
+    grouping: Grouping,
+    length: usize,
+    checksum: Checksum,
+}
+
+impl Default for EncodeOptions<'_> {
+    fn default() -> Self {
+        EncodeOptions {
+            alphabet: &Crockford,
+            grouping: Grouping::none(),
+            length: 0,
+            checksum: Checksum::Mod97_10,
+        }
+    }
+}
+
+impl<'a> EncodeOptions<'a> {
+    /// The default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode with the digits of `alphabet`, e.g. [`CrockfordUpper`].
+    pub fn alphabet(self, alphabet: &'a dyn Alphabet) -> Self {
+        EncodeOptions { alphabet, ..self }
+    }
+
+    /// Split into the groups of `grouping`; a `usize` splits every n
+    /// characters with '-'.
+    pub fn grouping(self, grouping: impl Into<Grouping>) -> Self {
+        EncodeOptions { grouping: grouping.into(), ..self }
+    }
+
+    /// Split every `n` characters, or not at all if `n` is 0, keeping the
+    /// separator.
+    pub fn split_every(self, n: usize) -> Self {
+        let grouping = Grouping::every(n).with_separator(self.grouping.separator);
+        EncodeOptions { grouping, ..self }
+    }
+
+    /// Split with `separator` instead of '-'.
+    pub fn separator(self, separator: char) -> Self {
+        let grouping = self.grouping.clone().with_separator(separator);
+        EncodeOptions { grouping, ..self }
+    }
+
+    /// Pad to at least `length` characters, including the check characters.
+    pub fn length(self, length: usize) -> Self {
+        EncodeOptions { length, ..self }
+    }
+
+    /// Append the check characters of `checksum`; `true` and `false` mean
+    /// mod 97-10 and none.
+    pub fn checksum(self, checksum: impl Into<Checksum>) -> Self {
+        EncodeOptions { checksum: checksum.into(), ..self }
+    }
+
+    /// Encode `number`.
+    pub fn encode(&self, number: u128) -> String {
+        let digits: Vec<char> = self.alphabet.digits().chars().collect();
+        let base = digits.len() as u128;
+        let mut encoded = Vec::new();
+        let mut num = number;
+        loop {
+            encoded.push(digits[(num % base) as usize]);
+            num /= base;
+            if num == 0 {
+                break;
+            }
+        }
+
+        let mut length = self.length;
+        let width = self.checksum.width();
+        if width > 0 && length > width {
+            length -= width;
+        }
+        if encoded.len() < length {
+            encoded.resize(length, digits[0]);
+        }
+        let mut encoded: String = encoded.into_iter().rev().collect();
+        let check = self.checksum.compute(number, &encoded);
+        encoded.push_str(&check);
+
+        self.grouping.apply(&encoded)
+    }
+}
+
+/// Encode a number with the digits of `alphabet`, as [`encode`] does with
+/// Crockford base32: split into the groups of `grouping`, padded with the
+/// zero digit to a minimum length (including the check characters), with
+/// check characters as `checksum` asks.
+#[deprecated(note = "use `EncodeOptions::new().alphabet(alphabet)` and `EncodeOptions::encode`")]
+pub fn encode_with(
+    alphabet: &dyn Alphabet,
+    number: u128,
+    grouping: impl Into<Grouping>,
+    length: usize,
+    checksum: impl Into<Checksum>,
+) -> String {
+    EncodeOptions { alphabet, grouping: grouping.into(), length, checksum: checksum.into() }
+        .encode(number)
+}
+
+/// Generate a random string of `length` characters (including the check
+/// characters) in the digits of `alphabet`, as [`generate`] does with
+/// Crockford base32, and with the same limits.
+pub fn generate_with(
+    alphabet: &dyn Alphabet,
+    length: usize,
+    grouping: impl Into<Grouping>,
+    checksum: impl Into<Checksum>,
+) -> Result<String, CrockfordError> {
+    generate_with_rng(&mut rand::rng(), alphabet, length, grouping, checksum)
+}
+
+/// Generate a random string as [`generate_with`] does, drawing the number
+/// from `rng` instead of the thread-local generator, so that a seeded `rng`
+/// (e.g. `StdRng::seed_from_u64`) generates the same strings every run.
+pub fn generate_with_rng<R: Rng + ?Sized>(
+    rng: &mut R,
+    alphabet: &dyn Alphabet,
+    length: usize,
+    grouping: impl Into<Grouping>,
+    checksum: impl Into<Checksum>,
+) -> Result<String, CrockfordError> {
+    let checksum = checksum.into();
+    let width = checksum.width();
+    if length <= width {
+        return Err(CrockfordError::InvalidLength(length, width + 1));
+    }
+
+    // fixes number size, otherwise decoding checksum check will fail
+    let digits = length - width;
+
+    // generate a random number between 0 and base^digits, or any u128 if
+    // base^digits doesn't fit
+    let base = alphabet.digits().chars().count() as u128;
+    let number = match u32::try_from(digits).ok().and_then(|d| base.checked_pow(d)) {
+        Some(n) => rng.random_range(0..n),
+        None => rng.random(),
+    };
+
+    // encode pads to `length` including the checksum, so every generated
+    // string has the same length
+    let options = EncodeOptions { alphabet, grouping: grouping.into(), length, checksum };
+    Ok(options.encode(number))
+}
+
+/// Decode a string in the digits of `alphabet`, trying each of `checksums`
+/// in turn and then no checksum, as [`decode_detect`] does. Fails with the
+/// error of the plain reading if none matches.
+pub fn decode_detect_with(
+    alphabet: &dyn Alphabet,
+    str: &str,
+    checksums: &[Checksum],
+) -> Result<(u128, Checksum), CrockfordError> {
+    for checksum in checksums.iter().filter(|c| **c != Checksum::None) {
+        if let Ok(number) = decode_with(alphabet, str, *checksum) {
+            return Ok((number, *checksum));
+        }
+    }
+    decode_with(alphabet, str, Checksum::None).map(|number| (number, Checksum::None))
+}
+
+/// What [`generate_batch`] generates: strings of `length` characters
+/// (including the check characters) in the digits of `alphabet`, split
+/// into the groups of `grouping`, that contain no word of `blocklist`. The
+/// default gives Crockford base32 strings like `nwbyp-29t86`.
+#[derive(Clone)]
+pub struct GenerateOptions<'a> {
+    pub alphabet: &'a dyn Alphabet,
+    pub length: usize,
+    pub grouping: Grouping,
+    pub checksum: Checksum,
+    pub blocklist: &'a [&'a str],
+}
+
+impl Default for GenerateOptions<'_> {
+    fn default() -> Self {
+        GenerateOptions {
+            alphabet: &Crockford,
+            length: 10,
+            grouping: Grouping::every(5),
+            checksum: Checksum::Mod97_10,
+            blocklist: &[],
+        }
+    }
+}
+
+/// How many random numbers [`generate_batch`] draws per string asked for,
+/// and at least, before it gives up on a blocklist that blocks too much.
+const MAX_DRAWS_PER_STRING: usize = 100;
+const MIN_DRAWS: usize = 1000;
+
+/// Generate `n` distinct random strings as `options` describe, in random
+/// order. Fails if there are fewer than `n` strings of the length, or if
+/// too many of them contain a blocked word.
+pub fn generate_batch(n: usize, options: &GenerateOptions) -> Result<Vec<String>, CrockfordError> {
+    generate_batch_with_rng(&mut rand::rng(), n, options)
+}
+
+/// Generate a batch as [`generate_batch`] does, drawing from `rng`.
+pub fn generate_batch_with_rng<R: Rng + ?Sized>(
+    rng: &mut R,
+    n: usize,
+    options: &GenerateOptions,
+) -> Result<Vec<String>, CrockfordError> {
+    let width = options.checksum.width();
+    if options.length <= width {
+        return Err(CrockfordError::InvalidLength(options.length, width + 1));
+    }
+    let base = options.alphabet.digits().chars().count() as u128;
+    let capacity = u32::try_from(options.length - width)
+        .ok()
+        .and_then(|digits| base.checked_pow(digits))
+        .unwrap_or(u128::MAX);
+    if n as u128 > capacity {
+        return Err(CrockfordError::CapacityExceeded(n, capacity));
+    }
+    let encoding = EncodeOptions {
+        alphabet: options.alphabet,
+        grouping: options.grouping.clone(),
+        length: options.length,
+        checksum: options.checksum,
+    };
+    let encode = |number: u128| encoding.encode(number);
+    let allowed = |encoded: &String| !contains_blocked(encoded, options.blocklist);
+
+    // When the batch takes up much of the capacity, drawing at random
+    // would mostly hit numbers already drawn: shuffle them all instead.
+    if capacity <= (n as u128).saturating_mul(2) {
+        let mut numbers: Vec<u128> = (0..capacity).collect();
+        numbers.shuffle(rng);
+        let batch: Vec<String> = numbers.into_iter().map(encode).filter(allowed).take(n).collect();
+        if batch.len() < n {
+            return Err(CrockfordError::CapacityExceeded(n, batch.len() as u128));
+        }
+        return Ok(batch);
+    }
+
+    // A blocklist can block most or all strings, so the draws are bounded.
+    let max_draws = n.saturating_mul(MAX_DRAWS_PER_STRING).max(MIN_DRAWS);
+    let mut seen = HashSet::with_capacity(n);
+    let mut batch = Vec::with_capacity(n);
+    for _ in 0..max_draws {
+        if batch.len() == n {
+            break;
+        }
+        let number = rng.random_range(0..capacity);
+        if seen.insert(number) {
+            let encoded = encode(number);
+            if allowed(&encoded) {
+                batch.push(encoded);
+            }
+        }
+    }
+    if batch.len() < n {
+        return Err(CrockfordError::CapacityExceeded(n, batch.len() as u128));
+    }
+    Ok(batch)
+}
+
+/// Decode a string in the digits of `alphabet` to a number, as [`decode`]
+/// does for Crockford base32, verifying the check characters `checksum`
+/// says it ends with.
+pub fn decode_with(
+    alphabet: &dyn Alphabet,
+    str: &str,
+    checksum: impl Into<Checksum>,
+) -> Result<u128, CrockfordError> {
+    let checksum = checksum.into();
+    // Separators can split the check characters, so they go first; the
+    // check characters are then taken before the alphabet's normalization,
+    // which could misread them.
+    let compact = str.replace(['-', '_', '.'], "");
+    let width = checksum.width();
+    if compact.len() < width || !compact.is_char_boundary(compact.len() - width) {
+        return Err(CrockfordError::InvalidChecksumFormat(compact));
+    }
+    let (body, check) = compact.split_at(compact.len() - width);
+    let encoded = alphabet.normalize(body);
+
+    let digits = alphabet.digits();
+    let base = digits.chars().count() as u128;
+    let mut number: u128 = 0;
+    for c in encoded.chars() {
+        let pos = digits.chars().position(|d| d == c);
+        let pos = pos.ok_or(CrockfordError::InvalidCharacter(c))?;
+        number = number
+            .checked_mul(base)
+            .and_then(|n| n.checked_add(pos as u128))
+            .ok_or_else(|| CrockfordError::Overflow(str.to_string()))?;
+    }
+
+    checksum.verify(alphabet, number, &encoded, check, str)?;
+    Ok(number)
 }
 
 #[cfg(test)]
+// The tests keep covering the deprecated `encode` and `encode_with`.
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
@@ -172,6 +833,21 @@ mod tests {
         assert_eq!(number, decoded);
     }
 
+    #[test]
+    fn test_encode_options() {
+        let options = EncodeOptions::new().length(10).split_every(5);
+        assert_eq!(options.encode(12345), "00000-c1s20");
+        assert_eq!(options.encode(12345), encode(12345, 5, 10, true));
+        assert_eq!(EncodeOptions::new().encode(12345), encode(12345, 0, 0, true));
+        assert_eq!(EncodeOptions::default().checksum(false).encode(12345), "c1s");
+        let options = options.separator(' ').split_every(4).checksum(Checksum::None);
+        assert_eq!(options.encode(12345), "0000 000c 1s");
+        let options = options.alphabet(&Base36).grouping(Grouping::none()).length(0);
+        assert_eq!(options.encode(12345), encode_with(&Base36, 12345, 0, 0, false));
+        let upper = EncodeOptions::new().alphabet(&CrockfordUpper).length(10);
+        assert_eq!(upper.encode(12345), "00000C1S20");
+    }
+
     #[test]
     fn test_with_checksum() {
         let number = 12345;
@@ -180,12 +856,252 @@ mod tests {
         assert_eq!(number, decoded);
     }
 
+    #[test]
+    fn test_encode_decode_u128() {
+        let uuid = 0x550e8400_e29b_41d4_a716_446655440000_u128;
+        let encoded = encode(uuid, 0, 0, true);
+        assert_eq!(decode(&encoded, true).unwrap(), uuid);
+        assert_eq!(encode(u128::MAX, 0, 0, false).len(), 26);
+        assert_eq!(decode(&encode(u128::MAX, 0, 0, false), false).unwrap(), u128::MAX);
+
+        let generated = generate(28, 0, true).unwrap();
+        assert_eq!(generated.len(), 28);
+        assert!(decode(&generated, true).is_ok());
+    }
+
+    #[test]
+    fn test_decode_overflow() {
+        let too_long = "z".repeat(27);
+        match decode(&too_long, false) {
+            Err(CrockfordError::Overflow(s)) => assert_eq!(s, too_long),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_alphabets() {
+        assert_eq!(encode_with(&Base36, 12345, 0, 0, false), "9ix");
+        assert_eq!(decode_with(&Base36, "9IX", false).unwrap(), 12345);
+        assert_eq!(encode_with(&Base32, 1, 0, 4, false), "AAAB");
+        assert_eq!(decode_with(&Base32, "aaab", false).unwrap(), 1);
+        assert_eq!(encode_with(&Base58, 0, 0, 0, false), "1");
+        assert_eq!(encode_with(&Base58, 57, 0, 0, false), "z");
+        assert!(matches!(
+            decode_with(&Base58, "0", false),
+            Err(CrockfordError::InvalidCharacter('0'))
+        ));
+        assert_eq!(encode_with(&Crockford, 12345, 2, 8, true), encode(12345, 2, 8, true));
+
+        let alphabets: [&dyn Alphabet; 4] = [&Crockford, &Base32, &Base36, &Base58];
+        for alphabet in alphabets {
+            let encoded = encode_with(alphabet, u128::MAX, 4, 0, true);
+            assert_eq!(decode_with(alphabet, &encoded, true).unwrap(), u128::MAX);
+            let generated = generate_with(alphabet, 10, 0, true).unwrap();
+            assert_eq!(generated.len(), 10);
+            assert!(decode_with(alphabet, &generated, true).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        // Crockford's check symbol of 1234 is 1234 % 37 = 13, d
+        assert_eq!(encode(1234, 0, 0, Checksum::CrockfordSymbol), "16jd");
+        assert_eq!(decode("16JD", Checksum::CrockfordSymbol).unwrap(), 1234);
+        assert_eq!(encode(36, 0, 0, Checksum::CrockfordSymbol), "14u");
+        assert!(matches!(
+            decode("16je", Checksum::CrockfordSymbol),
+            Err(CrockfordError::InvalidCheckSymbol(_, 'e'))
+        ));
+
+        assert_eq!(encode_with(&Base36, 12345, 0, 0, Checksum::Mod37_36), "9ixa");
+        assert_eq!(decode_with(&Base36, "9IXA", Checksum::Mod37_36).unwrap(), 12345);
+        assert!(decode_with(&Base36, "9ixb", Checksum::Mod37_36).is_err());
+        assert_eq!(encode(12345, 0, 0, Checksum::None), encode(12345, 0, 0, false));
+        assert_eq!(encode(12345, 0, 0, Checksum::Mod97_10), encode(12345, 0, 0, true));
+
+        let strategies = [
+            Checksum::None,
+            Checksum::Mod97_10,
+            Checksum::CrockfordSymbol,
+            Checksum::Mod37_36,
+        ];
+        let alphabets: [&dyn Alphabet; 4] = [&Crockford, &Base32, &Base36, &Base58];
+        for checksum in strategies {
+            for alphabet in alphabets {
+                let generated = generate_with(alphabet, 10, 3, checksum).unwrap();
+                assert_eq!(generated.replace('-', "").len(), 10);
+                assert!(decode_with(alphabet, &generated, checksum).is_ok(), "{}", generated);
+            }
+        }
+    }
+
     #[test]
     fn test_generate() {
-        let generated = generate(12, 4, true);
+        let generated = generate(12, 4, true).unwrap();
         assert!(!generated.is_empty());
         assert!(generated.contains('-'));
         assert_eq!(generated.split('-').count(), 3);
+        assert_eq!(generated.len(), 14);
+    }
+
+    #[test]
+    fn test_generate_with_rng() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut first = StdRng::seed_from_u64(42);
+        let mut second = StdRng::seed_from_u64(42);
+        let generated: Vec<String> = (0..3)
+            .map(|_| generate_with_rng(&mut first, &Crockford, 10, 5, true).unwrap())
+            .collect();
+        for expected in &generated {
+            let again = generate_with_rng(&mut second, &Crockford, 10, 5, true).unwrap();
+            assert_eq!(&again, expected);
+            assert!(decode(expected, true).is_ok());
+        }
+        assert_ne!(generated[0], generated[1]);
+    }
+
+    #[test]
+    fn test_generate_batch() {
+        let batch = generate_batch(1000, &GenerateOptions::default()).unwrap();
+        assert_eq!(batch.len(), 1000);
+        assert_eq!(batch.iter().collect::<HashSet<_>>().len(), 1000);
+        assert!(batch.iter().all(|s| s.len() == 11 && decode(s, true).is_ok()));
+
+        // 32 strings of one digit and two check digits
+        let short = GenerateOptions { length: 3, grouping: Grouping::none(), ..Default::default() };
+        let all = generate_batch(32, &short).unwrap();
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 32);
+        assert!(matches!(
+            generate_batch(33, &short),
+            Err(CrockfordError::CapacityExceeded(33, 32))
+        ));
+        let blocked = GenerateOptions { blocklist: &["a"], ..short.clone() };
+        assert!(matches!(
+            generate_batch(32, &blocked),
+            Err(CrockfordError::CapacityExceeded(32, found)) if found < 32
+        ));
+        // The two check digits contain a blocked digit in every string.
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        let all_blocked = GenerateOptions { blocklist: &digits, ..Default::default() };
+        assert!(matches!(
+            generate_batch(5, &all_blocked),
+            Err(CrockfordError::CapacityExceeded(5, 0))
+        ));
+        let base36 = GenerateOptions { alphabet: &Base36, checksum: Checksum::None, ..short };
+        assert_eq!(generate_batch(36 * 36 * 36, &base36).unwrap().len(), 46656);
+    }
+
+    #[test]
+    fn test_decode_detect() {
+        assert_eq!(decode_detect("00000-c1s20").unwrap(), (12345, Checksum::Mod97_10));
+        assert_eq!(decode_detect("00000-c1s").unwrap(), (12345, Checksum::None));
+        // 21 isn't the checksum of c1s, so the whole string is the number
+        let plain = decode("c1s21", false).unwrap();
+        assert_eq!(decode_detect("c1s21").unwrap(), (plain, Checksum::None));
+        assert!(matches!(decode_detect("c1s%"), Err(CrockfordError::InvalidCharacter('%'))));
+
+        let checksums = [Checksum::Mod97_10, Checksum::CrockfordSymbol];
+        assert_eq!(
+            decode_detect_with(&Crockford, "16jd", &checksums).unwrap(),
+            (1234, Checksum::CrockfordSymbol)
+        );
+    }
+
+    #[test]
+    fn test_generate_invalid_length() {
+        assert!(matches!(generate(2, 0, true), Err(CrockfordError::InvalidLength(2, 3))));
+        assert!(matches!(
+            generate(1, 0, Checksum::CrockfordSymbol),
+            Err(CrockfordError::InvalidLength(1, 2))
+        ));
+        assert!(matches!(generate(0, 0, false), Err(CrockfordError::InvalidLength(0, 1))));
+        assert_eq!(generate(1, 0, false).unwrap().len(), 1);
+        assert_eq!(
+            generate(2, 0, true).unwrap_err().to_string(),
+            "invalid length 2: must be at least 3"
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split("abcdefgh12", 4, '-'), "abcd-efgh-12");
+        assert_eq!(split("abcdefgh12", 5, '_'), "abcde_fgh12");
+        assert_eq!(split("abcdefgh12", 0, '-'), "abcdefgh12");
+        assert_eq!(decode(&encode(12345, 2, 8, true).replace('-', "."), true).unwrap(), 12345);
+    }
+
+    #[test]
+    fn test_grouping() {
+        let grouping = Grouping::pattern(&[4, 4, 2]).with_separator(' ');
+        let encoded = encode(12345, grouping.clone(), 10, true);
+        assert_eq!(encoded, "0000 0c1s 20");
+        assert_eq!(normalize_with(&encoded.to_uppercase(), &grouping), "00000c1s20");
+        assert_eq!(decode(&normalize_with(&encoded, &grouping), true).unwrap(), 12345);
+        assert_eq!(Grouping::pattern(&[3, 2]).apply("abcdefgh1"), "abc-de-fg-h1");
+        assert_eq!(Grouping::pattern(&[2, 0]).apply("abcdefgh1"), "ab-cdefgh1");
+        assert_eq!(Grouping::pattern(&[4, 4]).apply("abc"), "abc");
+        assert_eq!(Grouping::none().apply("abcdefgh1"), "abcdefgh1");
+        assert_eq!(Grouping::from(3).with_separator('/').apply("abcdefg"), "abc/def/g");
+    }
+
+    #[test]
+    fn test_uppercase() {
+        let encoded = encode_with(&CrockfordUpper, 12345, 5, 10, true);
+        assert_eq!(encoded, "00000-C1S20");
+        assert_eq!(decode(&encoded, true).unwrap(), 12345);
+        assert_eq!(decode_with(&CrockfordUpper, &encoded, true).unwrap(), 12345);
+        assert_eq!(decode_with(&CrockfordUpper, "00000-c1s20", true).unwrap(), 12345);
+        assert_eq!(decode_with(&CrockfordUpper, "oOOOO-CIS20", true).unwrap(), 12345);
+        let symbol = encode_with(&CrockfordUpper, 31, 0, 0, Checksum::CrockfordSymbol);
+        assert_eq!(symbol, "ZZ");
+        assert_eq!(decode(&symbol, Checksum::CrockfordSymbol).unwrap(), 31);
+        let generated = generate_with(&CrockfordUpper, 10, 0, Checksum::Mod37_36).unwrap();
+        assert_eq!(generated, generated.to_uppercase());
+        assert!(decode(&generated, Checksum::Mod37_36).is_ok());
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number(" 12345 ").unwrap(), 12345);
+        assert_eq!(parse_number(&u128::MAX.to_string()).unwrap(), u128::MAX);
+        assert!(matches!(parse_number("-5"), Err(CrockfordError::NumberOutOfRange(_))));
+        assert_eq!(parse_number("-0").unwrap(), 0);
+        assert_eq!(parse_number("-000").unwrap(), 0);
+        let too_large = "340282366920938463463374607431768211456";
+        assert!(matches!(parse_number(too_large), Err(CrockfordError::NumberOutOfRange(_))));
+        assert!(matches!(parse_number("12a"), Err(CrockfordError::InvalidNumber(_))));
+        assert!(matches!(parse_number("-"), Err(CrockfordError::InvalidNumber(_))));
+        assert_eq!(
+            parse_number("-5").unwrap_err().to_string(),
+            format!("number -5 out of range: must be between 0 and {}", u128::MAX)
+        );
+        assert_eq!(encode(u128::MAX, 0, 0, true), encode(u128::MAX, 0, 28, true));
+        assert_eq!(decode(&encode(u128::MAX, 0, 0, true), true).unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn test_contains_blocked() {
+        assert!(contains_blocked("a55-xy", BLOCKLIST));
+        assert!(contains_blocked("5H-17x", BLOCKLIST));
+        assert!(contains_blocked("c0ck0", BLOCKLIST));
+        assert!(!contains_blocked("nwbyp-29t86", BLOCKLIST));
+        assert!(contains_blocked("nwbyp-29t86", &["Yp29"]));
+        assert!(!contains_blocked("abc", &[""]));
+    }
+
+    #[test]
+    fn test_generate_filtered() {
+        for _ in 0..100 {
+            let generated = generate_filtered(5, 0, true, &["0", "1"]).unwrap();
+            assert!(!generated.contains('0') && !generated.contains('1'));
+        }
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        assert!(matches!(
+            generate_filtered(10, 5, true, &digits),
+            Err(CrockfordError::AllBlocked(1000))
+        ));
     }
 
     #[test]
@@ -0,0 +1,327 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+//! Colored, source-annotated error messages for the command-line tool:
+//! locate the value a JSON pointer refers to in the original document and
+//! print the offending line with the value underlined. Colors are used only
+//! on terminals, and never with `--no-color` or `NO_COLOR` set.
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static NO_COLOR: AtomicBool = AtomicBool::new(false);
+
+/// Turn colors off for the rest of the run, e.g. for `--no-color`.
+pub fn disable_color() {
+    NO_COLOR.store(true, Ordering::Relaxed);
+}
+
+/// Which stream a message is written to, as colors depend on whether that
+/// stream is a terminal.
+#[derive(Clone, Copy)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+fn use_color(stream: Stream) -> bool {
+    if NO_COLOR.load(Ordering::Relaxed)
+        || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+    {
+        return false;
+    }
+    match stream {
+        Stream::Stdout => std::io::stdout().is_terminal(),
+        Stream::Stderr => std::io::stderr().is_terminal(),
+    }
+}
+
+/// ANSI styles used in diagnostics and diffs.
+#[derive(Clone, Copy)]
+pub enum Style {
+    Error,
+    Warning,
+    Gutter,
+    Added,
+    Removed,
+}
+
+/// `text` in `style` when `stream` shows colors, unchanged otherwise.
+pub fn paint(text: &str, style: Style, stream: Stream) -> String {
+    if !use_color(stream) {
+        return text.to_string();
+    }
+    let code = match style {
+        Style::Error => "1;31",
+        Style::Warning => "1;33",
+        Style::Gutter => "1;34",
+        Style::Added => "32",
+        Style::Removed => "31",
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+/// A position in a source document: 1-based line and column, and the
+/// number of characters to underline on that line.
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+}
+
+/// Location of the value that `pointer` (an RFC 6901 JSON pointer, `""` for
+/// the whole document) refers to in `source`. A value spanning several lines
+/// is underlined up to the end of its first line.
+pub fn locate_pointer(source: &str, pointer: &str) -> Option<Location> {
+    let segments: Vec<String> = if pointer.is_empty() {
+        Vec::new()
+    } else {
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(|s| s.replace("~1", "/").replace("~0", "~"))
+            .collect()
+    };
+    let mut scanner = Scanner {
+        bytes: source.as_bytes(),
+        pos: 0,
+    };
+    let (start, end) = scanner.find(&segments)?;
+    Some(locate_span(source, start, end))
+}
+
+/// Location of the `line` and `column` reported by a JSON parse error such
+/// as `expected value at line 3 column 12`.
+pub fn locate_parse_error(source: &str, message: &str) -> Option<Location> {
+    let rest = &message[message.rfind("line ")? + 5..];
+    let (line, rest) = rest.split_once(" column ")?;
+    let line: usize = line.trim().parse().ok()?;
+    let column: usize = rest
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    let text = source.lines().nth(line.checked_sub(1)?)?;
+    Some(Location {
+        line,
+        column: column.clamp(1, text.chars().count().max(1)),
+        width: 1,
+    })
+}
+
+/// Location of the bytes `start..end` of `source`.
+pub fn locate_span(source: &str, start: usize, end: usize) -> Location {
+    let before = &source[..start];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = source[line_start..start].chars().count() + 1;
+    let value = &source[start..end];
+    let first_line = value.split('\n').next().unwrap_or(value);
+    Location {
+        line,
+        column,
+        width: first_line.trim_end().chars().count().max(1),
+    }
+}
+
+/// The source line at `location` with the located value underlined, e.g.
+///
+/// ```text
+///    |
+///  3 |   "type": 42,
+///    |           ^^
+/// ```
+pub fn snippet(source: &str, location: &Location, stream: Stream) -> String {
+    let text = source.lines().nth(location.line - 1).unwrap_or("");
+    let number = location.line.to_string();
+    let pad = " ".repeat(number.len());
+    let gutter = |label: &str| paint(&format!("{} |", label), Style::Gutter, stream);
+    let marker = paint(&"^".repeat(location.width), Style::Error, stream);
+    format!(
+        "{}\n{} {}\n{} {}{}",
+        gutter(&pad),
+        gutter(&number),
+        text,
+        gutter(&pad),
+        " ".repeat(location.column - 1),
+        marker
+    )
+}
+
+/// Just enough of a JSON parser to find the byte range of a value.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.skip_whitespace();
+        if self.peek()? == byte {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Byte range of the value at `segments` below the value at the current
+    /// position.
+    fn find(&mut self, segments: &[String]) -> Option<(usize, usize)> {
+        self.skip_whitespace();
+        let Some((segment, rest)) = segments.split_first() else {
+            let start = self.pos;
+            self.skip_value()?;
+            return Some((start, self.pos));
+        };
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek()? == b'}' {
+                        return None;
+                    }
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    if key == *segment {
+                        return self.find(rest);
+                    }
+                    self.skip_value()?;
+                    self.skip_whitespace();
+                    if self.peek()? == b',' {
+                        self.pos += 1;
+                    }
+                }
+            }
+            b'[' => {
+                let index: usize = segment.parse().ok()?;
+                self.pos += 1;
+                for _ in 0..index {
+                    self.skip_value()?;
+                    self.expect(b',')?;
+                }
+                self.find(rest)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a JSON string at the current position into its unescaped
+    /// value.
+    fn string(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        self.skip_string()?;
+        serde_json::from_slice(&self.bytes[start..self.pos]).ok()
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        if self.peek()? != b'"' {
+            return None;
+        }
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_value(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'"' => self.skip_string(),
+            open @ (b'{' | b'[') => {
+                let close = if open == b'{' { b'}' } else { b']' };
+                self.pos += 1;
+                let mut depth = 1;
+                while depth > 0 {
+                    match self.peek()? {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        }
+                        b if b == open => depth += 1,
+                        b if b == close => depth -= 1,
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+                Some(())
+            }
+            _ => {
+                while let Some(b) = self.peek() {
+                    if matches!(b, b',' | b'}' | b']') || b.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Some(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "{\n  \"id\": \"https://doi.org/10.5555/1\",\n  \"contributors\": [\n    {\"name\": \"A\"},\n    {\"name\": \"B\", \"type\": 42}\n  ],\n  \"a/b\": true\n}\n";
+
+    #[test]
+    fn test_locate_pointer() {
+        assert_eq!(
+            locate_pointer(DOCUMENT, "/contributors/1/type"),
+            Some(Location { line: 5, column: 27, width: 2 })
+        );
+        assert_eq!(
+            locate_pointer(DOCUMENT, "/id"),
+            Some(Location { line: 2, column: 9, width: 27 })
+        );
+        assert_eq!(
+            locate_pointer(DOCUMENT, "/a~1b"),
+            Some(Location { line: 7, column: 10, width: 4 })
+        );
+        assert_eq!(locate_pointer(DOCUMENT, "").map(|l| l.line), Some(1));
+        assert_eq!(locate_pointer(DOCUMENT, "/missing"), None);
+        assert_eq!(locate_pointer(DOCUMENT, "/contributors/5"), None);
+    }
+
+    #[test]
+    fn test_locate_parse_error() {
+        let source = "{\n  \"id\": ,\n}";
+        assert_eq!(
+            locate_parse_error(source, "parse error: expected value at line 2 column 9"),
+            Some(Location { line: 2, column: 9, width: 1 })
+        );
+        assert_eq!(locate_parse_error(source, "no position"), None);
+    }
+
+    #[test]
+    fn test_snippet_without_color() {
+        disable_color();
+        let location = Location { line: 5, column: 27, width: 2 };
+        assert_eq!(
+            snippet(DOCUMENT, &location, Stream::Stdout),
+            "  |\n5 |     {\"name\": \"B\", \"type\": 42}\n  |                           ^^"
+        );
+    }
+}
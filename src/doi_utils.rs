@@ -14,6 +14,11 @@ use std::string::ToString;
 use std::time::Duration;
 use url::Url;
 
+use crate::crockford::{
+    Alphabet, Crockford, CrockfordError, CrockfordUpper, EncodeOptions, GenerateOptions, Grouping,
+};
+use crate::utils::ValidationOptions;
+
 /// Extracts DOI prefix from URL
 pub fn prefix_from_url(s: &str) -> Result<String, Box<dyn Error>> {
     let url = Url::parse(s)?;
@@ -32,11 +37,58 @@ pub fn prefix_from_url(s: &str) -> Result<String, Box<dyn Error>> {
 
 /// Normalizes a DOI
 pub fn normalize_doi(doi: &str) -> String {
-    if let Some(doi_str) = validate_doi(doi) {
-        let resolver = doi_resolver(doi, false);
-        return format!("{}{}", resolver, doi_str.to_lowercase());
+    normalize_doi_with(doi, &NormalizeOptions::default())
+}
+
+/// Where [`normalize_doi_with`] points a DOI.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DoiResolver {
+    /// `https://handle.stage.datacite.org/` for DOIs given as DataCite
+    /// sandbox URLs, `https://doi.org/` for all others.
+    #[default]
+    Detect,
+    /// Always `https://doi.org/`.
+    DoiOrg,
+    /// Always `https://handle.stage.datacite.org/`.
+    Sandbox,
+    /// The bare DOI, without resolver.
+    Bare,
+    /// Another resolver, e.g. `https://hdl.handle.net/`, ending with `/`.
+    Custom(String),
+}
+
+/// How [`normalize_doi_with`] spells a DOI. The default is what
+/// [`normalize_doi`] does: lowercase, with the resolver detected from the
+/// input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Lowercase the DOI. DOIs are case-insensitive, but some workflows
+    /// must keep the case a legacy suffix was registered with.
+    pub lowercase: bool,
+    pub resolver: DoiResolver,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions { lowercase: true, resolver: DoiResolver::Detect }
     }
-    String::new()
+}
+
+/// Normalizes a DOI with the case and resolver given in `options`. Returns
+/// an empty string for an invalid DOI, like [`normalize_doi`].
+pub fn normalize_doi_with(doi: &str, options: &NormalizeOptions) -> String {
+    let Some(doi_str) = validate_doi(doi) else {
+        return String::new();
+    };
+    let doi_str = if options.lowercase { doi_str.to_lowercase() } else { doi_str };
+    let resolver = match &options.resolver {
+        DoiResolver::Detect => doi_resolver(doi, false),
+        DoiResolver::DoiOrg => "https://doi.org/".to_string(),
+        DoiResolver::Sandbox => "https://handle.stage.datacite.org/".to_string(),
+        DoiResolver::Bare => String::new(),
+        DoiResolver::Custom(resolver) => resolver.clone(),
+    };
+    format!("{}{}", resolver, doi_str)
 }
 
 /// Validates a DOI
@@ -53,6 +105,80 @@ pub fn validate_doi(doi: &str) -> Option<String> {
     None
 }
 
+/// Strips the punctuation that ends the sentence or list around a DOI,
+/// keeping closing brackets that belong to the DOI itself, as in
+/// `10.1016/S0140-6736(20)30183-5`.
+pub(crate) fn trim_doi(doi: &str) -> &str {
+    let mut doi = doi;
+    loop {
+        let Some(last) = doi.chars().last() else {
+            return doi;
+        };
+        let unbalanced = |open: char| doi.matches(open).count() < doi.matches(last).count();
+        let trim = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' => true,
+            ')' => unbalanced('('),
+            ']' => unbalanced('['),
+            '}' => unbalanced('{'),
+            _ => false,
+        };
+        if !trim {
+            return doi;
+        }
+        doi = &doi[..doi.len() - 1];
+    }
+}
+
+/// Recovers a DOI from a string copy-pasted out of a PDF, web page or email,
+/// which [`validate_doi`] rightly rejects: removes whitespace (including line
+/// breaks inside the DOI) and invisible characters, a `DOI:` label in any
+/// case, a doi.org resolver with or without scheme, the brackets, angle
+/// brackets or quotes around the DOI, and trailing punctuation. Returns the
+/// DOI as `validate_doi` does, or `None` if the cleaned string isn't a DOI.
+pub fn clean_doi(doi: &str) -> Option<String> {
+    lazy_static! {
+        static ref PREFIX: Regex =
+            Regex::new(r"^(?i:(?:https?://)?(?:dx\.)?doi\.org/|doi:?)").unwrap();
+    }
+    let mut doi: String = strip_invisible(doi).chars().filter(|c| !c.is_whitespace()).collect();
+    loop {
+        let before = doi.len();
+        let trimmed = doi
+            .trim_start_matches(['(', '[', '{', '<', '"', '\'', '\u{201C}', '\u{2018}', '\u{AB}'])
+            .trim_end_matches(['>', '"', '\'', '\u{201D}', '\u{2019}', '\u{BB}']);
+        let trimmed = trim_doi(trimmed);
+        let trimmed = match PREFIX.find(trimmed) {
+            Some(m) => &trimmed[m.end()..],
+            None => trimmed,
+        };
+        doi = trimmed.to_string();
+        if doi.len() == before {
+            break;
+        }
+    }
+    validate_doi(&doi)
+}
+
+/// Removes the soft hyphens, zero-width spaces and typographic hyphens that
+/// PDF text extraction leaves in identifiers broken across lines.
+pub(crate) fn strip_invisible(s: &str) -> String {
+    s.chars()
+        .filter(|c| !matches!(c, '\u{200B}'..='\u{200D}' | '\u{FEFF}' | '\u{AD}'))
+        .map(|c| if matches!(c, '\u{2010}' | '\u{2011}') { '-' } else { c })
+        .collect()
+}
+
+/// Validates a DOI as [`validate_doi`] does, but as strictly or leniently as
+/// `options` ask: with `cleanup` the DOI goes through [`clean_doi`] first,
+/// with `canonical_only` only the bare DOI and its doi.org URL are accepted.
+pub fn validate_doi_with_options(doi: &str, options: &ValidationOptions) -> Option<String> {
+    let valid = if options.cleanup { clean_doi(doi) } else { validate_doi(doi) }?;
+    if options.canonical_only && doi != valid && doi.to_lowercase() != normalize_doi(&valid) {
+        return None;
+    }
+    Some(valid)
+}
+
 /// Escapes a DOI, i.e. replaces '/' with '%2F'
 pub fn escape_doi(doi: &str) -> String {
     if let Some(doi_str) = validate_doi(doi) {
@@ -61,15 +187,119 @@ pub fn escape_doi(doi: &str) -> String {
     String::new()
 }
 
+/// The longest suffix [`SuffixShape`] allows: 26 base32 characters, which
+/// hold any `u128`, and the two checksum characters.
+pub const MAX_SUFFIX_LENGTH: usize = 28;
+
+/// Shape of a generated DOI suffix: its length including the two checksum
+/// characters, how often it is split with `separator`, and words random
+/// suffixes must not contain (e.g. `crockford::BLOCKLIST`). The default
+/// gives suffixes like `nwbyp-29t86`, without filtering; `uppercase` gives
+/// `NWBYP-29T86`, which decodes to the same number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuffixShape {
+    pub length: usize,
+    pub split_every: usize,
+    pub separator: char,
+    pub blocklist: Vec<String>,
+    pub uppercase: bool,
+}
+
+impl Default for SuffixShape {
+    fn default() -> Self {
+        SuffixShape {
+            length: 10,
+            split_every: 5,
+            separator: '-',
+            blocklist: Vec::new(),
+            uppercase: false,
+        }
+    }
+}
+
+impl SuffixShape {
+    /// Number of distinct random suffixes of this shape.
+    pub fn capacity(&self) -> u64 {
+        let digits = u32::try_from(self.length.saturating_sub(2)).unwrap_or(u32::MAX);
+        32_u64.saturating_pow(digits)
+    }
+
+    /// The digits suffixes are encoded with.
+    fn alphabet(&self) -> &'static dyn Alphabet {
+        if self.uppercase { &CrockfordUpper } else { &Crockford }
+    }
+
+    fn suffix(&self, encoded: &str) -> String {
+        crate::crockford::split(encoded, self.split_every, self.separator)
+    }
+}
+
 /// Encodes a DOI with a randomly generated suffix
 pub fn encode_doi(prefix: &str) -> String {
-    let suffix = crate::crockford::generate(10, 5, true);
-    let doi = format!("https://doi.org/{}/{}", prefix, suffix);
-    doi
+    encode_doi_with_shape(prefix, &SuffixShape::default()).expect("default shape is valid")
+}
+
+/// Encodes a DOI with a randomly generated suffix of the given shape,
+/// regenerated while it contains a word from `shape.blocklist`. Fails for
+/// a `shape.length` below 3.
+pub fn encode_doi_with_shape(prefix: &str, shape: &SuffixShape) -> Result<String, CrockfordError> {
+    let blocklist: Vec<&str> = shape.blocklist.iter().map(String::as_str).collect();
+    let generated = crate::crockford::generate_filtered_with(
+        shape.alphabet(),
+        shape.length,
+        0,
+        true,
+        &blocklist,
+    )?;
+    let suffix = shape.suffix(&generated);
+    Ok(format!("https://doi.org/{}/{}", prefix, suffix))
+}
+
+/// Encodes a DOI whose suffix is `number` in Crockford base32 with checksum,
+/// the inverse of `decode_doi`
+pub fn encode_doi_number(prefix: &str, number: u128) -> String {
+    encode_doi_number_with_shape(prefix, number, &SuffixShape::default())
+}
+
+/// Encodes a DOI whose suffix is `number`, padded and split as in `shape`
+pub fn encode_doi_number_with_shape(prefix: &str, number: u128, shape: &SuffixShape) -> String {
+    let options = EncodeOptions::new().alphabet(shape.alphabet()).length(shape.length);
+    let suffix = shape.suffix(&options.encode(number));
+    format!("https://doi.org/{}/{}", prefix, suffix)
+}
+
+/// Encodes `count` DOIs with randomly generated suffixes, none of them
+/// repeated
+pub fn encode_dois(prefix: &str, count: usize) -> Vec<String> {
+    encode_dois_with_shape(prefix, count, &SuffixShape::default()).expect("default shape is valid")
+}
+
+/// Encodes `count` distinct DOIs with random suffixes of the given shape,
+/// see [`crate::crockford::generate_batch`]. Fails for a `shape.length`
+/// below 3, and if `count` exceeds `shape.capacity()` or too many suffixes
+/// contain a word from `shape.blocklist`.
+pub fn encode_dois_with_shape(
+    prefix: &str,
+    count: usize,
+    shape: &SuffixShape,
+) -> Result<Vec<String>, CrockfordError> {
+    let blocklist: Vec<&str> = shape.blocklist.iter().map(String::as_str).collect();
+    let options = GenerateOptions {
+        alphabet: shape.alphabet(),
+        length: shape.length,
+        grouping: Grouping::none(),
+        blocklist: &blocklist,
+        ..Default::default()
+    };
+    let suffixes = crate::crockford::generate_batch(count, &options)?;
+    Ok(suffixes
+        .iter()
+        .map(|suffix| format!("https://doi.org/{}/{}", prefix, shape.suffix(suffix)))
+        .collect())
 }
 
 /// Decodes a DOI suffix to an integer
-pub fn decode_doi(doi: &str) -> i64 {
+pub fn decode_doi(doi: &str) -> u128 {
     if let Some(d) = validate_doi(doi) {
         let parts: Vec<&str> = d.split('/').collect();
         if parts.len() < 2 {
@@ -80,7 +310,7 @@ pub fn decode_doi(doi: &str) -> i64 {
         match crate::crockford::decode(suffix, true) {
             Ok(number) => return number,
             Err(e) => {
-                eprintln!("Error decoding DOI suffix: {}", e);
+                tracing::debug!("Error decoding DOI suffix: {}", e);
                 return 0;
             }
         }
@@ -90,20 +320,32 @@ pub fn decode_doi(doi: &str) -> i64 {
 
 /// Checks if a DOI resolves (i.e. redirects) via the DOI handle servers
 pub async fn is_registered_doi(doi: &str) -> bool {
+    matches!(doi_resolution_status(doi).await, Some(status) if status <= 308)
+}
+
+/// Returns the HTTP status the DOI handle servers answer for a DOI, without
+/// following the redirect to the landing page: a redirect (usually 302) for a
+/// registered DOI, 404 otherwise. `None` if the DOI is invalid or the request
+/// failed.
+pub async fn doi_resolution_status(doi: &str) -> Option<u16> {
     let url = normalize_doi(doi);
     if url.is_empty() {
-        return false;
+        return None;
     }
 
     let client = Client::builder()
         .timeout(Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
         .build()
         .unwrap_or_default();
 
-    match client.head(&url).send().await {
-        Ok(resp) => resp.status().as_u16() <= 308,
-        Err(_) => false,
-    }
+    tracing::debug!("HEAD {}", url);
+    client
+        .head(&url)
+        .send()
+        .await
+        .ok()
+        .map(|resp| resp.status().as_u16())
 }
 
 /// Validates a DOI prefix for a given DOI
@@ -149,15 +391,162 @@ pub fn get_doi_ra_sync(doi: &str) -> Option<String> {
         .build()
         .ok()?;
 
+    tracing::debug!("GET {}", url);
     let entries: Vec<RaEntry> = client.get(&url).send().ok()?.json().ok()?;
     let ra = entries.into_iter().next()?.ra;
     if ra.is_empty() { None } else { Some(ra) }
 }
 
+/// A DOI registered under a prefix, as listed by the Crossref or DataCite
+/// API: the DOI as a URL, its commonmeta type and its registration date.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct PrefixDoi {
+    pub doi: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub created: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalize_doi_with() {
+        let doi = "https://doi.org/10.1016/S0140-6736(20)30183-5";
+        assert_eq!(normalize_doi_with(doi, &NormalizeOptions::default()), normalize_doi(doi));
+        let keep_case = NormalizeOptions { lowercase: false, ..Default::default() };
+        assert_eq!(normalize_doi_with(doi, &keep_case), doi);
+        let bare = NormalizeOptions { lowercase: false, resolver: DoiResolver::Bare };
+        assert_eq!(normalize_doi_with(doi, &bare), "10.1016/S0140-6736(20)30183-5");
+        let sandbox = NormalizeOptions { resolver: DoiResolver::Sandbox, ..Default::default() };
+        assert_eq!(
+            normalize_doi_with("10.5438/ABC", &sandbox),
+            "https://handle.stage.datacite.org/10.5438/abc"
+        );
+        let handle = NormalizeOptions {
+            resolver: DoiResolver::Custom("https://hdl.handle.net/".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_doi_with("doi:10.5438/ABC", &handle),
+            "https://hdl.handle.net/10.5438/abc"
+        );
+        assert_eq!(normalize_doi_with("10.5438", &keep_case), "");
+    }
+
+    #[test]
+    fn test_trim_doi() {
+        assert_eq!(trim_doi("10.5555/12345678)."), "10.5555/12345678");
+        assert_eq!(trim_doi("10.1002/(sici)1097-0258"), "10.1002/(sici)1097-0258");
+        assert_eq!(trim_doi("10.5555/abc]"), "10.5555/abc");
+    }
+
+    #[test]
+    fn test_clean_doi() {
+        let cases = [
+            ("10.5555/12345678", "10.5555/12345678"),
+            ("DOI: 10.5555/12345678.", "10.5555/12345678"),
+            ("(doi:10.5555/12345678)", "10.5555/12345678"),
+            ("<https://doi.org/10.5555/12345678>", "10.5555/12345678"),
+            ("doi.org/10.5555/12345678,", "10.5555/12345678"),
+            ("\u{201C}https://dx.doi.org/10.5555/12345678\u{201D}", "10.5555/12345678"),
+            ("10.1016/S0140-\n6736(20)30183-5).", "10.1016/S0140-6736(20)30183-5"),
+            ("10.5555/1234\u{AD}5678", "10.5555/12345678"),
+            ("10.5555/abc\u{2010}def", "10.5555/abc-def"),
+            ("[DOI 10.5555/12345678]", "10.5555/12345678"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(clean_doi(input).as_deref(), Some(expected), "{:?}", input);
+        }
+        assert_eq!(clean_doi("ISBN 978-3-16-148410-0"), None);
+        assert_eq!(clean_doi("doi: pending"), None);
+    }
+
+    #[test]
+    fn test_validate_doi_with_options() {
+        let strict = ValidationOptions::strict();
+        let lenient = ValidationOptions::lenient();
+        for doi in ["10.5555/12345678", "https://doi.org/10.5555/12345678"] {
+            let valid = validate_doi_with_options(doi, &strict);
+            assert_eq!(valid.as_deref(), Some("10.5555/12345678"));
+        }
+        assert_eq!(validate_doi_with_options("doi:10.5555/12345678", &strict), None);
+        assert_eq!(validate_doi_with_options("http://dx.doi.org/10.5555/12345678", &strict), None);
+        assert_eq!(
+            validate_doi_with_options("doi:10.5555/12345678", &ValidationOptions::default())
+                .as_deref(),
+            Some("10.5555/12345678")
+        );
+        assert_eq!(validate_doi_with_options("DOI: 10.5555/12345678.", &strict), None);
+        assert_eq!(
+            validate_doi_with_options("DOI: 10.5555/12345678.", &lenient).as_deref(),
+            Some("10.5555/12345678")
+        );
+    }
+
+    #[test]
+    fn test_encode_doi_number_round_trip() {
+        let doi = encode_doi_number("10.5555", 12345);
+        assert_eq!(doi, encode_doi_number("10.5555", 12345));
+        assert!(doi.starts_with("https://doi.org/10.5555/"));
+        assert_eq!(decode_doi(&doi), 12345);
+    }
+
+    #[test]
+    fn test_encode_dois_with_shape() {
+        let shape = SuffixShape {
+            length: 10,
+            split_every: 4,
+            separator: '-',
+            blocklist: vec!["a".to_string()],
+            uppercase: false,
+        };
+        let dois = encode_dois_with_shape("10.5555", 20, &shape).unwrap();
+        for doi in &dois {
+            let suffix = doi.strip_prefix("https://doi.org/10.5555/").unwrap();
+            let groups: Vec<usize> = suffix.split('-').map(str::len).collect();
+            assert_eq!(groups, vec![4, 4, 2]);
+            assert!(!suffix.contains('a') && !suffix.contains('4'));
+        }
+        let doi = encode_doi_number_with_shape(
+            "10.5555",
+            12345,
+            &SuffixShape {
+                length: 8,
+                split_every: 0,
+                ..Default::default()
+            },
+        );
+        assert_eq!(doi.rsplit('/').next().unwrap().len(), 8);
+        assert_eq!(decode_doi(&doi), 12345);
+        assert_eq!(SuffixShape::default().capacity(), 1 << 40);
+        let upper = SuffixShape { uppercase: true, ..Default::default() };
+        let doi = encode_doi_number_with_shape("10.5555", 12345, &upper);
+        assert_eq!(doi, "https://doi.org/10.5555/00000-C1S20");
+        assert_eq!(decode_doi(&doi), 12345);
+        let doi = encode_doi_with_shape("10.5555", &upper).unwrap();
+        let suffix = doi.rsplit('/').next().unwrap();
+        assert_eq!(suffix, suffix.to_uppercase());
+        for doi in encode_dois_with_shape("10.5555", 3, &upper).unwrap() {
+            let suffix = doi.rsplit('/').next().unwrap();
+            assert_eq!(suffix, suffix.to_uppercase());
+        }
+        let short = SuffixShape { length: 2, ..Default::default() };
+        assert!(encode_doi_with_shape("10.5555", &short).is_err());
+        let shortest = SuffixShape { length: 3, ..Default::default() };
+        assert_eq!(encode_dois_with_shape("10.5555", 32, &shortest).unwrap().len(), 32);
+        assert!(encode_dois_with_shape("10.5555", 33, &shortest).is_err());
+    }
+
+    #[test]
+    fn test_encode_dois_unique() {
+        let dois = encode_dois("10.5555", 500);
+        assert_eq!(dois.len(), 500);
+        assert!(dois.iter().all(|doi| doi.starts_with("https://doi.org/10.5555/")));
+        assert_eq!(dois.iter().collect::<std::collections::HashSet<_>>().len(), 500);
+    }
+
     #[test]
     fn test_validate_doi_parity_cases() {
         let cases = [
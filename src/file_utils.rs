@@ -365,17 +365,17 @@ fn write_cache(path: &Path, bytes: &[u8]) {
     // between create and write_all never leaves a partial file that looks valid.
     let Some(parent) = path.parent() else { return };
     if let Err(e) = fs::create_dir_all(parent) {
-        eprintln!("warning: failed to create cache dir '{}': {}", parent.display(), e);
+        tracing::warn!("failed to create cache dir '{}': {}", parent.display(), e);
         return;
     }
     let tmp = path.with_extension("tmp");
     if let Err(e) = fs::write(&tmp, bytes) {
-        eprintln!("warning: failed to write cache '{}': {}", tmp.display(), e);
+        tracing::warn!("failed to write cache '{}': {}", tmp.display(), e);
         fs::remove_file(&tmp).ok();
         return;
     }
     if let Err(e) = fs::rename(&tmp, path) {
-        eprintln!("warning: failed to rename cache '{}': {}", tmp.display(), e);
+        tracing::warn!("failed to rename cache '{}': {}", tmp.display(), e);
         fs::remove_file(&tmp).ok();
     }
 }
@@ -522,7 +522,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
             match download_parallel(&client, url, dest, &part, t) {
                 Ok(n) => return Ok(n),
                 Err(FileError::RangeNotHonored) => {
-                    eprintln!("download: server ignored Range header — retrying sequentially");
+                    tracing::warn!("download: server ignored Range header — retrying sequentially");
                     effective_supports_range = false;
                     fs::remove_file(&part).ok();
                 }
@@ -535,7 +535,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
     let mut offset: u64 = fs::metadata(&part).map(|m| m.len()).unwrap_or(0);
 
     if !effective_supports_range {
-        eprintln!("download: server does not support Range requests — streaming without resume");
+        tracing::warn!("download: server does not support Range requests — streaming without resume");
         offset = 0;
         fs::remove_file(&part).ok();
     }
@@ -553,7 +553,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
         let of = total
             .map(|t| format!(" / {} ({:.1}%)", fmt_bytes(t), offset as f64 / t as f64 * 100.0))
             .unwrap_or_default();
-        eprintln!("download: resuming at {}{}", fmt_bytes(offset), of);
+        tracing::info!("download: resuming at {}{}", fmt_bytes(offset), of);
     }
 
     let mut file = fs::OpenOptions::new()
@@ -605,7 +605,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
                     });
                 }
                 let wait = retry_backoff(retries - 1);
-                eprintln!(
+                tracing::warn!(
                     "download: connect failed ({}) — retry {}/{} in {}",
                     describe_reqwest_error(&e),
                     retries,
@@ -635,7 +635,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
         // server stopped honouring Range. Fall through with offset=0 so the
         // full-body response is written from the start of the file.
         if effective_supports_range && status.as_u16() == 200 && offset > 0 {
-            eprintln!("download: server ignores Range header, restarting from 0");
+            tracing::warn!("download: server ignores Range header, restarting from 0");
             effective_supports_range = false;
             offset = 0;
             file.seek(io::SeekFrom::Start(0))?;
@@ -651,7 +651,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
         // skipping needed.
         if !effective_supports_range && offset > 0 && status.as_u16() == 200 {
             let mut to_skip = offset;
-            eprintln!(
+            tracing::info!(
                 "download: server returned 200 to Range request; skipping {} to resume",
                 fmt_bytes(to_skip)
             );
@@ -660,7 +660,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
                 match resp.read(&mut skip_buf[..READ_BUF.min(to_skip as usize)]) {
                     Ok(0) => {
                         // Server sent fewer bytes than we need to skip — full restart.
-                        eprintln!(
+                        tracing::warn!(
                             "download: skip hit EOF at {}; restarting from 0",
                             fmt_bytes(offset - to_skip)
                         );
@@ -677,7 +677,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
                         }
                     }
                     Err(e) => {
-                        eprintln!(
+                        tracing::warn!(
                             "download: skip error ({}); restarting from 0",
                             error_chain(&e)
                         );
@@ -727,7 +727,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
                         });
                     }
                     let wait = retry_backoff(retries - 1);
-                    eprintln!(
+                    tracing::warn!(
                         "download: read error at {} ({}) — retry {}/{} in {}",
                         fmt_bytes(offset),
                         detail,
@@ -766,7 +766,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
                     })
                     .unwrap_or_default();
                 let ts = chrono::Local::now().format("%H:%M:%S");
-                eprintln!(
+                tracing::info!(
                     "[{ts}] download: {}{}{} @ {}/s elapsed {}{}",
                     fmt_bytes(offset),
                     of_total,
@@ -798,7 +798,7 @@ fn download_to_path_resumable(url: &str, dest: &Path) -> Result<u64> {
 
     let ts = chrono::Local::now().format("%H:%M:%S");
     let final_bytes = total.unwrap_or(offset);
-    eprintln!(
+    tracing::info!(
         "[{ts}] download: complete — {} in {}",
         fmt_bytes(final_bytes),
         fmt_duration_short(overall_start.elapsed())
@@ -841,7 +841,7 @@ fn download_parallel(
     let n_chunks = queue.len();
     let n_workers = PARALLEL_TRANSFERS.min(n_chunks);
 
-    eprintln!(
+    tracing::info!(
         "download: {} / {} chunks, {} parallel connections",
         n_chunks,
         fmt_bytes(total),
@@ -921,7 +921,7 @@ fn download_parallel(
                                 });
                             }
                             let wait = retry_backoff(retries - 1);
-                            eprintln!(
+                            tracing::warn!(
                                 "download: chunk @{} connect error ({}) — retry {}/{} in {}",
                                 fmt_bytes(start),
                                 error_chain(&e),
@@ -958,7 +958,7 @@ fn download_parallel(
                                     });
                                 }
                                 let wait = retry_backoff(retries - 1);
-                                eprintln!(
+                                tracing::warn!(
                                     "download: read error in chunk @{} ({}) — retry {}/{} in {}",
                                     fmt_bytes(start),
                                     detail,
@@ -1002,7 +1002,7 @@ fn download_parallel(
                 String::new()
             };
             let ts = chrono::Local::now().format("%H:%M:%S");
-            eprintln!(
+            tracing::info!(
                 "[{ts}] download: {} / {} ({:.1}%) @ {}/s elapsed {}{}",
                 fmt_bytes(total_written),
                 fmt_bytes(total),
@@ -1043,7 +1043,7 @@ fn download_parallel(
     }
 
     let ts = chrono::Local::now().format("%H:%M:%S");
-    eprintln!(
+    tracing::info!(
         "[{ts}] download: complete — {} in {} ({} parallel connections)",
         fmt_bytes(total),
         fmt_duration_short(overall_start.elapsed()),
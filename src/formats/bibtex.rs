@@ -4,6 +4,8 @@ use biblatex::{
     Bibliography, Chunk, ChunksExt, DateValue, Entry, EntryType, PermissiveType,
     Person as BibPerson, Spanned,
 };
+use lazy_static::lazy_static;
+use regex::Regex;
 
 use crate::author_utils::normalize_contributor_roles;
 use crate::data::{Container, Contributor, Data, Description, Organization, Person, Title};
@@ -74,6 +76,39 @@ fn container_type_for(entry_type: &EntryType) -> &'static str {
 
 // ─── Reader helpers ───────────────────────────────────────────────────────────
 
+lazy_static! {
+    static ref FORMATTING_COMMAND: Regex = Regex::new(
+        r"\\(?:emph|text(?:it|bf|sc|tt|rm|sf|sl|up|md|normal|superscript|subscript)|mbox|url|mkbibquote|mkbibemph)\s*\{([^{}]*)\}"
+    )
+    .unwrap();
+}
+
+/// Strip the LaTeX markup that `biblatex` leaves in verbatim text.
+///
+/// `biblatex` already resolves accent and symbol commands (`{\"o}`, `\ss`,
+/// `\&`) and protective braces; what remains are formatting commands such
+/// as `\emph{…}`, non-breaking spaces and TeX-style quotes.
+fn delatex(text: &str) -> String {
+    let mut text = text.to_string();
+    // Formatting commands may be nested, e.g. `\textbf{\emph{x}}`.
+    loop {
+        let replaced = FORMATTING_COMMAND.replace_all(&text, "$1").into_owned();
+        if replaced == text {
+            break;
+        }
+        text = replaced;
+    }
+    text.replace("\\ldots", "…")
+        .replace("\\dots", "…")
+        .replace("``", "\u{201c}")
+        .replace("''", "\u{201d}")
+        .replace('~', " ")
+        .replace(['{', '}'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 fn person_to_contributor(person: BibPerson, role: &str) -> Contributor {
     // Organizations are wrapped in extra braces by the writer, e.g. `{ACME Corp}`.
     let roles = normalize_contributor_roles(&[role.to_string()], role);
@@ -82,16 +117,16 @@ fn person_to_contributor(person: BibPerson, role: &str) -> Contributor {
             || person.name.contains(' '))
     {
         let org_name = if person.name.starts_with('{') {
-            person.name[1..person.name.len() - 1].to_string()
+            delatex(&person.name[1..person.name.len() - 1])
         } else {
-            person.name
+            delatex(&person.name)
         };
         Contributor::organization(Organization { name: org_name, ..Default::default() }, roles)
     } else {
         Contributor::person(
             Person {
-                given_name: person.given_name,
-                family_name: person.name,
+                given_name: delatex(&person.given_name),
+                family_name: delatex(&person.name),
                 ..Default::default()
             },
             roles,
@@ -125,6 +160,17 @@ pub fn read(input: &str) -> Result<Data> {
     from_entry(entry)
 }
 
+/// Parse a `.bib` file and return every entry as a [`Data`] record, in
+/// file order. `@string` abbreviations and `crossref` inheritance are
+/// resolved by `biblatex`.
+pub fn read_all(input: &str) -> Result<Vec<Data>> {
+    let bib = Bibliography::parse(input).map_err(|e| Error::Parse(e.to_string()))?;
+    if bib.is_empty() {
+        return Err(Error::Parse("no entries found in BibTeX input".to_string()));
+    }
+    bib.iter().map(from_entry).collect()
+}
+
 fn from_entry(entry: &Entry) -> Result<Data> {
     let mut data = Data {
         type_: bib_to_cm_type(&entry.entry_type).to_string(),
@@ -158,13 +204,13 @@ fn from_entry(entry: &Entry) -> Result<Data> {
 
     // Titles
     if let Ok(title_chunks) = entry.title() {
-        let text = title_chunks.format_verbatim();
+        let text = delatex(&title_chunks.format_verbatim());
         if !text.is_empty() {
             data.title = text;
         }
     }
     if let Ok(sub_chunks) = entry.subtitle() {
-        let text = sub_chunks.format_verbatim();
+        let text = delatex(&sub_chunks.format_verbatim());
         if !text.is_empty() {
             data.additional_titles.push(Title {
                 title: text,
@@ -201,7 +247,7 @@ fn from_entry(entry: &Entry) -> Result<Data> {
 
     // Abstract
     if let Ok(abs_chunks) = entry.abstract_() {
-        let text = abs_chunks.format_verbatim();
+        let text = delatex(&abs_chunks.format_verbatim());
         if !text.is_empty() {
             data.description = text;
         }
@@ -209,7 +255,7 @@ fn from_entry(entry: &Entry) -> Result<Data> {
 
     // Note → additional description
     if let Ok(note_chunks) = entry.note() {
-        let text = note_chunks.format_verbatim();
+        let text = delatex(&note_chunks.format_verbatim());
         if !text.is_empty() {
             data.additional_descriptions.push(Description {
                 description: text,
@@ -230,13 +276,13 @@ fn from_entry(entry: &Entry) -> Result<Data> {
     // Publisher / institution
     if let Ok(pubs) = entry.publisher() {
         if let Some(pub_chunks) = pubs.into_iter().next() {
-            let name = pub_chunks.format_verbatim();
+            let name = delatex(&pub_chunks.format_verbatim());
             if !name.is_empty() {
                 data.publisher.name = name;
             }
         }
     } else if let Ok(inst_chunks) = entry.institution() {
-        let name = inst_chunks.format_verbatim();
+        let name = delatex(&inst_chunks.format_verbatim());
         if !name.is_empty() {
             data.publisher.name = name;
         }
@@ -263,13 +309,13 @@ fn from_entry(entry: &Entry) -> Result<Data> {
     let container_title = entry
         .journal()
         .ok()
-        .map(|c| c.format_verbatim())
+        .map(|c| delatex(&c.format_verbatim()))
         .filter(|s| !s.is_empty())
         .or_else(|| {
             entry
                 .book_title()
                 .ok()
-                .map(|c| c.format_verbatim())
+                .map(|c| delatex(&c.format_verbatim()))
                 .filter(|s| !s.is_empty())
         })
         .unwrap_or_default();
@@ -547,6 +593,58 @@ mod tests {
         assert!(matches!(cm_to_bib_type("Unknown"), EntryType::Misc));
     }
 
+    #[test]
+    fn test_delatex() {
+        assert_eq!(
+            delatex(r"The \emph{Drosophila}~genome: \textbf{\textit{nested}} ``quotes''"),
+            "The Drosophila genome: nested \u{201c}quotes\u{201d}"
+        );
+        assert_eq!(delatex("Plain {DNA} title"), "Plain DNA title");
+    }
+
+    #[test]
+    fn test_read_all_multiple_entries() {
+        let input = r#"
+@string{jbio = "Journal of Biology"}
+
+@article{mueller2020,
+  title = {Gene {\"U}bersicht in \emph{E.~coli}},
+  author = {M{\"u}ller, J{\"o}rg and Fran{\c c}ois, {\'E}mile},
+  journal = jbio,
+  year = {2020},
+  doi = {10.5555/12345678}
+}
+
+@book{smith2021,
+  title = {A Book},
+  author = {Smith, Jane},
+  publisher = {Acme},
+  year = {2021}
+}
+"#;
+        let list = read_all(input).unwrap();
+        assert_eq!(list.len(), 2);
+
+        let article = &list[0];
+        assert_eq!(article.type_, "JournalArticle");
+        assert_eq!(article.id, "https://doi.org/10.5555/12345678");
+        assert_eq!(article.title, "Gene Übersicht in E. coli");
+        assert_eq!(article.container.title, "Journal of Biology");
+        assert_eq!(article.contributors[0].family_name(), "Müller");
+        assert_eq!(article.contributors[0].given_name(), "Jörg");
+        assert_eq!(article.contributors[1].given_name(), "Émile");
+
+        let book = &list[1];
+        assert_eq!(book.type_, "Book");
+        assert_eq!(book.id, "smith2021");
+        assert_eq!(book.publisher.name, "Acme");
+    }
+
+    #[test]
+    fn test_read_all_rejects_empty_input() {
+        assert!(read_all("% just a comment\n").is_err());
+    }
+
     #[test]
     fn test_bare_doi() {
         assert_eq!(bare_doi("https://doi.org/10.1234/foo"), "10.1234/foo");
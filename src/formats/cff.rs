@@ -5,7 +5,8 @@ use crate::author_utils::{
     split_person_name,
 };
 use crate::data::{
-    Affiliation, Contributor, Data, License, Organization, Person, Publisher, Reference, Subject,
+    Affiliation, Contributor, Data, License, Organization, Person, Publisher, Reference, Relation,
+    Subject,
 };
 use crate::doi_utils::normalize_doi;
 use crate::error::{Error, Result};
@@ -203,20 +204,31 @@ fn parse_cff_contributors(authors: &[Value]) -> Vec<Contributor> {
 
 // ── Reference parsing ─────────────────────────────────────────────────────────
 
+/// DOI of a CFF reference: the `doi` key, else the first `identifiers`
+/// entry of type `doi`. Returns the normalized DOI URL or an empty string.
+fn reference_doi(r: &Value) -> String {
+    let direct = val_str_owned(get(r, "doi"));
+    let value = if !direct.is_empty() {
+        direct
+    } else {
+        val_seq(get(r, "identifiers"))
+            .iter()
+            .find(|id| val_str(get(id, "type")) == "doi")
+            .map(|id| val_str_owned(get(id, "value")))
+            .unwrap_or_default()
+    };
+    if value.is_empty() {
+        String::new()
+    } else {
+        normalize_doi(&value)
+    }
+}
+
 fn parse_cff_references(references: &[Value]) -> Vec<Reference> {
     references
         .iter()
         .filter_map(|r| {
-            // CFF spec field is "identifiers"; look for a DOI entry
-            let identifiers = val_seq(get(r, "identifiers"));
-            let doi_entry = identifiers
-                .iter()
-                .find(|id| val_str(get(id, "type")) == "doi")?;
-            let value = val_str(get(doi_entry, "value"));
-            if value.is_empty() {
-                return None;
-            }
-            let id = normalize_doi(value);
+            let id = reference_doi(r);
             if id.is_empty() {
                 return None;
             }
@@ -228,6 +240,61 @@ fn parse_cff_references(references: &[Value]) -> Vec<Reference> {
         .collect()
 }
 
+/// Map a CFF reference `type` to a commonmeta work type.
+fn cff_to_cm_type(type_: &str) -> &'static str {
+    match type_ {
+        "article" | "magazine-article" | "newspaper-article" => "JournalArticle",
+        "blog" => "BlogPost",
+        "book" => "Book",
+        "conference-paper" => "ProceedingsArticle",
+        "conference" | "proceedings" => "Proceedings",
+        "data" | "database" => "Dataset",
+        "manual" | "report" | "standard" => "Report",
+        "pamphlet" | "unpublished" => "Manuscript",
+        "software" | "software-code" | "software-container" | "software-executable"
+        | "software-virtual-machine" => "Software",
+        "thesis" => "Dissertation",
+        "website" => "WebPage",
+        _ => "Other",
+    }
+}
+
+/// Map `preferred-citation`, the work that users should cite instead of
+/// the software itself (usually the paper describing it), to a reference
+/// and, when it has a DOI, an `IsSupplementTo` relation.
+fn parse_preferred_citation(citation: &Value) -> Option<(Reference, Option<Relation>)> {
+    if !matches!(citation, Value::Mapping(_)) {
+        return None;
+    }
+    let id = reference_doi(citation);
+    let url = val_str_owned(get(citation, "url"));
+    let publisher = match get(citation, "journal") {
+        Value::String(s) => s.clone(),
+        _ => val_str_owned(get(get(citation, "publisher"), "name")),
+    };
+    let reference = Reference {
+        id: if id.is_empty() { normalize_id(&url) } else { id.clone() },
+        type_: cff_to_cm_type(val_str(get(citation, "type"))).to_string(),
+        title: val_str_owned(get(citation, "title")),
+        publisher,
+        publication_year: val_str_owned(get(citation, "year")),
+        volume: val_str_owned(get(citation, "volume")),
+        issue: val_str_owned(get(citation, "issue")),
+        first_page: val_str_owned(get(citation, "start")),
+        last_page: val_str_owned(get(citation, "end")),
+        ..Default::default()
+    };
+    if reference.id.is_empty() && reference.title.is_empty() {
+        return None;
+    }
+    let relation = (!id.is_empty()).then(|| Relation {
+        id,
+        type_: "IsSupplementTo".to_string(),
+        ..Default::default()
+    });
+    Some((reference, relation))
+}
+
 // ── Core reader ───────────────────────────────────────────────────────────────
 
 fn from_value(doc: &Value) -> Data {
@@ -309,12 +376,24 @@ fn from_value(doc: &Value) -> Data {
         .filter(|s| !s.subject.is_empty())
         .collect();
 
-    // References
-    let references = parse_cff_references(val_seq(get(doc, "references")));
+    // References, with the preferred citation first
+    let mut references = parse_cff_references(val_seq(get(doc, "references")));
+    let mut relations = Vec::new();
+    if let Some((preferred, relation)) = parse_preferred_citation(get(doc, "preferred-citation")) {
+        references.retain(|r| preferred.id.is_empty() || r.id != preferred.id);
+        references.insert(0, preferred);
+        relations.extend(relation);
+    }
+
+    // Type: CFF distinguishes software (the default) and datasets
+    let type_ = match val_str(get(doc, "type")) {
+        "dataset" => "Dataset",
+        _ => "Software",
+    };
 
     Data {
         id,
-        type_: "Software".to_string(),
+        type_: type_.to_string(),
         url,
         title,
         contributors,
@@ -324,6 +403,7 @@ fn from_value(doc: &Value) -> Data {
         version,
         subjects,
         references,
+        relations,
         publisher,
         ..Data::default()
     }
@@ -349,6 +429,7 @@ pub fn fetch(url: &str) -> Result<Data> {
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", cff_url);
     let text = client
         .get(&cff_url)
         .send()
@@ -477,6 +558,48 @@ references:
         assert_eq!(data.references[0].id, "https://doi.org/10.1000/ref.2024");
     }
 
+    #[test]
+    fn test_cff_preferred_citation() {
+        let cff = r#"
+cff-version: 1.2.0
+title: Test
+type: dataset
+authors:
+  - name: Author
+references:
+  - type: article
+    title: Described in
+    doi: 10.1000/paper.2024
+preferred-citation:
+  type: article
+  title: The paper describing the data
+  journal: Journal of Examples
+  year: 2024
+  volume: 12
+  start: 101
+  end: 110
+  doi: 10.1000/paper.2024
+  authors:
+    - family-names: Smith
+      given-names: Jane
+"#;
+        let data = read_yaml(cff).unwrap();
+        assert_eq!(data.type_, "Dataset");
+        assert_eq!(data.references.len(), 1);
+        let preferred = &data.references[0];
+        assert_eq!(preferred.id, "https://doi.org/10.1000/paper.2024");
+        assert_eq!(preferred.type_, "JournalArticle");
+        assert_eq!(preferred.title, "The paper describing the data");
+        assert_eq!(preferred.publisher, "Journal of Examples");
+        assert_eq!(preferred.publication_year, "2024");
+        assert_eq!(preferred.volume, "12");
+        assert_eq!(preferred.first_page, "101");
+        assert_eq!(preferred.last_page, "110");
+        assert_eq!(data.relations.len(), 1);
+        assert_eq!(data.relations[0].type_, "IsSupplementTo");
+        assert_eq!(data.relations[0].id, "https://doi.org/10.1000/paper.2024");
+    }
+
     #[test]
     fn test_cff_license_list() {
         let cff = r#"
@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use lazy_static::lazy_static;
+use regex::Regex;
 use unic_langid::LanguageIdentifier;
 
 use hayagriva::archive::{ArchivedStyle, locales};
@@ -262,9 +264,9 @@ pub fn write(data: &Data, style_name: Option<&str>, locale: Option<&str>) -> Res
     Ok(text.into_bytes())
 }
 
-/// Format a list of bibliography entries as HTML, one rendered entry per line.
+/// Render every entry of `list` as HTML, in bibliography order.
 /// Expensive style and locale setup is shared across the whole list.
-pub fn write_all(list: &[Data], style_name: Option<&str>, locale: Option<&str>) -> Result<Vec<u8>> {
+fn render_all(list: &[Data], style_name: Option<&str>, locale: Option<&str>) -> Result<Vec<String>> {
     if list.is_empty() {
         return Ok(Vec::new());
     }
@@ -292,7 +294,7 @@ pub fn write_all(list: &[Data], style_name: Option<&str>, locale: Option<&str>)
         locale_files: &locale_list,
     });
 
-    let lines: Vec<String> = result
+    Ok(result
         .bibliography
         .map(|bib| {
             bib.items
@@ -306,9 +308,86 @@ pub fn write_all(list: &[Data], style_name: Option<&str>, locale: Option<&str>)
                 })
                 .collect()
         })
-        .unwrap_or_default();
+        .unwrap_or_default())
+}
+
+/// Format a list of bibliography entries as HTML, one rendered entry per line.
+pub fn write_all(list: &[Data], style_name: Option<&str>, locale: Option<&str>) -> Result<Vec<u8>> {
+    Ok(render_all(list, style_name, locale)?.join("\n").into_bytes())
+}
+
+// ─── Reference lists ──────────────────────────────────────────────────────────
 
-    Ok(lines.join("\n").into_bytes())
+lazy_static! {
+    static ref HTML_LINK: Regex = Regex::new(r#"<a href="([^"]*)">(.*?)</a>"#).unwrap();
+    static ref HTML_ITALIC: Regex =
+        Regex::new(r#"<span style="font-style: italic;">(.*?)</span>"#).unwrap();
+    static ref HTML_BOLD: Regex =
+        Regex::new(r#"<span style="font-weight: bold;">(.*?)</span>"#).unwrap();
+    static ref HTML_TAG: Regex = Regex::new(r"<[^>]+>").unwrap();
+}
+
+/// Order a reference list by first author (family name, else display name),
+/// then publication year and title.
+fn sort_by_author_year(list: &[Data]) -> Vec<Data> {
+    let key = |data: &Data| {
+        let author = data
+            .contributors
+            .iter()
+            .find(|c| c.roles.iter().any(|r| r == "Author"))
+            .or_else(|| data.contributors.first())
+            .map(|c| {
+                if c.family_name().is_empty() {
+                    c.name()
+                } else {
+                    c.family_name().to_string()
+                }
+            })
+            .unwrap_or_default()
+            .to_lowercase();
+        let year = data.date_published.get(..4).unwrap_or("").to_string();
+        (author, year, data.title.to_lowercase())
+    };
+    let mut sorted = list.to_vec();
+    sorted.sort_by_cached_key(key);
+    sorted
+}
+
+/// Convert one rendered HTML entry to Markdown: links, italics and bold are
+/// kept, other markup is dropped.
+fn html_to_markdown(html: &str) -> String {
+    let text = HTML_LINK.replace_all(html, "[$2]($1)");
+    let text = HTML_ITALIC.replace_all(&text, "*$1*");
+    let text = HTML_BOLD.replace_all(&text, "**$1**");
+    let text = HTML_TAG.replace_all(&text, "");
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Format `list` as a Markdown reference list, ordered by author and year,
+/// one `- ` list item per entry with DOIs as links.
+pub fn write_markdown(list: &[Data], style_name: Option<&str>, locale: Option<&str>) -> Result<Vec<u8>> {
+    let entries = render_all(&sort_by_author_year(list), style_name, locale)?;
+    let items: Vec<String> = entries
+        .iter()
+        .map(|entry| format!("- {}\n", html_to_markdown(entry)))
+        .collect();
+    Ok(items.concat().into_bytes())
+}
+
+/// Format `list` as an HTML reference list (`<ul class="references">`),
+/// ordered by author and year, with DOIs as links.
+pub fn write_html(list: &[Data], style_name: Option<&str>, locale: Option<&str>) -> Result<Vec<u8>> {
+    let entries = render_all(&sort_by_author_year(list), style_name, locale)?;
+    let mut out = String::from("<ul class=\"references\">\n");
+    for entry in entries {
+        out.push_str(&format!("  <li>{entry}</li>\n"));
+    }
+    out.push_str("</ul>\n");
+    Ok(out.into_bytes())
 }
 
 // ─── Tests ───────────────────────────────────────────────────────────────────
@@ -358,4 +437,40 @@ mod tests {
         );
         assert!(lines[0].contains("Lovelace") || lines[1].contains("Lovelace"));
     }
+
+    #[test]
+    fn write_markdown_links_dois_and_orders_by_author() {
+        let first = load("journal_article.json");
+        let mut second = first.clone();
+        second.contributors[0] = crate::data::Contributor::person(
+            crate::data::Person {
+                given_name: "Charles".to_string(),
+                family_name: "Babbage".to_string(),
+                ..Default::default()
+            },
+            vec!["Author".to_string()],
+        );
+        let out = write_markdown(&[first, second], None, None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2, "{text}");
+        assert!(lines[0].starts_with("- Babbage"), "{text}");
+        assert!(lines[1].starts_with("- Lovelace"), "{text}");
+        assert!(
+            lines[1].contains("[https://doi.org/10.5555/12345678](https://doi.org/10.5555/12345678)"),
+            "{text}"
+        );
+        assert!(lines[1].contains("*Journal of Examples*"), "{text}");
+        assert!(!text.contains('<'), "{text}");
+    }
+
+    #[test]
+    fn write_html_wraps_entries_in_list() {
+        let data = load("journal_article.json");
+        let out = write_html(&[data], None, None).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("<ul class=\"references\">\n  <li>Lovelace"), "{text}");
+        assert!(text.contains("<a href=\"https://doi.org/10.5555/12345678\">"), "{text}");
+        assert!(text.ends_with("</li>\n</ul>\n"), "{text}");
+    }
 }
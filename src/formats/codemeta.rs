@@ -259,6 +259,7 @@ pub fn fetch(url: &str) -> Result<Data> {
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", codemeta_url);
     let mut doc: Value = client
         .get(&codemeta_url)
         .send()
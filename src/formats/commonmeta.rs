@@ -1,3 +1,4 @@
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 use serde::Serialize;
@@ -63,21 +64,127 @@ fn prepare(data: &Data) -> Data {
     out
 }
 
+/// Serialize a prepared record in canonical key order: `schema_version`
+/// first, as the reference to the schema the record validates against,
+/// followed by the properties in the order the v1.0 schema lists them
+/// (`id`, `type`, then alphabetical), which is `Data`'s field order.
+fn to_canonical(data: &Data) -> Result<Value> {
+    let value = serde_json::to_value(prepare(data)).map_err(|e| Error::Serialize(e.to_string()))?;
+    let Value::Object(mut fields) = value else {
+        return Err(Error::Serialize("commonmeta record is not an object".to_string()));
+    };
+    let mut out = serde_json::Map::with_capacity(fields.len());
+    if let Some(schema_version) = fields.remove("schema_version") {
+        out.insert("schema_version".to_string(), schema_version);
+    }
+    out.extend(fields);
+    Ok(Value::Object(out))
+}
+
 pub fn write(data: &Data) -> Result<Vec<u8>> {
-    let out = prepare(data);
+    let out = to_canonical(data)?;
     let bytes = serde_json::to_vec(&out).map_err(|e| Error::Serialize(e.to_string()))?;
     json_schema_errors(&bytes, Some("commonmeta"))?;
     Ok(bytes)
 }
 
 pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
-    let prepared: Vec<Data> = list.iter().map(prepare).collect();
+    let prepared = list.iter().map(to_canonical).collect::<Result<Vec<Value>>>()?;
     let bytes =
         serde_json::to_vec_pretty(&prepared).map_err(|e| Error::Serialize(e.to_string()))?;
     json_schema_errors(&bytes, Some("commonmeta"))?;
     Ok(bytes)
 }
 
+// ── Streaming JSON Lines writer (harvests) ────────────────────────────────────
+
+/// Number of records between flushes of a [`JsonLinesWriter`].
+const JSONL_FLUSH_EVERY: usize = 1_000;
+
+fn io_err(e: std::io::Error) -> Error {
+    if e.kind() == std::io::ErrorKind::StorageFull {
+        Error::DiskFull(e.to_string())
+    } else {
+        Error::Serialize(e.to_string())
+    }
+}
+
+/// Writes commonmeta records as JSON Lines (one compact, canonically ordered
+/// record per line) as they arrive, flushing every few records, so a
+/// harvest never has to be held in memory before it is written. Unlike
+/// [`write`], records are not validated against the schema.
+pub struct JsonLinesWriter<W: Write> {
+    inner: BufWriter<W>,
+    flush_every: usize,
+    count: usize,
+}
+
+impl<W: Write> JsonLinesWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self::with_flush_interval(inner, JSONL_FLUSH_EVERY)
+    }
+
+    /// Like [`JsonLinesWriter::new`], flushing after every `flush_every`
+    /// records (at least one).
+    pub fn with_flush_interval(inner: W, flush_every: usize) -> Self {
+        JsonLinesWriter {
+            inner: BufWriter::new(inner),
+            flush_every: flush_every.max(1),
+            count: 0,
+        }
+    }
+
+    /// Append one record.
+    pub fn write(&mut self, data: &Data) -> Result<()> {
+        serde_json::to_writer(&mut self.inner, &to_canonical(data)?)
+            .map_err(|e| Error::Serialize(e.to_string()))?;
+        self.inner.write_all(b"\n").map_err(io_err)?;
+        self.count += 1;
+        if self.count.is_multiple_of(self.flush_every) {
+            self.inner.flush().map_err(io_err)?;
+        }
+        Ok(())
+    }
+
+    /// Number of records written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Flush any buffered records and return the underlying writer.
+    pub fn finish(self) -> Result<W> {
+        self.inner.into_inner().map_err(|e| io_err(e.into_error()))
+    }
+}
+
+/// Write `list` as JSON Lines into memory; see [`JsonLinesWriter`] for the
+/// streaming variant.
+pub fn write_jsonl(list: &[Data]) -> Result<Vec<u8>> {
+    let mut writer = JsonLinesWriter::new(Vec::new());
+    for data in list {
+        writer.write(data)?;
+    }
+    writer.finish()
+}
+
+/// Serialize `data` as YAML, in the same canonical key order as [`write`].
+/// Easier to review in diffs than JSON; the record is schema-validated first.
+pub fn write_yaml(data: &Data) -> Result<Vec<u8>> {
+    write(data)?;
+    serde_yaml::to_string(&to_canonical(data)?)
+        .map(String::into_bytes)
+        .map_err(|e| Error::Serialize(e.to_string()))
+}
+
+/// Serialize `list` as a YAML sequence of records.
+pub fn write_yaml_all(list: &[Data]) -> Result<Vec<u8>> {
+    write_all(list)?;
+    let prepared = list.iter().map(to_canonical).collect::<Result<Vec<Value>>>()?;
+    serde_yaml::to_string(&prepared)
+        .map(String::into_bytes)
+        .map_err(|e| Error::Serialize(e.to_string()))
+}
+
 fn looks_like_v1(value: &Value) -> bool {
     let Some(obj) = value.as_object() else {
         return false;
@@ -802,6 +909,30 @@ mod tests {
         assert_eq!(roundtripped[0].subjects.len(), 2);
     }
 
+    #[test]
+    fn test_write_canonical_key_order() {
+        let bytes = write(&sample_data()).unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        let keys: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        assert_eq!(
+            keys,
+            ["schema_version", "id", "type", "contributors", "identifiers", "title"]
+        );
+        assert_eq!(value["schema_version"], COMMONMETA_V1_SCHEMA_URL);
+        // identical input always serializes to identical bytes
+        assert_eq!(bytes, write(&sample_data()).unwrap());
+        assert_eq!(read(std::str::from_utf8(&bytes).unwrap()).unwrap().title, "A Sample Title");
+    }
+
+    #[test]
+    fn test_write_all_canonical_key_order() {
+        let bytes = write_all(&[sample_data(), sample_data()]).unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        for record in value.as_array().unwrap() {
+            assert_eq!(record.as_object().unwrap().keys().next().unwrap(), "schema_version");
+        }
+    }
+
     #[test]
     fn test_read_parquet_all_empty() {
         let bytes = write_parquet_all(&[]).unwrap();
@@ -877,4 +1008,68 @@ mod tests {
 
         std::fs::remove_dir_all(&dir).ok();
     }
+
+    #[test]
+    fn test_write_jsonl_one_record_per_line() {
+        let mut second = sample_data();
+        second.id = "https://doi.org/10.1234/def".to_string();
+        let bytes = write_jsonl(&[sample_data(), second]).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(r#"{"schema_version":"#));
+        assert_eq!(read(lines[1]).unwrap().id, "https://doi.org/10.1234/def");
+    }
+
+    #[test]
+    fn test_jsonl_writer_flushes_incrementally() {
+        #[derive(Default)]
+        struct Sink {
+            bytes: Vec<u8>,
+            flushed: Vec<usize>,
+        }
+        impl Write for Sink {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.bytes.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.flushed.push(self.bytes.iter().filter(|b| **b == b'\n').count());
+                Ok(())
+            }
+        }
+
+        let mut writer = JsonLinesWriter::with_flush_interval(Sink::default(), 2);
+        for _ in 0..5 {
+            writer.write(&sample_data()).unwrap();
+        }
+        assert_eq!(writer.count(), 5);
+        let sink = writer.finish().unwrap();
+        assert_eq!(sink.flushed[..2], [2, 4]);
+        assert_eq!(sink.bytes.iter().filter(|b| **b == b'\n').count(), 5);
+    }
+
+    #[test]
+    fn test_write_yaml_canonical_order() {
+        let yaml = String::from_utf8(write_yaml(&sample_data()).unwrap()).unwrap();
+        let keys: Vec<&str> = yaml
+            .lines()
+            .filter(|l| !l.starts_with(' ') && !l.starts_with('-'))
+            .filter_map(|l| l.split(':').next())
+            .collect();
+        assert_eq!(keys[..3], ["schema_version", "id", "type"]);
+        assert!(yaml.contains("title: A Sample Title"));
+
+        let value: Value = serde_yaml::from_str(&yaml).unwrap();
+        let round: Data = serde_json::from_value(value).unwrap();
+        assert_eq!(round.contributors[0].family_name(), "Doe");
+    }
+
+    #[test]
+    fn test_write_yaml_all_is_sequence() {
+        let yaml = String::from_utf8(write_yaml_all(&[sample_data(), sample_data()]).unwrap()).unwrap();
+        let value: Vec<Value> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(value.len(), 2);
+        assert!(yaml.starts_with("- schema_version:"));
+    }
 }
@@ -11,6 +11,7 @@ use crate::data::{
     Person, Publisher, Reference, Subject, Title,
 };
 use crate::constants as C;
+use crate::doi_utils::PrefixDoi;
 use crate::error::{Error, Result};
 use crate::utils::normalize_id;
 
@@ -34,11 +35,6 @@ fn null_to_string_vec<'de, D: Deserializer<'de>>(
 
 // ─── Crossref API structs ────────────────────────────────────────────────────
 
-#[derive(Deserialize)]
-struct CrossrefResponse {
-    message: CrossrefWork,
-}
-
 #[allow(dead_code)]
 #[derive(Deserialize)]
 struct CrossrefListResponse {
@@ -609,11 +605,21 @@ fn from_work(w: CrossrefWork) -> Data {
 
 // ─── Public API ───────────────────────────────────────────────────────────────
 
-/// Parse a Crossref API JSON response (the full `{"status":"ok","message":{...}}` envelope).
+/// Parse a Crossref API work as JSON: either the full
+/// `{"status":"ok","message":{...}}` envelope returned by
+/// `https://api.crossref.org/works/{doi}`, or the bare work object (an item
+/// of a list response, or the output of `jq .message`).
 pub fn read_json(json: &str) -> Result<Data> {
-    let r: CrossrefResponse =
+    let mut value: serde_json::Value =
         serde_json::from_str(json).map_err(|e| Error::Parse(e.to_string()))?;
-    Ok(from_work(r.message))
+    let work = if value.get("message").is_some() {
+        value["message"].take()
+    } else {
+        value
+    };
+    let work: CrossrefWork =
+        serde_json::from_value(work).map_err(|e| Error::Parse(e.to_string()))?;
+    Ok(from_work(work))
 }
 
 /// Fetch a work from the Crossref REST API by DOI and convert it to `Data`.
@@ -631,6 +637,7 @@ pub fn fetch(doi: &str) -> Result<Data> {
         ))
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
     let json = client
         .get(&url)
         .send()
@@ -734,6 +741,7 @@ pub(crate) fn get_all(
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", url);
     let json = client
         .get(&url)
         .header("Cache-Control", "private")
@@ -964,6 +972,7 @@ pub fn fetch_page_with_cursor(
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", url);
     let json = client
         .get(&url)
         .header("Cache-Control", "private")
@@ -981,6 +990,90 @@ pub fn fetch_page_with_cursor(
     Ok((data, next_cursor))
 }
 
+#[derive(Deserialize)]
+struct PrefixResponse {
+    message: PrefixMessage,
+}
+
+#[derive(Deserialize)]
+struct PrefixMessage {
+    #[serde(default)]
+    items: Vec<PrefixItem>,
+    #[serde(rename = "next-cursor")]
+    next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PrefixItem {
+    #[serde(rename = "DOI", default, deserialize_with = "null_to_string")]
+    doi: String,
+    #[serde(rename = "type", default, deserialize_with = "null_to_string")]
+    type_: String,
+    #[serde(default)]
+    created: Option<CrossrefDate>,
+}
+
+/// Fetch one page of the DOIs registered under `prefix`, `rows` at a time.
+/// Pass `None` for the first page and the returned cursor for the next;
+/// the cursor is `None` after the last page.
+pub fn fetch_prefix_page(
+    prefix: &str,
+    cursor: Option<&str>,
+    rows: usize,
+) -> Result<(Vec<PrefixDoi>, Option<String>)> {
+    let url = Url::parse_with_params(
+        &format!("https://api.crossref.org/prefixes/{}/works", prefix),
+        &[
+            ("rows", rows.to_string().as_str()),
+            ("cursor", cursor.unwrap_or("*")),
+            ("select", "DOI,type,created"),
+        ],
+    )
+    .map_err(|e| Error::InvalidId(e.to_string()))?;
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+
+    tracing::debug!("GET {}", url);
+    let json = client
+        .get(url)
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    read_prefix_page(&json, rows)
+}
+
+fn read_prefix_page(json: &str, rows: usize) -> Result<(Vec<PrefixDoi>, Option<String>)> {
+    let response: PrefixResponse =
+        serde_json::from_str(json).map_err(|e| Error::Parse(e.to_string()))?;
+    let items: Vec<PrefixDoi> = response
+        .message
+        .items
+        .into_iter()
+        .map(|item| PrefixDoi {
+            doi: normalize_doi_url(&item.doi),
+            type_: C::cr_to_cm(&item.type_).to_string(),
+            created: item
+                .created
+                .and_then(|c| c.date_time)
+                .map(|d| d.chars().take(10).collect())
+                .unwrap_or_default(),
+        })
+        .collect();
+    // Crossref hands out a cursor even after the last page.
+    let next_cursor = response.message.next_cursor.filter(|_| items.len() == rows);
+    Ok((items, next_cursor))
+}
+
 // ─── Writer ───────────────────────────────────────────────────────────────────
 
 #[derive(Serialize)]
@@ -1631,4 +1724,36 @@ mod tests {
         assert_eq!(data.id, "https://example.org/article");
         assert!(data.identifiers.is_empty());
     }
+
+    #[test]
+    fn test_read_json_accepts_bare_work_without_envelope() {
+        let json = r#"{
+            "DOI":"10.1/a",
+            "type":"journal-article",
+            "title":["A Title"]
+        }"#;
+        let data = read_json(json).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.1/a");
+        assert_eq!(data.title, "A Title");
+    }
+
+    #[test]
+    fn test_read_prefix_page() {
+        let json = r#"{"message":{
+            "next-cursor":"DnF1ZXJ5VGhlbkZldGNo",
+            "items":[
+                {"DOI":"10.53731/r79z0kh-97aq74v-ag578","type":"posted-content",
+                 "created":{"date-time":"2022-09-14T09:12:45Z"}},
+                {"DOI":"10.53731/abc","type":"journal-article"}
+            ]
+        }}"#;
+        let (items, cursor) = read_prefix_page(json, 2).unwrap();
+        assert_eq!(items[0].doi, "https://doi.org/10.53731/r79z0kh-97aq74v-ag578");
+        assert_eq!(items[0].type_, "Article");
+        assert_eq!(items[0].created, "2022-09-14");
+        assert_eq!(items[1].created, "");
+        assert_eq!(cursor.as_deref(), Some("DnF1ZXJ5VGhlbkZldGNo"));
+        // A short page is the last one.
+        assert_eq!(read_prefix_page(json, 1000).unwrap().1, None);
+    }
 }
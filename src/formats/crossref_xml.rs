@@ -1,6 +1,5 @@
 use chrono::Utc;
 use quick_xml::de::from_str as xml_from_str;
-use quick_xml::se::Serializer;
 use rand::RngExt;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +15,7 @@ use crate::utils::{
     community_slug_as_url, dedupe_slice, issn_as_url, normalize_cc_url, normalize_orcid,
     normalize_ror, sanitize, title_case, validate_id,
 };
+use super::xml_output::{XmlOptions, serialize_xml};
 
 // ── XML output structs ────────────────────────────────────────────────────────
 // Field names drive XML element names; `@` prefix = attribute; `$text` = char data.
@@ -753,16 +753,23 @@ fn build_relations_program(data: &Data) -> Option<RelProgram> {
             continue;
         }
 
-        // Determine identifier type
-        let (id, id_type_raw) = validate_id(&rel.id);
-        let identifier_type = if id_type_raw == "URL" {
-            "uri".to_string()
-        } else if id_type_raw == "DOI" {
-            "doi".to_string()
-        } else if id_type_raw == "ISSN" {
-            "issn".to_string()
-        } else {
-            id_type_raw.to_lowercase()
+        // Determine identifier type; types Crossref doesn't know (SWHID,
+        // VIAF, GND, ...) are written as their URL.
+        let (mut id, id_type_raw) = validate_id(&rel.id);
+        let identifier_type = match id_type_raw {
+            "URL" => "uri".to_string(),
+            "DOI" => "doi".to_string(),
+            // RAiDs are DOIs, legacy RAiDs handles.
+            "RAID" => if validate_doi(&id).is_some() { "doi" } else { "handle" }.to_string(),
+            "ISSN" | "ISBN" | "PMID" | "PMCID" | "arXiv" | "ARK" | "UUID" | "" => {
+                id_type_raw.to_lowercase()
+            }
+            _ => {
+                id = crate::utils::Identifier::parse(&rel.id)
+                    .map(|identifier| identifier.to_url())
+                    .unwrap_or_default();
+                "uri".to_string()
+            }
         };
 
         if id.is_empty() {
@@ -1059,8 +1066,23 @@ fn convert(data: &Data) -> Body {
     body
 }
 
-fn build_doi_batch(body: Body) -> DoiBatch {
+/// Depositor and registrant recorded in the `<head>` of a deposit. Crossref
+/// requires all three; an empty `registrant` falls back to the publisher of
+/// the first record.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepositHead {
+    pub depositor: String,
+    pub email: String,
+    pub registrant: String,
+}
+
+fn build_doi_batch(body: Body, head: &DepositHead, publisher: &str) -> DoiBatch {
     let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let registrant = if head.registrant.is_empty() {
+        publisher
+    } else {
+        &head.registrant
+    };
     DoiBatch {
         xmlns: "http://www.crossref.org/schema/5.4.0",
         xmlns_ai: "http://www.crossref.org/AccessIndicators.xsd",
@@ -1071,34 +1093,28 @@ fn build_doi_batch(body: Body) -> DoiBatch {
             doi_batch_id: generate_batch_id(),
             timestamp,
             depositor: Depositor {
-                depositor_name: String::new(),
-                email_address: String::new(),
+                depositor_name: head.depositor.clone(),
+                email_address: head.email.clone(),
             },
-            registrant: String::new(),
+            registrant: registrant.to_string(),
         },
         body,
     }
 }
 
-fn serialize_doi_batch(doi_batch: DoiBatch) -> Result<Vec<u8>> {
-    let mut buf = String::new();
-    let mut ser = Serializer::new(&mut buf);
-    ser.indent(' ', 2);
-    doi_batch
-        .serialize(ser)
-        .map_err(|e| Error::Serialize(e.to_string()))?;
-
-    let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", buf);
-    Ok(xml.into_bytes())
-}
-
 // ── Public write function ─────────────────────────────────────────────────────
 
 pub fn write(data: &Data) -> Result<Vec<u8>> {
-    serialize_doi_batch(build_doi_batch(convert(data)))
+    write_deposit(std::slice::from_ref(data), &DepositHead::default(), &XmlOptions::default())
 }
 
 pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
+    write_deposit(list, &DepositHead::default(), &XmlOptions::default())
+}
+
+/// Write `list` as a single Crossref deposit (`doi_batch`) with `head` as
+/// its depositor and registrant, laid out according to `options`.
+pub fn write_deposit(list: &[Data], head: &DepositHead, options: &XmlOptions) -> Result<Vec<u8>> {
     let mut body = Body::default();
     for data in list {
         let part = convert(data);
@@ -1106,7 +1122,8 @@ pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
         body.journal.extend(part.journal);
         body.dissertation.extend(part.dissertation);
     }
-    serialize_doi_batch(build_doi_batch(body))
+    let publisher = list.first().map(|d| d.publisher.name.as_str()).unwrap_or("");
+    serialize_xml(build_doi_batch(body, head, publisher), options)
 }
 
 // ── XML input structs (Crossref API "unixsd" format) ─────────────────────────
@@ -1162,6 +1179,44 @@ struct XmlDOIRecord {
     crossref: XmlContent,
 }
 
+// ── XML input structs (Crossref deposit "doi_batch" format) ──────────────────
+// A deposit carries no query envelope: the work type is implied by the body
+// element and the DOI lives in `doi_data`. A batch may hold several works.
+
+#[derive(Deserialize, Default, Clone)]
+struct XmlDoiBatch {
+    #[serde(default)]
+    head: XmlDepositHead,
+    #[serde(default)]
+    body: XmlDepositBody,
+}
+
+#[derive(Deserialize, Default, Clone)]
+struct XmlDepositHead {
+    #[serde(default)]
+    registrant: String,
+}
+
+#[derive(Deserialize, Default, Clone)]
+struct XmlDepositBody {
+    #[serde(default)]
+    journal: Vec<XmlJournal>,
+    #[serde(default)]
+    posted_content: Vec<XmlPostedContent>,
+    #[serde(default)]
+    dissertation: Vec<XmlDissertation>,
+    #[serde(default)]
+    book: Vec<XmlBook>,
+    #[serde(default)]
+    conference: Vec<XmlConference>,
+    #[serde(default)]
+    database: Vec<XmlDatabase>,
+    #[serde(default)]
+    peer_review: Vec<XmlPeerReview>,
+    #[serde(default)]
+    sa_component: Vec<XmlSAComponent>,
+}
+
 #[derive(Deserialize, Default, Clone)]
 struct XmlContent {
     #[serde(default)]
@@ -1600,7 +1655,6 @@ struct XmlPublisherItem {
 
 #[derive(Deserialize, Default, Clone)]
 struct XmlDOIData {
-    #[allow(dead_code)]
     #[serde(default)]
     doi: String,
     #[serde(default)]
@@ -2508,7 +2562,6 @@ fn extract_titles_from_meta(meta: &XmlContent, type_str: &str) -> (String, Strin
 
 // ── Public read / fetch ───────────────────────────────────────────────────────
 
-/// Parse a Crossref XML API response (the full `crossref_result` envelope).
 /// Normalize namespace-prefixed elements that all map to the same serde field.
 /// quick-xml 0.37 strips namespace prefixes during deserialization, but its serde
 /// struct deserializer raises "duplicate field" when different prefixed tags (`fr:program`,
@@ -2523,7 +2576,17 @@ fn normalize_program_namespaces(xml: &str) -> String {
         .replace("</rel:program>", "</program>")
 }
 
+/// Parse Crossref XML: either a Crossref API response (the full
+/// `crossref_result` envelope) or a deposit (`doi_batch`). For deposits
+/// holding several works only the first is returned; use [`read_xml_all`]
+/// to read all of them.
 pub fn read_xml(input: &str) -> Result<Data> {
+    if is_deposit(input) {
+        return read_xml_all(input)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Parse("Crossref deposit contains no works".to_string()));
+    }
     let normalized = normalize_program_namespaces(input);
     let result: XmlCrossrefResult =
         xml_from_str(&normalized).map_err(|e| Error::Parse(e.to_string()))?;
@@ -2533,6 +2596,132 @@ pub fn read_xml(input: &str) -> Result<Data> {
     Ok(from_query(result.query_result.body.query))
 }
 
+/// Parse every work in a Crossref deposit (`doi_batch`), in document order.
+/// A Crossref API response always yields a single record.
+pub fn read_xml_all(input: &str) -> Result<Vec<Data>> {
+    if !is_deposit(input) {
+        return read_xml(input).map(|data| vec![data]);
+    }
+    let normalized = normalize_program_namespaces(input);
+    let batch: XmlDoiBatch =
+        xml_from_str(&normalized).map_err(|e| Error::Parse(e.to_string()))?;
+    Ok(deposit_queries(batch).into_iter().map(from_query).collect())
+}
+
+/// True when the root element is a deposit `doi_batch` rather than an API
+/// `crossref_result`.
+fn is_deposit(input: &str) -> bool {
+    let root = input
+        .split('<')
+        .skip(1)
+        .find(|tag| !tag.starts_with('?') && !tag.starts_with('!'))
+        .unwrap_or_default();
+    root.starts_with("doi_batch")
+}
+
+/// Wrap each work of a deposit in a synthetic resolved query, so deposits
+/// share the API-response conversion in [`from_query`]. The deposit's
+/// registrant stands in for the publisher name.
+fn deposit_queries(batch: XmlDoiBatch) -> Vec<XmlQuery> {
+    let body = batch.body;
+    let mut works: Vec<(&str, String, XmlContent)> = Vec::new();
+
+    for j in body.journal {
+        let doi = j.journal_article.doi_data.doi.clone();
+        let content = XmlContent {
+            journal: Some(j),
+            ..Default::default()
+        };
+        works.push(("journal_article", doi, content));
+    }
+    for pc in body.posted_content {
+        let doi = pc.doi_data.doi.clone();
+        let content = XmlContent {
+            posted_content: Some(pc),
+            ..Default::default()
+        };
+        works.push(("posted_content", doi, content));
+    }
+    for d in body.dissertation {
+        let doi = d.doi_data.doi.clone();
+        let content = XmlContent {
+            dissertation: Some(d),
+            ..Default::default()
+        };
+        works.push(("dissertation", doi, content));
+    }
+    for b in body.book {
+        let (doi_type, doi) = match &b.content_item {
+            Some(ci) => ("book_content", ci.doi_data.doi.clone()),
+            None => ("book_title", b.book_metadata.doi_data.doi.clone()),
+        };
+        let content = XmlContent {
+            book: Some(b),
+            ..Default::default()
+        };
+        works.push((doi_type, doi, content));
+    }
+    for c in body.conference {
+        let doi = c.conference_paper.doi_data.doi.clone();
+        let content = XmlContent {
+            conference: Some(c),
+            ..Default::default()
+        };
+        works.push(("conference_paper", doi, content));
+    }
+    for db in body.database {
+        let doi = db.dataset.doi_data.doi.clone();
+        let content = XmlContent {
+            database: Some(db),
+            ..Default::default()
+        };
+        works.push(("dataset", doi, content));
+    }
+    for pr in body.peer_review {
+        let doi = pr.doi_data.doi.clone();
+        let content = XmlContent {
+            peer_review: Some(pr),
+            ..Default::default()
+        };
+        works.push(("peer_review", doi, content));
+    }
+    for sa in body.sa_component {
+        let doi = sa
+            .component_list
+            .component
+            .first()
+            .map(|c| c.doi_data.doi.clone())
+            .unwrap_or_default();
+        let content = XmlContent {
+            sa_component: Some(sa),
+            ..Default::default()
+        };
+        works.push(("component", doi, content));
+    }
+
+    let crm_items = if batch.head.registrant.is_empty() {
+        Vec::new()
+    } else {
+        vec![XmlCrmItem {
+            name: "publisher-name".to_string(),
+            text: batch.head.registrant.clone(),
+        }]
+    };
+
+    works
+        .into_iter()
+        .map(|(doi_type, doi, crossref)| XmlQuery {
+            status: "resolved".to_string(),
+            doi: XmlDoi {
+                type_: doi_type.to_string(),
+                text: doi,
+            },
+            crm_items: crm_items.clone(),
+            doi_record: XmlDOIRecord { crossref },
+        })
+        .collect()
+}
+
 /// Fetch a work from the Crossref XML API by DOI.
 pub fn fetch(doi: &str) -> Result<Data> {
     let bare = doi
@@ -2551,6 +2740,7 @@ pub fn fetch(doi: &str) -> Result<Data> {
         ))
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
     let xml = client
         .get(&url)
         .send()
@@ -2562,10 +2752,241 @@ pub fn fetch(doi: &str) -> Result<Data> {
     read_xml(&xml)
 }
 
+// ── Deposit ───────────────────────────────────────────────────────────────────
+
+/// Crossref account used to deposit, and whether to deposit with the test
+/// system at test.crossref.org instead of registering real DOIs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepositLogin {
+    pub username: String,
+    pub password: String,
+    pub test: bool,
+}
+
+impl DepositLogin {
+    fn host(&self) -> &'static str {
+        if self.test {
+            "https://test.crossref.org"
+        } else {
+            "https://doi.crossref.org"
+        }
+    }
+}
+
+/// Outcome of a deposit, as reported by the Crossref submission log.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DepositResult {
+    /// Name the deposit was uploaded under, to look it up again later.
+    pub file_name: String,
+    /// "completed", or "queued", "in_process" or "unknown_submission" while
+    /// Crossref is still working on it.
+    pub status: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub submission_id: String,
+    pub record_count: usize,
+    pub success_count: usize,
+    pub warning_count: usize,
+    pub failure_count: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub records: Vec<DepositRecord>,
+}
+
+/// Result for a single DOI of a deposit.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DepositRecord {
+    pub doi: String,
+    /// "Success", "Warning" or "Failure".
+    pub status: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub message: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct XmlBatchDiagnostic {
+    #[serde(rename = "@status")]
+    status: String,
+    submission_id: String,
+    record_diagnostic: Vec<XmlRecordDiagnostic>,
+    batch_data: XmlBatchData,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct XmlRecordDiagnostic {
+    #[serde(rename = "@status")]
+    status: String,
+    doi: String,
+    msg: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct XmlBatchData {
+    record_count: usize,
+    success_count: usize,
+    warning_count: usize,
+    failure_count: usize,
+}
+
+/// Upload `xml` (a deposit written by [`write_deposit`]) to Crossref and
+/// wait for the submission log to report the outcome, checking every
+/// `poll_interval` for up to `timeout`. A deposit Crossref hasn't finished
+/// by then is returned with its current status.
+pub fn deposit(
+    xml: &[u8],
+    login: &DepositLogin,
+    poll_interval: std::time::Duration,
+    timeout: std::time::Duration,
+) -> Result<DepositResult> {
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    let file_name = format!("commonmeta-{}.xml", generate_batch_id());
+
+    let boundary = format!("commonmeta-{}", generate_batch_id());
+    let fields = [
+        ("operation", "doMDUpload"),
+        ("login_id", login.username.as_str()),
+        ("login_passwd", login.password.as_str()),
+    ];
+    let url = format!("{}/servlet/deposit", login.host());
+    tracing::debug!("POST {}", url);
+    let resp = client
+        .post(&url)
+        .header(
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .body(multipart_body(&boundary, &fields, &file_name, xml))
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    let status = resp.status();
+    let text = resp.text().map_err(|e| Error::Http(e.to_string()))?;
+    if !status.is_success() || !text.contains("SUCCESS") {
+        return Err(Error::Http(format!(
+            "Crossref rejected the deposit ({}): {}",
+            status,
+            strip_tags(&text)
+        )));
+    }
+    tracing::info!("Deposited {} with Crossref, waiting for the submission log", file_name);
+
+    let log_url = url::Url::parse_with_params(
+        &format!("{}/servlet/submissionDownload", login.host()),
+        &[
+            ("usr", login.username.as_str()),
+            ("pwd", login.password.as_str()),
+            ("file_name", file_name.as_str()),
+            ("type", "result"),
+        ],
+    )
+    .map_err(|e| Error::Http(e.to_string()))?;
+    let started = std::time::Instant::now();
+    loop {
+        std::thread::sleep(poll_interval.min(timeout.saturating_sub(started.elapsed())));
+        tracing::debug!("GET {}/servlet/submissionDownload?file_name={}", login.host(), file_name);
+        let log = fetch_submission_log(&client, &log_url)?;
+        let result = parse_submission_log(&log, &file_name)?;
+        if result.status == "completed" || started.elapsed() >= timeout {
+            return Ok(result);
+        }
+        tracing::debug!("Deposit {} is {}", file_name, result.status);
+    }
+}
+
+/// Fetch the submission log at `log_url`. The URL carries the account
+/// password, so errors leave it out.
+fn fetch_submission_log(client: &reqwest::blocking::Client, log_url: &url::Url) -> Result<String> {
+    client
+        .get(log_url.clone())
+        .send()
+        .and_then(|resp| resp.error_for_status())
+        .and_then(|resp| resp.text())
+        .map_err(|e| {
+            Error::Http(format!("failed to fetch the submission log: {}", e.without_url()))
+        })
+}
+
+/// A `multipart/form-data` body with `fields` and `xml` as the file part,
+/// as the Crossref deposit servlet expects.
+fn multipart_body(boundary: &str, fields: &[(&str, &str)], file_name: &str, xml: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, value) in fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                boundary, name, value
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"fname\"; filename=\"{}\"\r\nContent-Type: application/xml\r\n\r\n",
+            boundary, file_name
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(xml);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+/// The text of an HTML error page on one line.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push(' ');
+            }
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Read a `doi_batch_diagnostic` document from the submission log.
+fn parse_submission_log(xml: &str, file_name: &str) -> Result<DepositResult> {
+    let log: XmlBatchDiagnostic = xml_from_str(xml)
+        .map_err(|e| Error::Parse(format!("Crossref submission log: {}", e)))?;
+    Ok(DepositResult {
+        file_name: file_name.to_string(),
+        status: log.status,
+        submission_id: log.submission_id,
+        record_count: log.batch_data.record_count,
+        success_count: log.batch_data.success_count,
+        warning_count: log.batch_data.warning_count,
+        failure_count: log.batch_data.failure_count,
+        records: log
+            .record_diagnostic
+            .into_iter()
+            .map(|record| DepositRecord {
+                doi: record.doi,
+                status: record.status,
+                message: record.msg.trim().to_string(),
+            })
+            .collect(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{build_doi_data, build_titles};
-    use crate::data::{Data, Identifier, Title};
+    use super::{
+        build_doi_data, build_relations_program, build_titles, fetch_submission_log,
+        multipart_body, parse_submission_log, read_xml, read_xml_all, write_all, write_deposit,
+        DepositHead, XmlOptions,
+    };
+    use crate::data::{Data, Identifier, Publisher, Relation, Title};
 
     #[test]
     fn build_titles_prefers_primary_and_subtitle() {
@@ -2615,4 +3036,176 @@ mod tests {
         let doi_data = build_doi_data(&data);
         assert_eq!(doi_data.doi, "10.9999/abc");
     }
+
+    #[test]
+    fn build_relations_program_writes_raid_as_doi() {
+        let data = Data {
+            relations: vec![Relation {
+                id: "https://raid.org/10.80368/b1adfb3a".to_string(),
+                type_: "IsPartOf".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let program = build_relations_program(&data).unwrap();
+        let relation = program.related_items[0].inter_work.as_ref().unwrap();
+        assert_eq!(relation.identifier_type, "doi");
+        assert_eq!(relation.text, "10.80368/b1adfb3a");
+    }
+
+    #[test]
+    fn build_relations_program_writes_unsupported_types_as_uri() {
+        let swhid = "https://archive.softwareheritage.org/\
+            swh:1:dir:d198bc9d7a6bcf6db04f476d29314f157507d505";
+        let data = Data {
+            relations: vec![Relation {
+                id: swhid.to_string(),
+                type_: "IsPartOf".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let program = build_relations_program(&data).unwrap();
+        let relation = program.related_items[0].inter_work.as_ref().unwrap();
+        assert_eq!(relation.identifier_type, "uri");
+        assert_eq!(relation.text, swhid);
+    }
+
+    #[test]
+    fn read_xml_all_reads_every_work_in_deposit() {
+        let list = vec![
+            Data {
+                id: "https://doi.org/10.5555/a".to_string(),
+                type_: "JournalArticle".to_string(),
+                title: "First".to_string(),
+                ..Default::default()
+            },
+            Data {
+                id: "https://doi.org/10.5555/b".to_string(),
+                type_: "Article".to_string(),
+                title: "Second".to_string(),
+                ..Default::default()
+            },
+        ];
+        let xml = String::from_utf8(write_all(&list).unwrap()).unwrap();
+
+        let records = read_xml_all(&xml).unwrap();
+        let ids: Vec<&str> = records.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["https://doi.org/10.5555/a", "https://doi.org/10.5555/b"]);
+        assert_eq!(records[0].type_, "JournalArticle");
+        assert_eq!(records[1].title, "Second");
+
+        assert_eq!(read_xml(&xml).unwrap().id, "https://doi.org/10.5555/a");
+    }
+
+    #[test]
+    fn parse_submission_log_reads_counts_and_records() {
+        let log = r#"<?xml version="1.0" encoding="UTF-8"?>
+<doi_batch_diagnostic status="completed" sp="ds4.crossref.org">
+  <submission_id>1234567</submission_id>
+  <batch_id>5f1e6c2a</batch_id>
+  <record_diagnostic status="Success">
+    <doi>10.5555/abcd-efgh</doi>
+    <msg>Successfully added</msg>
+  </record_diagnostic>
+  <record_diagnostic status="Failure" msg_id="4">
+    <doi>10.5555/ijkm-npqr</doi>
+    <msg>Record not processed because submitted version is less or equal to previously submitted version</msg>
+  </record_diagnostic>
+  <batch_data>
+    <record_count>2</record_count>
+    <success_count>1</success_count>
+    <warning_count>0</warning_count>
+    <failure_count>1</failure_count>
+  </batch_data>
+</doi_batch_diagnostic>"#;
+        let result = parse_submission_log(log, "deposit.xml").unwrap();
+        assert_eq!(result.status, "completed");
+        assert_eq!(result.submission_id, "1234567");
+        assert_eq!((result.record_count, result.success_count, result.failure_count), (2, 1, 1));
+        assert_eq!(result.records[0].doi, "10.5555/abcd-efgh");
+        assert_eq!(result.records[1].status, "Failure");
+        assert!(result.records[1].message.starts_with("Record not processed"));
+
+        let queued = parse_submission_log(
+            r#"<doi_batch_diagnostic status="queued"><submission_id>1</submission_id><batch_id/></doi_batch_diagnostic>"#,
+            "deposit.xml",
+        )
+        .unwrap();
+        assert_eq!(queued.status, "queued");
+        assert_eq!(queued.record_count, 0);
+    }
+
+    #[test]
+    fn fetch_submission_log_errors_leave_out_the_password() {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap_or(0) > 0 && line != "\r\n" {
+                line.clear();
+            }
+            stream
+                .write_all(b"HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        });
+
+        let client = reqwest::blocking::Client::new();
+        let url = |addr| {
+            url::Url::parse_with_params(
+                &format!("http://{}/servlet/submissionDownload", addr),
+                &[("usr", "user"), ("pwd", "s3cret-pa55"), ("file_name", "deposit.xml")],
+            )
+            .unwrap()
+        };
+        let err = fetch_submission_log(&client, &url(addr)).unwrap_err().to_string();
+        server.join().unwrap();
+        assert!(err.contains("500"), "{}", err);
+        assert!(!err.contains("s3cret-pa55"), "{}", err);
+
+        // Nothing listens there any more, so sending fails.
+        let err = fetch_submission_log(&client, &url(addr)).unwrap_err().to_string();
+        assert!(!err.contains("s3cret-pa55"), "{}", err);
+    }
+
+    #[test]
+    fn multipart_body_has_fields_and_file() {
+        let body = multipart_body("xyz", &[("operation", "doMDUpload")], "d.xml", b"<doi_batch/>");
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.starts_with("--xyz\r\nContent-Disposition: form-data; name=\"operation\"\r\n\r\ndoMDUpload\r\n"));
+        assert!(body.contains("name=\"fname\"; filename=\"d.xml\"\r\nContent-Type: application/xml\r\n\r\n<doi_batch/>\r\n--xyz--\r\n"));
+    }
+
+    #[test]
+    fn write_deposit_fills_head() {
+        let data = Data {
+            id: "https://doi.org/10.59350/abc12-def34".to_string(),
+            type_: "BlogPost".to_string(),
+            title: "A Blog Post".to_string(),
+            url: "https://blog.example.org/posts/a-blog-post".to_string(),
+            date_published: "2024-05-01".to_string(),
+            publisher: Publisher {
+                name: "Front Matter".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let head = DepositHead {
+            depositor: "Example Blog".to_string(),
+            email: "info@example.org".to_string(),
+            ..Default::default()
+        };
+        let xml = String::from_utf8(write_deposit(&[data], &head, &XmlOptions::default()).unwrap()).unwrap();
+
+        assert!(xml.contains("<depositor_name>Example Blog</depositor_name>"));
+        assert!(xml.contains("<email_address>info@example.org</email_address>"));
+        // the registrant falls back to the publisher
+        assert!(xml.contains("<registrant>Front Matter</registrant>"));
+        assert!(xml.contains("<posted_content type=\"other\">"));
+        assert!(xml.contains("<doi>10.59350/abc12-def34</doi>"));
+    }
 }
@@ -0,0 +1,580 @@
+//! CSV/TSV reader and writer for spreadsheets of works, one work per row.
+//!
+//! Columns are matched to commonmeta fields by header name. A column map
+//! such as `title=Title,doi=DOI` overrides the match for individual fields
+//! when the spreadsheet uses its own headings. When writing, the same map
+//! selects the columns, their order and their headings, so a written file
+//! reads back with the map it was written with.
+
+use crate::author_utils::{cleanup_author, infer_contributor_type, split_person_name};
+use crate::constants as C;
+use crate::data::{
+    Container, Contributor, Data, Identifier, Organization, Person, Publisher, Subject, Title,
+};
+use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::error::{Error, Result};
+use crate::utils::{
+    get_language, normalize_scopus_eid, normalize_url, sanitize, validate_issn,
+    validate_scopus_eid,
+};
+
+/// Commonmeta fields that can be filled from a spreadsheet column, with the
+/// header names recognized for each when no column map entry is given.
+const FIELDS: &[(&str, &[&str])] = &[
+    ("id", &["id", "identifier"]),
+    ("doi", &["doi"]),
+    ("url", &["url", "link"]),
+    ("type", &["type", "work_type", "item_type"]),
+    ("title", &["title"]),
+    ("subtitle", &["subtitle"]),
+    ("authors", &["authors", "author", "creators", "creator"]),
+    ("editors", &["editors", "editor"]),
+    ("description", &["description", "abstract"]),
+    ("date_published", &["date_published", "date", "publication_date", "year"]),
+    ("publisher", &["publisher"]),
+    ("container", &["container", "journal", "container_title", "source"]),
+    ("issn", &["issn"]),
+    ("isbn", &["isbn"]),
+    ("eid", &["eid", "scopus_eid"]),
+    ("volume", &["volume"]),
+    ("issue", &["issue", "number"]),
+    ("pages", &["pages"]),
+    ("first_page", &["first_page", "start_page"]),
+    ("last_page", &["last_page", "end_page"]),
+    ("language", &["language"]),
+    ("license", &["license", "rights"]),
+    ("subjects", &["subjects", "subject", "keywords"]),
+    ("version", &["version"]),
+];
+
+/// Columns written when no column map is given: every field except the
+/// page range split, which `pages` already covers.
+const DEFAULT_COLUMNS: &[&str] = &[
+    "id",
+    "doi",
+    "url",
+    "type",
+    "title",
+    "subtitle",
+    "authors",
+    "editors",
+    "description",
+    "date_published",
+    "publisher",
+    "container",
+    "issn",
+    "isbn",
+    "volume",
+    "issue",
+    "pages",
+    "language",
+    "license",
+    "subjects",
+    "version",
+];
+
+/// Mapping from commonmeta fields to spreadsheet column headers.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnMap {
+    columns: Vec<(String, String)>,
+}
+
+impl ColumnMap {
+    /// Parse a `field=Column,field=Column` specification. Unknown fields are
+    /// rejected so that typos don't silently drop a column.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut columns = Vec::new();
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let Some((field, column)) = pair.split_once('=') else {
+                return Err(Error::Parse(format!(
+                    "invalid column mapping '{pair}', expected field=Column"
+                )));
+            };
+            let field = field.trim().to_lowercase();
+            if !FIELDS.iter().any(|(name, _)| *name == field) {
+                return Err(Error::Parse(format!("unknown commonmeta field '{field}'")));
+            }
+            columns.push((field, column.trim().to_string()));
+        }
+        Ok(Self { columns })
+    }
+
+    /// The columns to write, as (field, heading) pairs in map order, or the
+    /// default columns headed by their field names for an empty map.
+    fn write_columns(&self) -> Vec<(&'static str, String)> {
+        let field_name = |field: &str| {
+            FIELDS
+                .iter()
+                .map(|(name, _)| *name)
+                .find(|name| *name == field)
+                .unwrap_or("")
+        };
+        if self.columns.is_empty() {
+            DEFAULT_COLUMNS.iter().map(|f| (*f, f.to_string())).collect()
+        } else {
+            self.columns
+                .iter()
+                .map(|(field, column)| (field_name(field), column.clone()))
+                .collect()
+        }
+    }
+
+    /// Resolve every mapped field to a column index in `headers`.
+    fn resolve(&self, headers: &[String]) -> Result<Vec<(&'static str, usize)>> {
+        let normalized: Vec<String> = headers.iter().map(|h| normalize_header(h)).collect();
+        let mut fields = Vec::new();
+        for (field, aliases) in FIELDS {
+            let index = match self.columns.iter().find(|(f, _)| f == field) {
+                Some((_, column)) => Some(
+                    headers
+                        .iter()
+                        .position(|h| h.trim() == column)
+                        .or_else(|| normalized.iter().position(|h| *h == normalize_header(column)))
+                        .ok_or_else(|| Error::Parse(format!("column '{column}' not found")))?,
+                ),
+                None => aliases
+                    .iter()
+                    .find_map(|alias| normalized.iter().position(|h| h == alias)),
+            };
+            if let Some(index) = index {
+                fields.push((*field, index));
+            }
+        }
+        Ok(fields)
+    }
+}
+
+/// Lowercase a header and treat spaces and hyphens like underscores, so that
+/// "Date Published" and "date-published" both match `date_published`.
+fn normalize_header(header: &str) -> String {
+    header
+        .trim()
+        .trim_start_matches('\u{feff}')
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Tab-separated when the header line has more tabs than commas, and
+/// semicolon-separated (as written by spreadsheet programs in many locales)
+/// when it has more semicolons than commas.
+fn detect_delimiter(input: &str) -> u8 {
+    let header = input.lines().next().unwrap_or("");
+    let count = |c: char| header.matches(c).count();
+    let commas = count(',');
+    if count('\t') > commas {
+        b'\t'
+    } else if count(';') > commas {
+        b';'
+    } else {
+        b','
+    }
+}
+
+/// Split a multi-valued cell such as "Smith, Jane; Doe, John".
+fn split_values(value: &str) -> impl Iterator<Item = &str> {
+    value.split(['|', ';']).map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn parse_type(value: &str) -> String {
+    if let Some(type_) = C::WORK_TYPES
+        .iter()
+        .find(|t| t.eq_ignore_ascii_case(value))
+    {
+        return type_.to_string();
+    }
+    let lower = value.to_lowercase();
+    match C::csl_to_cm(&lower) {
+        "" => C::cr_to_cm(&lower).to_string(),
+        type_ => type_.to_string(),
+    }
+}
+
+fn parse_contributors(value: &str, role: &str) -> Vec<Contributor> {
+    split_values(value)
+        .filter_map(|name| cleanup_author(Some(name)))
+        .map(|name| {
+            let roles = vec![role.to_string()];
+            match infer_contributor_type("", "", "", "", &name, None).as_str() {
+                "Person" => {
+                    let (given_name, family_name, rest) = split_person_name(&name);
+                    let family_name = if family_name.is_empty() { rest } else { family_name };
+                    Contributor::person(
+                        Person {
+                            given_name,
+                            family_name,
+                            ..Default::default()
+                        },
+                        roles,
+                    )
+                }
+                _ => Contributor::organization(
+                    Organization {
+                        name,
+                        ..Default::default()
+                    },
+                    roles,
+                ),
+            }
+        })
+        .collect()
+}
+
+fn from_row(row: &::csv::StringRecord, fields: &[(&'static str, usize)]) -> Data {
+    let mut data = Data::default();
+    let mut container = Container::default();
+
+    for (field, index) in fields {
+        let value = row.get(*index).unwrap_or("").trim();
+        if value.is_empty() {
+            continue;
+        }
+        match *field {
+            "id" | "doi" => {
+                if let Some(doi) = validate_doi(value) {
+                    let doi = normalize_doi(&doi);
+                    data.identifiers.push(Identifier {
+                        identifier: doi.clone(),
+                        identifier_type: "DOI".to_string(),
+                        ..Default::default()
+                    });
+                    data.id = doi;
+                } else if data.id.is_empty() {
+                    data.id = value.to_string();
+                }
+            }
+            "url" => data.url = normalize_url(value, false, false).unwrap_or_default(),
+            "type" => data.type_ = parse_type(value),
+            "title" => data.title = value.to_string(),
+            "subtitle" => data.additional_titles.push(Title {
+                title: value.to_string(),
+                type_: "Subtitle".to_string(),
+                ..Default::default()
+            }),
+            "authors" => data.contributors.extend(parse_contributors(value, "Author")),
+            "editors" => data.contributors.extend(parse_contributors(value, "Editor")),
+            "description" => data.description = sanitize(value),
+            "date_published" => data.date_published = value.to_string(),
+            "publisher" => {
+                data.publisher = Publisher {
+                    name: value.to_string(),
+                    ..Default::default()
+                }
+            }
+            "container" => container.title = value.to_string(),
+            "issn" => {
+                if let Some(issn) = validate_issn(value) {
+                    container.identifier = issn;
+                    container.identifier_type = "ISSN".to_string();
+                }
+            }
+            "isbn" => data.identifiers.push(Identifier {
+                identifier: value.to_string(),
+                identifier_type: "ISBN".to_string(),
+                ..Default::default()
+            }),
+            "eid" => {
+                if let Some(eid) = validate_scopus_eid(value) {
+                    data.identifiers.push(Identifier {
+                        identifier: eid,
+                        identifier_type: "Scopus".to_string(),
+                        ..Default::default()
+                    });
+                }
+            }
+            "volume" => container.volume = value.to_string(),
+            "issue" => container.issue = value.to_string(),
+            "pages" => {
+                let (first, last) = value
+                    .split_once(['-', '–'])
+                    .unwrap_or((value, ""));
+                container.first_page = first.trim().to_string();
+                container.last_page = last.trim().to_string();
+            }
+            "first_page" => container.first_page = value.to_string(),
+            "last_page" => container.last_page = value.to_string(),
+            "language" => {
+                let iso = get_language(value, "");
+                data.language = if iso.is_empty() { value.to_string() } else { iso };
+            }
+            "license" => {
+                data.license = if value.starts_with("http") {
+                    crate::spdx::from_url(value)
+                } else {
+                    crate::spdx::from_id(value)
+                }
+            }
+            "subjects" => data.subjects.extend(split_values(value).map(|s| Subject {
+                subject: s.to_string(),
+                ..Default::default()
+            })),
+            "version" => data.version = value.to_string(),
+            _ => {}
+        }
+    }
+
+    if data.id.is_empty() {
+        data.id = data.url.clone();
+    }
+    // Spreadsheets keyed on Scopus EIDs often have neither DOI nor URL.
+    if data.id.is_empty() {
+        data.id = data
+            .identifiers
+            .iter()
+            .find(|i| i.identifier_type == "Scopus")
+            .map(|i| normalize_scopus_eid(&i.identifier))
+            .unwrap_or_default();
+    }
+    if data.type_.is_empty() {
+        data.type_ = if container.title.is_empty() {
+            "Other".to_string()
+        } else {
+            "JournalArticle".to_string()
+        };
+    }
+    if !container.is_empty() {
+        if container.type_.is_empty() && data.type_ == "JournalArticle" {
+            container.type_ = "Journal".to_string();
+        }
+        data.container = container;
+    }
+    data
+}
+
+fn join_contributors(data: &Data, role: &str) -> String {
+    data.contributors
+        .iter()
+        .filter(|c| c.roles.iter().any(|r| r == role))
+        .map(|c| match (c.family_name(), c.given_name()) {
+            ("", _) => c.name(),
+            (family, "") => family.to_string(),
+            (family, given) => format!("{family}, {given}"),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The cell value of `field` for `data`; multi-valued fields are joined with
+/// `; ` so that they split again on reading.
+fn cell(data: &Data, field: &str) -> String {
+    let c = &data.container;
+    match field {
+        "id" => data.id.clone(),
+        "doi" => validate_doi(&data.id).unwrap_or_default(),
+        "url" => data.url.clone(),
+        "type" => data.type_.clone(),
+        "title" => data.title.clone(),
+        "subtitle" => data
+            .additional_titles
+            .iter()
+            .find(|t| t.type_ == "Subtitle")
+            .map(|t| t.title.clone())
+            .unwrap_or_default(),
+        "authors" => join_contributors(data, "Author"),
+        "editors" => join_contributors(data, "Editor"),
+        "description" => data.description.clone(),
+        "date_published" => data.date_published.clone(),
+        "publisher" => data.publisher.name.clone(),
+        "container" => c.title.clone(),
+        "issn" if c.identifier_type == "ISSN" => c.identifier.clone(),
+        "isbn" => data
+            .identifiers
+            .iter()
+            .filter(|i| i.identifier_type == "ISBN")
+            .map(|i| i.identifier.as_str())
+            .collect::<Vec<_>>()
+            .join("; "),
+        "eid" => data
+            .identifiers
+            .iter()
+            .filter(|i| i.identifier_type == "Scopus")
+            .map(|i| i.identifier.as_str())
+            .collect::<Vec<_>>()
+            .join("; "),
+        "volume" => c.volume.clone(),
+        "issue" => c.issue.clone(),
+        "pages" if c.last_page.is_empty() => c.first_page.clone(),
+        "pages" => format!("{}-{}", c.first_page, c.last_page),
+        "first_page" => c.first_page.clone(),
+        "last_page" => c.last_page.clone(),
+        "language" => data.language.clone(),
+        "license" if data.license.id.is_empty() => data.license.url.clone(),
+        "license" => data.license.id.clone(),
+        "subjects" => data
+            .subjects
+            .iter()
+            .map(|s| s.subject.as_str())
+            .collect::<Vec<_>>()
+            .join("; "),
+        "version" => data.version.clone(),
+        _ => String::new(),
+    }
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Read every row of a CSV or TSV spreadsheet, using `map` to locate the
+/// columns. Headers not covered by `map` are matched by name.
+pub fn read_all(input: &str, map: &ColumnMap) -> Result<Vec<Data>> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .delimiter(detect_delimiter(input))
+        .flexible(true)
+        .trim(::csv::Trim::All)
+        .from_reader(input.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| Error::Parse(e.to_string()))?
+        .iter()
+        .map(str::to_string)
+        .collect();
+    let fields = map.resolve(&headers)?;
+    if fields.is_empty() {
+        return Err(Error::Parse(
+            "no spreadsheet column matches a commonmeta field".to_string(),
+        ));
+    }
+
+    let mut list = Vec::new();
+    for row in reader.records() {
+        let row = row.map_err(|e| Error::Parse(e.to_string()))?;
+        if row.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        list.push(from_row(&row, &fields));
+    }
+    Ok(list)
+}
+
+/// Read the first row of a spreadsheet, matching columns by header name.
+pub fn read(input: &str) -> Result<Data> {
+    read_all(input, &ColumnMap::default())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Parse("no rows found in spreadsheet".to_string()))
+}
+
+/// Write `list` as CSV with a header row, one work per row. `map` selects
+/// the columns and their headings; an empty map writes the default columns.
+pub fn write_all(list: &[Data], map: &ColumnMap) -> Result<Vec<u8>> {
+    let columns = map.write_columns();
+    let mut writer = ::csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(columns.iter().map(|(_, heading)| heading))
+        .map_err(|e| Error::Serialize(e.to_string()))?;
+    for data in list {
+        writer
+            .write_record(columns.iter().map(|(field, _)| cell(data, field)))
+            .map_err(|e| Error::Serialize(e.to_string()))?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| Error::Serialize(e.to_string()))
+}
+
+/// Write a single work as CSV with the default columns.
+pub fn write(data: &Data) -> Result<Vec<u8>> {
+    write_all(std::slice::from_ref(data), &ColumnMap::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_map_parse() {
+        let map = ColumnMap::parse("title=Title, doi=DOI").unwrap();
+        assert_eq!(map.columns.len(), 2);
+        assert_eq!(map.columns[1], ("doi".to_string(), "DOI".to_string()));
+        assert!(ColumnMap::parse("titel=Title").is_err());
+        assert!(ColumnMap::parse("title").is_err());
+    }
+
+    #[test]
+    fn test_read_all_with_column_map() {
+        let input = "Article Title,Digital Object Identifier,Writers,Journal,Pages,Year\n\
+            Alpine soils,10.5555/ABC,\"Müller, Anna; Alpine Research Group\",Soil Letters,12-19,2021\n\
+            ,,,,,\n\
+            Valley soils,https://doi.org/10.5555/def,\"Doe, John\",Soil Letters,,2022\n";
+        let map = ColumnMap::parse(
+            "title=Article Title,doi=Digital Object Identifier,authors=Writers",
+        )
+        .unwrap();
+        let list = read_all(input, &map).unwrap();
+        assert_eq!(list.len(), 2);
+
+        let data = &list[0];
+        assert_eq!(data.id, "https://doi.org/10.5555/abc");
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.title, "Alpine soils");
+        assert_eq!(data.date_published, "2021");
+        assert_eq!(data.contributors.len(), 2);
+        assert_eq!(data.contributors[0].family_name(), "Müller");
+        assert_eq!(data.contributors[1].name(), "Alpine Research Group");
+        assert_eq!(data.container.title, "Soil Letters");
+        assert_eq!(data.container.first_page, "12");
+        assert_eq!(data.container.last_page, "19");
+        assert_eq!(list[1].id, "https://doi.org/10.5555/def");
+    }
+
+    #[test]
+    fn test_read_tsv_by_header_name() {
+        let input = "Title\tType\tKeywords\tLanguage\tURL\n\
+            Field measurements\tdataset\thydrology; soil\tGerman\thttps://example.org/data/1\n";
+        let data = read(input).unwrap();
+        assert_eq!(data.type_, "Dataset");
+        assert_eq!(data.id, "https://example.org/data/1");
+        assert_eq!(data.subjects.len(), 2);
+        assert_eq!(data.language, "de");
+    }
+
+    #[test]
+    fn test_write_all_round_trips_with_column_map() {
+        let input = "Article Title,Digital Object Identifier,Writers,Journal,Pages,Year\n\
+            Alpine soils,10.5555/ABC,\"Müller, Anna; Alpine Research Group\",Soil Letters,12-19,2021\n";
+        let map = ColumnMap::parse(
+            "title=Article Title,doi=Digital Object Identifier,authors=Writers,pages=Pages",
+        )
+        .unwrap();
+        let list = read_all(input, &map).unwrap();
+        let output = String::from_utf8(write_all(&list, &map).unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "Article Title,Digital Object Identifier,Writers,Pages\n\
+             Alpine soils,10.5555/abc,\"Müller, Anna; Alpine Research Group\",12-19\n"
+        );
+        let round = read_all(&output, &map).unwrap();
+        assert_eq!(round[0].id, list[0].id);
+        assert_eq!(round[0].contributors.len(), 2);
+    }
+
+    #[test]
+    fn test_read_and_write_scopus_eid() {
+        let input = "EID,Title\n2-s2.0-85012345678,Alpine soils\n";
+        let data = read(input).unwrap();
+        assert_eq!(data.id, "https://www.scopus.com/inward/record.uri?eid=2-s2.0-85012345678");
+        assert_eq!(data.identifiers[0].identifier_type, "Scopus");
+        let map = ColumnMap::parse("eid=EID,title=Title").unwrap();
+        let output = String::from_utf8(write_all(&[data], &map).unwrap()).unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn test_write_default_columns() {
+        let data = read("Title\tType\tKeywords\nField measurements\tdataset\thydrology; soil\n").unwrap();
+        let output = String::from_utf8(write(&data).unwrap()).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap().split(',').count(), DEFAULT_COLUMNS.len());
+        assert_eq!(
+            lines.next().unwrap(),
+            ",,,Dataset,Field measurements,,,,,,,,,,,,,,,hydrology; soil,"
+        );
+    }
+
+    #[test]
+    fn test_read_all_rejects_missing_column() {
+        let map = ColumnMap::parse("title=Name").unwrap();
+        assert!(read_all("Title\nA\n", &map).is_err());
+    }
+}
@@ -26,7 +26,7 @@ use crate::data::{
     GeoLocation, Identifier, Organization, Person, Publisher, Reference, Relation, Subject, Title,
 };
 use crate::constants as C;
-use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::doi_utils::{PrefixDoi, normalize_doi, validate_doi};
 use crate::error::{Error, Result};
 use crate::utils::{
     normalize_cc_url, normalize_id, normalize_orcid, normalize_ror, normalize_url, sanitize,
@@ -324,6 +324,29 @@ fn parse_geo_coord(v: &Option<Value>) -> Option<f64> {
     }
 }
 
+/// The relatedIdentifierTypes of the DataCite schema that
+/// [`validate_id`] also returns.
+const RELATED_IDENTIFIER_TYPES: [&str; 8] =
+    ["ARK", "arXiv", "DOI", "IGSN", "ISBN", "ISSN", "PMID", "URN"];
+
+/// The value and relatedIdentifierType of a related identifier `id`, or an
+/// empty value if `id` isn't one. RAiDs are DOIs, and legacy RAiDs handles;
+/// types the schema doesn't know (SWHID, VIAF, GND, ...) are written as
+/// their URL.
+pub(crate) fn related_identifier(id: &str) -> (String, &'static str) {
+    let (identifier, identifier_type) = validate_id(id);
+    match identifier_type {
+        "RAID" if validate_doi(&identifier).is_some() => (identifier, "DOI"),
+        "RAID" => (identifier, "Handle"),
+        "" | "URL" => (identifier, identifier_type),
+        t if RELATED_IDENTIFIER_TYPES.contains(&t) => (identifier, identifier_type),
+        _ => match crate::utils::Identifier::parse(id).map(|i| i.to_url()) {
+            Some(url) if url.starts_with("https://") => (url, "URL"),
+            _ => (identifier, identifier_type),
+        },
+    }
+}
+
 pub(crate) fn is_reference_relation(rt: &str) -> bool {
     matches!(rt, "Cites" | "References")
 }
@@ -785,6 +808,7 @@ pub fn fetch(doi: &str) -> Result<Data> {
         ))
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
     let json = client
         .get(&url)
         .send()
@@ -796,6 +820,101 @@ pub fn fetch(doi: &str) -> Result<Data> {
     read_json(&json)
 }
 
+#[derive(Deserialize)]
+struct PrefixResponse {
+    #[serde(default)]
+    data: Vec<PrefixItem>,
+    #[serde(default)]
+    links: PrefixLinks,
+}
+
+#[derive(Deserialize)]
+struct PrefixItem {
+    attributes: PrefixAttributes,
+}
+
+#[derive(Deserialize)]
+struct PrefixAttributes {
+    #[serde(default, deserialize_with = "null_to_string")]
+    doi: String,
+    #[serde(default)]
+    types: Option<DcTypes>,
+    #[serde(default, deserialize_with = "null_to_string")]
+    created: String,
+}
+
+#[derive(Deserialize, Default)]
+struct PrefixLinks {
+    #[serde(default)]
+    next: Option<String>,
+}
+
+/// Fetch one page of the DOIs registered under `prefix`, `rows` (at most
+/// 1,000) at a time. Pass `None` for the first page and the returned
+/// cursor for the next; the cursor is `None` after the last page.
+pub fn fetch_prefix_page(
+    prefix: &str,
+    cursor: Option<&str>,
+    rows: usize,
+) -> Result<(Vec<PrefixDoi>, Option<String>)> {
+    let url = url::Url::parse_with_params(
+        "https://api.datacite.org/dois",
+        &[
+            ("prefix", prefix),
+            ("page[size]", rows.to_string().as_str()),
+            ("page[cursor]", cursor.unwrap_or("1")),
+            ("fields[dois]", "doi,types,created"),
+        ],
+    )
+    .map_err(|e| Error::InvalidId(e.to_string()))?;
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
+    let json = client
+        .get(url)
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    read_prefix_page(&json)
+}
+
+fn read_prefix_page(json: &str) -> Result<(Vec<PrefixDoi>, Option<String>)> {
+    let response: PrefixResponse =
+        serde_json::from_str(json).map_err(|e| Error::Parse(e.to_string()))?;
+    let items = response
+        .data
+        .into_iter()
+        .map(|item| PrefixDoi {
+            doi: normalize_doi(&item.attributes.doi),
+            type_: C::dc_to_cm(
+                &item.attributes.types.unwrap_or_default().resource_type_general,
+            )
+            .to_string(),
+            created: item.attributes.created.chars().take(10).collect(),
+        })
+        .collect();
+    // The next page is linked with its cursor in the page[cursor] parameter.
+    let next_cursor = response
+        .links
+        .next
+        .and_then(|next| url::Url::parse(&next).ok())
+        .and_then(|next| {
+            next.query_pairs()
+                .find(|(key, _)| key == "page[cursor]")
+                .map(|(_, cursor)| cursor.into_owned())
+        });
+    Ok((items, next_cursor))
+}
+
 // ── Writer ─────────────────────────────────────────────────────────────────────
 
 // ── Output structs ────────────────────────────────────────────────────────────
@@ -835,8 +954,9 @@ struct OutPayload {
     related_identifiers: Vec<OutRelatedIdentifier>,
     #[serde(skip_serializing_if = "String::is_empty")]
     version: String,
-    // DataCite event to trigger DOI state transition
-    event: &'static str,
+    // DataCite event to trigger DOI state transition; none leaves a draft
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<&'static str>,
 }
 
 #[derive(Serialize)]
@@ -1340,7 +1460,7 @@ fn convert(data: &Data) -> OutPayload {
     let mut related_identifiers: Vec<OutRelatedIdentifier> = Vec::new();
 
     for r in &data.relations {
-        let (identifier, identifier_type) = validate_id(&r.id);
+        let (identifier, identifier_type) = related_identifier(&r.id);
         if identifier.is_empty() {
             continue;
         }
@@ -1359,7 +1479,7 @@ fn convert(data: &Data) -> OutPayload {
     }
 
     for r in &data.references {
-        let (identifier, identifier_type) = validate_id(&r.id);
+        let (identifier, identifier_type) = related_identifier(&r.id);
         if identifier.is_empty() {
             continue;
         }
@@ -1392,7 +1512,7 @@ fn convert(data: &Data) -> OutPayload {
         rights_list,
         related_identifiers,
         version: data.version.clone(),
-        event: "publish",
+        event: Some("publish"),
     }
 }
 
@@ -1406,10 +1526,183 @@ pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
     serde_json::to_vec_pretty(&payloads).map_err(|e| Error::Parse(e.to_string()))
 }
 
+// ── Registration ──────────────────────────────────────────────────────────────
+
+/// DataCite repository account used to register DOIs, and whether to use
+/// the test system at api.test.datacite.org.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataciteLogin {
+    /// Repository ID, e.g. `DATACITE.EXAMPLE`.
+    pub repository_id: String,
+    pub password: String,
+    pub sandbox: bool,
+}
+
+impl DataciteLogin {
+    fn host(&self) -> &'static str {
+        if self.sandbox {
+            "https://api.test.datacite.org"
+        } else {
+            "https://api.datacite.org"
+        }
+    }
+}
+
+/// Outcome of registering a single DOI with DataCite.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DataciteResult {
+    pub doi: String,
+    /// "created" or "updated".
+    pub action: String,
+    /// "draft", "registered" or "findable".
+    pub state: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+/// The JSON:API document for creating or updating `data` with `event`
+/// ("draft", "register" or "publish").
+fn registration_payload(data: &Data, event: &str) -> Result<Value> {
+    let mut attributes = convert(data);
+    attributes.event = match event {
+        "draft" => None,
+        "register" => Some("register"),
+        "publish" => Some("publish"),
+        other => {
+            return Err(Error::Parse(format!(
+                "unknown DataCite event '{}' (expected draft, register or publish)",
+                other
+            )));
+        }
+    };
+    if validate_doi(&attributes.doi).is_none() {
+        return Err(Error::InvalidId(format!("no DOI to register for '{}'", data.id)));
+    }
+    Ok(serde_json::json!({
+        "data": {
+            "type": "dois",
+            "attributes": attributes,
+        }
+    }))
+}
+
+/// Error titles of a DataCite JSON:API error response, or the raw body.
+fn error_message(body: &str) -> String {
+    let titles: Vec<String> = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("errors").and_then(Value::as_array).cloned())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|e| {
+            let title = e.get("title")?.as_str()?;
+            Some(match e.get("source").and_then(Value::as_str) {
+                Some(source) => format!("{}: {}", source, title),
+                None => title.to_string(),
+            })
+        })
+        .collect();
+    if titles.is_empty() {
+        body.trim().to_string()
+    } else {
+        titles.join("; ")
+    }
+}
+
+/// Create or update the DOI of `data` with the DataCite REST API, then
+/// trigger `event`: "draft" leaves it a draft, "register" registers it
+/// without making it findable, "publish" makes it findable.
+pub fn register(data: &Data, login: &DataciteLogin, event: &str) -> Result<DataciteResult> {
+    let payload = registration_payload(data, event)?;
+    let doi = payload["data"]["attributes"]["doi"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+
+    let url = format!("{}/dois/{}", login.host(), doi);
+    tracing::debug!("GET {}", url);
+    let exists = client
+        .get(&url)
+        .basic_auth(&login.repository_id, Some(&login.password))
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .status()
+        .is_success();
+
+    let (request, action) = if exists {
+        tracing::debug!("PUT {}", url);
+        (client.put(&url), "updated")
+    } else {
+        let url = format!("{}/dois", login.host());
+        tracing::debug!("POST {}", url);
+        (client.post(&url), "created")
+    };
+    let resp = request
+        .basic_auth(&login.repository_id, Some(&login.password))
+        .header("Content-Type", "application/vnd.api+json")
+        .body(payload.to_string())
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    let status = resp.status();
+    let text = resp.text().map_err(|e| Error::Http(e.to_string()))?;
+    if !status.is_success() {
+        return Err(Error::Http(format!(
+            "DataCite rejected {} ({}): {}",
+            doi,
+            status,
+            error_message(&text)
+        )));
+    }
+
+    let body: Value = serde_json::from_str(&text).map_err(|e| Error::Parse(e.to_string()))?;
+    let attributes = &body["data"]["attributes"];
+    let field = |name: &str| attributes[name].as_str().unwrap_or_default().to_string();
+    Ok(DataciteResult {
+        doi,
+        action: action.to_string(),
+        state: field("state"),
+        url: field("url"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_registration_payload_events() {
+        let mut data = Data {
+            id: "https://doi.org/10.5555/abcd-1234".to_string(),
+            type_: "Dataset".to_string(),
+            url: "https://example.org/abcd".to_string(),
+            ..Default::default()
+        };
+
+        let payload = registration_payload(&data, "publish").unwrap();
+        assert_eq!(payload["data"]["type"], "dois");
+        assert_eq!(payload["data"]["attributes"]["doi"], "10.5555/abcd-1234");
+        assert_eq!(payload["data"]["attributes"]["event"], "publish");
+        let draft = registration_payload(&data, "draft").unwrap();
+        assert!(draft["data"]["attributes"].get("event").is_none());
+        assert!(registration_payload(&data, "hide").is_err());
+
+        data.id = "https://example.org/abcd".to_string();
+        assert!(registration_payload(&data, "draft").is_err());
+    }
+
+    #[test]
+    fn test_error_message() {
+        let body = r#"{"errors":[{"source":"doi","title":"This DOI has already been taken"}]}"#;
+        assert_eq!(error_message(body), "doi: This DOI has already been taken");
+        assert_eq!(error_message("Unauthorized\n"), "Unauthorized");
+    }
+
     /// Real-world VRAIX DataCite dumps use explicit JSON `null` (not a
     /// missing key) for `givenName`/`familyName` on organizational creators,
     /// which `#[serde(default)]` alone does not catch since default only
@@ -1469,4 +1762,59 @@ mod tests {
         let v: serde_json::Value = serde_json::from_slice(&out).unwrap();
         assert_eq!(v["doi"], "10.1234/identifier");
     }
+
+    #[test]
+    fn test_write_raid_relation_as_doi() {
+        let data = Data {
+            id: "https://doi.org/10.5555/1234".to_string(),
+            type_: "Dataset".to_string(),
+            relations: vec![crate::data::Relation {
+                id: "https://raid.org/10.80368/b1adfb3a".to_string(),
+                type_: "IsPartOf".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let out = write(&data).unwrap();
+        let v: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let related = &v["relatedIdentifiers"][0];
+        assert_eq!(related["relatedIdentifier"], "10.80368/b1adfb3a");
+        assert_eq!(related["relatedIdentifierType"], "DOI");
+    }
+
+    #[test]
+    fn test_write_viaf_relation_as_url() {
+        let data = Data {
+            id: "https://doi.org/10.5555/1234".to_string(),
+            type_: "Dataset".to_string(),
+            relations: vec![crate::data::Relation {
+                id: "https://viaf.org/viaf/102333412".to_string(),
+                type_: "IsDescribedBy".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let out = write(&data).unwrap();
+        let v: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let related = &v["relatedIdentifiers"][0];
+        assert_eq!(related["relatedIdentifier"], "https://viaf.org/viaf/102333412");
+        assert_eq!(related["relatedIdentifierType"], "URL");
+    }
+
+    #[test]
+    fn test_read_prefix_page() {
+        let json = r#"{
+            "data":[{"id":"10.5281/zenodo.1","attributes":{
+                "doi":"10.5281/zenodo.1",
+                "types":{"resourceTypeGeneral":"Dataset"},
+                "created":"2023-01-02T03:04:05.000Z"}}],
+            "links":{"next":"https://api.datacite.org/dois?fields%5Bdois%5D=doi&page%5Bcursor%5D=MTY3MjYyNjI0NTAwMA&page%5Bsize%5D=1&prefix=10.5281"}
+        }"#;
+        let (items, cursor) = read_prefix_page(json).unwrap();
+        assert_eq!(items[0].doi, "https://doi.org/10.5281/zenodo.1");
+        assert_eq!(items[0].type_, "Dataset");
+        assert_eq!(items[0].created, "2023-01-02");
+        assert_eq!(cursor.as_deref(), Some("MTY3MjYyNjI0NTAwMA"));
+        assert_eq!(read_prefix_page(r#"{"data":[],"links":{}}"#).unwrap().1, None);
+    }
 }
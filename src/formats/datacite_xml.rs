@@ -1,11 +1,11 @@
 use quick_xml::de::from_str as xml_from_str;
-use quick_xml::se::Serializer;
 use serde::{Deserialize, Serialize};
 
 use super::datacite::{
     dc_to_cm_relation, is_recognized_role, is_reference_relation,
-    is_supported_relation, normalize_commonmeta_role,
+    is_supported_relation, normalize_commonmeta_role, related_identifier,
 };
+use super::xml_output::{XmlOptions, serialize_xml};
 use crate::constants as C;
 use crate::author_utils::{
     cleanup_author, infer_contributor_type, normalize_contributor_roles, split_person_name,
@@ -1232,9 +1232,99 @@ fn cm_to_dc_relation_xml(cm: &str) -> &'static str {
     }
 }
 
-fn convert_to_xml(data: &Data) -> OutResource {
-    use crate::utils::validate_id;
+/// DataCite Metadata Schema versions the XML writer can target.
+pub const KERNEL_VERSIONS: &[&str] = &["4.5", "4.6", "4.7"];
+
+// Controlled-vocabulary values added after kernel-4.5, with the schema
+// version that introduced them.
+const RESOURCE_TYPES_ADDED: &[(&str, &str)] = &[
+    ("Award", "4.6"),
+    ("Project", "4.6"),
+    ("Poster", "4.7"),
+    ("Presentation", "4.7"),
+];
+const IDENTIFIER_TYPES_ADDED: &[(&str, &str)] = &[
+    ("CSTR", "4.6"),
+    ("RRID", "4.6"),
+    ("RAiD", "4.7"),
+    ("SWHID", "4.7"),
+];
+const CONTRIBUTOR_TYPES_ADDED: &[(&str, &str)] = &[("Translator", "4.6")];
+const RELATION_TYPES_ADDED: &[(&str, &str)] = &[
+    ("HasTranslation", "4.6"),
+    ("IsTranslationOf", "4.6"),
+    ("Other", "4.7"),
+];
+
+fn in_version(added: &[(&str, &str)], value: &str, version: &str) -> bool {
+    added
+        .iter()
+        .find(|(v, _)| *v == value)
+        .is_none_or(|(_, since)| parse_version(since) <= parse_version(version))
+}
+
+/// `"4.10"` as `(4, 10)`, so versions compare numerically rather than as
+/// strings. Missing or unparseable parts count as 0.
+fn parse_version(version: &str) -> (u32, u32) {
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+fn schema_location(version: &str) -> Option<&'static str> {
+    match version {
+        "4.5" => Some("http://datacite.org/schema/kernel-4 https://schema.datacite.org/meta/kernel-4.5/metadata.xsd"),
+        "4.6" => Some("http://datacite.org/schema/kernel-4 https://schema.datacite.org/meta/kernel-4.6/metadata.xsd"),
+        "4.7" => Some("http://datacite.org/schema/kernel-4 https://schema.datacite.org/meta/kernel-4.7/metadata.xsd"),
+        _ => None,
+    }
+}
+
+/// Rewrite `resource` so it only uses vocabulary defined in schema `version`.
+/// Unknown resource and contributor types fall back to `Other` (keeping the
+/// specific type as the free-text resourceType); related identifiers whose
+/// identifier or relation type the version lacks are dropped.
+fn downgrade_resource(resource: &mut OutResource, version: &str) {
+    let rt = &mut resource.resource_type;
+    if !in_version(RESOURCE_TYPES_ADDED, &rt.resource_type_general, version) {
+        if rt.text.is_empty() {
+            rt.text = rt.resource_type_general.clone();
+        }
+        rt.resource_type_general = "Other".to_string();
+    }
+
+    if let Some(contributors) = resource.contributors.as_mut() {
+        for c in &mut contributors.contributor {
+            if !in_version(CONTRIBUTOR_TYPES_ADDED, &c.contributor_type, version) {
+                c.contributor_type = "Other".to_string();
+            }
+        }
+    }
+
+    if let Some(related) = resource.related_identifiers.as_mut() {
+        related.related_identifier.retain(|r| {
+            in_version(IDENTIFIER_TYPES_ADDED, &r.identifier_type, version)
+                && in_version(RELATION_TYPES_ADDED, &r.relation_type, version)
+        });
+        for r in &mut related.related_identifier {
+            if !in_version(RESOURCE_TYPES_ADDED, &r.resource_type_general, version) {
+                r.resource_type_general = "Other".to_string();
+            }
+        }
+    }
+    if resource.related_identifiers.as_ref().is_some_and(|r| r.related_identifier.is_empty()) {
+        resource.related_identifiers = None;
+    }
 
+    if let Some(items) = resource.related_items.as_mut() {
+        for item in &mut items.related_item {
+            if !in_version(RESOURCE_TYPES_ADDED, &item.related_item_type, version) {
+                item.related_item_type = "Other".to_string();
+            }
+        }
+    }
+}
+
+fn convert_to_xml(data: &Data) -> OutResource {
     // DOI: strip https://doi.org/ prefix for the bare value
     let doi_val = data.id
         .trim_start_matches("https://doi.org/")
@@ -1341,7 +1431,7 @@ fn convert_to_xml(data: &Data) -> OutResource {
     // Related identifiers (relations + references)
     let mut rel_ids: Vec<OutRelatedIdentifier> = Vec::new();
     for r in &data.relations {
-        let (id, id_type) = validate_id(&r.id);
+        let (id, id_type) = related_identifier(&r.id);
         if id.is_empty() { continue; }
         let mapped = cm_to_dc_relation_xml(&r.type_);
         let relation_type = if mapped.is_empty() { r.type_.clone() } else { mapped.to_string() };
@@ -1353,7 +1443,7 @@ fn convert_to_xml(data: &Data) -> OutResource {
         });
     }
     for r in &data.references {
-        let (id, id_type) = validate_id(&r.id);
+        let (id, id_type) = related_identifier(&r.id);
         if id.is_empty() { continue; }
         rel_ids.push(OutRelatedIdentifier {
             identifier_type: id_type.to_string(),
@@ -1482,15 +1572,6 @@ fn convert_to_xml(data: &Data) -> OutResource {
     }
 }
 
-fn serialize_resource(resource: OutResource) -> Result<Vec<u8>> {
-    let mut buf = String::new();
-    let mut ser = Serializer::new(&mut buf);
-    ser.indent(' ', 2);
-    resource.serialize(ser).map_err(|e| Error::Serialize(e.to_string()))?;
-    let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", buf);
-    Ok(xml.into_bytes())
-}
-
 // ── Public API ─────────────────────────────────────────────────────────────────
 
 pub fn read_xml(input: &str) -> Result<Data> {
@@ -1508,6 +1589,7 @@ pub fn fetch(doi: &str) -> Result<Data> {
         ))
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
     let xml = client
         .get(&url)
         .header("Accept", "application/vnd.datacite.datacite+xml")
@@ -1521,14 +1603,32 @@ pub fn fetch(doi: &str) -> Result<Data> {
 }
 
 pub fn write(data: &Data) -> Result<Vec<u8>> {
-    let resource = convert_to_xml(data);
-    serialize_resource(resource)
+    write_version(data, "4.7", &XmlOptions::default())
 }
 
 pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
+    write_all_version(list, "4.7", &XmlOptions::default())
+}
+
+/// Write `data` against DataCite Metadata Schema `version` (one of
+/// [`KERNEL_VERSIONS`]), pointing `xsi:schemaLocation` at that kernel's XSD.
+pub fn write_version(data: &Data, version: &str, options: &XmlOptions) -> Result<Vec<u8>> {
+    let location = schema_location(version).ok_or_else(|| {
+        Error::UnsupportedFormat(format!(
+            "DataCite schema version {version} (expected one of {})",
+            KERNEL_VERSIONS.join(", ")
+        ))
+    })?;
+    let mut resource = convert_to_xml(data);
+    resource.xsi_schema_location = location;
+    downgrade_resource(&mut resource, version);
+    serialize_xml(resource, options)
+}
+
+pub fn write_all_version(list: &[Data], version: &str, options: &XmlOptions) -> Result<Vec<u8>> {
     let mut out = Vec::new();
     for data in list {
-        let bytes = write(data)?;
+        let bytes = write_version(data, version, options)?;
         if !out.is_empty() { out.push(b'\n'); }
         out.extend_from_slice(&bytes);
     }
@@ -1609,6 +1709,71 @@ mod tests {
         assert!(out.contains("<resourceType"), "expected resourceType element");
     }
 
+    #[test]
+    fn write_xml_related_identifier_types() {
+        let relation = |id: &str| crate::data::Relation {
+            id: id.to_string(),
+            type_: "IsPartOf".to_string(),
+            ..Default::default()
+        };
+        let data = Data {
+            id: "https://doi.org/10.5555/1234".to_string(),
+            type_: "Dataset".to_string(),
+            relations: vec![
+                relation("https://raid.org/10.80368/b1adfb3a"),
+                relation("https://hdl.handle.net/10378.1/1587071"),
+            ],
+            ..Default::default()
+        };
+        let bytes = write(&data).expect("write should succeed");
+        let out = std::str::from_utf8(&bytes).unwrap();
+        let raid = r#"relatedIdentifierType="DOI" relationType="IsPartOf">10.80368/b1adfb3a<"#;
+        assert!(out.contains(raid), "{}", out);
+        let legacy = r#"relatedIdentifierType="Handle" relationType="IsPartOf">10378.1/1587071<"#;
+        assert!(out.contains(legacy), "{}", out);
+        assert!(!out.contains("RAID"));
+    }
+
+    #[test]
+    fn write_xml_unsupported_related_identifier_types_as_url() {
+        let swhid = "https://archive.softwareheritage.org/\
+            swh:1:dir:d198bc9d7a6bcf6db04f476d29314f157507d505";
+        let data = Data {
+            id: "https://doi.org/10.5555/1234".to_string(),
+            type_: "Software".to_string(),
+            relations: vec![crate::data::Relation {
+                id: swhid.to_string(),
+                type_: "IsDerivedFrom".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let bytes = write(&data).expect("write should succeed");
+        let out = std::str::from_utf8(&bytes).unwrap();
+        let expected = r#"relatedIdentifierType="URL" relationType="IsDerivedFrom">"#;
+        assert!(out.contains(&format!("{}{}<", expected, swhid)), "{}", out);
+    }
+
+    #[test]
+    fn write_xml_gnd_relation_as_url() {
+        let gnd = "https://d-nb.info/gnd/118540238";
+        let data = Data {
+            id: "https://doi.org/10.5555/1234".to_string(),
+            type_: "Dataset".to_string(),
+            relations: vec![crate::data::Relation {
+                id: gnd.to_string(),
+                type_: "IsDescribedBy".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let bytes = write(&data).expect("write should succeed");
+        let out = std::str::from_utf8(&bytes).unwrap();
+        let expected = r#"relatedIdentifierType="URL" relationType="IsDescribedBy">"#;
+        assert!(out.contains(&format!("{}{}<", expected, gnd)), "{}", out);
+        assert!(!out.contains("GND"));
+    }
+
     #[test]
     fn write_xml_geolocation_round_trip() {
         let xml = include_str!("../../tests/fixtures/datacite_xml/geolocation.xml");
@@ -1619,6 +1784,57 @@ mod tests {
         assert!(!data2.geo_locations.is_empty());
     }
 
+    #[test]
+    fn in_version_compares_versions_numerically() {
+        assert!(in_version(RESOURCE_TYPES_ADDED, "Poster", "4.10"));
+        assert!(!in_version(RESOURCE_TYPES_ADDED, "Poster", "4.6"));
+        assert!(in_version(&[("Future", "4.10")], "Future", "4.10"));
+        assert!(!in_version(&[("Future", "4.10")], "Future", "4.9"));
+        assert_eq!(parse_version("4.10"), (4, 10));
+    }
+
+    #[test]
+    fn write_version_4_5_uses_kernel_4_5_vocabulary() {
+        let mut data = Data {
+            id: "https://doi.org/10.5555/poster".to_string(),
+            type_: "Presentation".to_string(),
+            title: "A Poster".to_string(),
+            date_published: "2024".to_string(),
+            ..Default::default()
+        };
+        data.contributors.push(crate::data::Contributor::person(
+            crate::data::Person {
+                given_name: "Jane".to_string(),
+                family_name: "Doe".to_string(),
+                ..Default::default()
+            },
+            vec!["Editor".to_string()],
+        ));
+        data.relations.push(crate::data::Relation {
+            id: "https://doi.org/10.5555/original".to_string(),
+            type_: "IsTranslationOf".to_string(),
+            ..Default::default()
+        });
+
+        let mut resource = convert_to_xml(&data);
+        resource.resource_type.resource_type_general = "Presentation".to_string();
+        resource.contributors.as_mut().unwrap().contributor[0].contributor_type =
+            "Translator".to_string();
+        downgrade_resource(&mut resource, "4.5");
+        assert_eq!(resource.resource_type.resource_type_general, "Other");
+        assert_eq!(resource.resource_type.text, "Presentation");
+        assert_eq!(resource.contributors.as_ref().unwrap().contributor[0].contributor_type, "Other");
+        assert!(resource.related_identifiers.is_none());
+
+        let xml = String::from_utf8(write_version(&data, "4.5", &XmlOptions::default()).unwrap()).unwrap();
+        assert!(xml.contains("https://schema.datacite.org/meta/kernel-4.5/metadata.xsd"));
+        assert!(xml.contains(r#"xmlns="http://datacite.org/schema/kernel-4""#));
+        assert!(!xml.contains("IsTranslationOf"));
+        let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+        assert!(xml.contains("IsTranslationOf"));
+        assert!(write_version(&data, "3.1", &XmlOptions::default()).is_err());
+    }
+
     #[test]
     fn write_xml_validates_against_xsd() {
         use crate::schema_utils::xml_schema_errors;
@@ -0,0 +1,582 @@
+//! Simple Dublin Core reader and writer, for `oai_dc` records as harvested
+//! via OAI-PMH.
+//!
+//! Accepts a bare `<oai_dc:dc>` element or a full OAI-PMH `GetRecord` /
+//! `ListRecords` response. Dublin Core carries no typed identifiers, so
+//! `dc:identifier` values are classified with [`validate_id`]. The writer
+//! emits a bare `<oai_dc:dc>` element, ready to be embedded in the
+//! `<metadata>` of an OAI-PMH record.
+
+use lazy_static::lazy_static;
+use quick_xml::de::from_str as xml_from_str;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::author_utils::{cleanup_author, infer_contributor_type, split_person_name};
+use crate::constants::{cm_to_dcmi, cm_to_eurepo, dcmi_to_cm};
+use crate::data::{
+    Container, Contributor, Data, Description, Identifier, License, Organization, Person,
+    Publisher, Subject, Title,
+};
+use crate::doi_utils::normalize_doi;
+use crate::error::{Error, Result};
+use crate::utils::{get_language, normalize_url, validate_id};
+use super::xml_output::{XmlOptions, serialize_xml};
+
+// ── XML struct definitions ─────────────────────────────────────────────────────
+// quick-xml strips the `dc:` / `oai_dc:` prefixes, except for `xml:lang`.
+
+#[derive(Deserialize, Default)]
+struct XmlOaiPmh {
+    #[serde(rename = "GetRecord", default)]
+    get_record: Option<XmlRecords>,
+    #[serde(rename = "ListRecords", default)]
+    list_records: Option<XmlRecords>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRecords {
+    #[serde(default)]
+    record: Vec<XmlRecord>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRecord {
+    #[serde(default)]
+    header: XmlHeader,
+    #[serde(default)]
+    metadata: XmlMetadata,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlHeader {
+    #[serde(rename = "@status", default)]
+    status: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlMetadata {
+    #[serde(default)]
+    dc: Option<XmlDc>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlText {
+    #[serde(rename = "@xml:lang", default)]
+    lang: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlDc {
+    #[serde(default)]
+    title: Vec<XmlText>,
+    #[serde(default)]
+    creator: Vec<XmlText>,
+    #[serde(default)]
+    subject: Vec<XmlText>,
+    #[serde(default)]
+    description: Vec<XmlText>,
+    #[serde(default)]
+    publisher: Vec<XmlText>,
+    #[serde(default)]
+    contributor: Vec<XmlText>,
+    #[serde(default)]
+    date: Vec<XmlText>,
+    #[serde(rename = "type", default)]
+    type_: Vec<XmlText>,
+    #[serde(default)]
+    identifier: Vec<XmlText>,
+    #[serde(default)]
+    source: Vec<XmlText>,
+    #[serde(default)]
+    language: Vec<XmlText>,
+    #[serde(default)]
+    rights: Vec<XmlText>,
+}
+
+// ── Conversion helpers ────────────────────────────────────────────────────────
+
+lazy_static! {
+    static ref ISO_DATE: Regex = Regex::new(r"^\d{4}(-\d{2}(-\d{2})?)?").unwrap();
+}
+
+fn values(elements: &[XmlText]) -> impl Iterator<Item = String> + '_ {
+    elements
+        .iter()
+        .map(|e| e.text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|t| !t.is_empty())
+}
+
+/// Creators and contributors are free text, usually `Family, Given`.
+fn parse_name(name: &str, role: &str) -> Option<Contributor> {
+    let cleaned = cleanup_author(Some(name))?;
+    let (given_name, family_name, fallback_name) = split_person_name(&cleaned);
+    let type_ = infer_contributor_type("", "", &given_name, &family_name, &cleaned, None);
+    let roles = vec![role.to_string()];
+    if type_ == "Person" {
+        Some(Contributor::person(
+            Person {
+                given_name,
+                family_name,
+                ..Default::default()
+            },
+            roles,
+        ))
+    } else {
+        let name = if fallback_name.is_empty() {
+            cleaned
+        } else {
+            fallback_name
+        };
+        Some(Contributor::organization(
+            Organization {
+                name,
+                ..Default::default()
+            },
+            roles,
+        ))
+    }
+}
+
+/// Pick the work type from the `dc:type` values: the first one that maps,
+/// preferring repository vocabularies over the generic DCMI `Text`.
+fn pick_type(types: &[String]) -> &'static str {
+    let mapped: Vec<&'static str> = types
+        .iter()
+        .map(|t| dcmi_to_cm(t.trim_start_matches("info:eu-repo/semantics/")))
+        .filter(|t| !t.is_empty())
+        .collect();
+    mapped
+        .iter()
+        .find(|t| **t != "Document")
+        .or_else(|| mapped.first())
+        .copied()
+        .unwrap_or("Other")
+}
+
+fn from_dc(dc: XmlDc) -> Data {
+    let mut data = Data::default();
+
+    // Identifiers: the first DOI becomes the id, the first URL the url
+    for value in values(&dc.identifier) {
+        let (identifier, identifier_type) = validate_id(&value);
+        match identifier_type {
+            "DOI" => {
+                let doi = normalize_doi(&value);
+                if data.id.is_empty() {
+                    data.id = doi.clone();
+                }
+                data.identifiers.push(Identifier {
+                    identifier: doi,
+                    identifier_type: "DOI".to_string(),
+                    ..Default::default()
+                });
+            }
+            "URL" => {
+                if data.url.is_empty() {
+                    data.url = normalize_url(&value, false, false).unwrap_or(value);
+                }
+            }
+            "" => {}
+            other => data.identifiers.push(Identifier {
+                identifier,
+                identifier_type: other.to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+    if data.id.is_empty() {
+        data.id = data.url.clone();
+    }
+
+    let types: Vec<String> = values(&dc.type_).collect();
+    data.type_ = pick_type(&types).to_string();
+
+    // Titles: the first is the main title, others are alternatives
+    for (i, title) in dc.title.iter().enumerate() {
+        let text = title.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            data.title = text;
+        } else {
+            data.additional_titles.push(Title {
+                title: text,
+                type_: "AlternativeTitle".to_string(),
+                language: title.lang.clone(),
+            });
+        }
+    }
+
+    data.contributors = values(&dc.creator)
+        .filter_map(|name| parse_name(&name, "Author"))
+        .chain(values(&dc.contributor).filter_map(|name| parse_name(&name, "Other")))
+        .collect();
+
+    // Dates: the earliest is taken as the publication date
+    let mut dates: Vec<String> = values(&dc.date)
+        .filter_map(|d| ISO_DATE.find(&d).map(|m| m.as_str().to_string()))
+        .collect();
+    dates.sort();
+    data.date_published = dates.into_iter().next().unwrap_or_default();
+
+    for (i, description) in values(&dc.description).enumerate() {
+        if i == 0 {
+            data.description = description;
+        } else {
+            data.additional_descriptions.push(Description {
+                description,
+                type_: "Other".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    data.subjects = values(&dc.subject)
+        .map(|subject| Subject {
+            subject,
+            ..Default::default()
+        })
+        .collect();
+
+    if let Some(name) = values(&dc.publisher).next() {
+        data.publisher = Publisher {
+            name,
+            ..Default::default()
+        };
+    }
+
+    if let Some(lang) = values(&dc.language).next() {
+        let iso = get_language(&lang, "");
+        data.language = if iso.is_empty() { lang } else { iso };
+    }
+
+    // Rights: the first URL that maps to a known license
+    data.license = values(&dc.rights)
+        .filter(|r| r.starts_with("http"))
+        .map(|url| crate::spdx::from_url(&url))
+        .find(|l| !l.id.is_empty())
+        .unwrap_or_else(License::default);
+
+    // Source: the containing journal or book, unless it is a link
+    if let Some(title) = values(&dc.source).find(|s| validate_id(s).1.is_empty()) {
+        data.container = Container {
+            title,
+            ..Default::default()
+        };
+    }
+
+    data
+}
+
+/// Name of the document's root element, without namespace prefix.
+fn root_element(input: &str) -> &str {
+    input
+        .split('<')
+        .skip(1)
+        .find(|tag| !tag.starts_with('?') && !tag.starts_with('!'))
+        .and_then(|tag| tag.split(|c: char| c.is_whitespace() || c == '>' || c == '/').next())
+        .map(|name| name.rsplit(':').next().unwrap_or(name))
+        .unwrap_or("")
+}
+
+// ── Public read ───────────────────────────────────────────────────────────────
+
+/// Parse every record in an `oai_dc` document, in document order. Records
+/// marked as deleted in an OAI-PMH response are skipped.
+pub fn read_xml_all(input: &str) -> Result<Vec<Data>> {
+    let records: Vec<XmlDc> = if root_element(input) == "OAI-PMH" {
+        let response: XmlOaiPmh = xml_from_str(input).map_err(|e| Error::Parse(e.to_string()))?;
+        response
+            .get_record
+            .into_iter()
+            .chain(response.list_records)
+            .flat_map(|r| r.record)
+            .filter(|r| r.header.status != "deleted")
+            .filter_map(|r| r.metadata.dc)
+            .collect()
+    } else {
+        vec![xml_from_str(input).map_err(|e| Error::Parse(e.to_string()))?]
+    };
+    Ok(records.into_iter().map(from_dc).collect())
+}
+
+/// Parse an `oai_dc` document and return its first record.
+pub fn read_xml(input: &str) -> Result<Data> {
+    read_xml_all(input)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Parse("no Dublin Core record found".to_string()))
+}
+
+// ── Output structs (XML serialization) ────────────────────────────────────────
+
+#[derive(Serialize)]
+#[serde(rename = "oai_dc:dc")]
+struct OutDc {
+    #[serde(rename = "@xmlns:oai_dc")]
+    xmlns_oai_dc: &'static str,
+    #[serde(rename = "@xmlns:dc")]
+    xmlns_dc: &'static str,
+    #[serde(rename = "@xmlns:xsi")]
+    xmlns_xsi: &'static str,
+    #[serde(rename = "@xsi:schemaLocation")]
+    xsi_schema_location: &'static str,
+    #[serde(rename = "dc:title", skip_serializing_if = "Vec::is_empty")]
+    title: Vec<OutText>,
+    #[serde(rename = "dc:creator", skip_serializing_if = "Vec::is_empty")]
+    creator: Vec<OutText>,
+    #[serde(rename = "dc:subject", skip_serializing_if = "Vec::is_empty")]
+    subject: Vec<OutText>,
+    #[serde(rename = "dc:description", skip_serializing_if = "Vec::is_empty")]
+    description: Vec<OutText>,
+    #[serde(rename = "dc:publisher", skip_serializing_if = "Vec::is_empty")]
+    publisher: Vec<OutText>,
+    #[serde(rename = "dc:contributor", skip_serializing_if = "Vec::is_empty")]
+    contributor: Vec<OutText>,
+    #[serde(rename = "dc:date", skip_serializing_if = "Vec::is_empty")]
+    date: Vec<OutText>,
+    #[serde(rename = "dc:type", skip_serializing_if = "Vec::is_empty")]
+    type_: Vec<OutText>,
+    #[serde(rename = "dc:identifier", skip_serializing_if = "Vec::is_empty")]
+    identifier: Vec<OutText>,
+    #[serde(rename = "dc:source", skip_serializing_if = "Vec::is_empty")]
+    source: Vec<OutText>,
+    #[serde(rename = "dc:language", skip_serializing_if = "Vec::is_empty")]
+    language: Vec<OutText>,
+    #[serde(rename = "dc:rights", skip_serializing_if = "Vec::is_empty")]
+    rights: Vec<OutText>,
+}
+
+#[derive(Serialize)]
+struct OutText {
+    #[serde(rename = "@xml:lang", skip_serializing_if = "str::is_empty")]
+    lang: String,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+fn out_texts<I: IntoIterator<Item = String>>(values: I) -> Vec<OutText> {
+    values
+        .into_iter()
+        .filter(|text| !text.is_empty())
+        .map(|text| OutText {
+            lang: String::new(),
+            text,
+        })
+        .collect()
+}
+
+/// Creators and contributors are written as `Family, Given`.
+fn display_name(c: &Contributor) -> String {
+    match (c.family_name(), c.given_name()) {
+        ("", "") => c.name().to_string(),
+        (family, "") => family.to_string(),
+        ("", given) => given.to_string(),
+        (family, given) => format!("{family}, {given}"),
+    }
+}
+
+fn to_dc(data: &Data) -> OutDc {
+    let mut title = out_texts([data.title.clone()]);
+    title.extend(
+        data.additional_titles
+            .iter()
+            .filter(|t| !t.title.is_empty())
+            .map(|t| OutText {
+                lang: t.language.clone(),
+                text: t.title.clone(),
+            }),
+    );
+
+    let (authors, others): (Vec<&Contributor>, Vec<&Contributor>) = data
+        .contributors
+        .iter()
+        .partition(|c| c.roles.iter().any(|r| r == "Author"));
+
+    let mut description = out_texts([data.description.clone()]);
+    description.extend(out_texts(
+        data.additional_descriptions.iter().map(|d| d.description.clone()),
+    ));
+
+    // DCMI type first, then the repository publication type if there is one
+    let mut types = vec![cm_to_dcmi(&data.type_).to_string()];
+    let eurepo = cm_to_eurepo(&data.type_);
+    if !eurepo.is_empty() {
+        types.push(format!("info:eu-repo/semantics/{eurepo}"));
+    }
+
+    // Identifiers: the id, the landing page, then any other identifiers
+    let mut identifiers: Vec<String> = vec![data.id.clone()];
+    if data.url != data.id {
+        identifiers.push(data.url.clone());
+    }
+    for i in &data.identifiers {
+        if !identifiers.contains(&i.identifier) {
+            identifiers.push(i.identifier.clone());
+        }
+    }
+
+    OutDc {
+        xmlns_oai_dc: "http://www.openarchives.org/OAI/2.0/oai_dc/",
+        xmlns_dc: "http://purl.org/dc/elements/1.1/",
+        xmlns_xsi: "http://www.w3.org/2001/XMLSchema-instance",
+        xsi_schema_location: "http://www.openarchives.org/OAI/2.0/oai_dc/ http://www.openarchives.org/OAI/2.0/oai_dc.xsd",
+        title,
+        creator: out_texts(authors.into_iter().map(display_name)),
+        subject: out_texts(data.subjects.iter().map(|s| s.subject.clone())),
+        description,
+        publisher: out_texts([data.publisher.name.clone()]),
+        contributor: out_texts(others.into_iter().map(display_name)),
+        date: out_texts([data.date_published.clone()]),
+        type_: out_texts(types),
+        identifier: out_texts(identifiers),
+        source: out_texts([data.container.title.clone()]),
+        language: out_texts([data.language.clone()]),
+        rights: out_texts([data.license.url.clone()]),
+    }
+}
+
+// ── Public write ──────────────────────────────────────────────────────────────
+
+/// Serialize `data` as a simple `oai_dc` Dublin Core record.
+pub fn write(data: &Data) -> Result<Vec<u8>> {
+    write_with(data, &XmlOptions::default())
+}
+
+/// Like [`write`], laid out according to `options`.
+pub fn write_with(data: &Data, options: &XmlOptions) -> Result<Vec<u8>> {
+    serialize_xml(to_dc(data), options)
+}
+
+/// Serialize every record in `list`, one `oai_dc` document per record.
+pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
+    write_all_with(list, &XmlOptions::default())
+}
+
+/// Like [`write_all`], laid out according to `options`.
+pub fn write_all_with(list: &[Data], options: &XmlOptions) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for data in list {
+        if !out.is_empty() {
+            out.push(b'\n');
+        }
+        out.extend_from_slice(&write_with(data, options)?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OAI_DC: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<oai_dc:dc xmlns:oai_dc="http://www.openarchives.org/OAI/2.0/oai_dc/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <dc:title xml:lang="en">Soil moisture in alpine meadows</dc:title>
+  <dc:title xml:lang="de">Bodenfeuchte in Almwiesen</dc:title>
+  <dc:creator>Müller, Anna</dc:creator>
+  <dc:creator>Alpine Research Group</dc:creator>
+  <dc:contributor>Smith, John</dc:contributor>
+  <dc:subject>hydrology</dc:subject>
+  <dc:description>Measurements from 2019 to 2021.</dc:description>
+  <dc:publisher>University of Innsbruck</dc:publisher>
+  <dc:date>2022-03-01T12:00:00Z</dc:date>
+  <dc:date>2021-11-15</dc:date>
+  <dc:type>Text</dc:type>
+  <dc:type>info:eu-repo/semantics/article</dc:type>
+  <dc:identifier>https://repository.example.org/record/42</dc:identifier>
+  <dc:identifier>https://doi.org/10.5555/DC.42</dc:identifier>
+  <dc:identifier>0000-0002-1825-0097</dc:identifier>
+  <dc:source>Journal of Alpine Hydrology</dc:source>
+  <dc:language>eng</dc:language>
+  <dc:rights>info:eu-repo/semantics/openAccess</dc:rights>
+  <dc:rights>https://creativecommons.org/licenses/by/4.0/</dc:rights>
+</oai_dc:dc>"#;
+
+    #[test]
+    fn read_xml_maps_dublin_core_elements() {
+        let data = read_xml(OAI_DC).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.5555/dc.42");
+        assert_eq!(data.url, "https://repository.example.org/record/42");
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.title, "Soil moisture in alpine meadows");
+        assert_eq!(data.additional_titles[0].title, "Bodenfeuchte in Almwiesen");
+        assert_eq!(data.additional_titles[0].language, "de");
+        assert_eq!(data.date_published, "2021-11-15");
+        assert_eq!(data.description, "Measurements from 2019 to 2021.");
+        assert_eq!(data.subjects[0].subject, "hydrology");
+        assert_eq!(data.publisher.name, "University of Innsbruck");
+        assert_eq!(data.language, "en");
+        assert_eq!(data.license.id, "CC-BY-4.0");
+        assert_eq!(data.container.title, "Journal of Alpine Hydrology");
+        assert_eq!(data.identifiers[1].identifier_type, "ORCID");
+    }
+
+    #[test]
+    fn read_xml_maps_creators_and_contributors() {
+        let data = read_xml(OAI_DC).unwrap();
+        assert_eq!(data.contributors.len(), 3);
+        assert_eq!(data.contributors[0].family_name(), "Müller");
+        assert_eq!(data.contributors[0].given_name(), "Anna");
+        assert_eq!(data.contributors[1].name(), "Alpine Research Group");
+        assert_eq!(data.contributors[2].roles, vec!["Other".to_string()]);
+    }
+
+    #[test]
+    fn read_xml_all_reads_oai_pmh_list_records() {
+        let input = r#"<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+  <responseDate>2024-01-01T00:00:00Z</responseDate>
+  <ListRecords>
+    <record>
+      <header><identifier>oai:example.org:1</identifier></header>
+      <metadata>
+        <oai_dc:dc xmlns:oai_dc="http://www.openarchives.org/OAI/2.0/oai_dc/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+          <dc:title>First</dc:title>
+          <dc:type>Dataset</dc:type>
+        </oai_dc:dc>
+      </metadata>
+    </record>
+    <record>
+      <header status="deleted"><identifier>oai:example.org:2</identifier></header>
+    </record>
+    <record>
+      <header><identifier>oai:example.org:3</identifier></header>
+      <metadata>
+        <oai_dc:dc xmlns:oai_dc="http://www.openarchives.org/OAI/2.0/oai_dc/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+          <dc:title>Third</dc:title>
+        </oai_dc:dc>
+      </metadata>
+    </record>
+  </ListRecords>
+</OAI-PMH>"#;
+        let list = read_xml_all(input).unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].title, "First");
+        assert_eq!(list[0].type_, "Dataset");
+        assert_eq!(list[1].title, "Third");
+        assert_eq!(list[1].type_, "Other");
+    }
+
+    #[test]
+    fn write_round_trips_through_read_xml() {
+        let data = read_xml(OAI_DC).unwrap();
+        let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+        assert!(xml.contains("<oai_dc:dc xmlns:oai_dc=\"http://www.openarchives.org/OAI/2.0/oai_dc/\""));
+        assert!(xml.contains("<dc:title xml:lang=\"de\">Bodenfeuchte in Almwiesen</dc:title>"));
+        assert!(xml.contains("<dc:creator>Müller, Anna</dc:creator>"));
+        assert!(xml.contains("<dc:type>info:eu-repo/semantics/article</dc:type>"));
+
+        let round = read_xml(&xml).unwrap();
+        assert_eq!(round.id, data.id);
+        assert_eq!(round.url, data.url);
+        assert_eq!(round.type_, "JournalArticle");
+        assert_eq!(round.title, data.title);
+        assert_eq!(round.contributors.len(), 3);
+        assert_eq!(round.date_published, "2021-11-15");
+        assert_eq!(round.license.id, "CC-BY-4.0");
+        assert_eq!(round.container.title, "Journal of Alpine Hydrology");
+    }
+}
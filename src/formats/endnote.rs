@@ -0,0 +1,469 @@
+//! EndNote XML reader.
+//!
+//! Maps the `<record>` elements of an EndNote XML export (`<xml><records>`)
+//! into commonmeta records, one per reference.
+
+use lazy_static::lazy_static;
+use quick_xml::de::from_str as xml_from_str;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::author_utils::{cleanup_author, split_person_name};
+use crate::constants::endnote_to_cm;
+use crate::data::{
+    Container, Contributor, Data, Identifier, Organization, Person, Publisher, Subject, Title,
+};
+use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::error::{Error, Result};
+use crate::utils::{get_language, sanitize, validate_issn};
+
+// ── XML struct definitions ─────────────────────────────────────────────────────
+// EndNote wraps most character data in `<style face=".." font=".." size="..">`
+// runs; those are removed before deserializing (see `preprocess`).
+
+#[derive(Deserialize, Default)]
+struct XmlEndNote {
+    #[serde(default)]
+    records: XmlRecords,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRecords {
+    #[serde(default)]
+    record: Vec<XmlRecord>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRecord {
+    #[serde(rename = "ref-type", default)]
+    ref_type: XmlRefType,
+    #[serde(default)]
+    contributors: XmlContributors,
+    #[serde(default)]
+    titles: XmlTitles,
+    #[serde(default)]
+    periodical: XmlPeriodical,
+    #[serde(default)]
+    pages: String,
+    #[serde(default)]
+    volume: String,
+    #[serde(default)]
+    number: String,
+    #[serde(default)]
+    edition: String,
+    #[serde(default)]
+    keywords: XmlKeywords,
+    #[serde(default)]
+    dates: XmlDates,
+    #[serde(default)]
+    publisher: String,
+    #[serde(default)]
+    isbn: String,
+    #[serde(rename = "electronic-resource-num", default)]
+    electronic_resource_num: String,
+    #[serde(default)]
+    urls: XmlUrls,
+    #[serde(rename = "abstract", default)]
+    abstract_: String,
+    #[serde(default)]
+    language: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRefType {
+    #[serde(rename = "@name", default)]
+    name: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlContributors {
+    #[serde(default)]
+    authors: XmlAuthors,
+    #[serde(rename = "secondary-authors", default)]
+    secondary_authors: XmlAuthors,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlAuthors {
+    #[serde(default)]
+    author: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlTitles {
+    #[serde(default)]
+    title: String,
+    #[serde(rename = "secondary-title", default)]
+    secondary_title: String,
+    #[serde(rename = "alt-title", default)]
+    alt_title: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlPeriodical {
+    #[serde(rename = "full-title", default)]
+    full_title: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlKeywords {
+    #[serde(default)]
+    keyword: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlDates {
+    #[serde(default)]
+    year: String,
+    #[serde(rename = "pub-dates", default)]
+    pub_dates: XmlPubDates,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlPubDates {
+    #[serde(default)]
+    date: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlUrls {
+    #[serde(rename = "related-urls", default)]
+    related_urls: XmlUrlList,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlUrlList {
+    #[serde(default)]
+    url: Vec<String>,
+}
+
+// ── Preprocessing ─────────────────────────────────────────────────────────────
+
+lazy_static! {
+    static ref STYLE_TAG: Regex = Regex::new(r"</?style\b[^>]*>").unwrap();
+    static ref ISO_DATE: Regex = Regex::new(r"^\d{4}-\d{2}(-\d{2})?$").unwrap();
+}
+
+fn preprocess(xml: &str) -> String {
+    STYLE_TAG.replace_all(xml, "").into_owned()
+}
+
+// ── Conversion helpers ────────────────────────────────────────────────────────
+
+/// EndNote joins repeated values of a field with carriage returns.
+fn split_values(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(['\r', '\n', ';'])
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+/// EndNote marks corporate authors with a trailing comma ("World Health
+/// Organization,"), everything else is "Family, Given" or "Given Family".
+fn get_contributor(name: &str, role: &str) -> Option<Contributor> {
+    let roles = vec![role.to_string()];
+    let trimmed = name.trim();
+    if let Some(org) = trimmed.strip_suffix(',') {
+        return cleanup_author(Some(org)).map(|name| {
+            Contributor::organization(
+                Organization {
+                    name,
+                    ..Default::default()
+                },
+                roles,
+            )
+        });
+    }
+    let name = cleanup_author(Some(trimmed))?;
+    let (given_name, family_name, rest) = split_person_name(&name);
+    if family_name.is_empty() {
+        return Some(Contributor::organization(
+            Organization {
+                name: rest,
+                ..Default::default()
+            },
+            roles,
+        ));
+    }
+    Some(Contributor::person(
+        Person {
+            given_name,
+            family_name,
+            ..Default::default()
+        },
+        roles,
+    ))
+}
+
+/// Use the full publication date when EndNote has one in ISO form,
+/// otherwise the year.
+fn get_date(dates: &XmlDates) -> String {
+    dates
+        .pub_dates
+        .date
+        .iter()
+        .map(|d| d.trim())
+        .find(|d| ISO_DATE.is_match(d))
+        .map(str::to_string)
+        .unwrap_or_else(|| dates.year.trim().to_string())
+}
+
+fn container_type(type_: &str) -> &'static str {
+    match type_ {
+        "JournalArticle" | "Article" => "Journal",
+        "BookChapter" => "Book",
+        "ProceedingsArticle" => "Proceedings",
+        _ => "",
+    }
+}
+
+fn from_record(record: XmlRecord) -> Data {
+    let mut data = Data {
+        type_: endnote_to_cm(record.ref_type.name.trim()).to_string(),
+        title: record.titles.title.trim().to_string(),
+        ..Data::default()
+    };
+
+    // DOI from the electronic resource number, or a doi.org link
+    let doi = split_values(&record.electronic_resource_num)
+        .chain(record.urls.related_urls.url.iter().map(|u| u.trim()))
+        .find_map(validate_doi);
+    if let Some(doi) = doi {
+        data.id = normalize_doi(&doi);
+        data.identifiers.push(Identifier {
+            identifier: data.id.clone(),
+            identifier_type: "DOI".to_string(),
+            ..Default::default()
+        });
+    }
+    data.url = record
+        .urls
+        .related_urls
+        .url
+        .iter()
+        .map(|u| u.trim())
+        .find(|u| u.starts_with("http") && validate_doi(u).is_none())
+        .unwrap_or_default()
+        .to_string();
+    if data.id.is_empty() {
+        data.id = data.url.clone();
+    }
+
+    if !record.titles.alt_title.trim().is_empty() {
+        data.additional_titles.push(Title {
+            title: record.titles.alt_title.trim().to_string(),
+            type_: "AlternativeTitle".to_string(),
+            ..Default::default()
+        });
+    }
+
+    // Authors of an edited book are its editors; secondary authors are the
+    // editors of the book or proceedings a section appears in.
+    let author_role = if record.ref_type.name == "Edited Book" {
+        "Editor"
+    } else {
+        "Author"
+    };
+    data.contributors = record
+        .contributors
+        .authors
+        .author
+        .iter()
+        .filter_map(|a| get_contributor(a, author_role))
+        .chain(
+            record
+                .contributors
+                .secondary_authors
+                .author
+                .iter()
+                .filter_map(|a| get_contributor(a, "Editor")),
+        )
+        .collect();
+
+    data.date_published = get_date(&record.dates);
+    data.description = sanitize(record.abstract_.trim());
+    if !record.publisher.trim().is_empty() {
+        data.publisher = Publisher {
+            name: record.publisher.trim().to_string(),
+            ..Default::default()
+        };
+    }
+    if !record.language.trim().is_empty() {
+        let iso = get_language(record.language.trim(), "");
+        data.language = if iso.is_empty() {
+            record.language.trim().to_string()
+        } else {
+            iso
+        };
+    }
+    data.subjects = record
+        .keywords
+        .keyword
+        .iter()
+        .flat_map(|k| split_values(k))
+        .map(|subject| Subject {
+            subject: subject.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    data.version = record.edition.trim().to_string();
+
+    // The `isbn` field holds the ISSN for serials
+    let mut issn = String::new();
+    for value in split_values(&record.isbn) {
+        if let Some(valid) = validate_issn(value) {
+            issn = valid;
+        } else {
+            data.identifiers.push(Identifier {
+                identifier: value.to_string(),
+                identifier_type: "ISBN".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    let container_type = container_type(&data.type_);
+    if !container_type.is_empty() {
+        let title = if record.periodical.full_title.trim().is_empty() {
+            record.titles.secondary_title.trim()
+        } else {
+            record.periodical.full_title.trim()
+        };
+        let (first_page, last_page) = record
+            .pages
+            .trim()
+            .split_once(['-', '–'])
+            .unwrap_or((record.pages.trim(), ""));
+        let container = Container {
+            type_: container_type.to_string(),
+            title: title.to_string(),
+            identifier_type: if issn.is_empty() { "" } else { "ISSN" }.to_string(),
+            identifier: issn,
+            volume: record.volume.trim().to_string(),
+            issue: record.number.trim().to_string(),
+            first_page: first_page.trim().to_string(),
+            last_page: last_page.trim().to_string(),
+            ..Default::default()
+        };
+        if !container.title.is_empty() || !container.identifier.is_empty() {
+            data.container = container;
+        }
+    }
+
+    data
+}
+
+// ── Public read ───────────────────────────────────────────────────────────────
+
+/// Parse every record in an EndNote XML export, in document order.
+pub fn read_xml_all(input: &str) -> Result<Vec<Data>> {
+    let endnote: XmlEndNote =
+        xml_from_str(&preprocess(input)).map_err(|e| Error::Parse(e.to_string()))?;
+    if endnote.records.record.is_empty() {
+        return Err(Error::Parse("no records found in EndNote XML".to_string()));
+    }
+    Ok(endnote.records.record.into_iter().map(from_record).collect())
+}
+
+/// Parse an EndNote XML export and return its first record.
+pub fn read_xml(input: &str) -> Result<Data> {
+    read_xml_all(input)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Parse("no records found in EndNote XML".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<xml><records>
+<record>
+  <database name="Library.enl" path="/Users/example/Library.enl">Library.enl</database>
+  <source-app name="EndNote" version="21.0">EndNote</source-app>
+  <rec-number>1</rec-number>
+  <ref-type name="Journal Article">17</ref-type>
+  <contributors><authors>
+    <author><style face="normal" font="default" size="100%">Müller, Anna</style></author>
+    <author><style face="normal" font="default" size="100%">World Health Organization,</style></author>
+  </authors></contributors>
+  <titles>
+    <title><style face="normal" font="default" size="100%">Soil moisture in </style><style face="italic" font="default" size="100%">alpine</style><style face="normal" font="default" size="100%"> meadows</style></title>
+    <secondary-title><style face="normal" font="default" size="100%">Journal of Alpine Hydrology</style></secondary-title>
+  </titles>
+  <periodical><full-title><style face="normal" font="default" size="100%">Journal of Alpine Hydrology</style></full-title></periodical>
+  <pages><style face="normal" font="default" size="100%">101-110</style></pages>
+  <volume><style face="normal" font="default" size="100%">12</style></volume>
+  <number><style face="normal" font="default" size="100%">3</style></number>
+  <keywords>
+    <keyword><style face="normal" font="default" size="100%">hydrology</style></keyword>
+    <keyword><style face="normal" font="default" size="100%">soil&#xD;grassland</style></keyword>
+  </keywords>
+  <dates><year><style face="normal" font="default" size="100%">2021</style></year>
+    <pub-dates><date><style face="normal" font="default" size="100%">2021-11-15</style></date></pub-dates></dates>
+  <isbn><style face="normal" font="default" size="100%">2049-3630</style></isbn>
+  <electronic-resource-num><style face="normal" font="default" size="100%">10.5555/ENDNOTE.1</style></electronic-resource-num>
+  <urls><related-urls><url><style face="normal" font="default" size="100%">https://example.org/articles/1</style></url></related-urls></urls>
+  <abstract><style face="normal" font="default" size="100%">Measurements from 2019 to 2021.</style></abstract>
+  <language><style face="normal" font="default" size="100%">English</style></language>
+</record>
+<record>
+  <ref-type name="Book Section">5</ref-type>
+  <contributors>
+    <authors><author>Doe, John</author></authors>
+    <secondary-authors><author>Roe, Jane</author></secondary-authors>
+  </contributors>
+  <titles><title>Valley soils</title><secondary-title>Handbook of Soils</secondary-title></titles>
+  <pages>45-67</pages>
+  <dates><year>2019</year><pub-dates><date>March</date></pub-dates></dates>
+  <publisher>Example Press</publisher>
+  <isbn>978-3-16-148410-0</isbn>
+</record>
+</records></xml>"#;
+
+    #[test]
+    fn read_xml_maps_journal_article() {
+        let data = read_xml(EXPORT).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.5555/endnote.1");
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.url, "https://example.org/articles/1");
+        assert_eq!(data.title, "Soil moisture in alpine meadows");
+        assert_eq!(data.date_published, "2021-11-15");
+        assert_eq!(data.language, "en");
+        assert_eq!(data.description, "Measurements from 2019 to 2021.");
+        assert_eq!(data.container.type_, "Journal");
+        assert_eq!(data.container.title, "Journal of Alpine Hydrology");
+        assert_eq!(data.container.identifier, "2049-3630");
+        assert_eq!(data.container.first_page, "101");
+        assert_eq!(data.container.last_page, "110");
+    }
+
+    #[test]
+    fn read_xml_parses_contributors_and_keywords() {
+        let data = read_xml(EXPORT).unwrap();
+        assert_eq!(data.contributors.len(), 2);
+        assert_eq!(data.contributors[0].family_name(), "Müller");
+        assert_eq!(data.contributors[1].name(), "World Health Organization");
+        let subjects: Vec<&str> = data.subjects.iter().map(|s| s.subject.as_str()).collect();
+        assert_eq!(subjects, ["hydrology", "soil", "grassland"]);
+    }
+
+    #[test]
+    fn read_xml_all_reads_every_record() {
+        let list = read_xml_all(EXPORT).unwrap();
+        assert_eq!(list.len(), 2);
+        let chapter = &list[1];
+        assert_eq!(chapter.type_, "BookChapter");
+        assert_eq!(chapter.date_published, "2019");
+        assert_eq!(chapter.container.title, "Handbook of Soils");
+        assert_eq!(chapter.contributors[1].roles, ["Editor"]);
+        assert_eq!(chapter.identifiers[0].identifier_type, "ISBN");
+        assert_eq!(chapter.publisher.name, "Example Press");
+    }
+
+    #[test]
+    fn read_xml_all_rejects_empty_export() {
+        assert!(read_xml_all("<xml><records></records></xml>").is_err());
+    }
+}
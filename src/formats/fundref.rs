@@ -0,0 +1,265 @@
+//! Client for the Crossref Funder Registry.
+//!
+//! Looks up funders by Crossref Funder ID via
+//! `https://api.crossref.org/funders/{id}` — name, alternative names,
+//! country and their place in the funder hierarchy — and searches them by
+//! name via `https://api.crossref.org/funders?query=...`, so that funding
+//! references can be normalized to registry IDs.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::utils::validate_crossref_funder_id;
+
+// ── Crossref API structs ──────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct FunderResponse {
+    message: FunderMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct FunderListResponse {
+    message: FunderListMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct FunderListMessage {
+    #[serde(default)]
+    items: Vec<FunderMessage>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct FunderMessage {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    alt_names: Vec<String>,
+    #[serde(default)]
+    location: String,
+    #[serde(default)]
+    replaces: Vec<String>,
+    #[serde(default)]
+    replaced_by: Vec<String>,
+    #[serde(default)]
+    hierarchy: serde_json::Value,
+    #[serde(default)]
+    hierarchy_names: HashMap<String, String>,
+}
+
+// ── Funders ───────────────────────────────────────────────────────────────────
+
+/// A funder in the Crossref Funder Registry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Funder {
+    /// The Crossref Funder ID without DOI prefix, e.g. `501100000780`.
+    pub id: String,
+    pub name: String,
+    pub alt_names: Vec<String>,
+    /// The country of the funder, e.g. `Germany`.
+    pub country: String,
+    /// The funders above this one, from the top of the hierarchy down to
+    /// its direct parent. Only filled by [`fetch_funder`].
+    pub parents: Vec<FunderName>,
+    /// The funders directly below this one. Only filled by [`fetch_funder`].
+    pub children: Vec<FunderName>,
+    /// IDs of the funders this one replaces, or is replaced by.
+    pub replaces: Vec<String>,
+    pub replaced_by: Vec<String>,
+}
+
+/// A funder in the hierarchy of another.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FunderName {
+    pub id: String,
+    pub name: String,
+}
+
+impl Funder {
+    /// The funder ID as DOI URL, e.g. `https://doi.org/10.13039/501100000780`.
+    pub fn doi(&self) -> String {
+        format!("https://doi.org/10.13039/{}", self.id)
+    }
+}
+
+fn from_message(message: FunderMessage) -> Funder {
+    let name_of = |id: &str| FunderName {
+        id: id.to_string(),
+        name: message.hierarchy_names.get(id).cloned().unwrap_or_default(),
+    };
+    let path = hierarchy_path(&message.hierarchy, &message.id).unwrap_or_default();
+    let parents = path.iter().map(|id| name_of(id)).collect();
+    let below = path
+        .iter()
+        .try_fold(&message.hierarchy, |node, id| node.get(id))
+        .and_then(|node| node.get(&message.id));
+    let children = below
+        .and_then(|node| node.as_object())
+        .map(|node| node.keys().filter(|id| *id != "more").map(|id| name_of(id)).collect())
+        .unwrap_or_default();
+    Funder {
+        id: message.id,
+        name: message.name,
+        alt_names: message.alt_names,
+        country: message.location,
+        parents,
+        children,
+        replaces: message.replaces,
+        replaced_by: message.replaced_by,
+    }
+}
+
+/// The IDs on the way from the top of `hierarchy` down to `id`, without
+/// `id` itself. The hierarchy nests funders by ID, e.g.
+/// `{"100000016": {"100000002": {"100000050": {"more": true}}}}`.
+fn hierarchy_path(hierarchy: &serde_json::Value, id: &str) -> Option<Vec<String>> {
+    let node = hierarchy.as_object()?;
+    if node.contains_key(id) {
+        return Some(vec![]);
+    }
+    node.iter().filter(|(key, _)| *key != "more").find_map(|(key, child)| {
+        let mut path = hierarchy_path(child, id)?;
+        path.insert(0, key.clone());
+        Some(path)
+    })
+}
+
+/// Read a funder from a `/funders/{id}` response.
+pub fn read_funder(json: &str) -> Result<Funder> {
+    let response: FunderResponse =
+        serde_json::from_str(json).map_err(|e| Error::Parse(format!("fundref: {}", e)))?;
+    Ok(from_message(response.message))
+}
+
+/// Read the funders from a `/funders?query=` response.
+pub fn read_funders(json: &str) -> Result<Vec<Funder>> {
+    let response: FunderListResponse =
+        serde_json::from_str(json).map_err(|e| Error::Parse(format!("fundref: {}", e)))?;
+    Ok(response.message.items.into_iter().map(from_message).collect())
+}
+
+/// The funder among `funders` whose name or an alternative name is `name`,
+/// ignoring case and surrounding whitespace.
+pub fn find_by_name(funders: Vec<Funder>, name: &str) -> Option<Funder> {
+    let name = name.trim().to_lowercase();
+    funders.into_iter().find(|funder| {
+        funder.name.to_lowercase() == name
+            || funder.alt_names.iter().any(|alt| alt.to_lowercase() == name)
+    })
+}
+
+// ── Fetch ─────────────────────────────────────────────────────────────────────
+
+fn get(url: &str) -> Result<String> {
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
+    client
+        .get(url)
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))
+}
+
+/// Fetch the funder with a Crossref Funder ID (bare or as DOI / DOI URL).
+pub fn fetch_funder(id: &str) -> Result<Funder> {
+    let id = validate_crossref_funder_id(id).ok_or_else(|| Error::InvalidId(id.to_string()))?;
+    read_funder(&get(&format!("https://api.crossref.org/funders/{}", id))?)
+}
+
+/// Search the registry for funders matching `name`, best match first.
+pub fn search_funders(name: &str) -> Result<Vec<Funder>> {
+    let url = url::Url::parse_with_params(
+        "https://api.crossref.org/funders",
+        &[("query", name), ("rows", "20")],
+    )
+    .map_err(|e| Error::Parse(e.to_string()))?;
+    read_funders(&get(url.as_str())?)
+}
+
+/// The funder named `name` (or with `name` as an alternative name), or
+/// `None` when the registry has no funder of exactly that name.
+pub fn match_funder(name: &str) -> Result<Option<Funder>> {
+    Ok(find_by_name(search_funders(name)?, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FUNDER: &str = r#"{"status": "ok", "message-type": "funder", "message": {
+        "id": "100000002",
+        "location": "United States",
+        "name": "National Institutes of Health",
+        "alt-names": ["NIH", "National Institute of Health"],
+        "uri": "http://dx.doi.org/10.13039/100000002",
+        "replaces": [],
+        "replaced-by": [],
+        "tokens": ["national", "institutes", "health", "nih"],
+        "hierarchy": {"100000016": {"100000002": {
+            "100000050": {"more": true},
+            "100000054": {"more": true}
+        }}},
+        "hierarchy-names": {
+            "100000016": "U.S. Department of Health and Human Services",
+            "100000002": "National Institutes of Health",
+            "100000050": "National Heart, Lung, and Blood Institute",
+            "100000054": "National Cancer Institute"
+        }
+    }}"#;
+
+    #[test]
+    fn test_read_funder() {
+        let funder = read_funder(FUNDER).unwrap();
+        assert_eq!(funder.id, "100000002");
+        assert_eq!(funder.name, "National Institutes of Health");
+        assert_eq!(funder.country, "United States");
+        assert_eq!(funder.doi(), "https://doi.org/10.13039/100000002");
+        assert_eq!(
+            funder.parents,
+            vec![FunderName {
+                id: "100000016".to_string(),
+                name: "U.S. Department of Health and Human Services".to_string(),
+            }]
+        );
+        let mut children: Vec<&str> = funder.children.iter().map(|c| c.name.as_str()).collect();
+        children.sort();
+        assert_eq!(
+            children,
+            vec!["National Cancer Institute", "National Heart, Lung, and Blood Institute"]
+        );
+    }
+
+    #[test]
+    fn test_read_funders_and_find_by_name() {
+        let json = r#"{"status": "ok", "message-type": "funder-list", "message": {
+            "items": [
+                {"id": "501100001659", "location": "Germany",
+                 "name": "Deutsche Forschungsgemeinschaft",
+                 "alt-names": ["DFG", "German Research Foundation"]},
+                {"id": "501100004189", "location": "Germany",
+                 "name": "Max-Planck-Gesellschaft", "alt-names": ["MPG"]}
+            ],
+            "total-results": 2
+        }}"#;
+        let funders = read_funders(json).unwrap();
+        assert_eq!(funders.len(), 2);
+        assert!(funders[0].parents.is_empty());
+        let dfg = find_by_name(funders.clone(), " german research foundation ").unwrap();
+        assert_eq!(dfg.id, "501100001659");
+        assert_eq!(find_by_name(funders, "Research Foundation"), None);
+    }
+}
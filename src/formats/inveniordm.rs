@@ -2117,6 +2117,7 @@ fn search_by_doi(
 ) -> Result<Option<String>> {
     let escaped = crate::doi_utils::escape_doi(doi);
     let url = format!("https://{}/api/records?q=doi:{}", host, escaped);
+    tracing::debug!("GET {}", url);
     let body: Value = client
         .get(&url)
         .header("Content-Type", "application/json")
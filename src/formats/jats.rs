@@ -0,0 +1,1161 @@
+//! JATS (Journal Article Tag Suite) reader and reference writer.
+//!
+//! Maps the `<front>` matter of a JATS `<article>` — `journal-meta` and
+//! `article-meta` — plus the DOIs in `<back><ref-list>` into a commonmeta
+//! record. The writer goes the other way for a single work, rendering it as
+//! an `<element-citation>` or `<mixed-citation>` for an article's reference
+//! list.
+
+use lazy_static::lazy_static;
+use quick_xml::de::from_str as xml_from_str;
+use quick_xml::escape::escape;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::author_utils::normalize_contributor_roles;
+use crate::data::{
+    Affiliation, Container, Contributor, Data, Description, FundingReference, Identifier, License,
+    Organization, Person, Publisher, Reference, Subject, Title,
+};
+use crate::doi_utils::normalize_doi;
+use crate::error::{Error, Result};
+use crate::utils::{normalize_id, normalize_orcid, normalize_ror, sanitize};
+
+// ── XML struct definitions ─────────────────────────────────────────────────────
+// quick-xml serde: `@` = attribute, `$text` = character data. Namespace
+// prefixes (`xlink:href`, `ali:license_ref`) are stripped, except for `xml:lang`.
+
+#[derive(Deserialize, Default)]
+struct XmlArticle {
+    #[serde(rename = "@article-type", default)]
+    article_type: String,
+    #[serde(rename = "@xml:lang", default)]
+    lang: String,
+    #[serde(default)]
+    front: XmlFront,
+    #[serde(default)]
+    back: XmlBack,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlFront {
+    #[serde(rename = "journal-meta", default)]
+    journal_meta: XmlJournalMeta,
+    #[serde(rename = "article-meta", default)]
+    article_meta: XmlArticleMeta,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlText {
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlTypedText {
+    #[serde(rename = "@pub-id-type", default)]
+    pub_id_type: String,
+    #[serde(rename = "@contrib-id-type", default)]
+    contrib_id_type: String,
+    #[serde(rename = "@institution-id-type", default)]
+    institution_id_type: String,
+    #[serde(rename = "@pub-type", default)]
+    pub_type: String,
+    #[serde(rename = "@publication-format", default)]
+    publication_format: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+// ── Journal meta ──────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlJournalMeta {
+    #[serde(rename = "journal-title-group", default)]
+    journal_title_group: XmlJournalTitleGroup,
+    #[serde(default)]
+    issn: Vec<XmlTypedText>,
+    #[serde(default)]
+    publisher: XmlPublisher,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlJournalTitleGroup {
+    #[serde(rename = "journal-title", default)]
+    journal_title: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlPublisher {
+    #[serde(rename = "publisher-name", default)]
+    publisher_name: String,
+}
+
+// ── Article meta ──────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlArticleMeta {
+    #[serde(rename = "article-id", default)]
+    article_id: Vec<XmlTypedText>,
+    #[serde(rename = "title-group", default)]
+    title_group: XmlTitleGroup,
+    #[serde(rename = "contrib-group", default)]
+    contrib_group: Vec<XmlContribGroup>,
+    #[serde(default)]
+    aff: Vec<XmlAff>,
+    #[serde(rename = "pub-date", default)]
+    pub_date: Vec<XmlDate>,
+    #[serde(default)]
+    volume: String,
+    #[serde(default)]
+    issue: String,
+    #[serde(default)]
+    fpage: String,
+    #[serde(default)]
+    lpage: String,
+    #[serde(rename = "elocation-id", default)]
+    elocation_id: String,
+    #[serde(default)]
+    history: XmlHistory,
+    #[serde(default)]
+    permissions: XmlPermissions,
+    #[serde(rename = "abstract", default)]
+    abstract_: Vec<XmlAbstract>,
+    #[serde(rename = "kwd-group", default)]
+    kwd_group: Vec<XmlKwdGroup>,
+    #[serde(rename = "funding-group", default)]
+    funding_group: Vec<XmlFundingGroup>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlTitleGroup {
+    #[serde(rename = "article-title", default)]
+    article_title: String,
+    #[serde(default)]
+    subtitle: String,
+    #[serde(rename = "trans-title-group", default)]
+    trans_title_group: Vec<XmlTransTitleGroup>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlTransTitleGroup {
+    #[serde(rename = "@xml:lang", default)]
+    lang: String,
+    #[serde(rename = "trans-title", default)]
+    trans_title: String,
+}
+
+// ── Contributors ──────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlContribGroup {
+    #[serde(default)]
+    contrib: Vec<XmlContrib>,
+    #[serde(default)]
+    aff: Vec<XmlAff>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlContrib {
+    #[serde(rename = "@contrib-type", default)]
+    contrib_type: String,
+    #[serde(rename = "contrib-id", default)]
+    contrib_id: Vec<XmlTypedText>,
+    #[serde(default)]
+    name: Option<XmlName>,
+    #[serde(default)]
+    collab: String,
+    #[serde(default)]
+    xref: Vec<XmlXref>,
+    #[serde(default)]
+    aff: Vec<XmlAff>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlName {
+    #[serde(default)]
+    surname: String,
+    #[serde(rename = "given-names", default)]
+    given_names: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlXref {
+    #[serde(rename = "@ref-type", default)]
+    ref_type: String,
+    #[serde(rename = "@rid", default)]
+    rid: String,
+}
+
+/// An affiliation, flattened to plain text by [`flatten_affiliations`]
+/// before parsing.
+#[derive(Deserialize, Default)]
+struct XmlAff {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+// ── Dates ─────────────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlDate {
+    #[serde(rename = "@pub-type", default)]
+    pub_type: String,
+    #[serde(rename = "@date-type", default)]
+    date_type: String,
+    #[serde(rename = "@publication-format", default)]
+    publication_format: String,
+    #[serde(default)]
+    year: String,
+    #[serde(default)]
+    month: String,
+    #[serde(default)]
+    day: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlHistory {
+    #[serde(default)]
+    date: Vec<XmlDate>,
+}
+
+// ── Permissions ───────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlPermissions {
+    #[serde(rename = "copyright-year", default)]
+    copyright_year: String,
+    #[serde(default)]
+    license: Vec<XmlLicense>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlLicense {
+    #[serde(rename = "@href", default)]
+    href: String,
+    #[serde(rename = "license_ref", default)]
+    license_ref: Vec<XmlText>,
+}
+
+// ── Abstract, keywords, funding ───────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlAbstract {
+    #[serde(rename = "@abstract-type", default)]
+    abstract_type: String,
+    #[serde(rename = "@xml:lang", default)]
+    lang: String,
+    #[serde(default)]
+    p: Vec<XmlText>,
+    #[serde(default)]
+    sec: Vec<XmlSec>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlSec {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    p: Vec<XmlText>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlKwdGroup {
+    #[serde(default)]
+    kwd: Vec<XmlText>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlFundingGroup {
+    #[serde(rename = "award-group", default)]
+    award_group: Vec<XmlAwardGroup>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlAwardGroup {
+    #[serde(rename = "funding-source", default)]
+    funding_source: Vec<XmlFundingSource>,
+    #[serde(rename = "award-id", default)]
+    award_id: Vec<XmlText>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlFundingSource {
+    #[serde(rename = "institution-wrap", default)]
+    institution_wrap: Option<XmlInstitutionWrap>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlInstitutionWrap {
+    #[serde(default)]
+    institution: String,
+    #[serde(rename = "institution-id", default)]
+    institution_id: Vec<XmlTypedText>,
+}
+
+// ── References ────────────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Default)]
+struct XmlBack {
+    #[serde(rename = "ref-list", default)]
+    ref_list: Vec<XmlRefList>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRefList {
+    #[serde(rename = "ref", default)]
+    ref_: Vec<XmlRef>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRef {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "element-citation", default)]
+    element_citation: Vec<XmlCitation>,
+    #[serde(rename = "mixed-citation", default)]
+    mixed_citation: Vec<XmlCitation>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlCitation {
+    #[serde(rename = "@publication-type", default)]
+    publication_type: String,
+    #[serde(rename = "article-title", default)]
+    article_title: String,
+    #[serde(default)]
+    source: String,
+    #[serde(default)]
+    year: String,
+    #[serde(default)]
+    volume: String,
+    #[serde(default)]
+    issue: String,
+    #[serde(default)]
+    fpage: String,
+    #[serde(default)]
+    lpage: String,
+    #[serde(rename = "pub-id", default)]
+    pub_id: Vec<XmlTypedText>,
+}
+
+// ── Pre-processing ────────────────────────────────────────────────────────────
+
+lazy_static! {
+    /// Inline formatting and linking elements whose text is kept but whose
+    /// tags would otherwise split mixed content into several text nodes.
+    static ref INLINE_TAG: Regex = Regex::new(
+        r"</?(?:italic|bold|sup|sub|sc|monospace|underline|overline|roman|sans-serif|named-content|styled-content|inline-formula|ext-link|uri|email|break)\b[^>]*>"
+    )
+    .unwrap();
+    static ref AFF: Regex = Regex::new(r"(?s)<aff\b([^>]*)>(.*?)</aff>").unwrap();
+    static ref LABEL: Regex = Regex::new(r"(?s)<label\b[^>]*>.*?</label>").unwrap();
+    static ref ANY_TAG: Regex = Regex::new(r"<[^>]+>").unwrap();
+}
+
+/// Collapse runs of whitespace, as introduced by pretty-printed XML.
+fn clean(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Replace the content of every `<aff>` with its plain text (minus the
+/// `<label>`), so that `University X, <country>Germany</country>` is not
+/// truncated at the first child element.
+fn flatten_affiliations(xml: &str) -> String {
+    AFF.replace_all(xml, |caps: &regex::Captures| {
+        let inner = LABEL.replace_all(&caps[2], "");
+        let text = ANY_TAG.replace_all(&inner, " ");
+        let text = clean(&text).replace(" ,", ",");
+        format!("<aff{}>{}</aff>", &caps[1], text)
+    })
+    .into_owned()
+}
+
+fn preprocess(xml: &str) -> String {
+    flatten_affiliations(&INLINE_TAG.replace_all(xml, ""))
+}
+
+// ── Conversion helpers ────────────────────────────────────────────────────────
+
+/// Format a JATS date as an ISO 8601 partial date (YYYY, YYYY-MM, YYYY-MM-DD).
+fn date_to_iso(date: &XmlDate) -> String {
+    let year = date.year.trim();
+    let month = date.month.trim().parse::<u32>().ok();
+    let day = date.day.trim().parse::<u32>().ok();
+    match (year.is_empty(), month, day) {
+        (true, _, _) => String::new(),
+        (false, Some(m), Some(d)) => format!("{year}-{m:02}-{d:02}"),
+        (false, Some(m), None) => format!("{year}-{m:02}"),
+        (false, None, _) => year.to_string(),
+    }
+}
+
+/// Pick the publication date: electronic before print, and any explicit
+/// publication date before `collection` (issue) dates.
+fn pick_pub_date(dates: &[XmlDate]) -> String {
+    let rank = |d: &XmlDate| -> u8 {
+        let kind = if d.date_type.is_empty() {
+            d.pub_type.as_str()
+        } else {
+            d.date_type.as_str()
+        };
+        match (kind, d.publication_format.as_str()) {
+            ("epub", _) | ("pub", "electronic") => 0,
+            ("pub", _) | ("ppub", _) | ("epub-ppub", _) | ("", _) => 1,
+            ("collection", _) => 2,
+            _ => 3,
+        }
+    };
+    dates
+        .iter()
+        .filter(|d| !d.year.trim().is_empty())
+        .min_by_key(|d| rank(d))
+        .map(date_to_iso)
+        .unwrap_or_default()
+}
+
+fn pick_issn(issns: &[XmlTypedText]) -> String {
+    issns
+        .iter()
+        .find(|i| i.pub_type == "epub" || i.publication_format == "electronic")
+        .or_else(|| issns.first())
+        .map(|i| i.text.trim().to_string())
+        .unwrap_or_default()
+}
+
+fn map_contrib_role(contrib_type: &str) -> &'static str {
+    match contrib_type {
+        "editor" => "Editor",
+        "translator" => "Translator",
+        "reviewer" => "Reviewer",
+        _ => "Author",
+    }
+}
+
+fn convert_contributors(meta: &XmlArticleMeta) -> Vec<Contributor> {
+    // Affiliations referenced via <xref ref-type="aff" rid="…"/> can live in
+    // the contrib-group or directly in article-meta.
+    let shared_affs: Vec<&XmlAff> = meta
+        .aff
+        .iter()
+        .chain(meta.contrib_group.iter().flat_map(|g| g.aff.iter()))
+        .collect();
+    let mut out = Vec::new();
+    for contrib in meta.contrib_group.iter().flat_map(|g| g.contrib.iter()) {
+        let role = map_contrib_role(&contrib.contrib_type);
+        let roles = normalize_contributor_roles(&[role.to_string()], role);
+        let mut affiliations: Vec<Affiliation> = contrib
+            .xref
+            .iter()
+            .filter(|x| x.ref_type == "aff")
+            .flat_map(|x| x.rid.split_whitespace())
+            .filter_map(|rid| shared_affs.iter().copied().find(|a| a.id == rid))
+            .chain(contrib.aff.iter())
+            .map(|a| Affiliation {
+                name: a.text.trim().to_string(),
+                ..Default::default()
+            })
+            .filter(|a| !a.name.is_empty())
+            .collect();
+        affiliations.dedup_by(|a, b| a.name == b.name);
+
+        if let Some(name) = contrib.name.as_ref().filter(|n| !n.surname.is_empty()) {
+            let orcid = contrib
+                .contrib_id
+                .iter()
+                .find(|id| id.contrib_id_type == "orcid")
+                .map(|id| normalize_orcid(id.text.trim()))
+                .unwrap_or_default();
+            out.push(Contributor::person(
+                Person {
+                    id: orcid,
+                    given_name: clean(&name.given_names),
+                    family_name: clean(&name.surname),
+                    affiliations,
+                    ..Default::default()
+                },
+                roles,
+            ));
+        } else if !contrib.collab.trim().is_empty() {
+            out.push(Contributor::organization(
+                Organization {
+                    name: clean(&contrib.collab),
+                    ..Default::default()
+                },
+                roles,
+            ));
+        }
+    }
+    out
+}
+
+fn abstract_text(a: &XmlAbstract) -> String {
+    let mut parts: Vec<String> = a.p.iter().map(|p| clean(&p.text)).collect();
+    for sec in &a.sec {
+        let body = sec
+            .p
+            .iter()
+            .map(|p| clean(&p.text))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let title = clean(&sec.title);
+        parts.push(if title.is_empty() {
+            body
+        } else {
+            format!("{title}: {body}")
+        });
+    }
+    parts.retain(|p| !p.is_empty());
+    sanitize(&parts.join(" "))
+}
+
+/// Map JATS `abstract-type` values to commonmeta description types.
+fn map_abstract_type(raw: &str) -> &'static str {
+    match raw {
+        "" | "summary" | "teaser" | "precis" => "Abstract",
+        "executive-summary" | "graphical" | "key-points" => "Summary",
+        "methods" => "Methods",
+        _ => "Other",
+    }
+}
+
+fn convert_funding(groups: &[XmlFundingGroup]) -> Vec<FundingReference> {
+    let mut out = Vec::new();
+    for award in groups.iter().flat_map(|g| g.award_group.iter()) {
+        let award_number = award
+            .award_id
+            .iter()
+            .map(|a| clean(&a.text))
+            .find(|a| !a.is_empty())
+            .unwrap_or_default();
+        for source in &award.funding_source {
+            let (funder_name, funder_id) = match &source.institution_wrap {
+                Some(wrap) => {
+                    let funder_id = wrap
+                        .institution_id
+                        .iter()
+                        .find_map(|id| {
+                            let value = id.text.trim();
+                            match id.institution_id_type.as_str() {
+                                "ror" => Some(normalize_ror(value)).filter(|r| !r.is_empty()),
+                                "doi" | "DOI" | "FundRef" | "crossref-funder-id" => {
+                                    Some(normalize_doi(value)).filter(|d| !d.is_empty())
+                                }
+                                _ => None,
+                            }
+                        })
+                        .unwrap_or_default();
+                    (clean(&wrap.institution), funder_id)
+                }
+                None => (clean(&source.text), String::new()),
+            };
+            if funder_name.is_empty() {
+                continue;
+            }
+            out.push(FundingReference {
+                funder_id,
+                funder_name,
+                award_number: award_number.clone(),
+                ..Default::default()
+            });
+        }
+    }
+    out
+}
+
+fn map_publication_type(raw: &str) -> &'static str {
+    match raw {
+        "journal" => "JournalArticle",
+        "book" => "Book",
+        "chapter" => "BookChapter",
+        "confproc" | "conf-proc" => "ProceedingsArticle",
+        "data" | "dataset" => "Dataset",
+        "preprint" => "Article",
+        "report" => "Report",
+        "software" => "Software",
+        "thesis" => "Dissertation",
+        "web" | "webpage" => "WebPage",
+        _ => "",
+    }
+}
+
+fn convert_references(back: &XmlBack) -> Vec<Reference> {
+    let mut out = Vec::new();
+    for r in back.ref_list.iter().flat_map(|l| l.ref_.iter()) {
+        let Some(citation) = r
+            .element_citation
+            .first()
+            .or_else(|| r.mixed_citation.first())
+        else {
+            continue;
+        };
+        let id = citation
+            .pub_id
+            .iter()
+            .find(|p| p.pub_id_type == "doi")
+            .map(|p| normalize_doi(p.text.trim()))
+            .unwrap_or_default();
+        out.push(Reference {
+            key: r.id.clone(),
+            id,
+            type_: map_publication_type(&citation.publication_type).to_string(),
+            title: clean(&citation.article_title),
+            publisher: clean(&citation.source),
+            publication_year: citation.year.trim().to_string(),
+            volume: citation.volume.trim().to_string(),
+            issue: citation.issue.trim().to_string(),
+            first_page: citation.fpage.trim().to_string(),
+            last_page: citation.lpage.trim().to_string(),
+            ..Default::default()
+        });
+    }
+    out
+}
+
+fn map_article_type(article_type: &str) -> &'static str {
+    match article_type {
+        "preprint" => "Article",
+        "book-review" | "product-review" => "PeerReview",
+        _ => "JournalArticle",
+    }
+}
+
+fn from_article(article: XmlArticle) -> Data {
+    let journal = &article.front.journal_meta;
+    let meta = &article.front.article_meta;
+    let mut data = Data {
+        type_: map_article_type(&article.article_type).to_string(),
+        language: article.lang.clone(),
+        ..Data::default()
+    };
+
+    // Identifiers: DOI becomes the record id
+    for article_id in &meta.article_id {
+        let value = article_id.text.trim();
+        let (identifier, identifier_type) = match article_id.pub_id_type.as_str() {
+            "doi" => (normalize_doi(value), "DOI"),
+            "pmid" => (value.to_string(), "PMID"),
+            "pmcid" | "pmc" => (value.to_string(), "PMCID"),
+            _ => continue,
+        };
+        if identifier.is_empty() {
+            continue;
+        }
+        if identifier_type == "DOI" && data.id.is_empty() {
+            data.id = identifier.clone();
+        }
+        data.identifiers.push(Identifier {
+            identifier,
+            identifier_type: identifier_type.to_string(),
+            ..Default::default()
+        });
+    }
+
+    // Titles
+    data.title = clean(&meta.title_group.article_title);
+    let subtitle = clean(&meta.title_group.subtitle);
+    if !subtitle.is_empty() {
+        data.additional_titles.push(Title {
+            title: subtitle,
+            type_: "Subtitle".to_string(),
+            ..Default::default()
+        });
+    }
+    for trans in &meta.title_group.trans_title_group {
+        let title = clean(&trans.trans_title);
+        if !title.is_empty() {
+            data.additional_titles.push(Title {
+                title,
+                type_: "TranslatedTitle".to_string(),
+                language: trans.lang.clone(),
+            });
+        }
+    }
+
+    data.contributors = convert_contributors(meta);
+
+    // Dates
+    data.date_published = pick_pub_date(&meta.pub_date);
+    for date in &meta.history.date {
+        let iso = date_to_iso(date);
+        match date.date_type.as_str() {
+            "received" => data.dates.submitted = iso,
+            "accepted" => data.dates.accepted = iso,
+            _ => {}
+        }
+    }
+    data.dates.copyrighted = meta.permissions.copyright_year.trim().to_string();
+
+    // License: xlink:href on <license>, or the NISO ALI <license_ref>
+    let license_url = meta
+        .permissions
+        .license
+        .iter()
+        .flat_map(|l| {
+            std::iter::once(l.href.trim().to_string())
+                .chain(l.license_ref.iter().map(|r| r.text.trim().to_string()))
+        })
+        .find(|url| !url.is_empty());
+    data.license = match license_url {
+        Some(url) => crate::spdx::from_url(&normalize_id(&url)),
+        None => License::default(),
+    };
+
+    // Abstracts: the untyped abstract is the description, others additional
+    for a in &meta.abstract_ {
+        let text = abstract_text(a);
+        if text.is_empty() {
+            continue;
+        }
+        let type_ = map_abstract_type(&a.abstract_type);
+        if type_ == "Abstract" && data.description.is_empty() {
+            data.description = text;
+        } else {
+            data.additional_descriptions.push(Description {
+                description: text,
+                type_: type_.to_string(),
+                language: a.lang.clone(),
+            });
+        }
+    }
+
+    data.subjects = meta
+        .kwd_group
+        .iter()
+        .flat_map(|g| g.kwd.iter())
+        .map(|k| clean(&k.text))
+        .filter(|k| !k.is_empty())
+        .map(|subject| Subject {
+            subject,
+            ..Default::default()
+        })
+        .collect();
+
+    data.funding_references = convert_funding(&meta.funding_group);
+
+    // Container and publisher
+    let issn = pick_issn(&journal.issn);
+    let first_page = if meta.fpage.trim().is_empty() {
+        meta.elocation_id.trim().to_string()
+    } else {
+        meta.fpage.trim().to_string()
+    };
+    data.container = Container {
+        type_: "Journal".to_string(),
+        title: clean(&journal.journal_title_group.journal_title),
+        identifier_type: if issn.is_empty() { "" } else { "ISSN" }.to_string(),
+        identifier: issn,
+        volume: meta.volume.trim().to_string(),
+        issue: meta.issue.trim().to_string(),
+        first_page,
+        last_page: meta.lpage.trim().to_string(),
+        ..Default::default()
+    };
+    if data.container.title.is_empty() && data.container.identifier.is_empty() {
+        data.container = Container::default();
+    }
+    data.publisher = Publisher {
+        name: clean(&journal.publisher.publisher_name),
+        ..Default::default()
+    };
+
+    data.references = convert_references(&article.back);
+
+    data
+}
+
+// ── Public read ───────────────────────────────────────────────────────────────
+
+/// Parse a JATS `<article>` document.
+pub fn read_xml(input: &str) -> Result<Data> {
+    let article: XmlArticle =
+        xml_from_str(&preprocess(input)).map_err(|e| Error::Parse(e.to_string()))?;
+    if article.front.article_meta.title_group.article_title.is_empty()
+        && article.front.article_meta.article_id.is_empty()
+    {
+        return Err(Error::Parse("JATS article has no article-meta".to_string()));
+    }
+    Ok(from_article(article))
+}
+
+// ── Reference writer ──────────────────────────────────────────────────────────
+// Citations are mixed content (elements interleaved with punctuation), which
+// serde cannot express, so the fragments are assembled as escaped strings.
+
+fn cm_to_publication_type(type_: &str) -> &'static str {
+    match type_ {
+        "JournalArticle" => "journal",
+        "Book" => "book",
+        "BookChapter" => "chapter",
+        "Proceedings" | "ProceedingsArticle" => "confproc",
+        "Dataset" => "data",
+        "Article" => "preprint",
+        "Report" => "report",
+        "Software" => "software",
+        "Dissertation" => "thesis",
+        "BlogPost" | "WebPage" => "web",
+        _ => "other",
+    }
+}
+
+fn element(tag: &str, text: &str) -> String {
+    format!("<{tag}>{}</{tag}>", escape(text))
+}
+
+/// `<person-group>` for the contributors with `role`, or `""` if there are
+/// none. Mixed citations separate names with commas.
+fn person_group(data: &Data, role: &str, group_type: &str, mixed: bool) -> String {
+    let names: Vec<String> = data
+        .contributors
+        .iter()
+        .filter(|c| c.roles.iter().any(|r| r == role))
+        .map(|c| {
+            if c.person.is_some() {
+                let mut name = element("surname", c.family_name());
+                if !c.given_name().is_empty() {
+                    name.push_str(if mixed { " " } else { "" });
+                    name.push_str(&element("given-names", c.given_name()));
+                }
+                format!("<name>{name}</name>")
+            } else {
+                element("collab", &c.name())
+            }
+        })
+        .collect();
+    if names.is_empty() {
+        return String::new();
+    }
+    let separator = if mixed { ", " } else { "" };
+    format!(
+        "<person-group person-group-type=\"{group_type}\">{}</person-group>",
+        names.join(separator)
+    )
+}
+
+/// Books, reports and similar stand-alone works are cited by their title as
+/// `source`, followed by the publisher.
+fn is_standalone(type_: &str) -> bool {
+    matches!(
+        type_,
+        "Book" | "Dissertation" | "Proceedings" | "Report" | "Software" | "WebPage"
+    )
+}
+
+/// The title elements of a citation: the work's own title and the `source`
+/// it appeared in, falling back to the publisher (e.g. a data repository).
+fn title_elements(data: &Data) -> (String, String) {
+    let source = if data.container.title.is_empty() {
+        data.publisher.name.as_str()
+    } else {
+        data.container.title.as_str()
+    };
+    match data.type_.as_str() {
+        t if is_standalone(t) => (String::new(), element("source", &data.title)),
+        "BookChapter" => (element("chapter-title", &data.title), element("source", source)),
+        "Dataset" => (element("data-title", &data.title), element("source", source)),
+        _ if source.is_empty() => (element("article-title", &data.title), String::new()),
+        _ => (element("article-title", &data.title), element("source", source)),
+    }
+}
+
+fn render_citation(data: &Data, mixed: bool) -> String {
+    let tag = if mixed { "mixed-citation" } else { "element-citation" };
+    let c = &data.container;
+    let (title, source) = title_elements(data);
+    let year = data.date_published.get(..4).unwrap_or("");
+    let doi = crate::doi_utils::validate_doi(&data.id).unwrap_or_default();
+    let publisher = if (is_standalone(&data.type_) || data.type_ == "BookChapter")
+        && !data.publisher.name.is_empty()
+    {
+        element("publisher-name", &data.publisher.name)
+    } else {
+        String::new()
+    };
+
+    let mut out = format!(
+        "<{tag} publication-type=\"{}\">",
+        cm_to_publication_type(&data.type_)
+    );
+    if mixed {
+        let mut parts: Vec<String> = Vec::new();
+        let authors = person_group(data, "Author", "author", true);
+        if !authors.is_empty() {
+            parts.push(authors);
+        }
+        if !title.is_empty() {
+            parts.push(title);
+        }
+        let editors = person_group(data, "Editor", "editor", true);
+        if !editors.is_empty() {
+            parts.push(format!("In: {editors}, editors"));
+        }
+        if !source.is_empty() {
+            parts.push(source);
+        }
+        if !publisher.is_empty() {
+            parts.push(publisher);
+        }
+        let mut issued = String::new();
+        if !year.is_empty() {
+            issued.push_str(&element("year", year));
+        }
+        if !c.volume.is_empty() {
+            issued.push(';');
+            issued.push_str(&element("volume", &c.volume));
+            if !c.issue.is_empty() {
+                issued.push_str(&format!("({})", element("issue", &c.issue)));
+            }
+        }
+        if !c.first_page.is_empty() {
+            issued.push(':');
+            issued.push_str(&element("fpage", &c.first_page));
+            if !c.last_page.is_empty() {
+                issued.push('–');
+                issued.push_str(&element("lpage", &c.last_page));
+            }
+        }
+        if !issued.is_empty() {
+            parts.push(issued);
+        }
+        if !doi.is_empty() {
+            parts.push(format!("doi:<pub-id pub-id-type=\"doi\">{}</pub-id>", escape(&doi)));
+        }
+        out.push_str(&parts.join(". "));
+        // A DOI ends the citation without trailing punctuation.
+        if doi.is_empty() && !parts.is_empty() {
+            out.push('.');
+        }
+    } else {
+        out.push_str(&person_group(data, "Author", "author", false));
+        out.push_str(&person_group(data, "Editor", "editor", false));
+        out.push_str(&title);
+        out.push_str(&source);
+        out.push_str(&publisher);
+        for (name, value) in [
+            ("year", year),
+            ("volume", c.volume.as_str()),
+            ("issue", c.issue.as_str()),
+            ("fpage", c.first_page.as_str()),
+            ("lpage", c.last_page.as_str()),
+        ] {
+            if !value.is_empty() {
+                out.push_str(&element(name, value));
+            }
+        }
+        if !doi.is_empty() {
+            out.push_str(&format!("<pub-id pub-id-type=\"doi\">{}</pub-id>", escape(&doi)));
+        } else if !data.url.is_empty() {
+            out.push_str(&format!(
+                "<ext-link ext-link-type=\"uri\" xlink:href=\"{}\">{}</ext-link>",
+                escape(&data.url),
+                escape(&data.url)
+            ));
+        }
+    }
+    out.push_str(&format!("</{tag}>"));
+    out
+}
+
+// ── Public write ──────────────────────────────────────────────────────────────
+
+/// Render `data` as a JATS `<element-citation>` fragment.
+pub fn write(data: &Data) -> Result<Vec<u8>> {
+    Ok(render_citation(data, false).into_bytes())
+}
+
+/// Render `data` as a JATS `<mixed-citation>` fragment, with the punctuation
+/// of a Vancouver-style reference between the tagged elements.
+pub fn write_mixed(data: &Data) -> Result<Vec<u8>> {
+    Ok(render_citation(data, true).into_bytes())
+}
+
+/// Render `list` as a `<ref-list>`, one `<ref id="ref-N">` per record.
+pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
+    let mut out = String::from("<ref-list>\n");
+    for (i, data) in list.iter().enumerate() {
+        out.push_str(&format!(
+            "  <ref id=\"ref-{}\">{}</ref>\n",
+            i + 1,
+            render_citation(data, false)
+        ));
+    }
+    out.push_str("</ref-list>\n");
+    Ok(out.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE article PUBLIC "-//NLM//DTD JATS (Z39.96) Journal Publishing DTD v1.3 20210610//EN" "JATS-journalpublishing1-3.dtd">
+<article xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:ali="http://www.niso.org/schemas/ali/1.0/" article-type="research-article" xml:lang="en">
+  <front>
+    <journal-meta>
+      <journal-title-group><journal-title>Journal of Examples</journal-title></journal-title-group>
+      <issn pub-type="ppub">1234-5678</issn>
+      <issn pub-type="epub">2049-3630</issn>
+      <publisher><publisher-name>Example Press</publisher-name></publisher>
+    </journal-meta>
+    <article-meta>
+      <article-id pub-id-type="doi">10.5555/jats.2024.1</article-id>
+      <article-id pub-id-type="pmid">38000001</article-id>
+      <title-group>
+        <article-title>Gene expression in <italic>E. coli</italic></article-title>
+        <subtitle>A case study</subtitle>
+      </title-group>
+      <contrib-group>
+        <contrib contrib-type="author">
+          <contrib-id contrib-id-type="orcid">https://orcid.org/0000-0002-1825-0097</contrib-id>
+          <name><surname>Smith</surname><given-names>Jane</given-names></name>
+          <xref ref-type="aff" rid="aff1"><sup>1</sup></xref>
+        </contrib>
+        <contrib contrib-type="author">
+          <collab>The Example Consortium</collab>
+        </contrib>
+        <aff id="aff1"><label>1</label>University of Example, <country>Germany</country></aff>
+      </contrib-group>
+      <pub-date date-type="pub" publication-format="print"><year>2024</year><month>05</month></pub-date>
+      <pub-date date-type="pub" publication-format="electronic"><day>02</day><month>04</month><year>2024</year></pub-date>
+      <volume>12</volume>
+      <issue>3</issue>
+      <fpage>101</fpage>
+      <lpage>110</lpage>
+      <history>
+        <date date-type="received"><day>10</day><month>01</month><year>2024</year></date>
+        <date date-type="accepted"><day>15</day><month>03</month><year>2024</year></date>
+      </history>
+      <permissions>
+        <copyright-year>2024</copyright-year>
+        <license xlink:href="https://creativecommons.org/licenses/by/4.0/">
+          <license-p>This article is licensed under CC BY 4.0.</license-p>
+        </license>
+      </permissions>
+      <abstract>
+        <sec><title>Background</title><p>We studied <bold>genes</bold>.</p></sec>
+        <sec><title>Results</title><p>They were expressed.</p></sec>
+      </abstract>
+      <kwd-group><kwd>genetics</kwd><kwd>bacteria</kwd></kwd-group>
+      <funding-group>
+        <award-group>
+          <funding-source>
+            <institution-wrap>
+              <institution>Example Foundation</institution>
+              <institution-id institution-id-type="ror">https://ror.org/02nr0ka47</institution-id>
+            </institution-wrap>
+          </funding-source>
+          <award-id>EF-42</award-id>
+        </award-group>
+      </funding-group>
+    </article-meta>
+  </front>
+  <back>
+    <ref-list>
+      <ref id="R1">
+        <element-citation publication-type="journal">
+          <article-title>An earlier paper</article-title>
+          <source>Other Journal</source>
+          <year>2020</year>
+          <pub-id pub-id-type="doi">10.5555/earlier</pub-id>
+        </element-citation>
+      </ref>
+    </ref-list>
+  </back>
+</article>"#;
+
+    #[test]
+    fn read_xml_maps_article_meta() {
+        let data = read_xml(ARTICLE).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.5555/jats.2024.1");
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.language, "en");
+        assert_eq!(data.title, "Gene expression in E. coli");
+        assert_eq!(data.additional_titles[0].title, "A case study");
+        assert_eq!(data.identifiers[1].identifier_type, "PMID");
+        assert_eq!(data.date_published, "2024-04-02");
+        assert_eq!(data.dates.submitted, "2024-01-10");
+        assert_eq!(data.dates.accepted, "2024-03-15");
+        assert_eq!(data.license.id, "CC-BY-4.0");
+        assert_eq!(
+            data.description,
+            "Background: We studied genes. Results: They were expressed."
+        );
+        assert_eq!(data.subjects.len(), 2);
+        assert_eq!(data.publisher.name, "Example Press");
+        assert_eq!(data.container.title, "Journal of Examples");
+        assert_eq!(data.container.identifier, "2049-3630");
+        assert_eq!(data.container.first_page, "101");
+        assert_eq!(data.container.last_page, "110");
+    }
+
+    #[test]
+    fn read_xml_maps_contributors_and_affiliations() {
+        let data = read_xml(ARTICLE).unwrap();
+        assert_eq!(data.contributors.len(), 2);
+        let person = &data.contributors[0];
+        assert_eq!(person.family_name(), "Smith");
+        assert_eq!(person.given_name(), "Jane");
+        assert_eq!(person.id(), "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(person.affiliations()[0].name, "University of Example, Germany");
+        assert_eq!(data.contributors[1].name(), "The Example Consortium");
+    }
+
+    #[test]
+    fn read_xml_maps_funding_and_references() {
+        let data = read_xml(ARTICLE).unwrap();
+        assert_eq!(data.funding_references[0].funder_name, "Example Foundation");
+        assert_eq!(data.funding_references[0].funder_id, "https://ror.org/02nr0ka47");
+        assert_eq!(data.funding_references[0].award_number, "EF-42");
+        assert_eq!(data.references[0].key, "R1");
+        assert_eq!(data.references[0].id, "https://doi.org/10.5555/earlier");
+        assert_eq!(data.references[0].type_, "JournalArticle");
+    }
+
+    #[test]
+    fn write_renders_element_citation() {
+        let data = read_xml(ARTICLE).unwrap();
+        let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+        assert_eq!(
+            xml,
+            "<element-citation publication-type=\"journal\">\
+             <person-group person-group-type=\"author\">\
+             <name><surname>Smith</surname><given-names>Jane</given-names></name>\
+             <collab>The Example Consortium</collab></person-group>\
+             <article-title>Gene expression in E. coli</article-title>\
+             <source>Journal of Examples</source>\
+             <year>2024</year><volume>12</volume><issue>3</issue>\
+             <fpage>101</fpage><lpage>110</lpage>\
+             <pub-id pub-id-type=\"doi\">10.5555/jats.2024.1</pub-id>\
+             </element-citation>"
+        );
+    }
+
+    #[test]
+    fn write_mixed_renders_punctuated_citation() {
+        let data = read_xml(ARTICLE).unwrap();
+        let xml = String::from_utf8(write_mixed(&data).unwrap()).unwrap();
+        assert!(xml.starts_with("<mixed-citation publication-type=\"journal\"><person-group"));
+        assert!(xml.contains("<surname>Smith</surname> <given-names>Jane</given-names></name>, <collab>"));
+        assert!(xml.contains(
+            "<year>2024</year>;<volume>12</volume>(<issue>3</issue>):<fpage>101</fpage>–<lpage>110</lpage>. doi:"
+        ));
+        assert!(xml.ends_with("</pub-id></mixed-citation>"));
+    }
+
+    #[test]
+    fn write_all_numbers_refs() {
+        let data = read_xml(ARTICLE).unwrap();
+        let xml = String::from_utf8(write_all(&[data.clone(), data]).unwrap()).unwrap();
+        assert!(xml.starts_with("<ref-list>"));
+        assert!(xml.contains("<ref id=\"ref-2\"><element-citation"));
+    }
+
+    #[test]
+    fn read_xml_rejects_non_article() {
+        assert!(read_xml("<resource><identifier/></resource>").is_err());
+    }
+}
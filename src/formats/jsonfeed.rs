@@ -700,6 +700,7 @@ pub fn fetch(id: &str) -> Result<Data> {
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", api_url);
     let resp = client
         .get(&api_url)
         .send()
@@ -0,0 +1,697 @@
+//! MARC 21 bibliographic reader, for MARCXML and binary (ISO 2709) records.
+//!
+//! Both serializations are first parsed into the same [`MarcRecord`] shape;
+//! [`from_record`] then maps the main fields (leader, 020/022/024, 100/110,
+//! 245, 250, 260/264, 520, 650, 700/710, 773, 856) into commonmeta.
+
+use lazy_static::lazy_static;
+use quick_xml::de::from_str as xml_from_str;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::author_utils::split_person_name;
+use crate::data::{
+    Container, Contributor, Data, Identifier, Organization, Person, Publisher, Subject, Title,
+};
+use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::error::{Error, Result};
+use crate::utils::{get_language, validate_issn, validate_lccn};
+
+// ── Record model ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default)]
+struct MarcRecord {
+    leader: String,
+    control_fields: Vec<(String, String)>,
+    data_fields: Vec<DataField>,
+}
+
+#[derive(Debug, Default)]
+struct DataField {
+    tag: String,
+    ind1: char,
+    ind2: char,
+    subfields: Vec<(char, String)>,
+}
+
+impl MarcRecord {
+    fn control(&self, tag: &str) -> &str {
+        self.control_fields
+            .iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or("")
+    }
+
+    fn fields<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a DataField> + 'a {
+        self.data_fields.iter().filter(move |f| f.tag == tag)
+    }
+
+    fn first(&self, tag: &str, code: char) -> String {
+        self.fields(tag)
+            .find_map(|f| f.get(code))
+            .map(trim_isbd)
+            .unwrap_or_default()
+    }
+}
+
+impl DataField {
+    fn get(&self, code: char) -> Option<&str> {
+        self.subfields
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn all(&self, code: char) -> impl Iterator<Item = &str> {
+        self.subfields
+            .iter()
+            .filter(move |(c, _)| *c == code)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+// ── MARCXML ───────────────────────────────────────────────────────────────────
+// quick-xml strips the `marc:` prefix used by some exports.
+
+#[derive(Deserialize, Default)]
+struct XmlCollection {
+    #[serde(default)]
+    record: Vec<XmlRecord>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlRecord {
+    #[serde(default)]
+    leader: String,
+    #[serde(default)]
+    controlfield: Vec<XmlControlField>,
+    #[serde(default)]
+    datafield: Vec<XmlDataField>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlControlField {
+    #[serde(rename = "@tag", default)]
+    tag: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlDataField {
+    #[serde(rename = "@tag", default)]
+    tag: String,
+    #[serde(rename = "@ind1", default)]
+    ind1: String,
+    #[serde(rename = "@ind2", default)]
+    ind2: String,
+    #[serde(default)]
+    subfield: Vec<XmlSubfield>,
+}
+
+#[derive(Deserialize, Default)]
+struct XmlSubfield {
+    #[serde(rename = "@code", default)]
+    code: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+fn indicator(s: &str) -> char {
+    s.chars().next().unwrap_or(' ')
+}
+
+impl From<XmlRecord> for MarcRecord {
+    fn from(record: XmlRecord) -> Self {
+        MarcRecord {
+            leader: record.leader,
+            control_fields: record
+                .controlfield
+                .into_iter()
+                .map(|c| (c.tag, c.text))
+                .collect(),
+            data_fields: record
+                .datafield
+                .into_iter()
+                .map(|d| DataField {
+                    ind1: indicator(&d.ind1),
+                    ind2: indicator(&d.ind2),
+                    tag: d.tag,
+                    subfields: d
+                        .subfield
+                        .into_iter()
+                        .filter_map(|s| s.code.chars().next().map(|c| (c, s.text)))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn parse_marcxml(input: &str) -> Result<Vec<MarcRecord>> {
+    let records = if root_element(input) == "collection" {
+        let collection: XmlCollection =
+            xml_from_str(input).map_err(|e| Error::Parse(e.to_string()))?;
+        collection.record
+    } else {
+        vec![xml_from_str::<XmlRecord>(input).map_err(|e| Error::Parse(e.to_string()))?]
+    };
+    Ok(records.into_iter().map(MarcRecord::from).collect())
+}
+
+/// Name of the document's root element, without namespace prefix.
+fn root_element(input: &str) -> &str {
+    input
+        .split('<')
+        .skip(1)
+        .find(|tag| !tag.starts_with('?') && !tag.starts_with('!'))
+        .and_then(|tag| tag.split(|c: char| c.is_whitespace() || c == '>' || c == '/').next())
+        .map(|name| name.rsplit(':').next().unwrap_or(name))
+        .unwrap_or("")
+}
+
+// ── Binary MARC 21 (ISO 2709) ─────────────────────────────────────────────────
+
+const RECORD_TERMINATOR: u8 = 0x1D;
+const FIELD_TERMINATOR: u8 = 0x1E;
+const SUBFIELD_DELIMITER: u8 = 0x1F;
+
+fn parse_binary_record(bytes: &[u8]) -> Result<MarcRecord> {
+    let invalid = |msg: &str| Error::Parse(format!("invalid MARC 21 record: {msg}"));
+    if bytes.len() < 24 {
+        return Err(invalid("shorter than the leader"));
+    }
+    let leader = String::from_utf8_lossy(&bytes[..24]).to_string();
+    let base_address: usize = leader
+        .get(12..17)
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid("base address is not numeric"))?;
+    if base_address > bytes.len() || base_address < 25 {
+        return Err(invalid("base address out of range"));
+    }
+    // The directory runs from the leader to the field terminator before
+    // the base address, in 12-byte entries: tag (3), length (4), start (5).
+    let directory = &bytes[24..base_address - 1];
+    let mut record = MarcRecord {
+        leader,
+        ..Default::default()
+    };
+    for entry in directory.chunks_exact(12) {
+        if !entry.is_ascii() {
+            return Err(invalid("directory is not ASCII"));
+        }
+        let entry = String::from_utf8_lossy(entry);
+        let tag = entry[..3].to_string();
+        let length: usize = entry[3..7]
+            .parse()
+            .map_err(|_| invalid("field length is not numeric"))?;
+        let start: usize = entry[7..12]
+            .parse()
+            .map_err(|_| invalid("field start is not numeric"))?;
+        let field = bytes
+            .get(base_address + start..base_address + start + length)
+            .ok_or_else(|| invalid("field outside of record"))?;
+        let field = field.strip_suffix(&[FIELD_TERMINATOR]).unwrap_or(field);
+        if tag.starts_with("00") {
+            record
+                .control_fields
+                .push((tag, String::from_utf8_lossy(field).to_string()));
+            continue;
+        }
+        let mut parts = field.split(|b| *b == SUBFIELD_DELIMITER);
+        let indicators = parts.next().unwrap_or_default();
+        let subfields = parts
+            .filter_map(|part| {
+                let (code, value) = part.split_first()?;
+                Some((*code as char, String::from_utf8_lossy(value).to_string()))
+            })
+            .collect();
+        record.data_fields.push(DataField {
+            tag,
+            ind1: indicators.first().map(|b| *b as char).unwrap_or(' '),
+            ind2: indicators.get(1).map(|b| *b as char).unwrap_or(' '),
+            subfields,
+        });
+    }
+    Ok(record)
+}
+
+fn parse_binary(input: &[u8]) -> Result<Vec<MarcRecord>> {
+    input
+        .split(|b| *b == RECORD_TERMINATOR)
+        .filter(|r| !r.iter().all(u8::is_ascii_whitespace))
+        .map(parse_binary_record)
+        .collect()
+}
+
+// ── Conversion helpers ────────────────────────────────────────────────────────
+
+lazy_static! {
+    static ref YEAR: Regex = Regex::new(r"\b(1[5-9]\d{2}|20\d{2})\b").unwrap();
+    static ref ISBN: Regex = Regex::new(r"^[0-9Xx-]{10,17}").unwrap();
+    static ref VOLUME: Regex = Regex::new(r"(?i)\bv(?:ol)?\.?\s*(\w+)").unwrap();
+    static ref ISSUE: Regex = Regex::new(r"(?i)\b(?:no|nr|issue)\.?\s*(\w+)").unwrap();
+    static ref PAGES: Regex = Regex::new(r"(?i)\bp+\.?\s*(\w+)(?:\s*-\s*(\w+))?").unwrap();
+}
+
+/// Remove the ISBD punctuation (` /`, ` :`, ` ;`, `,`, final `.`) that
+/// catalogers append to subfield values.
+fn trim_isbd(value: &str) -> String {
+    let value = value
+        .trim()
+        .trim_end_matches([' ', '/', ':', ';', ',', '='])
+        .trim_end();
+    // Keep the period of a trailing initial ("Smith, J.") or abbreviation.
+    let keep_period = value
+        .rsplit(' ')
+        .next()
+        .map(|word| word.len() <= 3 && word.ends_with('.'))
+        .unwrap_or(false);
+    if keep_period {
+        value.to_string()
+    } else {
+        value.trim_end_matches('.').to_string()
+    }
+}
+
+/// Work type from leader positions 06 (type of record) and 07
+/// (bibliographic level), refined by a 502 dissertation note or a 773
+/// host item entry.
+fn marc_to_cm_type(record: &MarcRecord) -> &'static str {
+    let mut leader = record.leader.chars().skip(6);
+    let type_of_record = leader.next().unwrap_or(' ');
+    let level = leader.next().unwrap_or(' ');
+    match type_of_record {
+        'a' | 't' if record.fields("502").next().is_some() => "Dissertation",
+        'a' => match level {
+            'a' | 'b' if record.fields("773").next().is_some() => {
+                if record.fields("773").any(|f| f.get('x').is_some()) {
+                    "JournalArticle"
+                } else {
+                    "BookChapter"
+                }
+            }
+            'a' | 'b' => "JournalArticle",
+            's' | 'i' => "Journal",
+            'c' => "Collection",
+            _ => "Book",
+        },
+        't' => "Manuscript",
+        'c' | 'd' | 'i' | 'j' => "Sound",
+        'e' | 'f' => "Map",
+        'g' => "Audiovisual",
+        'k' => "Image",
+        'm' => "Software",
+        'p' => "Collection",
+        'r' => "PhysicalObject",
+        _ => "Other",
+    }
+}
+
+fn name_contributor(field: &DataField, default_role: &str) -> Option<Contributor> {
+    let name = trim_isbd(field.get('a')?);
+    if name.is_empty() {
+        return None;
+    }
+    // Relator term ($e) or code ($4)
+    let is_editor = field
+        .all('e')
+        .chain(field.all('4'))
+        .any(|r| r.starts_with("edt") || r.trim().starts_with("ed"));
+    let role = if is_editor { "Editor" } else { default_role };
+    let roles = vec![role.to_string()];
+    if field.tag.ends_with("00") {
+        let (given_name, family_name, _) = split_person_name(&name);
+        Some(Contributor::person(
+            Person {
+                given_name,
+                family_name,
+                ..Default::default()
+            },
+            roles,
+        ))
+    } else {
+        let name = match field.get('b') {
+            Some(unit) => format!("{}. {}", name, trim_isbd(unit)),
+            None => name,
+        };
+        Some(Contributor::organization(
+            Organization {
+                name,
+                ..Default::default()
+            },
+            roles,
+        ))
+    }
+}
+
+fn convert_host_item(record: &MarcRecord) -> Container {
+    let Some(host) = record.fields("773").next() else {
+        return Container::default();
+    };
+    let issn = host.get('x').and_then(validate_issn).unwrap_or_default();
+    let related = host.all('g').collect::<Vec<_>>().join(" ");
+    let capture = |re: &Regex, group: usize| {
+        re.captures(&related)
+            .and_then(|c| c.get(group))
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default()
+    };
+    Container {
+        type_: if issn.is_empty() { "Book" } else { "Journal" }.to_string(),
+        title: host.get('t').map(trim_isbd).unwrap_or_default(),
+        identifier_type: if issn.is_empty() { "" } else { "ISSN" }.to_string(),
+        identifier: issn,
+        volume: capture(&VOLUME, 1),
+        issue: capture(&ISSUE, 1),
+        first_page: capture(&PAGES, 1),
+        last_page: capture(&PAGES, 2),
+        ..Default::default()
+    }
+}
+
+fn from_record(record: &MarcRecord) -> Data {
+    let mut data = Data {
+        type_: marc_to_cm_type(record).to_string(),
+        ..Data::default()
+    };
+
+    // DOI from 024 (source in $2), else from an 856 link
+    let doi = record
+        .fields("024")
+        .filter(|f| f.ind1 == '7' && f.get('2').map(|s| s.eq_ignore_ascii_case("doi")) == Some(true))
+        .find_map(|f| f.get('a'))
+        .or_else(|| {
+            record
+                .fields("856")
+                .flat_map(|f| f.all('u'))
+                .find(|u| validate_doi(u).is_some())
+        })
+        .map(normalize_doi)
+        .unwrap_or_default();
+    let url = record
+        .fields("856")
+        .flat_map(|f| f.all('u'))
+        .find(|u| validate_doi(u).is_none())
+        .unwrap_or_default()
+        .to_string();
+    if !doi.is_empty() {
+        data.identifiers.push(Identifier {
+            identifier: doi.clone(),
+            identifier_type: "DOI".to_string(),
+            ..Default::default()
+        });
+    }
+    for isbn in record.fields("020").filter_map(|f| f.get('a')) {
+        if let Some(m) = ISBN.find(isbn.trim()) {
+            data.identifiers.push(Identifier {
+                identifier: m.as_str().to_string(),
+                identifier_type: "ISBN".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+    for lccn in record.fields("010").filter_map(|f| f.get('a')) {
+        if let Some(lccn) = validate_lccn(lccn) {
+            data.identifiers.push(Identifier {
+                identifier: lccn,
+                identifier_type: "LCCN".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+    for issn in record.fields("022").filter_map(|f| f.get('a')) {
+        if let Some(issn) = validate_issn(issn.trim()) {
+            data.identifiers.push(Identifier {
+                identifier: issn,
+                identifier_type: "ISSN".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+    data.id = if doi.is_empty() { url.clone() } else { doi };
+    data.url = url;
+
+    // Title statement: $a title, $b remainder, $n/$p part number and name
+    if let Some(title) = record.fields("245").next() {
+        let main = std::iter::once(title.get('a').unwrap_or_default())
+            .chain(title.all('n'))
+            .chain(title.all('p'))
+            .map(trim_isbd)
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(". ");
+        data.title = main;
+        if let Some(subtitle) = title.get('b').map(trim_isbd).filter(|s| !s.is_empty()) {
+            data.additional_titles.push(Title {
+                title: subtitle,
+                type_: "Subtitle".to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Contributors: main entry (100/110), then added entries (700/710)
+    data.contributors = ["100", "110", "700", "710"]
+        .iter()
+        .flat_map(|tag| record.fields(tag))
+        .filter_map(|f| name_contributor(f, "Author"))
+        .collect();
+
+    data.version = record.first("250", 'a');
+
+    // Publication: 264 (RDA, second indicator 1 = publication) or 260
+    let imprint = record
+        .fields("264")
+        .find(|f| f.ind2 == '1')
+        .or_else(|| record.fields("260").next());
+    if let Some(imprint) = imprint {
+        if let Some(name) = imprint.get('b').map(trim_isbd).filter(|n| !n.is_empty()) {
+            data.publisher = Publisher {
+                name,
+                ..Default::default()
+            };
+        }
+        if let Some(year) = imprint.get('c').and_then(|c| YEAR.find(c)) {
+            data.date_published = year.as_str().to_string();
+        }
+    }
+    // Fall back to Date 1 in the fixed-length data elements (008/07-10)
+    if data.date_published.is_empty() {
+        let date1 = record.control("008").get(7..11).unwrap_or("");
+        if YEAR.is_match(date1) {
+            data.date_published = date1.to_string();
+        }
+    }
+
+    // Language: 041 $a, else 008/35-37 (MARC language codes)
+    let lang = match record.first("041", 'a') {
+        l if !l.is_empty() => l,
+        _ => record.control("008").get(35..38).unwrap_or("").trim().to_string(),
+    };
+    if !lang.is_empty() {
+        let iso = get_language(&lang, "");
+        data.language = if iso.is_empty() { lang } else { iso };
+    }
+
+    // Summary is prose, so its final period is kept
+    data.description = record
+        .fields("520")
+        .find_map(|f| f.get('a'))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default();
+
+    data.subjects = ["650", "651", "653"]
+        .iter()
+        .flat_map(|tag| record.fields(tag))
+        .filter_map(|f| f.get('a'))
+        .map(trim_isbd)
+        .filter(|s| !s.is_empty())
+        .map(|subject| Subject {
+            subject,
+            ..Default::default()
+        })
+        .collect();
+
+    // License: a URL in the terms governing use (540 $u)
+    if let Some(url) = record.fields("540").find_map(|f| f.get('u')) {
+        data.license = crate::spdx::from_url(url.trim());
+    }
+
+    data.container = convert_host_item(record);
+
+    data
+}
+
+// ── Public read ───────────────────────────────────────────────────────────────
+
+/// Parse every record of a MARCXML `<collection>`/`<record>` document or a
+/// binary MARC 21 file, in order.
+pub fn read_all(input: &str) -> Result<Vec<Data>> {
+    let records = if input.trim_start().starts_with('<') {
+        parse_marcxml(input)?
+    } else {
+        parse_binary(input.as_bytes())?
+    };
+    if records.is_empty() {
+        return Err(Error::Parse("no MARC records found".to_string()));
+    }
+    Ok(records.iter().map(from_record).collect())
+}
+
+/// Parse MARCXML or binary MARC 21 and return the first record.
+pub fn read(input: &str) -> Result<Data> {
+    read_all(input)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Parse("no MARC records found".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARCXML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<collection xmlns="http://www.loc.gov/MARC21/slim">
+  <record>
+    <leader>01142cam  2200301 a 4500</leader>
+    <controlfield tag="001">12345</controlfield>
+    <controlfield tag="008">190312s2019    nyu           000 0 eng  </controlfield>
+    <datafield tag="010" ind1=" " ind2=" ">
+      <subfield code="a">  2016022992</subfield>
+    </datafield>
+    <datafield tag="020" ind1=" " ind2=" ">
+      <subfield code="a">9780262035613 (hardcover)</subfield>
+    </datafield>
+    <datafield tag="024" ind1="7" ind2=" ">
+      <subfield code="a">10.5555/marc.book</subfield>
+      <subfield code="2">doi</subfield>
+    </datafield>
+    <datafield tag="100" ind1="1" ind2=" ">
+      <subfield code="a">Goodfellow, Ian,</subfield>
+      <subfield code="e">author.</subfield>
+    </datafield>
+    <datafield tag="245" ind1="1" ind2="0">
+      <subfield code="a">Deep learning /</subfield>
+      <subfield code="c">Ian Goodfellow, Yoshua Bengio, and Aaron Courville.</subfield>
+    </datafield>
+    <datafield tag="250" ind1=" " ind2=" ">
+      <subfield code="a">1st ed.</subfield>
+    </datafield>
+    <datafield tag="264" ind1=" " ind2="1">
+      <subfield code="a">Cambridge, Massachusetts :</subfield>
+      <subfield code="b">The MIT Press,</subfield>
+      <subfield code="c">[2016]</subfield>
+    </datafield>
+    <datafield tag="520" ind1=" " ind2=" ">
+      <subfield code="a">An introduction to a broad range of topics in deep learning.</subfield>
+    </datafield>
+    <datafield tag="650" ind1=" " ind2="0">
+      <subfield code="a">Machine learning.</subfield>
+    </datafield>
+    <datafield tag="700" ind1="1" ind2=" ">
+      <subfield code="a">Bengio, Yoshua,</subfield>
+      <subfield code="e">author.</subfield>
+    </datafield>
+    <datafield tag="700" ind1="1" ind2=" ">
+      <subfield code="a">Smith, J.</subfield>
+      <subfield code="e">editor.</subfield>
+    </datafield>
+    <datafield tag="856" ind1="4" ind2="0">
+      <subfield code="u">https://www.deeplearningbook.org</subfield>
+    </datafield>
+  </record>
+</collection>"#;
+
+    /// Build a binary MARC 21 record from (tag, field data) pairs.
+    fn binary_record(leader_prefix: &str, fields: &[(&str, &str)]) -> Vec<u8> {
+        let mut directory = String::new();
+        let mut data = Vec::new();
+        for (tag, content) in fields {
+            let mut field = content.replace('$', "\u{1F}").into_bytes();
+            field.push(FIELD_TERMINATOR);
+            directory.push_str(&format!("{tag}{:04}{:05}", field.len(), data.len()));
+            data.extend(field);
+        }
+        let base_address = 24 + directory.len() + 1;
+        let total = base_address + data.len() + 1;
+        let mut record = format!("{total:05}{leader_prefix}22{base_address:05}   4500")
+            .into_bytes();
+        record.extend(directory.into_bytes());
+        record.push(FIELD_TERMINATOR);
+        record.extend(data);
+        record.push(RECORD_TERMINATOR);
+        record
+    }
+
+    #[test]
+    fn read_marcxml_book() {
+        let data = read(MARCXML).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.5555/marc.book");
+        assert_eq!(data.type_, "Book");
+        assert_eq!(data.url, "https://www.deeplearningbook.org");
+        assert_eq!(data.title, "Deep learning");
+        assert_eq!(data.version, "1st ed.");
+        assert_eq!(data.publisher.name, "The MIT Press");
+        assert_eq!(data.date_published, "2016");
+        assert_eq!(data.language, "en");
+        assert_eq!(data.subjects[0].subject, "Machine learning");
+        assert_eq!(data.identifiers[1].identifier, "9780262035613");
+        assert_eq!(data.identifiers[1].identifier_type, "ISBN");
+        assert_eq!(data.identifiers[2].identifier, "2016022992");
+        assert_eq!(data.identifiers[2].identifier_type, "LCCN");
+        assert_eq!(
+            data.description,
+            "An introduction to a broad range of topics in deep learning."
+        );
+    }
+
+    #[test]
+    fn read_marcxml_contributors() {
+        let data = read(MARCXML).unwrap();
+        assert_eq!(data.contributors.len(), 3);
+        assert_eq!(data.contributors[0].family_name(), "Goodfellow");
+        assert_eq!(data.contributors[0].given_name(), "Ian");
+        assert_eq!(data.contributors[1].family_name(), "Bengio");
+        assert_eq!(data.contributors[2].given_name(), "J.");
+        assert_eq!(data.contributors[2].roles, vec!["Editor".to_string()]);
+    }
+
+    #[test]
+    fn read_binary_article_with_host_item() {
+        let record = binary_record(
+            "naa a",
+            &[
+                ("001", "article-1"),
+                ("008", "200101s2020    xx            000 0 ger d"),
+                ("100", "1 $aMüller, Anna."),
+                ("245", "10$aSoil moisture :$bmeasurements from the Alps."),
+                ("260", "  $c2020."),
+                ("773", "0 $tJournal of Alpine Hydrology.$gVol. 12, no. 3 (2020), p. 101-110$x2049-3630"),
+            ],
+        );
+        let input = String::from_utf8(record).unwrap();
+        let list = read_all(&input).unwrap();
+        assert_eq!(list.len(), 1);
+        let data = &list[0];
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.title, "Soil moisture");
+        assert_eq!(data.additional_titles[0].title, "measurements from the Alps");
+        assert_eq!(data.contributors[0].family_name(), "Müller");
+        assert_eq!(data.date_published, "2020");
+        assert_eq!(data.container.title, "Journal of Alpine Hydrology");
+        assert_eq!(data.container.identifier, "2049-3630");
+        assert_eq!(data.container.volume, "12");
+        assert_eq!(data.container.issue, "3");
+        assert_eq!(data.container.first_page, "101");
+        assert_eq!(data.container.last_page, "110");
+    }
+
+    #[test]
+    fn trim_isbd_punctuation() {
+        assert_eq!(trim_isbd("Deep learning /"), "Deep learning");
+        assert_eq!(trim_isbd("The MIT Press,"), "The MIT Press");
+        assert_eq!(trim_isbd("Smith, J."), "Smith, J.");
+    }
+}
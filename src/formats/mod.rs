@@ -6,22 +6,33 @@ pub mod commonmeta;
 pub mod crossref;
 pub mod crossref_xml;
 pub mod csl;
+pub mod csv;
 pub mod datacite;
 pub mod datacite_xml;
+pub mod dublincore;
+pub mod endnote;
+pub mod fundref;
 pub mod inveniordm;
+pub mod jats;
 pub mod jsonfeed;
+pub mod marc21;
+pub mod openaire;
 pub mod openalex;
+pub mod orcid;
 pub mod ris;
 pub mod ror;
 pub mod ror_countries;
 pub mod schemaorg;
 pub mod sqlite_stream;
 pub mod vraix;
+pub mod wikidata;
+pub mod xml_output;
 
 use crate::data::Data;
 use crate::error::{Error, Result};
 
 pub fn read(format: &str, input: &str) -> Result<Data> {
+    tracing::trace!("read {} input ({} bytes)", format, input.len());
     match format {
         "commonmeta" => commonmeta::read(input),
         "crossref" => {
@@ -78,6 +89,8 @@ pub fn read(format: &str, input: &str) -> Result<Data> {
         "schemaorg" => {
             if input.trim_start().starts_with('{') {
                 schemaorg::read_json(input)
+            } else if input.trim_start().starts_with('<') {
+                schemaorg::read_html(input)
             } else {
                 schemaorg::fetch(input)
             }
@@ -97,7 +110,13 @@ pub fn read(format: &str, input: &str) -> Result<Data> {
                 codemeta::fetch(input)
             }
         }
+        "csv" => csv::read(input),
         "ris" => ris::read(input),
+        "jats" => jats::read_xml(input),
+        "dublincore" => dublincore::read_xml(input),
+        "endnote" => endnote::read_xml(input),
+        "marc21" => marc21::read(input),
+        "openaire" => openaire::read_json(input),
         "openalex" => {
             if input.trim_start().starts_with('{') {
                 openalex::read_json(input)
@@ -117,6 +136,34 @@ pub fn read(format: &str, input: &str) -> Result<Data> {
     }
 }
 
+/// Read every record contained in `input`. Formats that can carry several
+/// records in one document (`bibtex` files, `csv` spreadsheets, `crossref_xml`
+/// deposits, OAI-PMH `dublincore` responses, `endnote` exports, `marc21`
+/// collections, `openaire` dumps and API responses, the works of an `orcid`
+/// record) return all of them; every other format yields a single-element
+/// list.
+pub fn read_list(format: &str, input: &str) -> Result<Vec<Data>> {
+    match format {
+        "bibtex" => bibtex::read_all(input),
+        "csv" => csv::read_all(input, &csv::ColumnMap::default()),
+        "dublincore" => dublincore::read_xml_all(input),
+        "endnote" => endnote::read_xml_all(input),
+        "marc21" => marc21::read_all(input),
+        "openaire" => openaire::read_json_all(input),
+        "orcid" => {
+            if input.trim_start().starts_with('{') {
+                orcid::read_json_all(input)
+            } else {
+                orcid::fetch_all(input)
+            }
+        }
+        "crossref_xml" if input.trim_start().starts_with('<') => {
+            crossref_xml::read_xml_all(input)
+        }
+        _ => read(format, input).map(|data| vec![data]),
+    }
+}
+
 pub fn write(format: &str, data: &Data) -> Result<Vec<u8>> {
     write_citation(format, data, None, None)
 }
@@ -131,7 +178,12 @@ pub fn write_citation(
         "commonmeta" => commonmeta::write(data),
         "crossref" => crossref::write(data),
         "crossref_xml" => crossref_xml::write(data),
+        "csv" => csv::write(data),
         "datacite_xml" => datacite_xml::write(data),
+        "dublincore" => dublincore::write(data),
+        "jats" => jats::write(data),
+        "jsonl" => commonmeta::write_jsonl(std::slice::from_ref(data)),
+        "openaire" => openaire::write(data),
         "ris" => ris::write(data),
         "csl" => csl::write(data),
         "datacite" => datacite::write(data),
@@ -139,7 +191,10 @@ pub fn write_citation(
         "bibtex" => bibtex::write(data),
         "schemaorg" => schemaorg::write(data),
         "citation" => citation::write(data, style, locale),
+        "html" => citation::write_html(std::slice::from_ref(data), style, locale),
+        "markdown" => citation::write_markdown(std::slice::from_ref(data), style, locale),
         "ror" => ror::write(data),
+        "yaml" => commonmeta::write_yaml(data),
         other => Err(Error::UnsupportedFormat(other.to_string())),
     }
 }
@@ -154,13 +209,36 @@ pub fn write_all_citation(
         "commonmeta" => commonmeta::write_all(list),
         "crossref" => crossref::write_all(list),
         "csl" => csl::write_all(list),
+        "csv" => csv::write_all(list, &csv::ColumnMap::default()),
         "datacite" => datacite::write_all(list),
         "inveniordm" => inveniordm::write_all(list),
         "schemaorg" => schemaorg::write_all(list),
         "ror" => ror::write_json_all(list),
         "citation" => citation::write_all(list, style, locale),
+        "html" => citation::write_html(list, style, locale),
+        "markdown" => citation::write_markdown(list, style, locale),
         "crossref_xml" => crossref_xml::write_all(list),
         "datacite_xml" => datacite_xml::write_all(list),
+        "yaml" => commonmeta::write_yaml_all(list),
+        "dublincore" => dublincore::write_all(list),
+        "jats" => jats::write_all(list),
+        "jsonl" => commonmeta::write_jsonl(list),
+        "openaire" => openaire::write_all(list),
         other => Err(Error::UnsupportedFormat(other.to_string())),
     }
 }
+
+/// Write `list` to one of the XML document formats (`crossref_xml`,
+/// `datacite_xml`, `dublincore`, `openaire`) laid out according to
+/// `options`.
+pub fn write_xml(format: &str, list: &[Data], options: &xml_output::XmlOptions) -> Result<Vec<u8>> {
+    match format {
+        "crossref_xml" => {
+            crossref_xml::write_deposit(list, &crossref_xml::DepositHead::default(), options)
+        }
+        "datacite_xml" => datacite_xml::write_all_version(list, "4.7", options),
+        "dublincore" => dublincore::write_all_with(list, options),
+        "openaire" => openaire::write_all_with(list, options),
+        other => Err(Error::UnsupportedFormat(format!("{other} is not an XML document format"))),
+    }
+}
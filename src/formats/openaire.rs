@@ -0,0 +1,1111 @@
+//! OpenAIRE Graph reader for research products, as found in the Graph
+//! dumps (one product per line) and Graph API responses, and a writer for
+//! the OpenAIRE Guidelines for Literature Repositories (v4) application
+//! profile, so records can be harvested by OpenAIRE from an OAI-PMH
+//! `oai_openaire` endpoint.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::data::{
+    Container, Contributor, Data, Description, Identifier, License, Organization, Person,
+    Publisher, Subject, Title,
+};
+use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::error::{Error, Result};
+use crate::utils::{get_language, normalize_orcid, sanitize, validate_id};
+use super::xml_output::{XmlOptions, serialize_xml};
+
+fn null_as_empty<'de, D>(d: D) -> std::result::Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(d)?.unwrap_or_default())
+}
+
+fn null_as_default<'de, D, T>(d: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(d)?.unwrap_or_default())
+}
+
+// ── OpenAIRE Graph structs ─────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResearchProduct {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    id: String,
+    #[serde(rename = "type", default, deserialize_with = "null_as_empty")]
+    type_: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pids: Vec<Pid>,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    main_title: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    sub_title: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    authors: Vec<Author>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    descriptions: Vec<String>,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    publication_date: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    publisher: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    language: Language,
+    #[serde(default, deserialize_with = "null_as_default")]
+    subjects: Vec<SubjectEntry>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    container: Journal,
+    #[serde(default, deserialize_with = "null_as_default")]
+    instances: Vec<Instance>,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    version: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    code_repository_url: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Pid {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    scheme: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    value: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Author {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    full_name: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    name: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    surname: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pid: AuthorPid,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AuthorPid {
+    #[serde(default, deserialize_with = "null_as_default")]
+    id: Pid,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Language {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    code: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SubjectEntry {
+    #[serde(default, deserialize_with = "null_as_default")]
+    subject: Pid,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Journal {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    name: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    issn_online: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    issn_printed: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    issn_linking: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    vol: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    iss: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    sp: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    ep: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Instance {
+    #[serde(rename = "type", default, deserialize_with = "null_as_empty")]
+    type_: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pids: Vec<Pid>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    alternate_identifiers: Vec<Pid>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    urls: Vec<String>,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    license: String,
+}
+
+/// Graph API search response: `{"header": {...}, "results": [...]}`.
+#[derive(Debug, Deserialize)]
+struct ListResponse {
+    results: Option<Vec<ResearchProduct>>,
+}
+
+// ── Type mappings ─────────────────────────────────────────────────────────────
+
+/// OpenAIRE instance type (COAR-aligned labels) → commonmeta work type.
+fn instance_to_cm_type(instance_type: &str) -> &'static str {
+    match instance_type {
+        "Article" | "Data Paper" | "Software Paper" => "JournalArticle",
+        "Preprint" => "Article",
+        "Book" => "Book",
+        "Part of book or chapter of book" => "BookChapter",
+        "Conference object" => "ProceedingsArticle",
+        "Doctoral thesis" | "Master thesis" | "Bachelor thesis" | "Thesis" => "Dissertation",
+        "Report" | "Research" | "Project deliverable" => "Report",
+        "Review" | "Book review" => "Review",
+        "Patent" => "Patent",
+        "Lecture" => "Presentation",
+        "Image" => "Image",
+        "Audio" => "Sound",
+        "Film" => "Audiovisual",
+        "Dataset" | "Collection" => "Dataset",
+        "Software" => "Software",
+        _ => "",
+    }
+}
+
+/// Research product type (`publication`, `dataset`, `software`, `other`).
+fn product_to_cm_type(product_type: &str) -> &'static str {
+    match product_type {
+        "publication" => "Document",
+        "dataset" => "Dataset",
+        "software" => "Software",
+        _ => "Other",
+    }
+}
+
+fn pid_identifier_type(scheme: &str) -> &'static str {
+    match scheme.to_lowercase().as_str() {
+        "doi" => "DOI",
+        "pmid" => "PMID",
+        "pmc" | "pmcid" => "PMCID",
+        "arxiv" => "ARXIV",
+        "handle" => "Handle",
+        "urn" => "URN",
+        "ark" => "ARK",
+        "swhid" => "SWHID",
+        _ => "",
+    }
+}
+
+fn get_contributors(authors: &[Author]) -> Vec<Contributor> {
+    authors
+        .iter()
+        .filter_map(|a| {
+            let roles = vec!["Author".to_string()];
+            let orcid = if a.pid.id.scheme.starts_with("orcid") {
+                normalize_orcid(&a.pid.id.value)
+            } else {
+                String::new()
+            };
+            if !a.surname.is_empty() {
+                Some(Contributor::person(
+                    Person {
+                        id: orcid,
+                        given_name: a.name.trim().to_string(),
+                        family_name: a.surname.trim().to_string(),
+                        ..Default::default()
+                    },
+                    roles,
+                ))
+            } else if !a.full_name.trim().is_empty() {
+                Some(Contributor::organization(
+                    Organization {
+                        name: a.full_name.trim().to_string(),
+                        ..Default::default()
+                    },
+                    roles,
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// ── Core conversion ───────────────────────────────────────────────────────────
+
+/// Convert a research product; one without an id, pids or title is not a
+/// product at all.
+fn from_product(product: ResearchProduct) -> Result<Data> {
+    if product.id.is_empty() && product.pids.is_empty() && product.main_title.is_empty() {
+        return Err(Error::Parse(
+            "OpenAIRE research product has no id, pids or title".to_string(),
+        ));
+    }
+    let mut data = Data {
+        provider: "OpenAIRE".to_string(),
+        ..Data::default()
+    };
+
+    // Identifiers from the product and its instances, de-duplicated
+    let pids = product.pids.iter().chain(
+        product
+            .instances
+            .iter()
+            .flat_map(|i| i.pids.iter().chain(i.alternate_identifiers.iter())),
+    );
+    for pid in pids {
+        let identifier_type = pid_identifier_type(&pid.scheme);
+        let value = pid.value.trim();
+        if identifier_type.is_empty() || value.is_empty() {
+            continue;
+        }
+        let identifier = if identifier_type == "DOI" {
+            normalize_doi(value)
+        } else {
+            value.to_string()
+        };
+        if identifier.is_empty()
+            || data
+                .identifiers
+                .iter()
+                .any(|i| i.identifier == identifier && i.identifier_type == identifier_type)
+        {
+            continue;
+        }
+        if identifier_type == "DOI" && data.id.is_empty() {
+            data.id = identifier.clone();
+        }
+        data.identifiers.push(Identifier {
+            identifier,
+            identifier_type: identifier_type.to_string(),
+            ..Default::default()
+        });
+    }
+
+    data.url = product
+        .instances
+        .iter()
+        .flat_map(|i| i.urls.iter())
+        .find(|u| u.starts_with("http") && !u.contains("doi.org/"))
+        .cloned()
+        .unwrap_or_else(|| product.code_repository_url.clone());
+    if data.id.is_empty() {
+        data.id = data.url.clone();
+    }
+
+    // Type: the most specific instance type, else the product type
+    data.type_ = product
+        .instances
+        .iter()
+        .map(|i| instance_to_cm_type(&i.type_))
+        .find(|t| !t.is_empty())
+        .unwrap_or_else(|| product_to_cm_type(&product.type_))
+        .to_string();
+
+    data.title = product.main_title.trim().to_string();
+    if !product.sub_title.trim().is_empty() {
+        data.additional_titles.push(Title {
+            title: product.sub_title.trim().to_string(),
+            type_: "Subtitle".to_string(),
+            ..Default::default()
+        });
+    }
+
+    data.contributors = get_contributors(&product.authors);
+
+    let mut descriptions = product
+        .descriptions
+        .iter()
+        .map(|d| sanitize(d))
+        .filter(|d| !d.is_empty());
+    data.description = descriptions.next().unwrap_or_default();
+    data.additional_descriptions = descriptions
+        .map(|description| Description {
+            description,
+            type_: "Other".to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+    data.date_published = product.publication_date.clone();
+
+    if !product.publisher.is_empty() {
+        data.publisher = Publisher {
+            name: product.publisher.clone(),
+            ..Default::default()
+        };
+    }
+
+    // "und" marks an undetermined language
+    if !product.language.code.is_empty() && product.language.code != "und" {
+        let iso = get_language(&product.language.code, "");
+        data.language = if iso.is_empty() {
+            product.language.code.clone()
+        } else {
+            iso
+        };
+    }
+
+    data.subjects = product
+        .subjects
+        .iter()
+        .map(|s| s.subject.value.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .map(|subject| Subject {
+            subject,
+            ..Default::default()
+        })
+        .collect();
+
+    data.license = product
+        .instances
+        .iter()
+        .map(|i| i.license.trim())
+        .find(|l| !l.is_empty())
+        .map(|l| {
+            if l.starts_with("http") {
+                crate::spdx::from_url(l)
+            } else {
+                crate::spdx::from_id(l)
+            }
+        })
+        .unwrap_or_else(License::default);
+
+    let journal = &product.container;
+    let issn = [&journal.issn_online, &journal.issn_printed, &journal.issn_linking]
+        .into_iter()
+        .find(|i| !i.is_empty())
+        .cloned()
+        .unwrap_or_default();
+    if !journal.name.is_empty() || !issn.is_empty() {
+        data.container = Container {
+            type_: "Journal".to_string(),
+            title: journal.name.clone(),
+            identifier_type: if issn.is_empty() { "" } else { "ISSN" }.to_string(),
+            identifier: issn,
+            volume: journal.vol.trim().to_string(),
+            issue: journal.iss.trim().to_string(),
+            first_page: journal.sp.trim().to_string(),
+            last_page: journal.ep.trim().to_string(),
+            ..Default::default()
+        };
+    }
+
+    data.version = product.version.clone();
+
+    Ok(data)
+}
+
+// ── Guidelines v4 output structs (XML serialization) ─────────────────────────
+
+#[derive(Serialize)]
+#[serde(rename = "oaire:resource")]
+struct OutResource {
+    #[serde(rename = "@xmlns:datacite")]
+    xmlns_datacite: &'static str,
+    #[serde(rename = "@xmlns:dc")]
+    xmlns_dc: &'static str,
+    #[serde(rename = "@xmlns:oaire")]
+    xmlns_oaire: &'static str,
+    #[serde(rename = "@xmlns:xsi")]
+    xmlns_xsi: &'static str,
+    #[serde(rename = "@xsi:schemaLocation")]
+    xsi_schema_location: &'static str,
+    #[serde(rename = "datacite:titles")]
+    titles: OutTitles,
+    #[serde(rename = "datacite:creators", skip_serializing_if = "Option::is_none")]
+    creators: Option<OutCreators>,
+    #[serde(rename = "datacite:contributors", skip_serializing_if = "Option::is_none")]
+    contributors: Option<OutContributors>,
+    #[serde(rename = "oaire:fundingReferences", skip_serializing_if = "Option::is_none")]
+    funding_references: Option<OutFundingReferences>,
+    #[serde(rename = "datacite:alternateIdentifiers", skip_serializing_if = "Option::is_none")]
+    alternate_identifiers: Option<OutAlternateIdentifiers>,
+    #[serde(rename = "datacite:relatedIdentifiers", skip_serializing_if = "Option::is_none")]
+    related_identifiers: Option<OutRelatedIdentifiers>,
+    #[serde(rename = "datacite:dates", skip_serializing_if = "Option::is_none")]
+    dates: Option<OutDates>,
+    #[serde(rename = "dc:language", skip_serializing_if = "str::is_empty")]
+    language: String,
+    #[serde(rename = "dc:publisher", skip_serializing_if = "str::is_empty")]
+    publisher: String,
+    #[serde(rename = "oaire:resourceType")]
+    resource_type: OutResourceType,
+    #[serde(rename = "dc:description", skip_serializing_if = "Vec::is_empty")]
+    descriptions: Vec<String>,
+    #[serde(rename = "datacite:identifier")]
+    identifier: OutIdentifier,
+    #[serde(rename = "datacite:rights")]
+    rights: OutRights,
+    #[serde(rename = "datacite:subjects", skip_serializing_if = "Option::is_none")]
+    subjects: Option<OutSubjects>,
+    #[serde(rename = "oaire:licenseCondition", skip_serializing_if = "Option::is_none")]
+    license_condition: Option<OutLicenseCondition>,
+    #[serde(rename = "oaire:citationTitle", skip_serializing_if = "str::is_empty")]
+    citation_title: String,
+    #[serde(rename = "oaire:citationVolume", skip_serializing_if = "str::is_empty")]
+    citation_volume: String,
+    #[serde(rename = "oaire:citationIssue", skip_serializing_if = "str::is_empty")]
+    citation_issue: String,
+    #[serde(rename = "oaire:citationStartPage", skip_serializing_if = "str::is_empty")]
+    citation_start_page: String,
+    #[serde(rename = "oaire:citationEndPage", skip_serializing_if = "str::is_empty")]
+    citation_end_page: String,
+}
+
+#[derive(Serialize)]
+struct OutTitles {
+    #[serde(rename = "datacite:title")]
+    title: Vec<OutTitle>,
+}
+
+#[derive(Serialize)]
+struct OutTitle {
+    #[serde(rename = "@xml:lang", skip_serializing_if = "str::is_empty")]
+    lang: String,
+    #[serde(rename = "@titleType", skip_serializing_if = "str::is_empty")]
+    title_type: String,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+#[derive(Serialize)]
+struct OutCreators {
+    #[serde(rename = "datacite:creator")]
+    creator: Vec<OutCreator>,
+}
+
+#[derive(Serialize)]
+struct OutCreator {
+    #[serde(rename = "datacite:creatorName")]
+    name: OutName,
+    #[serde(rename = "datacite:givenName", skip_serializing_if = "str::is_empty")]
+    given_name: String,
+    #[serde(rename = "datacite:familyName", skip_serializing_if = "str::is_empty")]
+    family_name: String,
+    #[serde(rename = "datacite:nameIdentifier", skip_serializing_if = "Option::is_none")]
+    name_identifier: Option<OutNameIdentifier>,
+    #[serde(rename = "datacite:affiliation", skip_serializing_if = "Vec::is_empty")]
+    affiliations: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct OutContributors {
+    #[serde(rename = "datacite:contributor")]
+    contributor: Vec<OutContributor>,
+}
+
+#[derive(Serialize)]
+struct OutContributor {
+    #[serde(rename = "@contributorType")]
+    contributor_type: &'static str,
+    #[serde(rename = "datacite:contributorName")]
+    name: OutName,
+    #[serde(rename = "datacite:givenName", skip_serializing_if = "str::is_empty")]
+    given_name: String,
+    #[serde(rename = "datacite:familyName", skip_serializing_if = "str::is_empty")]
+    family_name: String,
+    #[serde(rename = "datacite:nameIdentifier", skip_serializing_if = "Option::is_none")]
+    name_identifier: Option<OutNameIdentifier>,
+    #[serde(rename = "datacite:affiliation", skip_serializing_if = "Vec::is_empty")]
+    affiliations: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct OutName {
+    #[serde(rename = "@nameType")]
+    name_type: &'static str,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+#[derive(Serialize)]
+struct OutNameIdentifier {
+    #[serde(rename = "@nameIdentifierScheme")]
+    scheme: &'static str,
+    #[serde(rename = "@schemeURI")]
+    scheme_uri: &'static str,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutFundingReferences {
+    #[serde(rename = "oaire:fundingReference")]
+    funding_reference: Vec<OutFundingReference>,
+}
+
+#[derive(Serialize)]
+struct OutFundingReference {
+    #[serde(rename = "oaire:funderName")]
+    funder_name: String,
+    #[serde(rename = "oaire:funderIdentifier", skip_serializing_if = "Option::is_none")]
+    funder_identifier: Option<OutFunderIdentifier>,
+    #[serde(rename = "oaire:awardNumber", skip_serializing_if = "Option::is_none")]
+    award_number: Option<OutAwardNumber>,
+    #[serde(rename = "oaire:awardTitle", skip_serializing_if = "str::is_empty")]
+    award_title: String,
+}
+
+#[derive(Serialize)]
+struct OutFunderIdentifier {
+    #[serde(rename = "@funderIdentifierType")]
+    identifier_type: &'static str,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutAwardNumber {
+    #[serde(rename = "@awardURI", skip_serializing_if = "str::is_empty")]
+    award_uri: String,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutAlternateIdentifiers {
+    #[serde(rename = "datacite:alternateIdentifier")]
+    alternate_identifier: Vec<OutAlternateIdentifier>,
+}
+
+#[derive(Serialize)]
+struct OutAlternateIdentifier {
+    #[serde(rename = "@alternateIdentifierType")]
+    identifier_type: String,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutRelatedIdentifiers {
+    #[serde(rename = "datacite:relatedIdentifier")]
+    related_identifier: Vec<OutRelatedIdentifier>,
+}
+
+#[derive(Serialize)]
+struct OutRelatedIdentifier {
+    #[serde(rename = "@relatedIdentifierType")]
+    identifier_type: &'static str,
+    #[serde(rename = "@relationType")]
+    relation_type: String,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutDates {
+    #[serde(rename = "datacite:date")]
+    date: Vec<OutDate>,
+}
+
+#[derive(Serialize)]
+struct OutDate {
+    #[serde(rename = "@dateType")]
+    date_type: &'static str,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutResourceType {
+    #[serde(rename = "@resourceTypeGeneral")]
+    resource_type_general: &'static str,
+    #[serde(rename = "@uri")]
+    uri: String,
+    #[serde(rename = "$text")]
+    label: &'static str,
+}
+
+#[derive(Serialize)]
+struct OutIdentifier {
+    #[serde(rename = "@identifierType")]
+    identifier_type: &'static str,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Serialize)]
+struct OutRights {
+    #[serde(rename = "@rightsURI")]
+    rights_uri: String,
+    #[serde(rename = "$text")]
+    label: &'static str,
+}
+
+#[derive(Serialize)]
+struct OutSubjects {
+    #[serde(rename = "datacite:subject")]
+    subject: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct OutLicenseCondition {
+    #[serde(rename = "@uri")]
+    uri: String,
+    #[serde(rename = "@startDate", skip_serializing_if = "str::is_empty")]
+    start_date: String,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+// ── Guidelines v4 vocabularies ────────────────────────────────────────────────
+
+/// Commonmeta work type → COAR resource type (label, concept id) and the
+/// OpenAIRE `resourceTypeGeneral`.
+fn cm_to_coar(type_: &str) -> (&'static str, &'static str, &'static str) {
+    match type_ {
+        "Article" => ("preprint", "c_816b", "literature"),
+        "BlogPost" => ("blog post", "c_6947", "literature"),
+        "Book" => ("book", "c_2f33", "literature"),
+        "BookChapter" => ("book part", "c_3248", "literature"),
+        "Dataset" => ("dataset", "c_ddb1", "dataset"),
+        "Dissertation" => ("doctoral thesis", "c_db06", "literature"),
+        "JournalArticle" => ("journal article", "c_6501", "literature"),
+        "Patent" => ("patent", "c_15cd", "literature"),
+        "PeerReview" | "Review" => ("review", "c_efa0", "literature"),
+        "Presentation" => ("conference presentation", "c_c94f", "literature"),
+        "Proceedings" => ("conference proceedings", "c_f744", "literature"),
+        "ProceedingsArticle" => ("conference paper", "c_5794", "literature"),
+        "Report" => ("report", "c_93fc", "literature"),
+        "ComputationalNotebook" | "Software" => ("software", "c_5ce6", "software"),
+        "Audiovisual" => ("video", "c_12ce", "other research product"),
+        "Image" | "Figure" => ("image", "c_c513", "other research product"),
+        "Sound" => ("sound", "c_18cc", "other research product"),
+        "Document" | "Manuscript" | "WebPage" => ("text", "c_18cf", "literature"),
+        _ => ("other", "c_1843", "other research product"),
+    }
+}
+
+fn funder_identifier_type(id: &str) -> &'static str {
+    if id.starts_with("https://ror.org/") {
+        "ROR"
+    } else if id.starts_with("https://doi.org/10.13039/") {
+        "Crossref Funder ID"
+    } else {
+        "Other"
+    }
+}
+
+/// Commonmeta role → DataCite `contributorType` as used by the Guidelines.
+fn cm_to_contributor_type(role: &str) -> &'static str {
+    match role {
+        "ContactPerson" => "ContactPerson",
+        "DataCuration" => "DataCurator",
+        "Editor" => "Editor",
+        "ProjectLeader" => "ProjectLeader",
+        "ProjectManager" => "ProjectManager",
+        "ProjectMember" => "ProjectMember",
+        "Researcher" => "Researcher",
+        "Supervision" => "Supervisor",
+        _ => "Other",
+    }
+}
+
+type OutNameParts = (OutName, String, String, Option<OutNameIdentifier>, Vec<String>);
+
+fn out_name(c: &Contributor) -> OutNameParts {
+    let (name_type, text) = match &c.person {
+        Some(p) if !p.family_name.is_empty() && !p.given_name.is_empty() => {
+            ("Personal", format!("{}, {}", p.family_name, p.given_name))
+        }
+        Some(_) => ("Personal", c.name()),
+        None => ("Organizational", c.name()),
+    };
+    let name_identifier = c.id().starts_with("https://orcid.org/").then(|| OutNameIdentifier {
+        scheme: "ORCID",
+        scheme_uri: "https://orcid.org",
+        value: c.id().to_string(),
+    });
+    let affiliations = c
+        .affiliations()
+        .iter()
+        .map(|a| a.name.clone())
+        .filter(|n| !n.is_empty())
+        .collect();
+    (
+        OutName { name_type, text },
+        c.given_name().to_string(),
+        c.family_name().to_string(),
+        name_identifier,
+        affiliations,
+    )
+}
+
+fn to_guidelines(data: &Data) -> OutResource {
+    let mut titles = vec![OutTitle {
+        lang: data.language.clone(),
+        title_type: String::new(),
+        text: data.title.clone(),
+    }];
+    titles.extend(data.additional_titles.iter().map(|t| OutTitle {
+        lang: t.language.clone(),
+        title_type: t.type_.clone(),
+        text: t.title.clone(),
+    }));
+
+    let mut creators = Vec::new();
+    let mut contributors = Vec::new();
+    for c in &data.contributors {
+        let (name, given_name, family_name, name_identifier, affiliations) = out_name(c);
+        if c.roles.iter().any(|r| r == "Author") {
+            creators.push(OutCreator {
+                name,
+                given_name,
+                family_name,
+                name_identifier,
+                affiliations,
+            });
+        } else {
+            let role = c.roles.first().map(String::as_str).unwrap_or("");
+            contributors.push(OutContributor {
+                contributor_type: cm_to_contributor_type(role),
+                name,
+                given_name,
+                family_name,
+                name_identifier,
+                affiliations,
+            });
+        }
+    }
+
+    let funding: Vec<OutFundingReference> = data
+        .funding_references
+        .iter()
+        .filter(|f| !f.funder_name.is_empty())
+        .map(|f| OutFundingReference {
+            funder_name: f.funder_name.clone(),
+            funder_identifier: (!f.funder_id.is_empty()).then(|| OutFunderIdentifier {
+                identifier_type: funder_identifier_type(&f.funder_id),
+                value: f.funder_id.clone(),
+            }),
+            award_number: (!f.award_number.is_empty()).then(|| OutAwardNumber {
+                award_uri: f.award_id.clone(),
+                value: f.award_number.clone(),
+            }),
+            award_title: f.award_title.clone(),
+        })
+        .collect();
+
+    let doi = validate_doi(&data.id).unwrap_or_default();
+    let identifier = match (doi.is_empty(), data.url.is_empty()) {
+        (false, _) => OutIdentifier { identifier_type: "DOI", value: doi },
+        (true, false) => OutIdentifier { identifier_type: "URL", value: data.url.clone() },
+        (true, true) => OutIdentifier { identifier_type: "URL", value: data.id.clone() },
+    };
+    let alternate: Vec<OutAlternateIdentifier> = data
+        .identifiers
+        .iter()
+        .filter(|i| i.identifier_type != "DOI" && !i.identifier.is_empty())
+        .map(|i| OutAlternateIdentifier {
+            identifier_type: i.identifier_type.clone(),
+            value: i.identifier.clone(),
+        })
+        .collect();
+
+    let mut related = Vec::new();
+    for (id, relation_type) in data
+        .relations
+        .iter()
+        .map(|r| (r.id.as_str(), r.type_.as_str()))
+        .chain(data.references.iter().map(|r| (r.id.as_str(), "References")))
+    {
+        let (value, identifier_type) = validate_id(id);
+        if matches!(identifier_type, "DOI" | "URL" | "PMID" | "ISSN") {
+            related.push(OutRelatedIdentifier {
+                identifier_type,
+                relation_type: relation_type.to_string(),
+                value,
+            });
+        }
+    }
+
+    let mut dates = Vec::new();
+    for (date_type, value) in [
+        ("Accepted", &data.dates.accepted),
+        ("Issued", &data.date_published),
+        ("Available", &data.dates.available),
+    ] {
+        if !value.is_empty() {
+            dates.push(OutDate { date_type, value: value.clone() });
+        }
+    }
+
+    let mut descriptions = vec![data.description.clone()];
+    descriptions.extend(data.additional_descriptions.iter().map(|d| d.description.clone()));
+    descriptions.retain(|d| !d.is_empty());
+
+    // Access rights are not part of commonmeta: an open license implies
+    // open access, otherwise only the metadata is vouched for.
+    let (rights_label, rights_code) = if is_open_license(&data.license) {
+        ("open access", "c_abf2")
+    } else {
+        ("metadata only access", "c_14cb")
+    };
+
+    let (label, coar, general) = cm_to_coar(&data.type_);
+    let c = &data.container;
+
+    OutResource {
+        xmlns_datacite: "http://datacite.org/schema/kernel-4",
+        xmlns_dc: "http://purl.org/dc/elements/1.1/",
+        xmlns_oaire: "http://namespace.openaire.eu/schema/oaire/",
+        xmlns_xsi: "http://www.w3.org/2001/XMLSchema-instance",
+        xsi_schema_location: "http://namespace.openaire.eu/schema/oaire/ https://www.openaire.eu/schema/repo-lit/4.0/openaire.xsd",
+        titles: OutTitles { title: titles },
+        creators: (!creators.is_empty()).then_some(OutCreators { creator: creators }),
+        contributors: (!contributors.is_empty())
+            .then_some(OutContributors { contributor: contributors }),
+        funding_references: (!funding.is_empty())
+            .then_some(OutFundingReferences { funding_reference: funding }),
+        alternate_identifiers: (!alternate.is_empty())
+            .then_some(OutAlternateIdentifiers { alternate_identifier: alternate }),
+        related_identifiers: (!related.is_empty())
+            .then_some(OutRelatedIdentifiers { related_identifier: related }),
+        dates: (!dates.is_empty()).then_some(OutDates { date: dates }),
+        language: data.language.clone(),
+        publisher: data.publisher.name.clone(),
+        resource_type: OutResourceType {
+            resource_type_general: general,
+            uri: format!("http://purl.org/coar/resource_type/{coar}"),
+            label,
+        },
+        descriptions,
+        identifier,
+        rights: OutRights {
+            rights_uri: format!("http://purl.org/coar/access_right/{rights_code}"),
+            label: rights_label,
+        },
+        subjects: (!data.subjects.is_empty()).then(|| OutSubjects {
+            subject: data.subjects.iter().map(|s| s.subject.clone()).collect(),
+        }),
+        license_condition: (!data.license.url.is_empty()).then(|| OutLicenseCondition {
+            uri: data.license.url.clone(),
+            start_date: data.date_published.clone(),
+            text: if data.license.id.is_empty() {
+                data.license.url.clone()
+            } else {
+                data.license.id.clone()
+            },
+        }),
+        citation_title: c.title.clone(),
+        citation_volume: c.volume.clone(),
+        citation_issue: c.issue.clone(),
+        citation_start_page: c.first_page.clone(),
+        citation_end_page: c.last_page.clone(),
+    }
+}
+
+/// Whether `license` is a recognized SPDX license that is OSI-approved, or a
+/// Creative Commons or Open Data Commons license without NonCommercial or
+/// NoDerivatives terms.
+fn is_open_license(license: &License) -> bool {
+    let Some(entry) = [&license.id, &license.url]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .find_map(|s| crate::spdx::search(s))
+    else {
+        return false;
+    };
+    let id = entry.license_id.as_str();
+    let open_content = id == "CC0-1.0"
+        || id == "PDDL-1.0"
+        || id.starts_with("CC-BY-")
+        || id.starts_with("ODC-By-")
+        || id.starts_with("ODbL-");
+    entry.is_osi_approved || (open_content && !id.contains("-NC") && !id.contains("-ND"))
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Parse a single OpenAIRE Graph research product.
+pub fn read_json(input: &str) -> Result<Data> {
+    let product: ResearchProduct =
+        serde_json::from_str(input).map_err(|e| Error::Parse(e.to_string()))?;
+    from_product(product)
+}
+
+/// Parse every research product in a Graph API response (`results`) or a
+/// Graph dump file (one product per line).
+pub fn read_json_all(input: &str) -> Result<Vec<Data>> {
+    if let Ok(ListResponse { results: Some(results) }) = serde_json::from_str(input) {
+        return results.into_iter().map(from_product).collect();
+    }
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(read_json)
+        .collect()
+}
+
+/// Serialize `data` as an `oaire:resource` record following the OpenAIRE
+/// Guidelines for Literature Repositories v4.
+pub fn write(data: &Data) -> Result<Vec<u8>> {
+    write_with(data, &XmlOptions::default())
+}
+
+/// Like [`write`], laid out according to `options`.
+pub fn write_with(data: &Data, options: &XmlOptions) -> Result<Vec<u8>> {
+    serialize_xml(to_guidelines(data), options)
+}
+
+/// Serialize every record in `list`, one `oaire:resource` document per record.
+pub fn write_all(list: &[Data]) -> Result<Vec<u8>> {
+    write_all_with(list, &XmlOptions::default())
+}
+
+/// Like [`write_all`], laid out according to `options`.
+pub fn write_all_with(list: &[Data], options: &XmlOptions) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for data in list {
+        if !out.is_empty() {
+            out.push(b'\n');
+        }
+        out.extend_from_slice(&write_with(data, options)?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRODUCT: &str = r#"{
+      "id": "50|doi_________::0123456789abcdef0123456789abcdef",
+      "type": "publication",
+      "mainTitle": "Soil moisture in alpine meadows",
+      "subTitle": null,
+      "authors": [
+        {"fullName": "Müller, Anna", "name": "Anna", "surname": "Müller", "rank": 1,
+         "pid": {"id": {"scheme": "orcid", "value": "0000-0002-1825-0097"}, "provenance": null}},
+        {"fullName": "Alpine Research Group", "name": null, "surname": null, "rank": 2, "pid": null}
+      ],
+      "descriptions": ["<p>Measurements from 2019 to 2021.</p>"],
+      "publicationDate": "2021-11-15",
+      "publisher": "Example Press",
+      "language": {"code": "eng", "label": "English"},
+      "subjects": [{"subject": {"scheme": "keyword", "value": "hydrology"}, "provenance": null}],
+      "container": {"name": "Journal of Alpine Hydrology", "issnPrinted": "1234-5678",
+                    "issnOnline": "2049-3630", "vol": "12", "iss": "3", "sp": "101", "ep": "110"},
+      "pids": [{"scheme": "doi", "value": "10.5555/OPENAIRE.1"}],
+      "instances": [
+        {"type": "Article",
+         "pids": [{"scheme": "doi", "value": "10.5555/openaire.1"}],
+         "alternateIdentifiers": [{"scheme": "pmid", "value": "38000001"}],
+         "urls": ["https://doi.org/10.5555/openaire.1", "https://repository.example.org/record/1"],
+         "license": "http://creativecommons.org/licenses/by/4.0/"}
+      ]
+    }"#;
+
+    #[test]
+    fn read_json_maps_research_product() {
+        let data = read_json(PRODUCT).unwrap();
+        assert_eq!(data.id, "https://doi.org/10.5555/openaire.1");
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.url, "https://repository.example.org/record/1");
+        assert_eq!(data.title, "Soil moisture in alpine meadows");
+        assert_eq!(data.description, "Measurements from 2019 to 2021.");
+        assert_eq!(data.date_published, "2021-11-15");
+        assert_eq!(data.publisher.name, "Example Press");
+        assert_eq!(data.language, "en");
+        assert_eq!(data.subjects[0].subject, "hydrology");
+        assert_eq!(data.license.id, "CC-BY-4.0");
+        assert_eq!(data.container.identifier, "2049-3630");
+        assert_eq!(data.container.first_page, "101");
+        assert_eq!(data.identifiers.len(), 2);
+        assert_eq!(data.identifiers[1].identifier_type, "PMID");
+        assert_eq!(data.provider, "OpenAIRE");
+    }
+
+    #[test]
+    fn read_json_maps_authors() {
+        let data = read_json(PRODUCT).unwrap();
+        assert_eq!(data.contributors.len(), 2);
+        assert_eq!(data.contributors[0].family_name(), "Müller");
+        assert_eq!(data.contributors[0].id(), "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(data.contributors[1].name(), "Alpine Research Group");
+    }
+
+    #[test]
+    fn read_json_all_reads_dump_lines_and_api_results() {
+        let line = PRODUCT.replace('\n', " ");
+        let dump = format!("{line}\n{line}\n");
+        assert_eq!(read_json_all(&dump).unwrap().len(), 2);
+
+        let response = format!(r#"{{"header": {{"numFound": 1}}, "results": [{line}]}}"#);
+        let list = read_json_all(&response).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].title, "Soil moisture in alpine meadows");
+    }
+
+    #[test]
+    fn read_json_all_reads_empty_api_results() {
+        let compact = r#"{"header": {"numFound": 0}, "results": []}"#;
+        assert!(read_json_all(compact).unwrap().is_empty());
+        let pretty = "{\n  \"header\": {\"numFound\": 0},\n  \"results\": []\n}\n";
+        assert!(read_json_all(pretty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn read_json_rejects_empty_product() {
+        assert!(read_json(r#"{"header": {"numFound": 0}}"#).is_err());
+    }
+
+    #[test]
+    fn write_follows_literature_guidelines() {
+        let data = read_json(PRODUCT).unwrap();
+        let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+        assert!(xml.contains("<oaire:resource xmlns:datacite=\"http://datacite.org/schema/kernel-4\""));
+        assert!(xml.contains("https://www.openaire.eu/schema/repo-lit/4.0/openaire.xsd"));
+        assert!(xml.contains(
+            "<oaire:resourceType resourceTypeGeneral=\"literature\" uri=\"http://purl.org/coar/resource_type/c_6501\">journal article</oaire:resourceType>"
+        ));
+        assert!(xml.contains("<datacite:identifier identifierType=\"DOI\">10.5555/openaire.1</datacite:identifier>"));
+        assert!(xml.contains("<datacite:creatorName nameType=\"Personal\">Müller, Anna</datacite:creatorName>"));
+        assert!(xml.contains(
+            "<datacite:nameIdentifier nameIdentifierScheme=\"ORCID\" schemeURI=\"https://orcid.org\">"
+        ));
+        assert!(xml.contains(
+            "<datacite:rights rightsURI=\"http://purl.org/coar/access_right/c_abf2\">open access</datacite:rights>"
+        ));
+        assert!(xml.contains("<datacite:date dateType=\"Issued\">2021-11-15</datacite:date>"));
+        assert!(xml.contains("<oaire:citationTitle>Journal of Alpine Hydrology</oaire:citationTitle>"));
+        assert!(xml.contains("<oaire:citationStartPage>101</oaire:citationStartPage>"));
+    }
+
+    #[test]
+    fn write_reports_non_open_licenses_as_metadata_only() {
+        let metadata_only = "<datacite:rights rightsURI=\"http://purl.org/coar/access_right/c_14cb\">\
+            metadata only access</datacite:rights>";
+        for url in [
+            "https://creativecommons.org/licenses/by-nc-nd/4.0/",
+            "https://www.elsevier.com/about/policies/open-access-licenses/article-specific",
+        ] {
+            let data = Data {
+                id: "https://doi.org/10.5555/openaire.2".to_string(),
+                type_: "JournalArticle".to_string(),
+                title: "Closed".to_string(),
+                license: crate::spdx::from_url(url),
+                ..Default::default()
+            };
+            let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+            assert!(xml.contains(metadata_only), "{url}");
+            assert!(xml.contains("oaire:licenseCondition"), "{url}");
+        }
+    }
+
+    #[test]
+    fn write_without_license_or_doi() {
+        let data = Data {
+            id: "https://example.org/record/7".to_string(),
+            type_: "Software".to_string(),
+            title: "Tool".to_string(),
+            ..Default::default()
+        };
+        let xml = String::from_utf8(write(&data).unwrap()).unwrap();
+        assert!(xml.contains("<datacite:identifier identifierType=\"URL\">https://example.org/record/7</datacite:identifier>"));
+        assert!(xml.contains("resourceTypeGeneral=\"software\""));
+        assert!(xml.contains("metadata only access"));
+        assert!(!xml.contains("oaire:licenseCondition"));
+    }
+}
@@ -556,6 +556,7 @@ fn fetch_citations(client: &reqwest::blocking::Client, url: &str) -> Vec<Citatio
         url.trim_end_matches('&')
     );
 
+    tracing::debug!("GET {}", paged_url);
     let text = match client.get(&paged_url).send().and_then(|r| r.text()) {
         Ok(t) => t,
         Err(_) => return Vec::new(),
@@ -0,0 +1,324 @@
+//! Reader for the works of an ORCID record.
+//!
+//! Fetches `https://pub.orcid.org/v3.0/{orcid}/record` and turns each work
+//! summary into a commonmeta record, with the ORCID holder as its only known
+//! contributor. Work summaries carry title, type, publication date, journal
+//! title and external IDs — enough to list and cite a person's works, not
+//! the full metadata of each one.
+
+use serde::Deserialize;
+
+use crate::constants as C;
+use crate::data::{Container, Contributor, Data, Identifier, Person};
+use crate::doi_utils::normalize_doi;
+use crate::error::{Error, Result};
+use crate::utils::{normalize_orcid, validate_orcid};
+
+// ── ORCID API structs ─────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct Record {
+    #[serde(rename = "orcid-identifier")]
+    orcid_identifier: OrcidIdentifier,
+    #[serde(default)]
+    person: Option<PersonRecord>,
+    #[serde(rename = "activities-summary", default)]
+    activities_summary: Option<ActivitiesSummary>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrcidIdentifier {
+    path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PersonRecord {
+    #[serde(default)]
+    name: Option<Name>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Name {
+    #[serde(rename = "given-names", default)]
+    given_names: Option<Value>,
+    #[serde(rename = "family-name", default)]
+    family_name: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Value {
+    #[serde(default)]
+    value: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ActivitiesSummary {
+    #[serde(default)]
+    works: Option<Works>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Works {
+    #[serde(default)]
+    group: Vec<WorkGroup>,
+}
+
+/// The summaries of one work from different sources (e.g. the person and
+/// Crossref), most preferred first.
+#[derive(Debug, Deserialize)]
+struct WorkGroup {
+    #[serde(rename = "work-summary", default)]
+    work_summary: Vec<WorkSummary>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkSummary {
+    #[serde(rename = "put-code", default)]
+    put_code: u64,
+    #[serde(default)]
+    title: Option<WorkTitle>,
+    #[serde(rename = "external-ids", default)]
+    external_ids: Option<ExternalIds>,
+    #[serde(default)]
+    url: Option<Value>,
+    #[serde(rename = "type", default)]
+    type_: String,
+    #[serde(rename = "publication-date", default)]
+    publication_date: Option<PublicationDate>,
+    #[serde(rename = "journal-title", default)]
+    journal_title: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkTitle {
+    #[serde(default)]
+    title: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalIds {
+    #[serde(rename = "external-id", default)]
+    external_id: Vec<ExternalId>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalId {
+    #[serde(rename = "external-id-type", default)]
+    type_: String,
+    #[serde(rename = "external-id-value", default)]
+    value: String,
+    #[serde(rename = "external-id-relationship", default)]
+    relationship: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PublicationDate {
+    #[serde(default)]
+    year: Option<Value>,
+    #[serde(default)]
+    month: Option<Value>,
+    #[serde(default)]
+    day: Option<Value>,
+}
+
+fn value(v: &Option<Value>) -> String {
+    v.as_ref()
+        .and_then(|v| v.value.as_deref())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+// ── Reader ────────────────────────────────────────────────────────────────────
+
+/// Read the works of an ORCID record (`/v3.0/{orcid}/record` JSON).
+pub fn read_json_all(input: &str) -> Result<Vec<Data>> {
+    let record: Record = serde_json::from_str(input).map_err(|e| Error::Parse(e.to_string()))?;
+    let orcid = normalize_orcid(&record.orcid_identifier.path);
+    let name = record.person.and_then(|p| p.name);
+    let holder = Contributor::person(
+        Person {
+            id: orcid.clone(),
+            given_name: name.as_ref().map(|n| value(&n.given_names)).unwrap_or_default(),
+            family_name: name.as_ref().map(|n| value(&n.family_name)).unwrap_or_default(),
+            ..Default::default()
+        },
+        vec!["Author".to_string()],
+    );
+
+    let groups = record
+        .activities_summary
+        .and_then(|a| a.works)
+        .map(|w| w.group)
+        .unwrap_or_default();
+    Ok(groups
+        .into_iter()
+        .filter_map(|group| group.work_summary.into_iter().next())
+        .map(|work| from_work_summary(work, &orcid, &holder))
+        .collect())
+}
+
+fn from_work_summary(work: WorkSummary, orcid: &str, holder: &Contributor) -> Data {
+    let mut data = Data {
+        type_: C::orcid_to_cm(&work.type_).to_string(),
+        title: work.title.as_ref().map(|t| value(&t.title)).unwrap_or_default(),
+        url: value(&work.url),
+        contributors: vec![holder.clone()],
+        provider: "ORCID".to_string(),
+        ..Default::default()
+    };
+
+    // Only the IDs of the work itself, not of the journal or book it's part of.
+    let external_ids = work.external_ids.map(|e| e.external_id).unwrap_or_default();
+    for id in external_ids
+        .iter()
+        .filter(|id| id.relationship.as_deref().unwrap_or("self") == "self")
+    {
+        let (identifier, identifier_type) = match id.type_.as_str() {
+            "doi" => (normalize_doi(&id.value), "DOI"),
+            "arxiv" => (id.value.clone(), "arXiv"),
+            "bibcode" => (id.value.clone(), "Bibcode"),
+            "handle" => (id.value.clone(), "Handle"),
+            "isbn" => (id.value.clone(), "ISBN"),
+            "pmc" => (id.value.clone(), "PMCID"),
+            "pmid" => (id.value.clone(), "PMID"),
+            "uri" => (id.value.clone(), "URL"),
+            "urn" => (id.value.clone(), "URN"),
+            _ => (id.value.clone(), "Other"),
+        };
+        if !identifier.is_empty() {
+            data.identifiers.push(Identifier {
+                identifier,
+                identifier_type: identifier_type.to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    // ID: the DOI if there is one, else the URL, else the work in the record.
+    data.id = data
+        .identifiers
+        .iter()
+        .find(|i| i.identifier_type == "DOI")
+        .map(|i| i.identifier.clone())
+        .unwrap_or_else(|| data.url.clone());
+    if data.id.is_empty() {
+        data.id = format!("{}/work/{}", orcid, work.put_code);
+    }
+
+    if let Some(date) = &work.publication_date {
+        data.date_published = [&date.year, &date.month, &date.day]
+            .into_iter()
+            .map(value)
+            .take_while(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+    }
+
+    let journal_title = value(&work.journal_title);
+    if !journal_title.is_empty() {
+        data.container = Container {
+            type_: if data.type_ == "JournalArticle" { "Journal" } else { "" }.to_string(),
+            title: journal_title,
+            ..Default::default()
+        };
+    }
+    data
+}
+
+// ── Fetch ─────────────────────────────────────────────────────────────────────
+
+/// Fetch the works of the ORCID record `orcid` (an ORCID ID or URL) from the
+/// ORCID public API.
+pub fn fetch_all(orcid: &str) -> Result<Vec<Data>> {
+    let id = validate_orcid(orcid).ok_or_else(|| Error::InvalidId(orcid.to_string()))?;
+    let url = format!("https://pub.orcid.org/v3.0/{}/record", id);
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    tracing::debug!("GET {}", url);
+    let json = client
+        .get(&url)
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    read_json_all(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORD: &str = r#"{
+        "orcid-identifier": {"path": "0000-0003-1419-2405"},
+        "person": {"name": {
+            "given-names": {"value": "Martin"},
+            "family-name": {"value": "Fenner"}
+        }},
+        "activities-summary": {"works": {"group": [
+            {"work-summary": [{
+                "put-code": 1,
+                "title": {"title": {"value": "A Journal Article"}},
+                "external-ids": {"external-id": [
+                    {"external-id-type": "doi", "external-id-value": "10.5555/ABC",
+                     "external-id-relationship": "self"},
+                    {"external-id-type": "issn", "external-id-value": "1234-5678",
+                     "external-id-relationship": "part-of"}
+                ]},
+                "type": "journal-article",
+                "publication-date": {"year": {"value": "2021"}, "month": {"value": "03"},
+                                     "day": null},
+                "journal-title": {"value": "Example Journal"}
+            }, {
+                "put-code": 2,
+                "title": {"title": {"value": "The same article from Crossref"}},
+                "type": "journal-article"
+            }]},
+            {"work-summary": [{
+                "put-code": 3,
+                "title": {"title": {"value": "A Talk"}},
+                "external-ids": null,
+                "url": null,
+                "type": "lecture-speech",
+                "publication-date": null,
+                "journal-title": null
+            }]}
+        ]}}
+    }"#;
+
+    #[test]
+    fn test_read_json_all() {
+        let works = read_json_all(RECORD).unwrap();
+        assert_eq!(works.len(), 2);
+
+        let article = &works[0];
+        assert_eq!(article.id, "https://doi.org/10.5555/abc");
+        assert_eq!(article.type_, "JournalArticle");
+        assert_eq!(article.title, "A Journal Article");
+        assert_eq!(article.date_published, "2021-03");
+        assert_eq!(article.container.title, "Example Journal");
+        assert_eq!(article.container.type_, "Journal");
+        assert_eq!(article.identifiers.len(), 1);
+        assert_eq!(article.contributors[0].id(), "https://orcid.org/0000-0003-1419-2405");
+        assert_eq!(article.contributors[0].family_name(), "Fenner");
+
+        let talk = &works[1];
+        assert_eq!(talk.id, "https://orcid.org/0000-0003-1419-2405/work/3");
+        assert_eq!(talk.type_, "Presentation");
+        assert!(talk.date_published.is_empty());
+    }
+
+    #[test]
+    fn test_fetch_all_rejects_invalid_orcid() {
+        assert!(matches!(fetch_all("0000-0000-0000-0000"), Err(Error::InvalidId(_))));
+    }
+}
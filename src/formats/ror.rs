@@ -6,7 +6,7 @@ use std::path::Path;
 
 use crate::data::{Data, Identifier, Relation};
 use crate::error::{Error, Result};
-use crate::utils::{normalize_ror, validate_id, validate_ror};
+use crate::utils::{normalize_ror, validate_grid, validate_id, validate_ror};
 
 use crate::formats::ror_countries::ROR_COUNTRIES;
 
@@ -787,7 +787,7 @@ pub fn write_sqlite(list: &[Ror], path: &Path, version: Option<&str>, date: Opti
     bar.finish_and_clear();
 
     // Build FTS5 index in one bulk pass over the content table.
-    eprintln!("Building FTS index...");
+    tracing::info!("Building FTS index...");
     conn.execute_batch(ROR_SQLITE_FTS5_DDL)
         .map_err(|e| Error::Parse(format!("failed to create FTS5 table: {}", e)))?;
     conn.execute(
@@ -1058,6 +1058,19 @@ pub fn match_affiliation(affiliation: &str) -> Result<Vec<AffiliationMatch>> {
         return Ok(vec![]);
     }
 
+    let mut resp = query_affiliation(&cleaned)?;
+
+    // Convert the raw Ror struct to Data for each item
+    for item in &mut resp.items {
+        let ror = std::mem::take(&mut item.organization_raw);
+        item.organization = from_ror(ror);
+    }
+
+    Ok(resp.items)
+}
+
+/// Query the ROR v2 affiliation endpoint with an already cleaned string.
+fn query_affiliation(cleaned: &str) -> Result<AffiliationResponse> {
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!(
             "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
@@ -1072,6 +1085,7 @@ pub fn match_affiliation(affiliation: &str) -> Result<Vec<AffiliationMatch>> {
         encoded
     );
 
+    tracing::debug!("GET {}", api_url);
     let text = client
         .get(&api_url)
         .send()
@@ -1081,16 +1095,7 @@ pub fn match_affiliation(affiliation: &str) -> Result<Vec<AffiliationMatch>> {
         .text()
         .map_err(|e| Error::Http(e.to_string()))?;
 
-    let mut resp: AffiliationResponse =
-        serde_json::from_str(&text).map_err(|e| Error::Parse(e.to_string()))?;
-
-    // Convert the raw Ror struct to Data for each item
-    for item in &mut resp.items {
-        let ror = std::mem::take(&mut item.organization_raw);
-        item.organization = from_ror(ror);
-    }
-
-    Ok(resp.items)
+    serde_json::from_str(&text).map_err(|e| Error::Parse(e.to_string()))
 }
 
 /// Match a free-text affiliation string against a local ROR SQLite database
@@ -1169,6 +1174,156 @@ pub fn match_affiliation_sqlite(affiliation: &str, db_path: &Path) -> Result<Vec
     Ok(matches)
 }
 
+/// Fill in the ROR ID of every person's affiliation that has a name but no
+/// ID, from the chosen match for the name: in the local database at
+/// `db_path` when given, else via the ROR affiliation API. Each distinct
+/// name is matched once. Returns the number of affiliations matched.
+pub fn match_affiliations(list: &mut [Data], db_path: Option<&Path>) -> Result<usize> {
+    let mut chosen: HashMap<String, Option<String>> = HashMap::new();
+    let mut matched = 0;
+    let affiliations = list
+        .iter_mut()
+        .flat_map(|data| data.contributors.iter_mut())
+        .filter_map(|contributor| contributor.person.as_mut())
+        .flat_map(|person| person.affiliations.iter_mut())
+        .filter(|a| a.id.is_empty() && !a.name.trim().is_empty());
+    for affiliation in affiliations {
+        if !chosen.contains_key(&affiliation.name) {
+            let candidates = match db_path {
+                Some(db_path) => match_affiliation_sqlite(&affiliation.name, db_path)?,
+                None => match_affiliation(&affiliation.name)?,
+            };
+            let id = candidates.into_iter().find(|m| m.chosen).map(|m| m.organization.id);
+            chosen.insert(affiliation.name.clone(), id);
+        }
+        if let Some(Some(id)) = chosen.get(&affiliation.name) {
+            affiliation.id = id.clone();
+            matched += 1;
+        }
+    }
+    Ok(matched)
+}
+
+// ── Organization lookup ───────────────────────────────────────────────────────
+
+/// An organization at a glance, as shown by `commonmeta ror`: its names,
+/// where it is, and how it relates to other organizations. `score` is set
+/// for search results (0–1, from the ROR affiliation API).
+#[derive(Debug, Default, Serialize)]
+pub struct OrganizationSummary {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub acronyms: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub country: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub country_code: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub established: Option<i32>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub website: String,
+    pub status: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+}
+
+/// Summarize a ROR record. Aliases are every name other than the display
+/// name and acronyms, i.e. aliases and labels in other languages.
+pub fn summarize(ror: &Ror) -> OrganizationSummary {
+    let name = get_display_name(ror);
+    let has_type = |n: &Name, t: &str| n.types.iter().any(|nt| nt == t);
+    let country = ror
+        .locations
+        .first()
+        .map(|l| &l.geonames_details);
+    OrganizationSummary {
+        id: normalize_ror(&ror.id),
+        aliases: ror
+            .names
+            .iter()
+            .filter(|n| n.value != name && !has_type(n, "acronym"))
+            .map(|n| n.value.clone())
+            .collect(),
+        acronyms: ror
+            .names
+            .iter()
+            .filter(|n| has_type(n, "acronym"))
+            .map(|n| n.value.clone())
+            .collect(),
+        name,
+        country: country.map(|c| c.country_name.clone()).unwrap_or_default(),
+        country_code: country.map(|c| c.country_code.clone()).unwrap_or_default(),
+        types: ror.types.clone(),
+        established: ror.established,
+        website: get_website_url(ror),
+        status: ror.status.clone(),
+        relationships: ror
+            .relationships
+            .iter()
+            .map(|r| Relationship {
+                type_: r.type_.clone(),
+                label: r.label.clone(),
+                id: normalize_ror(&r.id),
+            })
+            .collect(),
+        score: None,
+    }
+}
+
+/// Fetch the raw ROR record of the organization with ROR ID `id` (bare or
+/// as URL) from the ROR API.
+pub fn fetch_organization(id: &str) -> Result<Ror> {
+    let ror_id = validate_ror(id).ok_or_else(|| Error::InvalidId(id.to_string()))?;
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    let api_url = format!("https://api.ror.org/v2/organizations/{}", ror_id);
+    tracing::debug!("GET {}", api_url);
+    let text = client
+        .get(&api_url)
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))?;
+    serde_json::from_str::<Ror>(&text).map_err(|e| Error::Parse(e.to_string()))
+}
+
+/// Search organizations by name with the ROR affiliation API, best match
+/// first. Each summary carries the score the API gave the match.
+pub fn search_organizations(name: &str) -> Result<Vec<OrganizationSummary>> {
+    let cleaned = clean_search_string(name);
+    if cleaned.is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(summarize_matches(query_affiliation(&cleaned)?))
+}
+
+fn summarize_matches(response: AffiliationResponse) -> Vec<OrganizationSummary> {
+    let mut results: Vec<OrganizationSummary> = response
+        .items
+        .iter()
+        .map(|item| OrganizationSummary {
+            score: Some(item.score),
+            ..summarize(&item.organization_raw)
+        })
+        .collect();
+    results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    results
+}
+
 // ── Public API ────────────────────────────────────────────────────────────────
 
 pub fn read_json(input: &str) -> Result<Data> {
@@ -1190,17 +1345,7 @@ pub fn fetch(input: &str) -> Result<Data> {
 
     let ror = if id_type == "ROR" {
         // Direct lookup by ROR ID
-        let ror_id = validate_ror(&id).unwrap_or(id.clone());
-        let api_url = format!("https://api.ror.org/v2/organizations/{}", ror_id);
-        let text = client
-            .get(&api_url)
-            .send()
-            .map_err(|e| Error::Http(e.to_string()))?
-            .error_for_status()
-            .map_err(|e| Error::Http(e.to_string()))?
-            .text()
-            .map_err(|e| Error::Http(e.to_string()))?;
-        serde_json::from_str::<Ror>(&text).map_err(|e| Error::Parse(e.to_string()))?
+        fetch_organization(&id)?
     } else {
         // Query by other org identifier (Crossref Funder ID, GRID, Wikidata, ISNI)
         let org_types = ["ROR", "Crossref Funder ID", "GRID", "Wikidata", "ISNI"];
@@ -1212,6 +1357,7 @@ pub fn fetch(input: &str) -> Result<Data> {
         }
         let encoded: String = url::form_urlencoded::byte_serialize(id.as_bytes()).collect();
         let api_url = format!("https://api.ror.org/v2/organizations?query={}", encoded);
+        tracing::debug!("GET {}", api_url);
         let text = client
             .get(&api_url)
             .send()
@@ -1265,6 +1411,113 @@ pub fn fetch_sqlite(id: &str, db_path: &Path) -> Result<Data> {
     Ok(from_ror(ror))
 }
 
+// ── GRID crosswalk ────────────────────────────────────────────────────────────
+//
+// GRID was retired in 2021 and its identifiers live on only as `grid`
+// external_ids on ROR records. Legacy Crossref deposits still carry them in
+// `<institution_id type="grid">`, so conversion upgrades them to ROR.
+
+/// Maps GRID IDs (e.g. `grid.4709.a`) to ROR URLs, built from ROR records or
+/// from the `organizations` table written by [`write_sqlite`].
+#[derive(Debug, Clone, Default)]
+pub struct GridCrosswalk {
+    map: HashMap<String, String>,
+}
+
+impl GridCrosswalk {
+    /// Build the crosswalk from the `grid` external_ids of ROR records.
+    pub fn from_rors(list: &[Ror]) -> Self {
+        let mut crosswalk = GridCrosswalk::default();
+        for ror in list {
+            crosswalk.insert(&ror.id, &ror.external_ids);
+        }
+        crosswalk
+    }
+
+    /// Build the crosswalk from a local ROR SQLite database.
+    pub fn read_sqlite(db_path: &Path) -> Result<Self> {
+        let conn = rusqlite::Connection::open(db_path)
+            .map_err(|e| Error::Parse(format!("failed to open sqlite '{}': {}", db_path.display(), e)))?;
+        let mut stmt = conn
+            .prepare("SELECT id, external_ids FROM organizations WHERE external_ids LIKE '%grid%'")
+            .map_err(|e| Error::Parse(format!("failed to prepare GRID query: {}", e)))?;
+        let mut rows = stmt
+            .query([])
+            .map_err(|e| Error::Parse(format!("GRID query failed: {}", e)))?;
+
+        let mut crosswalk = GridCrosswalk::default();
+        while let Some(row) = rows.next().map_err(|e| Error::Parse(e.to_string()))? {
+            let id: String = row.get(0).map_err(|e| Error::Parse(format!("read id: {}", e)))?;
+            let json: String = row
+                .get(1)
+                .map_err(|e| Error::Parse(format!("read external_ids '{}': {}", id, e)))?;
+            let external_ids: Vec<ExternalId> = serde_json::from_str(&json).unwrap_or_default();
+            crosswalk.insert(&id, &external_ids);
+        }
+        Ok(crosswalk)
+    }
+
+    fn insert(&mut self, ror_id: &str, external_ids: &[ExternalId]) {
+        let Some(ror) = validate_ror(ror_id) else { return };
+        for ext in external_ids.iter().filter(|e| e.type_.eq_ignore_ascii_case("grid")) {
+            for grid in ext.all.iter().chain(std::iter::once(&ext.preferred)) {
+                if let Some(grid) = validate_grid(grid) {
+                    self.map.insert(grid, normalize_ror(&ror));
+                }
+            }
+        }
+    }
+
+    /// Returns the ROR URL for a GRID ID (bare or as a grid.ac URL), or
+    /// `None` if the ID is malformed or not in the crosswalk.
+    pub fn grid_to_ror(&self, grid: &str) -> Option<String> {
+        validate_grid(grid).and_then(|g| self.map.get(&g).cloned())
+    }
+
+    /// Replace GRID IDs on affiliations, organization contributors and
+    /// funders with their ROR URLs. Returns the number of IDs upgraded.
+    pub fn upgrade(&self, data: &mut Data) -> usize {
+        let mut upgraded = 0;
+        let mut upgrade_id = |id: &mut String| {
+            if let Some(ror) = self.grid_to_ror(id) {
+                *id = ror;
+                upgraded += 1;
+            }
+        };
+        for contributor in data.contributors.iter_mut() {
+            if let Some(person) = contributor.person.as_mut() {
+                for affiliation in person.affiliations.iter_mut() {
+                    upgrade_id(&mut affiliation.id);
+                }
+            }
+            if let Some(organization) = contributor.organization.as_mut() {
+                upgrade_id(&mut organization.id);
+            }
+        }
+        for funding in data.funding_references.iter_mut() {
+            upgrade_id(&mut funding.funder_id);
+        }
+        upgraded
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Returns true if any affiliation, organization contributor or funder in
+/// `data` is identified by a GRID ID.
+pub fn has_grid_ids(data: &Data) -> bool {
+    data.contributors.iter().any(|c| {
+        c.affiliations().iter().any(|a| validate_grid(&a.id).is_some())
+            || c.organization.as_ref().is_some_and(|o| validate_grid(&o.id).is_some())
+    }) || data.funding_references.iter().any(|f| validate_grid(&f.funder_id).is_some())
+}
+
 // ── Zenodo release helpers ────────────────────────────────────────────────────
 
 /// Concept record ID for the ROR data archive on Zenodo.
@@ -1332,6 +1585,7 @@ pub fn fetch_latest_ror_release() -> Result<RorRelease> {
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", url);
     let text = client
         .get(&url)
         .send()
@@ -1439,7 +1693,7 @@ mod tests {
     {"value": "IS", "types": ["acronym"], "lang": ""}
   ],
   "relationships": [
-    {"type": "related", "label": "Our Society", "id": "https://ror.org/045gyfv07"}
+    {"type": "related", "label": "Our Society", "id": "https://ror.org/045gyfv44"}
   ],
   "status": "active",
   "types": ["nonprofit"]
@@ -1500,7 +1754,7 @@ mod tests {
     fn test_ror_relations() {
         let data = read_json(ROR_ORG).unwrap();
         assert_eq!(data.relations.len(), 1);
-        assert_eq!(data.relations[0].id, "https://ror.org/045gyfv07");
+        assert_eq!(data.relations[0].id, "https://ror.org/045gyfv44");
         assert_eq!(data.relations[0].type_, "References");
     }
 
@@ -1585,7 +1839,7 @@ mod tests {
         // Relationships
         let rels = v["relationships"].as_array().unwrap();
         assert_eq!(rels[0]["type"].as_str().unwrap(), "related");
-        assert_eq!(rels[0]["id"].as_str().unwrap(), "https://ror.org/045gyfv07");
+        assert_eq!(rels[0]["id"].as_str().unwrap(), "https://ror.org/045gyfv44");
     }
 
     #[test]
@@ -1765,7 +2019,7 @@ mod tests {
     fn test_convert_ror_csv_relationships() {
         let ror = sample_ror();
         let row = convert_ror_csv(&ror);
-        assert_eq!(row.relationships, "Related: https://ror.org/045gyfv07");
+        assert_eq!(row.relationships, "Related: https://ror.org/045gyfv44");
     }
 
     #[test]
@@ -1868,4 +2122,142 @@ mod tests {
         assert_eq!(release.version, "v2.9");
         assert_eq!(release.date, "2026-06-23");
     }
+
+    #[test]
+    fn test_summarize() {
+        let ror: Ror = serde_json::from_str(ROR_ORG).unwrap();
+        let summary = summarize(&ror);
+        assert_eq!(summary.id, "https://ror.org/02nr0ka47");
+        assert_eq!(summary.name, "Impactstory");
+        assert!(summary.aliases.is_empty());
+        assert_eq!(summary.acronyms, vec!["IS"]);
+        assert_eq!(summary.country, "United States");
+        assert_eq!(summary.country_code, "US");
+        assert_eq!(summary.established, Some(2013));
+        assert_eq!(summary.relationships[0].label, "Our Society");
+        assert_eq!(summary.score, None);
+    }
+
+    #[test]
+    fn test_summarize_matches_sorts_by_score() {
+        let json = format!(
+            r#"{{"number_of_results": 2, "items": [
+                {{"substring": "IS", "score": 0.5, "matching_type": "ACRONYM",
+                  "chosen": false, "organization": {}}},
+                {{"substring": "Impactstory", "score": 1.0, "matching_type": "PHRASE",
+                  "chosen": true, "organization": {{"id": "https://ror.org/045gyfv44",
+                  "names": [{{"value": "Our Society", "types": ["ror_display"]}}]}}}}
+            ]}}"#,
+            ROR_ORG
+        );
+        let response: AffiliationResponse = serde_json::from_str(&json).unwrap();
+        let results = summarize_matches(response);
+        assert_eq!(results[0].name, "Our Society");
+        assert_eq!(results[0].score, Some(1.0));
+        assert_eq!(results[1].name, "Impactstory");
+    }
+
+    #[test]
+    fn test_grid_crosswalk() {
+        let ror: Ror = serde_json::from_str(ROR_ORG).unwrap();
+        let crosswalk = GridCrosswalk::from_rors(&[ror]);
+        assert_eq!(crosswalk.len(), 1);
+        assert_eq!(
+            crosswalk.grid_to_ror("grid.465570.2"),
+            Some("https://ror.org/02nr0ka47".to_string())
+        );
+        assert_eq!(
+            crosswalk.grid_to_ror("https://www.grid.ac/institutes/grid.465570.2"),
+            Some("https://ror.org/02nr0ka47".to_string())
+        );
+        assert_eq!(crosswalk.grid_to_ror("grid.4709.a"), None);
+        assert_eq!(crosswalk.grid_to_ror("not a grid"), None);
+    }
+
+    #[test]
+    fn test_grid_crosswalk_upgrade() {
+        use crate::data::{Affiliation, Contributor, FundingReference, Person};
+
+        let ror: Ror = serde_json::from_str(ROR_ORG).unwrap();
+        let crosswalk = GridCrosswalk::from_rors(&[ror]);
+        let mut data = Data {
+            contributors: vec![Contributor::person(
+                Person {
+                    family_name: "Priem".to_string(),
+                    affiliations: vec![
+                        Affiliation {
+                            id: "grid.465570.2".to_string(),
+                            name: "Impactstory".to_string(),
+                            ..Default::default()
+                        },
+                        Affiliation { id: "grid.4709.a".to_string(), ..Default::default() },
+                    ],
+                    ..Default::default()
+                },
+                vec!["Author".to_string()],
+            )],
+            funding_references: vec![FundingReference {
+                funder_id: "grid.465570.2".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(has_grid_ids(&data));
+        assert_eq!(crosswalk.upgrade(&mut data), 2);
+        let affiliations = data.contributors[0].affiliations();
+        assert_eq!(affiliations[0].id, "https://ror.org/02nr0ka47");
+        assert_eq!(affiliations[1].id, "grid.4709.a");
+        assert_eq!(data.funding_references[0].funder_id, "https://ror.org/02nr0ka47");
+    }
+
+    #[test]
+    fn test_grid_crosswalk_read_sqlite() {
+        let ror: Ror = serde_json::from_str(ROR_ORG).unwrap();
+        let path = std::env::temp_dir().join(format!("ror-grid-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        write_sqlite(&[ror], &path, None, None).unwrap();
+        let crosswalk = GridCrosswalk::read_sqlite(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            crosswalk.grid_to_ror("grid.465570.2"),
+            Some("https://ror.org/02nr0ka47".to_string())
+        );
+    }
+
+    #[test]
+    fn test_match_affiliations_sqlite() {
+        use crate::data::{Affiliation, Contributor, Person};
+
+        let ror: Ror = serde_json::from_str(ROR_ORG).unwrap();
+        let path = std::env::temp_dir().join(format!("ror-match-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        write_sqlite(&[ror], &path, None, None).unwrap();
+
+        let affiliation = |id: &str, name: &str| Affiliation {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let person = Person {
+            family_name: "Priem".to_string(),
+            affiliations: vec![
+                affiliation("", "Impactstory"),
+                affiliation("", "Unknown Institute of Nothing"),
+                affiliation("https://ror.org/045gyfv44", "Impactstory"),
+            ],
+            ..Default::default()
+        };
+        let mut list = vec![Data {
+            contributors: vec![Contributor::person(person, vec![])],
+            ..Default::default()
+        }];
+        let matched = match_affiliations(&mut list, Some(&path)).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(matched, 1);
+        let affiliations = list[0].contributors[0].affiliations();
+        assert_eq!(affiliations[0].id, "https://ror.org/02nr0ka47");
+        assert_eq!(affiliations[1].id, "");
+        assert_eq!(affiliations[2].id, "https://ror.org/045gyfv44");
+    }
 }
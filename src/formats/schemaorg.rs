@@ -1,6 +1,6 @@
 //! Schema.org JSON-LD reader and writer.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use crate::author_utils::{
@@ -8,12 +8,15 @@ use crate::author_utils::{
     split_person_name,
 };
 use crate::constants as C;
-use crate::data::{Contributor, Data, Identifier, Organization, Person, Publisher, Subject, Title};
+use crate::data::{
+    Container, Contributor, Data, Identifier, Organization, Person, Publisher, Reference, Relation,
+    Subject, Title,
+};
 use crate::doi_utils::{normalize_doi, validate_doi};
 use crate::error::{Error, Result};
 use crate::utils::{
-    normalize_cc_url, normalize_id, normalize_orcid, normalize_ror, normalize_url, sanitize,
-    validate_id,
+    issn_as_url, normalize_cc_url, normalize_id, normalize_orcid, normalize_ror, normalize_url,
+    sanitize, validate_id, validate_issn,
 };
 
 /// Extract a language code from an `inLanguage` value, which can be either a
@@ -51,7 +54,6 @@ pub(crate) struct SoContributor {
 }
 
 /// Citation / reference entry.
-#[allow(dead_code)]
 #[derive(Deserialize, Default)]
 struct SoCitation {
     #[serde(rename = "@id", default)]
@@ -62,18 +64,47 @@ struct SoCitation {
     name: String,
 }
 
-/// Periodical (journal) embedded in a ScholarlyArticle.
-#[allow(dead_code)]
+/// Container of a work: `isPartOf` (e.g. a Blog or Periodical), `periodical`
+/// embedded in a ScholarlyArticle, or `includedInDataCatalog` of a Dataset.
 #[derive(Deserialize, Default)]
 struct SoPeriodical {
     #[serde(rename = "@id", default)]
-    _id: String,
+    id: String,
     #[serde(rename = "@type", default)]
-    _type: String,
+    type_: String,
     #[serde(default)]
     name: String,
+    // issn can be a single string or an array (print and electronic)
     #[serde(default)]
-    issn: String,
+    issn: Option<Value>,
+}
+
+/// Deserialize a container that may be given as an object, a bare string
+/// (URL or name) or an array of either; arrays yield their first usable entry.
+fn periodical_value<'de, D>(d: D) -> std::result::Result<Option<SoPeriodical>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Value>::deserialize(d)?.and_then(periodical_from_value))
+}
+
+fn periodical_from_value(v: Value) -> Option<SoPeriodical> {
+    match v {
+        Value::String(s) if s.trim().is_empty() => None,
+        Value::String(s) if s.starts_with("http://") || s.starts_with("https://") => {
+            Some(SoPeriodical {
+                id: s,
+                ..Default::default()
+            })
+        }
+        Value::String(s) => Some(SoPeriodical {
+            name: s,
+            ..Default::default()
+        }),
+        Value::Array(arr) => arr.into_iter().find_map(periodical_from_value),
+        Value::Object(_) => serde_json::from_value(v).ok(),
+        _ => None,
+    }
 }
 
 /// Publisher embedded in Schema.org JSON-LD.
@@ -109,7 +140,6 @@ struct SoContent {
     #[serde(default)]
     editor: Option<Value>,
     // citation / references list
-    #[allow(dead_code)]
     #[serde(default)]
     citation: Vec<SoCitation>,
     #[serde(rename = "dateCreated", default)]
@@ -136,9 +166,17 @@ struct SoContent {
     license: String,
     #[serde(default)]
     name: String,
-    #[allow(dead_code)]
-    #[serde(default)]
+    // containers can be an object, a string URL or name, or an array
+    #[serde(default, deserialize_with = "periodical_value")]
     periodical: Option<SoPeriodical>,
+    #[serde(rename = "isPartOf", default, deserialize_with = "periodical_value")]
+    is_part_of: Option<SoPeriodical>,
+    #[serde(
+        rename = "includedInDataCatalog",
+        default,
+        deserialize_with = "periodical_value"
+    )]
+    included_in_data_catalog: Option<SoPeriodical>,
     #[serde(default)]
     publisher: Option<SoPublisher>,
     #[serde(default)]
@@ -146,6 +184,24 @@ struct SoContent {
     // version can be string or number
     #[serde(default)]
     version: Option<Value>,
+    // position within the container; strings or numbers
+    #[serde(rename = "volumeNumber", default)]
+    volume_number: Option<Value>,
+    #[serde(rename = "issueNumber", default)]
+    issue_number: Option<Value>,
+    #[serde(rename = "pageStart", default)]
+    page_start: Option<Value>,
+    #[serde(rename = "pageEnd", default)]
+    page_end: Option<Value>,
+}
+
+/// String form of a JSON-LD value that may be a string or a number.
+fn value_to_string(v: &Option<Value>) -> String {
+    match v {
+        Some(Value::String(s)) => s.trim().to_string(),
+        Some(Value::Number(n)) => n.to_string(),
+        _ => String::new(),
+    }
 }
 
 // ── Contributor helpers ───────────────────────────────────────────────────────
@@ -250,6 +306,54 @@ pub(crate) fn get_contributor(v: SoContributor, default_role: &str) -> Contribut
     }
 }
 
+// ── Container helpers ─────────────────────────────────────────────────────────
+
+/// Map a Schema.org container to a commonmeta `Container`, plus an `IsPartOf`
+/// relation when the container has a persistent identifier (ISSN or DOI).
+fn get_container(p: SoPeriodical, work_type: &str) -> (Container, Option<Relation>) {
+    let issn = match &p.issn {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(arr)) => arr
+            .iter()
+            .find_map(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+    let type_ = match p.type_.as_str() {
+        "Blog" => "Blog",
+        "DataCatalog" => "DataRepository",
+        "Book" => "Book",
+        "Periodical" if work_type == "JournalArticle" => "Journal",
+        _ if work_type == "Dataset" => "DataRepository",
+        _ => "Periodical",
+    };
+
+    let (identifier, identifier_type, relation_id) = if let Some(issn) = validate_issn(&issn) {
+        (issn.clone(), "ISSN", issn_as_url(&issn))
+    } else if validate_doi(&p.id).is_some() {
+        let doi = normalize_doi(&p.id);
+        (doi.clone(), "DOI", doi)
+    } else if let Some(url) = normalize_url(&p.id, true, false) {
+        (url, "URL", String::new())
+    } else {
+        (String::new(), "", String::new())
+    };
+
+    let container = Container {
+        identifier,
+        identifier_type: identifier_type.to_string(),
+        type_: type_.to_string(),
+        title: p.name,
+        ..Default::default()
+    };
+    let relation = (!relation_id.is_empty()).then(|| Relation {
+        id: relation_id,
+        type_: "IsPartOf".to_string(),
+        ..Default::default()
+    });
+    (container, relation)
+}
+
 // ── Core conversion ───────────────────────────────────────────────────────────
 
 fn from_content(content: SoContent) -> Data {
@@ -447,6 +551,46 @@ fn from_content(content: SoContent) -> Data {
         }
     }
 
+    // Container — isPartOf, falling back to periodical / includedInDataCatalog
+    if let Some(container) = content
+        .is_part_of
+        .or(content.periodical)
+        .or(content.included_in_data_catalog)
+    {
+        let (container, relation) = get_container(container, &data.type_);
+        data.container = container;
+        data.relations.extend(relation);
+    }
+    let volume = value_to_string(&content.volume_number);
+    let issue = value_to_string(&content.issue_number);
+    let first_page = value_to_string(&content.page_start);
+    let last_page = value_to_string(&content.page_end);
+    if !volume.is_empty() || !issue.is_empty() || !first_page.is_empty() {
+        if data.container.type_.is_empty() {
+            data.container.type_ = "Periodical".to_string();
+        }
+        data.container.volume = volume;
+        data.container.issue = issue;
+        data.container.first_page = first_page;
+        data.container.last_page = last_page;
+    }
+
+    // References
+    data.references = content
+        .citation
+        .into_iter()
+        .filter(|c| !c.id.is_empty() || !c.name.is_empty())
+        .map(|c| Reference {
+            id: normalize_id(&c.id),
+            type_: match C::so_to_cm(&c.type_) {
+                "" | "Other" => String::new(),
+                t => t.to_string(),
+            },
+            reference: c.name,
+            ..Default::default()
+        })
+        .collect();
+
     // URL
     if let Some(url) = normalize_url(&content.url, true, false) {
         data.url = url;
@@ -473,6 +617,13 @@ pub fn read_json(input: &str) -> Result<Data> {
     Ok(from_content(content))
 }
 
+/// Read a landing page's HTML: embedded JSON-LD, completed from Highwire
+/// `citation_*`, Dublin Core and OpenGraph `<meta>` tags. Unlike [`fetch`],
+/// this never hands off to the Crossref or DataCite reader.
+pub fn read_html(html: &str) -> Result<Data> {
+    Ok(from_content(extract_content(html)?))
+}
+
 /// Fetch a URL, extract its JSON-LD, and parse into `Data`.
 ///
 /// falls back to `<meta>` tags, then dispatches to Crossref or DataCite when the
@@ -488,6 +639,7 @@ pub fn fetch(url: &str) -> Result<Data> {
         .build()
         .map_err(|e| Error::Http(e.to_string()))?;
 
+    tracing::debug!("GET {}", url);
     let html = client
         .get(url)
         .send()
@@ -497,7 +649,10 @@ pub fn fetch(url: &str) -> Result<Data> {
         .text()
         .map_err(|e| Error::Http(e.to_string()))?;
 
-    let content = extract_content(&html)?;
+    let mut content = extract_content(&html)?;
+    if content.url.is_empty() {
+        content.url = url.to_string();
+    }
 
     // If DOI belongs to a known RA, hand off to that reader
     if let Some(bare) = validate_doi(&content.id) {
@@ -514,6 +669,27 @@ pub fn fetch(url: &str) -> Result<Data> {
     Ok(from_content(content))
 }
 
+/// Non-empty `content` values of all `<meta>` tags matching any of `selectors`,
+/// in selector order.
+fn meta_values(doc: &scraper::Html, selectors: &[&str]) -> Vec<String> {
+    selectors
+        .iter()
+        .filter_map(|sel| scraper::Selector::parse(sel).ok())
+        .flat_map(|sel| {
+            doc.select(&sel)
+                .filter_map(|el| el.value().attr("content"))
+                .map(|val| val.trim().to_string())
+                .filter(|val| !val.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// First non-empty `content` value among `selectors`, or an empty string.
+fn meta_value(doc: &scraper::Html, selectors: &[&str]) -> String {
+    meta_values(doc, selectors).into_iter().next().unwrap_or_default()
+}
+
 /// Extract Schema.org JSON-LD (+ meta-tag fallbacks) from raw HTML.
 fn extract_content(html: &str) -> Result<SoContent> {
     use scraper::{Html, Selector};
@@ -567,6 +743,26 @@ fn extract_content(html: &str) -> Result<SoContent> {
             }
         }
     }
+    // Highwire tags identify the kind of scholarly work more precisely than
+    // OpenGraph's generic `article` / `website`.
+    if matches!(content.type_.as_str(), "" | "article" | "website") {
+        let highwire_type = if !meta_value(&doc, &["meta[name='citation_journal_title']"]).is_empty() {
+            "ScholarlyArticle"
+        } else if !meta_value(&doc, &["meta[name='citation_dissertation_institution']"]).is_empty() {
+            "Dissertation"
+        } else if !meta_value(&doc, &["meta[name='citation_technical_report_institution']"])
+            .is_empty()
+        {
+            "Report"
+        } else {
+            match content.type_.as_str() {
+                "article" => "Article",
+                "website" => "WebSite",
+                _ => "",
+            }
+        };
+        content.type_ = highwire_type.to_string();
+    }
 
     // ── Name / headline fallbacks ─────────────────────────────────────────────
     if content.name.is_empty() {
@@ -624,7 +820,8 @@ fn extract_content(html: &str) -> Result<SoContent> {
                 && let Some(el) = doc.select(&sel).next()
                 && let Some(val) = el.value().attr("content")
             {
-                content.date_published = val.to_string();
+                // Highwire dates are often written as YYYY/MM/DD
+                content.date_published = val.replace('/', "-");
                 break;
             }
         }
@@ -648,6 +845,19 @@ fn extract_content(html: &str) -> Result<SoContent> {
     }
 
     // ── Language fallback ─────────────────────────────────────────────────────
+    if extract_in_language(&content.in_language).is_empty() {
+        let lang = meta_value(
+            &doc,
+            &[
+                "meta[name='citation_language']",
+                "meta[name='dc.language']",
+                "meta[name='DC.language']",
+            ],
+        );
+        if !lang.is_empty() {
+            content.in_language = Some(Value::String(lang));
+        }
+    }
     if extract_in_language(&content.in_language).is_empty()
         && let Ok(sel) = Selector::parse("html")
         && let Some(el) = doc.select(&sel).next()
@@ -665,6 +875,117 @@ fn extract_content(html: &str) -> Result<SoContent> {
         content.license = href.to_string();
     }
 
+    // ── Author fallbacks ──────────────────────────────────────────────────────
+    if content.author.is_none() && content.creator.is_none() {
+        let mut authors = meta_values(&doc, &["meta[name='citation_author']"]);
+        if authors.is_empty() {
+            authors = meta_values(
+                &doc,
+                &["meta[name='dc.creator']", "meta[name='DC.creator']", "meta[name='author']"],
+            );
+        }
+        if !authors.is_empty() {
+            content.author = Some(Value::Array(
+                authors
+                    .into_iter()
+                    .map(|name| serde_json::json!({ "name": name }))
+                    .collect(),
+            ));
+        }
+    }
+
+    // ── Container fallbacks ───────────────────────────────────────────────────
+    if content.is_part_of.is_none() && content.periodical.is_none() {
+        let name = meta_value(
+            &doc,
+            &[
+                "meta[name='citation_journal_title']",
+                "meta[name='citation_conference_title']",
+                "meta[name='citation_inbook_title']",
+                "meta[name='dc.source']",
+                "meta[name='DC.source']",
+            ],
+        );
+        let issns = meta_values(&doc, &["meta[name='citation_issn']"]);
+        if !name.is_empty() || !issns.is_empty() {
+            content.is_part_of = Some(SoPeriodical {
+                type_: "Periodical".to_string(),
+                name,
+                issn: Some(Value::Array(issns.into_iter().map(Value::String).collect())),
+                ..Default::default()
+            });
+        }
+    }
+    let positions = [
+        (&mut content.volume_number, "meta[name='citation_volume']"),
+        (&mut content.issue_number, "meta[name='citation_issue']"),
+        (&mut content.page_start, "meta[name='citation_firstpage']"),
+        (&mut content.page_end, "meta[name='citation_lastpage']"),
+    ];
+    for (field, sel_str) in positions {
+        if field.is_none() {
+            let val = meta_value(&doc, &[sel_str]);
+            if !val.is_empty() {
+                *field = Some(Value::String(val));
+            }
+        }
+    }
+
+    // ── Publisher fallbacks ───────────────────────────────────────────────────
+    if content.publisher.is_none() {
+        let name = meta_value(
+            &doc,
+            &[
+                "meta[name='citation_publisher']",
+                "meta[name='dc.publisher']",
+                "meta[name='DC.publisher']",
+                "meta[property='og:site_name']",
+            ],
+        );
+        if !name.is_empty() {
+            content.publisher = Some(SoPublisher {
+                name,
+                ..Default::default()
+            });
+        }
+    }
+
+    // ── Keyword fallbacks ─────────────────────────────────────────────────────
+    if content.keywords.is_none() {
+        let keywords: Vec<Value> = meta_values(
+            &doc,
+            &[
+                "meta[name='citation_keywords']",
+                "meta[name='dc.subject']",
+                "meta[name='DC.subject']",
+                "meta[name='keywords']",
+            ],
+        )
+        .iter()
+        .flat_map(|k| k.split([';', ',']))
+        .map(|k| Value::String(k.trim().to_string()))
+        .filter(|k| k.as_str() != Some(""))
+        .collect();
+        if !keywords.is_empty() {
+            content.keywords = Some(Value::Array(keywords));
+        }
+    }
+
+    // ── URL fallbacks ─────────────────────────────────────────────────────────
+    if content.url.is_empty() {
+        content.url = meta_value(
+            &doc,
+            &["meta[name='citation_abstract_html_url']", "meta[property='og:url']"],
+        );
+    }
+    if content.url.is_empty()
+        && let Ok(sel) = Selector::parse("link[rel='canonical']")
+        && let Some(el) = doc.select(&sel).next()
+        && let Some(href) = el.value().attr("href")
+    {
+        content.url = href.to_string();
+    }
+
     // ── author/creator synonyms ───────────────────────────────────────────────
     if content.author.is_none()
         && let Some(creator) = content.creator.take()
@@ -1059,6 +1380,99 @@ mod tests {
         assert_eq!(data.additional_titles[0].type_, "Subtitle");
     }
 
+    #[test]
+    fn schemaorg_reader_maps_periodical_to_journal_container() {
+        let input = r#"{
+          "@context": "https://schema.org",
+          "@type": "ScholarlyArticle",
+          "name": "A Study of Things",
+          "periodical": {
+            "@type": "Periodical",
+            "name": "Journal of Examples",
+            "issn": ["1234-5679", "2049-3630"]
+          }
+        }"#;
+
+        let data = read_json(input).unwrap();
+        assert_eq!(data.container.type_, "Journal");
+        assert_eq!(data.container.title, "Journal of Examples");
+        assert_eq!(data.container.identifier, "1234-5679");
+        assert_eq!(data.container.identifier_type, "ISSN");
+        assert_eq!(data.relations[0].type_, "IsPartOf");
+        assert_eq!(
+            data.relations[0].id,
+            "https://portal.issn.org/resource/ISSN/1234-5679"
+        );
+    }
+
+    #[test]
+    fn schemaorg_reader_accepts_is_part_of_as_string() {
+        let input = include_str!("../../tests/fixtures/schemaorg/schemaorg_is_part_of_string.json");
+
+        let data = read_json(input).unwrap();
+        assert_eq!(data.title, "Eating your own Dog Food");
+        assert_eq!(data.container.type_, "Periodical");
+        assert_eq!(data.container.identifier, "https://blog.datacite.org/");
+        assert_eq!(data.container.identifier_type, "URL");
+    }
+
+    #[test]
+    fn schemaorg_reader_accepts_included_in_data_catalog_as_array() {
+        let input = include_str!("../../tests/fixtures/schemaorg/schemaorg_is_part_of_array.json");
+
+        let data = read_json(input).unwrap();
+        assert_eq!(data.type_, "Dataset");
+        assert_eq!(data.container.type_, "DataRepository");
+        assert_eq!(data.container.title, "Example Data Repository");
+        assert_eq!(data.container.identifier, "https://data.example.org/");
+        assert_eq!(data.container.identifier_type, "URL");
+    }
+
+    #[test]
+    fn schemaorg_read_html_falls_back_to_meta_tags() {
+        let html = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta property="og:type" content="article">
+  <meta property="og:title" content="OpenGraph title">
+  <meta property="og:site_name" content="Example Press">
+  <meta property="og:url" content="https://journal.example.org/article/42">
+  <meta name="citation_title" content="Gene expression in bacteria">
+  <meta name="citation_author" content="Smith, Jane">
+  <meta name="citation_author" content="Doe, John">
+  <meta name="citation_journal_title" content="Journal of Examples">
+  <meta name="citation_issn" content="2049-3630">
+  <meta name="citation_volume" content="12">
+  <meta name="citation_issue" content="3">
+  <meta name="citation_firstpage" content="101">
+  <meta name="citation_lastpage" content="110">
+  <meta name="citation_publication_date" content="2024/04/02">
+  <meta name="citation_keywords" content="genetics; bacteria">
+  <meta name="DC.publisher" content="Example Society">
+</head>
+<body></body>
+</html>"#;
+
+        let data = read_html(html).unwrap();
+        assert_eq!(data.type_, "JournalArticle");
+        assert_eq!(data.title, "Gene expression in bacteria");
+        assert_eq!(data.contributors.len(), 2);
+        assert_eq!(data.contributors[0].family_name(), "Smith");
+        assert_eq!(data.contributors[1].given_name(), "John");
+        assert_eq!(data.container.type_, "Journal");
+        assert_eq!(data.container.title, "Journal of Examples");
+        assert_eq!(data.container.identifier, "2049-3630");
+        assert_eq!(data.container.volume, "12");
+        assert_eq!(data.container.issue, "3");
+        assert_eq!(data.container.first_page, "101");
+        assert_eq!(data.container.last_page, "110");
+        assert_eq!(data.publisher.name, "Example Society");
+        assert_eq!(data.subjects.len(), 2);
+        assert_eq!(data.language, "en");
+        assert_eq!(data.url, "https://journal.example.org/article/42");
+        assert_eq!(data.date_published, "2024-04-02");
+    }
+
     #[test]
     fn schemaorg_writer_prefers_primary_title_and_sets_headline_from_subtitle() {
         let data = Data {
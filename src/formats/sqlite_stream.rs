@@ -846,7 +846,7 @@ pub fn stream_zst_pidbox_to_sqlite(
 
     let mut page_raw = vec![0u8; fhdr.page_size];
 
-    eprintln!(
+    tracing::info!(
         "  SQLite page_size={}, db_pages={}, table root={}",
         fhdr.page_size,
         if fhdr.db_page_count > 0 { fhdr.db_page_count.to_string() } else { "unknown".to_string() },
@@ -900,7 +900,7 @@ pub fn stream_zst_pidbox_to_sqlite(
     {
         let leftover_buf = page_buf.leftover_count();
         if leftover_buf > 0 {
-            eprintln!(
+            tracing::info!(
                 "  pass 1: {leftover_buf} buffered pages from other tables/indexes (expected)"
             );
         }
@@ -917,7 +917,7 @@ pub fn stream_zst_pidbox_to_sqlite(
         let still_needed = target.len() + overflow_map.len();
         if still_needed == 0 || (limit > 0 && written >= limit) { break; }
 
-        eprintln!(
+        tracing::info!(
             "  pass {}: {} pages still needed — rescanning {} GiB compressed file …",
             pass + 1,
             still_needed,
@@ -931,7 +931,7 @@ pub fn stream_zst_pidbox_to_sqlite(
         )?;
 
         if matched < CONVERGENCE_THRESHOLD {
-            eprintln!(
+            tracing::info!(
                 "  pass {}: {} pages matched — converged, stopping",
                 pass + 1, matched
             );
@@ -951,23 +951,23 @@ pub fn stream_zst_pidbox_to_sqlite(
         let converted = prepared.len();
         written += converted;
         if converted < n {
-            eprintln!("  tail: {n} records found, {converted} converted ({} failed)", n - converted);
+            tracing::info!("  tail: {n} records found, {converted} converted ({} failed)", n - converted);
         }
         write_sqlite_batch_rows(&out_conn, prepared)?;
     }
 
     // ── Final diagnostics ──────────────────────────────────────────────
     if !target.is_empty() {
-        eprintln!(
-            "  warning: {} B-tree pages still unresolved after {} passes — \
+        tracing::warn!(
+            "{} B-tree pages still unresolved after {} passes — \
              some records may be missing",
             target.len(),
             MAX_EXTRA_PASSES + 1,
         );
     }
     if !overflow_map.is_empty() {
-        eprintln!(
-            "  warning: {} overflow chains unresolved — \
+        tracing::warn!(
+            "{} overflow chains unresolved — \
              {} records with large raw_metadata may be truncated",
             overflow_map.len(),
             overflow_map.len(),
@@ -120,14 +120,8 @@ fn route_raw_metadata(source_id: i64, raw_metadata: &str) -> Result<Data> {
 }
 
 fn read_crossref_row(raw_metadata: &str) -> Result<Data> {
-    let value: Value =
-        serde_json::from_str(raw_metadata).map_err(|e| Error::Parse(e.to_string()))?;
-    let json_text = if value.get("message").is_some() {
-        raw_metadata.to_string()
-    } else {
-        json!({ "message": value }).to_string()
-    };
-    crossref::read_json(&json_text)
+    // Rows hold either the API envelope or the bare work; the reader takes both.
+    crossref::read_json(raw_metadata)
 }
 
 fn read_datacite_row(raw_metadata: &str) -> Result<Data> {
@@ -0,0 +1,308 @@
+//! Client for Wikidata items.
+//!
+//! Fetches `https://www.wikidata.org/wiki/Special:EntityData/{qid}.json` and
+//! reads the external identifiers of the item (ORCID, ROR, ISNI, DOI, ...),
+//! and goes the other way with a SPARQL query against
+//! `https://query.wikidata.org/sparql`: from an identifier to the item that
+//! carries it.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::utils::{Identifier, validate_wikidata};
+
+/// Wikidata properties for external identifiers, and the identifier type
+/// (as named by [`Identifier::type_name`]) each holds.
+pub const PROPERTIES: [(&str, &str); 14] = [
+    ("P496", "ORCID"),
+    ("P6782", "ROR"),
+    ("P213", "ISNI"),
+    ("P356", "DOI"),
+    ("P214", "VIAF"),
+    ("P227", "GND"),
+    ("P244", "LCCN"),
+    ("P2427", "GRID"),
+    ("P3153", "Crossref Funder ID"),
+    ("P698", "PMID"),
+    ("P932", "PMCID"),
+    ("P212", "ISBN"),
+    ("P236", "ISSN"),
+    ("P10283", "OpenAlex"),
+];
+
+// ── Wikidata API structs ──────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct EntityData {
+    #[serde(default)]
+    entities: HashMap<String, Entity>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entity {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    labels: HashMap<String, Label>,
+    #[serde(default)]
+    claims: HashMap<String, Vec<Claim>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Label {
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Claim {
+    mainsnak: Snak,
+    #[serde(default)]
+    rank: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Snak {
+    #[serde(default)]
+    datavalue: Option<DataValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataValue {
+    value: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct SparqlResponse {
+    results: SparqlResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct SparqlResults {
+    #[serde(default)]
+    bindings: Vec<HashMap<String, SparqlValue>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SparqlValue {
+    value: String,
+}
+
+// ── Items ─────────────────────────────────────────────────────────────────────
+
+/// A Wikidata item with its English label and the external identifiers
+/// listed in [`PROPERTIES`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WikidataItem {
+    /// The QID, e.g. `Q42`.
+    pub id: String,
+    pub label: String,
+    pub identifiers: Vec<Identifier>,
+}
+
+/// Read an item from Special:EntityData JSON. Values that aren't valid
+/// identifiers of their property's type, and deprecated statements, are
+/// left out.
+pub fn read_entity(json: &str) -> Result<WikidataItem> {
+    let data: EntityData =
+        serde_json::from_str(json).map_err(|e| Error::Parse(format!("wikidata: {}", e)))?;
+    // A redirected QID answers with the entity it redirects to.
+    let entity = data
+        .entities
+        .into_values()
+        .next()
+        .ok_or_else(|| Error::Parse("wikidata: no entity".to_string()))?;
+
+    let label = entity
+        .labels
+        .get("en")
+        .or_else(|| entity.labels.values().next())
+        .map(|l| l.value.clone())
+        .unwrap_or_default();
+    let mut identifiers = Vec::new();
+    for (property, type_name) in PROPERTIES {
+        let values = entity
+            .claims
+            .get(property)
+            .into_iter()
+            .flatten()
+            .filter(|claim| claim.rank != "deprecated")
+            .filter_map(|claim| claim.mainsnak.datavalue.as_ref()?.value.as_str());
+        for value in values {
+            if let Some(identifier) = Identifier::parse_as(type_name, value)
+                && !identifiers.contains(&identifier)
+            {
+                identifiers.push(identifier);
+            }
+        }
+    }
+    Ok(WikidataItem { id: entity.id, label, identifiers })
+}
+
+/// The value of `identifier` as Wikidata stores it, and the property it is
+/// stored under, or `None` for identifier types not in [`PROPERTIES`].
+/// Wikidata keeps DOIs uppercase and ISNIs in blocks of four.
+fn property_value(identifier: &Identifier) -> Option<(&'static str, String)> {
+    let type_name = identifier.type_name();
+    let (property, _) = PROPERTIES.iter().find(|(_, t)| *t == type_name)?;
+    let value = match identifier {
+        Identifier::Doi(doi) => doi.to_uppercase(),
+        Identifier::Isni(isni) => isni
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+            .collect::<Vec<_>>()
+            .join(" "),
+        other => other.value().to_string(),
+    };
+    Some((property, value))
+}
+
+/// The SPARQL query for the items carrying `identifier`.
+fn lookup_query(identifier: &Identifier) -> Option<String> {
+    let (property, value) = property_value(identifier)?;
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    Some(format!("SELECT ?item WHERE {{ ?item wdt:{} \"{}\" . }} LIMIT 10", property, value))
+}
+
+/// Read the bindings of a SPARQL JSON result as rows of variable → value.
+pub fn read_sparql_results(json: &str) -> Result<Vec<HashMap<String, String>>> {
+    let response: SparqlResponse =
+        serde_json::from_str(json).map_err(|e| Error::Parse(format!("wikidata sparql: {}", e)))?;
+    Ok(response
+        .results
+        .bindings
+        .into_iter()
+        .map(|row| row.into_iter().map(|(k, v)| (k, v.value)).collect())
+        .collect())
+}
+
+// ── Fetch ─────────────────────────────────────────────────────────────────────
+
+fn client() -> Result<reqwest::blocking::Client> {
+    reqwest::blocking::Client::builder()
+        .user_agent(format!(
+            "commonmeta-rs/{} (https://github.com/front-matter/commonmeta-rs; mailto:info@front-matter.de)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| Error::Http(e.to_string()))
+}
+
+fn get(url: &str) -> Result<String> {
+    tracing::debug!("GET {}", url);
+    client()?
+        .get(url)
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .error_for_status()
+        .map_err(|e| Error::Http(e.to_string()))?
+        .text()
+        .map_err(|e| Error::Http(e.to_string()))
+}
+
+/// Fetch the Wikidata item `id` (a QID or Wikidata URL).
+pub fn fetch_item(id: &str) -> Result<WikidataItem> {
+    let qid = validate_wikidata(id).ok_or_else(|| Error::InvalidId(id.to_string()))?;
+    let url = format!("https://www.wikidata.org/wiki/Special:EntityData/{}.json", qid);
+    read_entity(&get(&url)?)
+}
+
+/// Run a SPARQL query against the Wikidata Query Service and return the
+/// rows of the result.
+pub fn query(sparql: &str) -> Result<Vec<HashMap<String, String>>> {
+    let url = url::Url::parse_with_params(
+        "https://query.wikidata.org/sparql",
+        &[("query", sparql), ("format", "json")],
+    )
+    .map_err(|e| Error::Parse(e.to_string()))?;
+    read_sparql_results(&get(url.as_str())?)
+}
+
+/// The QIDs of the Wikidata items carrying `id`, e.g. an ORCID or ROR ID
+/// (any spelling [`Identifier::parse`] accepts). Fails for identifier types
+/// Wikidata has no property for.
+pub fn find_items(id: &str) -> Result<Vec<String>> {
+    let identifier = Identifier::parse(id).ok_or_else(|| Error::InvalidId(id.to_string()))?;
+    let sparql = lookup_query(&identifier).ok_or_else(|| {
+        Error::InvalidId(format!("{}: no Wikidata property for {}", id, identifier.type_name()))
+    })?;
+    Ok(query(&sparql)?
+        .into_iter()
+        .filter_map(|row| {
+            // Items come back as entity URIs, http://www.wikidata.org/entity/Q42.
+            let uri = row.get("item")?;
+            validate_wikidata(uri.rsplit('/').next()?)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTITY: &str = r#"{"entities": {"Q42": {
+        "id": "Q42",
+        "labels": {"de": {"language": "de", "value": "Douglas Adams"},
+                   "en": {"language": "en", "value": "Douglas Adams"}},
+        "claims": {
+            "P213": [{"mainsnak": {"snaktype": "value", "property": "P213",
+                "datavalue": {"value": "0000 0001 2103 2683", "type": "string"}},
+                "rank": "normal"}],
+            "P214": [{"mainsnak": {"snaktype": "value", "property": "P214",
+                "datavalue": {"value": "113230702", "type": "string"}},
+                "rank": "normal"},
+                {"mainsnak": {"snaktype": "value", "property": "P214",
+                "datavalue": {"value": "999999", "type": "string"}},
+                "rank": "deprecated"}],
+            "P496": [{"mainsnak": {"snaktype": "somevalue", "property": "P496"},
+                "rank": "normal"}],
+            "P31": [{"mainsnak": {"snaktype": "value", "property": "P31",
+                "datavalue": {"value": {"id": "Q5"}, "type": "wikibase-entityid"}},
+                "rank": "normal"}]
+        }
+    }}}"#;
+
+    #[test]
+    fn test_read_entity() {
+        let item = read_entity(ENTITY).unwrap();
+        assert_eq!(item.id, "Q42");
+        assert_eq!(item.label, "Douglas Adams");
+        assert_eq!(
+            item.identifiers,
+            vec![
+                Identifier::Isni("0000000121032683".to_string()),
+                Identifier::Viaf("113230702".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lookup_query() {
+        let doi = Identifier::parse("https://doi.org/10.5555/abc").unwrap();
+        assert_eq!(
+            lookup_query(&doi).as_deref(),
+            Some(r#"SELECT ?item WHERE { ?item wdt:P356 "10.5555/ABC" . } LIMIT 10"#)
+        );
+        let isni = Identifier::Isni("0000000121032683".to_string());
+        assert_eq!(
+            property_value(&isni),
+            Some(("P213", "0000 0001 2103 2683".to_string()))
+        );
+        let ror = Identifier::parse("https://ror.org/02nr0ka47").unwrap();
+        assert_eq!(property_value(&ror), Some(("P6782", "02nr0ka47".to_string())));
+        assert_eq!(lookup_query(&Identifier::Uuid("x".to_string())), None);
+    }
+
+    #[test]
+    fn test_read_sparql_results() {
+        let json = r#"{"head": {"vars": ["item"]}, "results": {"bindings": [
+            {"item": {"type": "uri", "value": "http://www.wikidata.org/entity/Q42"}}
+        ]}}"#;
+        let rows = read_sparql_results(json).unwrap();
+        assert_eq!(rows[0]["item"], "http://www.wikidata.org/entity/Q42");
+    }
+}
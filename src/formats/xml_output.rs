@@ -0,0 +1,125 @@
+//! Layout options shared by the XML writers (`crossref_xml`, `datacite_xml`,
+//! `dublincore`, `openaire`).
+
+use quick_xml::se::Serializer;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+/// How an XML document is laid out. The default matches what the writers
+/// have always produced: two-space indentation and a full
+/// `<?xml version="1.0" encoding="UTF-8"?>` declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlOptions {
+    /// Put every element on its own, indented line; `false` writes the
+    /// document without any whitespace between elements.
+    pub pretty: bool,
+    /// Character used for indentation when `pretty` is set.
+    pub indent_char: char,
+    /// Number of `indent_char`s per nesting level.
+    pub indent_size: usize,
+    /// Start the document with an XML declaration.
+    pub declaration: bool,
+    /// `encoding` attribute of the declaration, or `None` to leave it out.
+    /// This is only a label: output is always UTF-8.
+    pub encoding: Option<String>,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        XmlOptions {
+            pretty: true,
+            indent_char: ' ',
+            indent_size: 2,
+            declaration: true,
+            encoding: Some("UTF-8".to_string()),
+        }
+    }
+}
+
+impl XmlOptions {
+    /// Single-line output with the default declaration.
+    pub fn compact() -> Self {
+        XmlOptions {
+            pretty: false,
+            ..Default::default()
+        }
+    }
+}
+
+/// Serialize `value` as an XML document laid out according to `options`.
+pub(crate) fn serialize_xml<T: Serialize>(value: T, options: &XmlOptions) -> Result<Vec<u8>> {
+    let mut buf = String::new();
+    let mut ser = Serializer::new(&mut buf);
+    if options.pretty {
+        ser.indent(options.indent_char, options.indent_size);
+    }
+    value
+        .serialize(ser)
+        .map_err(|e| Error::Serialize(e.to_string()))?;
+
+    let mut out = String::with_capacity(buf.len() + 40);
+    if options.declaration {
+        out.push_str("<?xml version=\"1.0\"");
+        if let Some(encoding) = &options.encoding {
+            out.push_str(&format!(" encoding=\"{encoding}\""));
+        }
+        out.push_str("?>");
+        if options.pretty {
+            out.push('\n');
+        }
+    }
+    out.push_str(&buf);
+    Ok(out.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename = "root")]
+    struct Root {
+        child: Vec<String>,
+    }
+
+    fn root() -> Root {
+        Root {
+            child: vec!["a".to_string(), "b".to_string()],
+        }
+    }
+
+    #[test]
+    fn default_is_indented_with_declaration() {
+        let xml = String::from_utf8(serialize_xml(root(), &XmlOptions::default()).unwrap()).unwrap();
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<root>\n  <child>a</child>\n  <child>b</child>\n</root>"
+        );
+    }
+
+    #[test]
+    fn compact_and_custom_layouts() {
+        let xml = String::from_utf8(serialize_xml(root(), &XmlOptions::compact()).unwrap()).unwrap();
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><root><child>a</child><child>b</child></root>"
+        );
+
+        let options = XmlOptions {
+            indent_char: '\t',
+            indent_size: 1,
+            declaration: false,
+            ..Default::default()
+        };
+        let xml = String::from_utf8(serialize_xml(root(), &options).unwrap()).unwrap();
+        assert_eq!(xml, "<root>\n\t<child>a</child>\n\t<child>b</child>\n</root>");
+
+        let options = XmlOptions {
+            encoding: None,
+            ..XmlOptions::compact()
+        };
+        let xml = String::from_utf8(serialize_xml(root(), &options).unwrap()).unwrap();
+        assert!(xml.starts_with("<?xml version=\"1.0\"?><root>"));
+    }
+}
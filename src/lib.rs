@@ -4,6 +4,7 @@
 //! format modules read into it and write out of it.
 
 pub mod author_utils;
+pub mod checksums;
 pub mod constants;
 pub mod crockford;
 pub mod data;
@@ -11,6 +12,7 @@ pub mod doi_utils;
 pub mod error;
 pub mod file_utils;
 mod formats;
+pub mod merge;
 pub mod progress;
 pub mod schema_utils;
 pub mod spdx;
@@ -18,11 +20,20 @@ pub mod utils;
 pub mod vocabularies;
 
 pub use data::Data;
+pub use doi_utils::PrefixDoi;
 pub use error::{Error, Result};
 pub use formats::crossref;
+pub use formats::commonmeta::JsonLinesWriter;
+pub use formats::crossref_xml::{DepositHead, DepositLogin, DepositRecord, DepositResult};
+pub use formats::datacite::{DataciteLogin, DataciteResult};
+pub use formats::fundref::Funder;
 pub use formats::inveniordm::PushResult;
 pub use formats::ror::AffiliationMatch;
+pub use formats::ror::GridCrosswalk;
+pub use formats::ror::OrganizationSummary;
 pub use formats::ror::RorRelease;
+pub use formats::wikidata::WikidataItem;
+pub use formats::xml_output::XmlOptions;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -31,6 +42,28 @@ pub fn read(from: &str, input: &str) -> Result<Data> {
     formats::read(from, input)
 }
 
+/// Read every record from `from` format. Multi-record inputs such as a
+/// `.bib` file or a Crossref deposit yield one [`Data`] per entry.
+pub fn read_list(from: &str, input: &str) -> Result<Vec<Data>> {
+    formats::read_list(from, input)
+}
+
+/// Read every row of a CSV or TSV spreadsheet. `map` is a column mapping
+/// such as `title=Title,doi=DOI`; columns it doesn't mention are matched to
+/// commonmeta fields by header name.
+pub fn read_csv(input: &str, map: &str) -> Result<Vec<Data>> {
+    let map = formats::csv::ColumnMap::parse(map)?;
+    formats::csv::read_all(input, &map)
+}
+
+/// Write `list` as CSV, one work per row. `map` uses the same
+/// `field=Column` syntax as [`read_csv`] and selects the columns, their
+/// order and headings; when empty, a default set of columns is written.
+pub fn write_csv(list: &[Data], map: &str) -> Result<Vec<u8>> {
+    let map = formats::csv::ColumnMap::parse(map)?;
+    formats::csv::write_all(list, &map)
+}
+
 /// Read from one format and write to another in a single call.
 pub fn convert(from: &str, to: &str, input: &str) -> Result<Vec<u8>> {
     let data = formats::read(from, input)?;
@@ -42,6 +75,60 @@ pub fn write(to: &str, data: &Data) -> Result<Vec<u8>> {
     formats::write(to, data)
 }
 
+/// Write `list` as one Crossref deposit, with `head` supplying the
+/// depositor name, email address and registrant of the `doi_batch`.
+pub fn write_crossref_xml(
+    list: &[Data],
+    head: &DepositHead,
+    options: &XmlOptions,
+) -> Result<Vec<u8>> {
+    formats::crossref_xml::write_deposit(list, head, options)
+}
+
+/// Write `list` as one Crossref deposit and submit it with `login`, then
+/// wait up to `timeout` for the submission log to report which DOIs were
+/// registered.
+pub fn register_crossref(
+    list: &[Data],
+    head: &DepositHead,
+    login: &DepositLogin,
+    timeout: std::time::Duration,
+) -> Result<DepositResult> {
+    let xml = formats::crossref_xml::write_deposit(list, head, &XmlOptions::default())?;
+    let poll_interval = std::time::Duration::from_secs(5);
+    formats::crossref_xml::deposit(&xml, login, poll_interval, timeout)
+}
+
+/// Create or update the DOI of `data` with the DataCite REST API and
+/// trigger `event` ("draft", "register" or "publish").
+pub fn register_datacite(
+    data: &Data,
+    login: &DataciteLogin,
+    event: &str,
+) -> Result<DataciteResult> {
+    formats::datacite::register(data, login, event)
+}
+
+/// Write `list` as DataCite XML against Metadata Schema `version`
+/// (`"4.5"`, `"4.6"` or `"4.7"`), for repositories that only accept an
+/// older kernel.
+pub fn write_datacite_xml(list: &[Data], version: &str, options: &XmlOptions) -> Result<Vec<u8>> {
+    formats::datacite_xml::write_all_version(list, version, options)
+}
+
+/// Write `list` to `to` (`crossref_xml`, `datacite_xml`, `dublincore` or
+/// `openaire`) with compact or custom-indented output, or without the XML
+/// declaration, for whitespace-sensitive consumers.
+pub fn write_xml(to: &str, list: &[Data], options: &XmlOptions) -> Result<Vec<u8>> {
+    formats::write_xml(to, list, options)
+}
+
+/// Render `data` as a JATS `<mixed-citation>`; `write("jats", ..)` gives the
+/// `<element-citation>` form.
+pub fn write_jats_mixed_citation(data: &Data) -> Result<Vec<u8>> {
+    formats::jats::write_mixed(data)
+}
+
 /// Like [`write`], but forwards `style` and `locale` to the citation writer.
 /// For non-`"citation"` formats both parameters are ignored.
 pub fn write_with_style(
@@ -53,6 +140,12 @@ pub fn write_with_style(
     formats::write_citation(to, data, style, locale)
 }
 
+/// Write `data` as commonmeta YAML, with the keys in the same canonical
+/// order as the JSON output.
+pub fn write_yaml(data: &Data) -> Result<Vec<u8>> {
+    formats::commonmeta::write_yaml(data)
+}
+
 /// Write a ROR-derived record as raw ROR-shaped JSON (as opposed to
 /// `write("ror", data)`, which produces InvenioRDM vocabulary YAML).
 pub fn write_ror_json(data: &Data) -> Result<Vec<u8>> {
@@ -95,6 +188,18 @@ pub fn fetch_ror_sqlite(
     formats::ror::fetch_sqlite(id, db_path)
 }
 
+/// Replace legacy GRID IDs on affiliations, organization contributors and
+/// funders with ROR URLs, using the `grid` external_ids in the local ROR
+/// database at `db_path`. The crosswalk is only loaded when some record
+/// actually carries a GRID ID. Returns the number of IDs upgraded.
+pub fn upgrade_grid_ids(list: &mut [Data], db_path: &std::path::Path) -> Result<usize> {
+    if !list.iter().any(formats::ror::has_grid_ids) {
+        return Ok(0);
+    }
+    let crosswalk = GridCrosswalk::read_sqlite(db_path)?;
+    Ok(list.iter_mut().map(|data| crosswalk.upgrade(data)).sum())
+}
+
 /// Write a list of ROR records to a SQLite3 database at `path` with an
 /// `organizations` table. Existing file is deleted first. JSON array columns
 /// (`types`, `locations`, `names`, `external_ids`) are queryable via SQLite's
@@ -127,12 +232,69 @@ pub fn fetch_installed_vraix_date(db_path: &std::path::Path) -> Result<Option<St
     formats::vraix::fetch_installed_vraix_date(db_path)
 }
 
+/// Look up the organization with ROR ID `id` with the ROR API: its names,
+/// country and relationships.
+pub fn fetch_ror_organization(id: &str) -> Result<OrganizationSummary> {
+    formats::ror::fetch_organization(id).map(|ror| formats::ror::summarize(&ror))
+}
+
+/// Search ROR organizations by name, best match first, with the score the
+/// ROR affiliation API gave each match.
+pub fn search_ror_organizations(name: &str) -> Result<Vec<OrganizationSummary>> {
+    formats::ror::search_organizations(name)
+}
+
+/// Fetch a funder from the Crossref Funder Registry by Crossref Funder ID,
+/// with its name, country and place in the funder hierarchy.
+pub fn fetch_funder(id: &str) -> Result<Funder> {
+    formats::fundref::fetch_funder(id)
+}
+
+/// Search the Crossref Funder Registry by funder name, best match first.
+pub fn search_funders(name: &str) -> Result<Vec<Funder>> {
+    formats::fundref::search_funders(name)
+}
+
+/// The funder in the Crossref Funder Registry with exactly this name or
+/// alternative name (ignoring case), if any.
+pub fn match_funder(name: &str) -> Result<Option<Funder>> {
+    formats::fundref::match_funder(name)
+}
+
+/// Fetch a Wikidata item (QID or URL) with its external identifiers (ORCID,
+/// ROR, ISNI, DOI, VIAF, ...).
+pub fn fetch_wikidata_item(id: &str) -> Result<WikidataItem> {
+    formats::wikidata::fetch_item(id)
+}
+
+/// Find the QIDs of the Wikidata items carrying an identifier, e.g. an ORCID
+/// or ROR ID.
+pub fn find_wikidata_items(id: &str) -> Result<Vec<String>> {
+    formats::wikidata::find_items(id)
+}
+
+/// Run a SPARQL query against the Wikidata Query Service; each row maps the
+/// query's variables to their values.
+pub fn query_wikidata(sparql: &str) -> Result<Vec<std::collections::HashMap<String, String>>> {
+    formats::wikidata::query(sparql)
+}
+
 /// Match a free-text affiliation string against ROR organizations using the
 /// ROR v2 affiliation endpoint.
 pub fn match_ror_affiliation(affiliation: &str) -> Result<Vec<AffiliationMatch>> {
     formats::ror::match_affiliation(affiliation)
 }
 
+/// Fill in the ROR IDs of the named affiliations without ID in `list`, from
+/// the local ROR database at `db_path` or, when `None`, the ROR affiliation
+/// API. Returns the number of affiliations matched.
+pub fn match_ror_affiliations(
+    list: &mut [Data],
+    db_path: Option<&std::path::Path>,
+) -> Result<usize> {
+    formats::ror::match_affiliations(list, db_path)
+}
+
 /// Match a free-text affiliation string against a local ROR SQLite database
 /// written by [`write_ror_sqlite`]. Uses Turso's Tantivy-backed FTS index for
 /// full-text search across all organization name variants. Returns results in
@@ -241,6 +403,29 @@ pub fn crossref_fetch_page_with_cursor(
     )
 }
 
+/// Fetch one page of the DOIs registered under `prefix` with `agency`
+/// (`crossref` or `datacite`), `rows` at a time, using cursor-based
+/// pagination.
+///
+/// Pass `cursor = None` for the first page and the returned cursor for every
+/// subsequent one. Returns `(dois, next_cursor)`; stop when `next_cursor` is
+/// `None`.
+pub fn fetch_prefix_dois(
+    agency: &str,
+    prefix: &str,
+    cursor: Option<&str>,
+    rows: usize,
+) -> Result<(Vec<PrefixDoi>, Option<String>)> {
+    match agency {
+        "crossref" => formats::crossref::fetch_prefix_page(prefix, cursor, rows),
+        "datacite" => formats::datacite::fetch_prefix_page(prefix, cursor, rows),
+        other => Err(Error::UnsupportedFormat(format!(
+            "listing DOIs registered with {}",
+            other
+        ))),
+    }
+}
+
 /// Stream a VRAIX daily dump at `input_path` directly to a commonmeta SQLite
 /// database at `output_path` in batches of 10 000 rows, converting with
 /// `from`-specific parser and writing each batch in a single transaction.
@@ -295,8 +480,9 @@ pub fn write_list(list: &[Data], to: &str) -> Result<Vec<u8>> {
 }
 
 /// Like `write_list`, but passes CSL `style`/`locale` through to the
-/// citation writer when `to == "citation"` (ignored for every other format,
-/// same as `convert_citation`/`write_citation`).
+/// citation writer when `to` is `"citation"`, `"markdown"` or `"html"`
+/// (ignored for every other format, same as
+/// `convert_citation`/`write_citation`).
 pub fn write_list_citation(
     list: &[Data],
     to: &str,
@@ -314,6 +500,11 @@ pub fn write_list_citation(
             | "schemaorg"
             | "ror"
             | "citation"
+            | "markdown"
+            | "html"
+            | "csv"
+            | "jsonl"
+            | "yaml"
             | "crossref_xml"
             | "datacite_xml"
     ) {
@@ -539,7 +730,7 @@ mod tests {
     fn test_write_list_ror_uses_json_array_batch_writer() {
         let mut a = sample_data("https://ror.org/0342dzm54");
         a.title = "Org A".to_string();
-        let mut b = sample_data("https://ror.org/0521rfr06");
+        let mut b = sample_data("https://ror.org/0521rfr81");
         b.title = "Org B".to_string();
 
         let bytes = write_list(&[a, b], "ror").unwrap();
@@ -0,0 +1,175 @@
+/*
+ * Copyright © 2026 Front Matter <info@front-matter.de>
+ */
+
+//! Diagnostics for the command-line tool. The library reports progress,
+//! HTTP requests, retries and format decisions as `tracing` events; this
+//! module prints them to stderr, filtered by `--verbose` and `--quiet`.
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+use crate::diagnostic::{Stream, Style, paint};
+
+/// Number of warnings logged by commonmeta so far, for `--strict`.
+static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// Install the stderr logger. Progress messages (info) are shown by
+/// default, `-v` adds debug events such as HTTP requests and format
+/// detection, `-vv` adds trace events and debug output from the HTTP
+/// stack. `--quiet` leaves only the results on stdout; warnings are still
+/// counted.
+pub fn init(verbose: u8, quiet: bool) {
+    if quiet {
+        commonmeta::progress::set_hidden(true);
+    }
+    let level = match (quiet, verbose) {
+        (true, _) => Level::WARN,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+    let logger = StderrLogger {
+        level,
+        dependencies: verbose >= 2,
+        quiet,
+        next_span: AtomicU64::new(1),
+    };
+    // Only fails if a logger is already installed, e.g. in tests.
+    tracing::subscriber::set_global_default(logger).ok();
+}
+
+/// Number of warnings logged so far.
+pub fn warnings() -> usize {
+    WARNINGS.load(Ordering::Relaxed)
+}
+
+struct StderrLogger {
+    /// Most verbose level shown for events from commonmeta itself.
+    level: Level,
+    /// Whether debug events from dependencies (reqwest, hyper, ...) are
+    /// shown; otherwise only their warnings and errors are.
+    dependencies: bool,
+    /// Count warnings without printing anything.
+    quiet: bool,
+    next_span: AtomicU64,
+}
+
+impl StderrLogger {
+    fn max_level(&self, target: &str) -> Level {
+        if target.starts_with("commonmeta") {
+            self.level
+        } else if self.dependencies {
+            Level::DEBUG
+        } else {
+            Level::WARN
+        }
+    }
+}
+
+impl Subscriber for StderrLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= self.max_level(metadata.target())
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::from_level(self.level))
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(self.next_span.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let metadata = event.metadata();
+        if *metadata.level() == Level::WARN && metadata.target().starts_with("commonmeta") {
+            WARNINGS.fetch_add(1, Ordering::Relaxed);
+        }
+        if self.quiet {
+            return;
+        }
+        let mut line = LineVisitor::default();
+        event.record(&mut line);
+        eprintln!("{}", format_line(*metadata.level(), metadata.target(), &line.0));
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+/// Collects the message of an event followed by its other fields as
+/// `key=value`.
+#[derive(Default)]
+struct LineVisitor(String);
+
+impl Visit for LineVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.0);
+            write!(self.0, "{:?}{}", value, fields).ok();
+        } else {
+            write!(self.0, " {}={:?}", field.name(), value).ok();
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.record_debug(field, &format_args!("{}", value));
+        } else {
+            write!(self.0, " {}={}", field.name(), value).ok();
+        }
+    }
+}
+
+/// Info events are printed as they are, so progress output looks as it did
+/// before; other levels are labelled, and debug and trace events name the
+/// module they come from.
+fn format_line(level: Level, target: &str, message: &str) -> String {
+    match level {
+        Level::ERROR => format!("{} {}", paint("error:", Style::Error, Stream::Stderr), message),
+        Level::WARN => format!("{} {}", paint("warning:", Style::Warning, Stream::Stderr), message),
+        Level::INFO => message.to_string(),
+        Level::DEBUG => format!("debug [{}]: {}", target, message),
+        Level::TRACE => format!("trace [{}]: {}", target, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_line() {
+        assert_eq!(format_line(Level::INFO, "commonmeta", "list: done"), "list: done");
+        assert_eq!(
+            format_line(Level::WARN, "commonmeta::file_utils", "download: retry 1/5"),
+            "warning: download: retry 1/5"
+        );
+        assert_eq!(
+            format_line(Level::DEBUG, "commonmeta::formats", "GET https://api.crossref.org"),
+            "debug [commonmeta::formats]: GET https://api.crossref.org"
+        );
+    }
+
+    #[test]
+    fn test_dependency_events_need_vv() {
+        let logger = StderrLogger {
+            level: Level::DEBUG,
+            dependencies: false,
+            quiet: false,
+            next_span: AtomicU64::new(1),
+        };
+        assert_eq!(logger.max_level("commonmeta::doi_utils"), Level::DEBUG);
+        assert_eq!(logger.max_level("hyper_util::client"), Level::WARN);
+    }
+}
@@ -1,38 +1,176 @@
-use clap::Command;
+use std::process::ExitCode;
 
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+pub mod checksums;
 mod cmd;
 pub mod crockford;
+mod diagnostic;
 pub mod doi_utils;
+mod logging;
 pub mod utils;
 
-fn main() -> Result<(), String> {
-    let matches = Command::new("commonmeta")
+/// The full command-line interface, also walked by `completions` and `man`.
+fn cli() -> Command {
+    Command::new("commonmeta")
         .version(env!("CARGO_PKG_VERSION"))
         .author("Front Matter <info@front-matter.de>")
         .about("Commonmeta")
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .short('v')
+                .help("Log HTTP requests and format decisions to stderr (-vv for more)")
+                .action(ArgAction::Count)
+                .global(true),
+        )
+        .arg(
+            Arg::new("quiet")
+                .long("quiet")
+                .short('q')
+                .help("Print nothing but the results")
+                .action(ArgAction::SetTrue)
+                .global(true)
+                .conflicts_with("verbose"),
+        )
+        .arg(
+            Arg::new("no-color")
+                .long("no-color")
+                .help("Print diagnostics without colors (also set by NO_COLOR)")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("strict")
+                .long("strict")
+                .help("Fail with exit code 1 if any warnings were logged")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .subcommand(cmd::check::command())
+        .subcommand(cmd::completions::command())
         .subcommand(cmd::convert::command())
         .subcommand(cmd::decode::command())
+        .subcommand(cmd::diff::command())
         .subcommand(cmd::dump::command())
         .subcommand(cmd::encode::command())
+        .subcommand(cmd::enrich::command())
+        .subcommand(cmd::extract::command())
+        .subcommand(cmd::fetch::command())
         .subcommand(cmd::import::command())
         .subcommand(cmd::install::command())
         .subcommand(cmd::list::command())
+        .subcommand(cmd::man::command())
         .subcommand(cmd::r#match::command())
+        .subcommand(cmd::orcid::command())
+        .subcommand(cmd::prefix::command())
         .subcommand(cmd::push::command())
         .subcommand(cmd::put::command())
-        .get_matches();
+        .subcommand(cmd::ra::command())
+        .subcommand(cmd::register::command())
+        .subcommand(cmd::ror::command())
+        .subcommand(cmd::sample::command())
+        .subcommand(cmd::serve::command())
+        .subcommand(cmd::validate::command())
+        .subcommand(cmd::watch::command())
+}
 
-    match matches.subcommand() {
-        Some(("convert", sub_matches)) => cmd::convert::execute(sub_matches),
-        Some(("decode", sub_matches)) => cmd::decode::execute(sub_matches),
-        Some(("package", sub_matches)) => cmd::dump::execute(sub_matches),
-        Some(("encode", sub_matches)) => cmd::encode::execute(sub_matches),
-        Some(("import", sub_matches)) => cmd::import::execute(sub_matches),
-        Some(("install", sub_matches)) => cmd::install::execute(sub_matches),
-        Some(("list", sub_matches)) => cmd::list::execute(sub_matches),
-        Some(("match", sub_matches)) => cmd::r#match::execute(sub_matches),
-        Some(("push", sub_matches)) => cmd::push::execute(sub_matches),
-        Some(("put", sub_matches)) => cmd::put::execute(sub_matches),
+/// Exit codes: 0 when everything succeeded, 1 when some items of a batch
+/// failed (or, with `--strict`, anything logged a warning), 2 for invalid
+/// input and 3 for network errors.
+fn main() -> ExitCode {
+    let matches = cli().get_matches();
+    if matches.get_flag("no-color") {
+        diagnostic::disable_color();
+    }
+    logging::init(matches.get_count("verbose"), matches.get_flag("quiet"));
+
+    let result = strict(run(&matches), logging::warnings(), matches.get_flag("strict"));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            eprintln!(
+                "{} {}",
+                diagnostic::paint("error:", diagnostic::Style::Error, diagnostic::Stream::Stderr),
+                failure
+            );
+            ExitCode::from(failure.exit_code())
+        }
+    }
+}
+
+/// The outcome of a command that succeeded but logged `warnings`: with
+/// `--strict` any warning makes it a partial failure.
+fn strict(
+    result: Result<(), cmd::Failure>,
+    warnings: usize,
+    strict: bool,
+) -> Result<(), cmd::Failure> {
+    result.and_then(|()| match warnings {
+        n if n > 0 && strict => Err(cmd::Failure::Partial(format!(
+            "{} warning(s) logged with --strict",
+            n
+        ))),
         _ => Ok(()),
+    })
+}
+
+fn run(matches: &ArgMatches) -> Result<(), cmd::Failure> {
+    match matches.subcommand() {
+        Some(("check", sub_matches)) => cmd::check::execute(sub_matches)?,
+        Some(("completions", sub_matches)) => cmd::completions::execute(sub_matches, cli())?,
+        Some(("convert", sub_matches)) => cmd::convert::execute(sub_matches)?,
+        Some(("decode", sub_matches)) => cmd::decode::execute(sub_matches)?,
+        Some(("diff", sub_matches)) => cmd::diff::execute(sub_matches)?,
+        Some(("package", sub_matches)) => cmd::dump::execute(sub_matches)?,
+        Some(("encode", sub_matches)) => cmd::encode::execute(sub_matches)?,
+        Some(("enrich", sub_matches)) => cmd::enrich::execute(sub_matches)?,
+        Some(("extract", sub_matches)) => cmd::extract::execute(sub_matches)?,
+        Some(("fetch", sub_matches)) => cmd::fetch::execute(sub_matches)?,
+        Some(("import", sub_matches)) => cmd::import::execute(sub_matches)?,
+        Some(("install", sub_matches)) => cmd::install::execute(sub_matches)?,
+        Some(("list", sub_matches)) => cmd::list::execute(sub_matches)?,
+        Some(("man", sub_matches)) => cmd::man::execute(sub_matches, cli())?,
+        Some(("match", sub_matches)) => cmd::r#match::execute(sub_matches)?,
+        Some(("orcid", sub_matches)) => cmd::orcid::execute(sub_matches)?,
+        Some(("prefix", sub_matches)) => cmd::prefix::execute(sub_matches)?,
+        Some(("push", sub_matches)) => cmd::push::execute(sub_matches)?,
+        Some(("put", sub_matches)) => cmd::put::execute(sub_matches)?,
+        Some(("ra", sub_matches)) => cmd::ra::execute(sub_matches)?,
+        Some(("register", sub_matches)) => cmd::register::execute(sub_matches)?,
+        Some(("ror", sub_matches)) => cmd::ror::execute(sub_matches)?,
+        Some(("sample", sub_matches)) => cmd::sample::execute(sub_matches)?,
+        Some(("serve", sub_matches)) => cmd::serve::execute(sub_matches)?,
+        Some(("validate", sub_matches)) => cmd::validate::execute(sub_matches)?,
+        Some(("watch", sub_matches)) => cmd::watch::execute(sub_matches)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_turns_warnings_into_failure() {
+        let failure = strict(Ok(()), 2, true).unwrap_err();
+        assert_eq!(failure.exit_code(), 1);
+        assert_eq!(failure.to_string(), "2 warning(s) logged with --strict");
+
+        assert!(strict(Ok(()), 2, false).is_ok());
+        assert!(strict(Ok(()), 0, true).is_ok());
+    }
+
+    #[test]
+    fn test_strict_keeps_the_original_failure() {
+        let failure = strict(Err(cmd::Failure::Network("timeout".to_string())), 1, true);
+        assert_eq!(failure.unwrap_err().exit_code(), 3);
+    }
+
+    #[test]
+    fn test_strict_flag_is_global() {
+        let matches = cli().get_matches_from(["commonmeta", "decode", "--strict", "abc"]);
+        assert!(matches.get_flag("strict"));
     }
 }
@@ -0,0 +1,255 @@
+//! Merging records of the same work from several sources.
+//!
+//! Registration agencies, aggregators and landing pages each know part of
+//! a record: a Crossref deposit for a blog post may lack the abstract and
+//! ORCID IDs that OpenAlex or the post's own schema.org markup carry.
+//! [`merge`] combines such records in order of precedence — a field set by
+//! an earlier record is kept, an empty one is filled from the first later
+//! record that has it.
+
+use crate::data::{Container, Contributor, Data};
+
+/// Merge `records`, given from highest to lowest precedence, into one.
+///
+/// Scalar fields and whole lists are taken from the first record that has
+/// them. Two lists are combined instead: identifiers and relations are the
+/// union of all records, and contributors of the leading record are
+/// completed with the IDs and affiliations of the matching contributor
+/// (same family name and given-name initial, or same organization name)
+/// from later records.
+pub fn merge(records: &[Data]) -> Data {
+    let mut records = records.iter();
+    let Some(first) = records.next() else {
+        return Data::default();
+    };
+    let mut merged = first.clone();
+    for other in records {
+        merge_into(&mut merged, other);
+    }
+    merged
+}
+
+fn merge_into(base: &mut Data, other: &Data) {
+    fill(&mut base.id, &other.id);
+    fill(&mut base.type_, &other.type_);
+    fill(&mut base.additional_type, &other.additional_type);
+    fill(&mut base.content, &other.content);
+    fill(&mut base.date_published, &other.date_published);
+    fill(&mut base.date_updated, &other.date_updated);
+    fill(&mut base.description, &other.description);
+    fill(&mut base.image, &other.image);
+    fill(&mut base.language, &other.language);
+    fill(&mut base.title, &other.title);
+    fill(&mut base.url, &other.url);
+    fill(&mut base.version, &other.version);
+
+    merge_container(&mut base.container, &other.container);
+    if base.dates.is_empty() {
+        base.dates = other.dates.clone();
+    }
+    if base.license.is_empty() {
+        base.license = other.license.clone();
+    }
+    if base.publisher.is_empty() {
+        base.publisher = other.publisher.clone();
+    }
+
+    fill_list(&mut base.additional_descriptions, &other.additional_descriptions);
+    fill_list(&mut base.additional_titles, &other.additional_titles);
+    fill_list(&mut base.archive_locations, &other.archive_locations);
+    fill_list(&mut base.citations, &other.citations);
+    fill_list(&mut base.files, &other.files);
+    fill_list(&mut base.funding_references, &other.funding_references);
+    fill_list(&mut base.geo_locations, &other.geo_locations);
+    fill_list(&mut base.references, &other.references);
+    fill_list(&mut base.subjects, &other.subjects);
+
+    for identifier in &other.identifiers {
+        if !base.identifiers.iter().any(|i| {
+            i.identifier.eq_ignore_ascii_case(&identifier.identifier)
+                && i.identifier_type == identifier.identifier_type
+        }) {
+            base.identifiers.push(identifier.clone());
+        }
+    }
+    for relation in &other.relations {
+        if !base
+            .relations
+            .iter()
+            .any(|r| r.id.eq_ignore_ascii_case(&relation.id) && r.type_ == relation.type_)
+        {
+            base.relations.push(relation.clone());
+        }
+    }
+
+    merge_contributors(&mut base.contributors, &other.contributors);
+}
+
+fn fill(base: &mut String, other: &str) {
+    if base.is_empty() && !other.is_empty() {
+        *base = other.to_string();
+    }
+}
+
+fn fill_list<T: Clone>(base: &mut Vec<T>, other: &[T]) {
+    if base.is_empty() {
+        *base = other.to_vec();
+    }
+}
+
+fn merge_container(base: &mut Container, other: &Container) {
+    if base.is_empty() {
+        *base = other.clone();
+        return;
+    }
+    fill(&mut base.identifier, &other.identifier);
+    fill(&mut base.identifier_type, &other.identifier_type);
+    fill(&mut base.type_, &other.type_);
+    fill(&mut base.title, &other.title);
+    fill(&mut base.description, &other.description);
+    fill(&mut base.language, &other.language);
+    fill(&mut base.platform, &other.platform);
+    fill(&mut base.image, &other.image);
+    fill(&mut base.first_page, &other.first_page);
+    fill(&mut base.last_page, &other.last_page);
+    fill(&mut base.volume, &other.volume);
+    fill(&mut base.issue, &other.issue);
+    if base.license.is_none() {
+        base.license = other.license.clone();
+    }
+}
+
+fn merge_contributors(base: &mut Vec<Contributor>, other: &[Contributor]) {
+    if base.is_empty() {
+        *base = other.to_vec();
+        return;
+    }
+    for contributor in base.iter_mut() {
+        let Some(matching) = other.iter().find(|o| same_contributor(contributor, o)) else {
+            continue;
+        };
+        if let (Some(person), Some(other)) = (&mut contributor.person, &matching.person) {
+            fill(&mut person.id, &other.id);
+            fill(&mut person.given_name, &other.given_name);
+            fill_list(&mut person.affiliations, &other.affiliations);
+        }
+        if let (Some(organization), Some(other)) =
+            (&mut contributor.organization, &matching.organization)
+        {
+            fill(&mut organization.id, &other.id);
+        }
+        fill_list(&mut contributor.roles, &matching.roles);
+    }
+}
+
+/// Whether `a` and `b` are the same person or organization: the same ID
+/// if both have one, otherwise the same family name and (if both are
+/// known) given-name initial, or the same organization name.
+fn same_contributor(a: &Contributor, b: &Contributor) -> bool {
+    if !a.id().is_empty() && !b.id().is_empty() {
+        return a.id().eq_ignore_ascii_case(b.id());
+    }
+    match (&a.person, &b.person, &a.organization, &b.organization) {
+        (Some(a), Some(b), _, _) => {
+            if a.family_name.is_empty() || !a.family_name.eq_ignore_ascii_case(&b.family_name) {
+                return false;
+            }
+            let initial = |name: &str| name.chars().next().map(|c| c.to_lowercase().to_string());
+            match (initial(&a.given_name), initial(&b.given_name)) {
+                (Some(a), Some(b)) => a == b,
+                _ => true,
+            }
+        }
+        (_, _, Some(a), Some(b)) => !a.name.is_empty() && a.name.eq_ignore_ascii_case(&b.name),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Affiliation, Identifier, Person};
+
+    fn person(id: &str, given_name: &str, family_name: &str) -> Contributor {
+        Contributor::person(
+            Person {
+                id: id.to_string(),
+                given_name: given_name.to_string(),
+                family_name: family_name.to_string(),
+                ..Default::default()
+            },
+            vec!["Author".to_string()],
+        )
+    }
+
+    #[test]
+    fn test_merge_fills_empty_fields_in_order() {
+        let crossref = Data {
+            id: "https://doi.org/10.5555/1".to_string(),
+            type_: "BlogPost".to_string(),
+            title: "From Crossref".to_string(),
+            contributors: vec![person("", "Jane", "Doe"), person("", "Max", "Mustermann")],
+            ..Default::default()
+        };
+        let openalex = Data {
+            id: "https://doi.org/10.5555/1".to_string(),
+            type_: "Article".to_string(),
+            title: "From OpenAlex".to_string(),
+            description: "The abstract.".to_string(),
+            contributors: vec![person("https://orcid.org/0000-0002-1825-0097", "J.", "doe")],
+            ..Default::default()
+        };
+        let mut landing_page = Data {
+            description: "The summary.".to_string(),
+            language: "en".to_string(),
+            contributors: vec![person("", "Max", "Mustermann")],
+            ..Default::default()
+        };
+        landing_page.contributors[0].person.as_mut().unwrap().affiliations = vec![Affiliation {
+            name: "Example University".to_string(),
+            ..Default::default()
+        }];
+
+        let merged = merge(&[crossref, openalex, landing_page]);
+        assert_eq!(merged.type_, "BlogPost");
+        assert_eq!(merged.title, "From Crossref");
+        assert_eq!(merged.description, "The abstract.");
+        assert_eq!(merged.language, "en");
+        assert_eq!(merged.contributors.len(), 2);
+        assert_eq!(merged.contributors[0].id(), "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(merged.contributors[0].given_name(), "Jane");
+        assert_eq!(merged.contributors[1].affiliations()[0].name, "Example University");
+    }
+
+    #[test]
+    fn test_merge_unions_identifiers() {
+        let identifier = |id: &str, type_: &str| Identifier {
+            identifier: id.to_string(),
+            identifier_type: type_.to_string(),
+            ..Default::default()
+        };
+        let a = Data {
+            identifiers: vec![identifier("https://doi.org/10.5555/1", "DOI")],
+            ..Default::default()
+        };
+        let b = Data {
+            identifiers: vec![
+                identifier("https://doi.org/10.5555/1", "DOI"),
+                identifier("https://openalex.org/W1", "OpenAlex"),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(merge(&[a, b]).identifiers.len(), 2);
+        assert_eq!(merge(&[]), Data::default());
+    }
+
+    #[test]
+    fn test_same_contributor() {
+        assert!(same_contributor(&person("", "Jane", "Doe"), &person("", "", "DOE")));
+        assert!(!same_contributor(&person("", "Jane", "Doe"), &person("", "Max", "Doe")));
+        assert!(!same_contributor(
+            &person("https://orcid.org/1", "Jane", "Doe"),
+            &person("https://orcid.org/2", "Jane", "Doe")
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use indicatif::{ProgressBar, ProgressStyle};
 
@@ -6,12 +7,23 @@ const COUNT_TEMPLATE: &str = "{prefix} {bar:40.cyan/blue} {pos}/{len} ({eta})";
 const BYTES_TEMPLATE: &str =
     "{prefix} {bar:40.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})";
 
+static HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// Hide every progress bar created from now on, e.g. for a `--quiet` run.
+pub fn set_hidden(hidden: bool) {
+    HIDDEN.store(hidden, Ordering::Relaxed);
+}
+
+fn hidden() -> bool {
+    HIDDEN.load(Ordering::Relaxed) || !std::io::stderr().is_terminal()
+}
+
 /// A progress bar over a known number of items (e.g. records to convert or
 /// render). Renders to stderr, and is a no-op when stderr isn't a terminal
 /// (redirected to a file, piped, CI, etc.) so non-interactive output stays
-/// clean.
+/// clean, or after [`set_hidden`].
 pub fn count_bar(prefix: &str, total: u64) -> ProgressBar {
-    if !std::io::stderr().is_terminal() {
+    if hidden() {
         return ProgressBar::hidden();
     }
     let bar = ProgressBar::new(total);
@@ -27,7 +39,7 @@ pub fn count_bar(prefix: &str, total: u64) -> ProgressBar {
 /// A progress bar over a known number of bytes (e.g. a file download).
 /// Same terminal-detection behavior as [`count_bar`].
 pub fn bytes_bar(prefix: &str, total_bytes: u64) -> ProgressBar {
-    if !std::io::stderr().is_terminal() {
+    if hidden() {
         return ProgressBar::hidden();
     }
     let bar = ProgressBar::new(total_bytes);
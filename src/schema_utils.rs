@@ -7,6 +7,7 @@ use std::sync::{Arc, OnceLock};
 use fastxml::schema::fetcher::{FetchResult, FileFetcher, SchemaFetcher};
 use fastxml::schema::{Schema, Validator};
 use fastxml::schema::fetcher::error::FetchError;
+use jsonschema::{ValidationError, error::ValidationErrorKind};
 use serde_json::Value;
 
 use crate::error::{Error, Result};
@@ -30,10 +31,38 @@ pub fn known_schemata() -> &'static [&'static str] {
     SCHEMATA
 }
 
+/// A single JSON Schema violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON pointer to the offending value, empty for the document root.
+    pub pointer: String,
+    pub message: String,
+}
+
 /// Validate a JSON document against one of the bundled schema names.
 ///
 /// If `schema` is `None`, the default `DEFAULT_SCHEMA` (`commonmeta`) is used.
 pub fn json_schema_errors(document: &[u8], schema: Option<&str>) -> Result<()> {
+    let violations = json_schema_violations(document, schema)?;
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::Parse(format!(
+        "json schema validation failed ({} errors): {}",
+        violations.len(),
+        violations
+            .iter()
+            .map(|v| v.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    )))
+}
+
+/// Like [`json_schema_errors`], but return every violation with the JSON
+/// pointer of the value it concerns. An empty list means the document is
+/// valid; errors are reserved for unknown schemas and unparseable input.
+pub fn json_schema_violations(document: &[u8], schema: Option<&str>) -> Result<Vec<SchemaViolation>> {
     let schema_name = schema.unwrap_or(DEFAULT_SCHEMA);
     let Some(schema_file) = schema_file_name(schema_name) else {
         return Err(Error::UnsupportedFormat(format!(
@@ -53,23 +82,42 @@ pub fn json_schema_errors(document: &[u8], schema: Option<&str>) -> Result<()> {
     let compiled =
         jsonschema::validator_for(&validation_schema).map_err(|e| Error::Parse(e.to_string()))?;
 
-    let validation_errors: Vec<String> = match compiled.validate(&document_json) {
-        Ok(()) => Vec::new(),
-        Err(_) => compiled
-            .iter_errors(&document_json)
-            .map(|e| e.to_string())
-            .collect(),
-    };
-
-    if validation_errors.is_empty() {
-        return Ok(());
+    let mut violations = Vec::new();
+    for error in compiled.iter_errors(&document_json) {
+        collect_violations(&error, &mut violations);
     }
+    Ok(violations)
+}
 
-    Err(Error::Parse(format!(
-        "json schema validation failed ({} errors): {}",
-        validation_errors.len(),
-        validation_errors.join("; ")
-    )))
+/// Flatten `error` into `out`. An `anyOf`/`oneOf` failure where only one
+/// alternative applies to the value (the others just want a different type,
+/// such as the commonmeta schema's "record or array of records" root) is
+/// replaced by that alternative's own, more specific, errors.
+fn collect_violations(error: &ValidationError<'_>, out: &mut Vec<SchemaViolation>) {
+    if let ValidationErrorKind::AnyOf { context } | ValidationErrorKind::OneOfNotValid { context } =
+        error.kind()
+    {
+        let pointer = error.instance_path().to_string();
+        let applicable: Vec<_> = context
+            .iter()
+            .filter(|branch| {
+                !branch.iter().all(|e| {
+                    matches!(e.kind(), ValidationErrorKind::Type { .. })
+                        && e.instance_path().to_string() == pointer
+                })
+            })
+            .collect();
+        if let [branch] = applicable.as_slice() {
+            for e in branch.iter() {
+                collect_violations(e, out);
+            }
+            return;
+        }
+    }
+    out.push(SchemaViolation {
+        pointer: error.instance_path().to_string(),
+        message: error.to_string(),
+    });
 }
 
 // ── XSD validation ─────────────────────────────────────────────────────────────
@@ -283,8 +331,8 @@ fn load_schema(schema_file: &str) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        DEFAULT_SCHEMA, SCHEMA_VERSION, json_schema_errors, known_schemata, schema_file_name,
-        xml_schema_errors,
+        DEFAULT_SCHEMA, SCHEMA_VERSION, json_schema_errors, json_schema_violations,
+        known_schemata, schema_file_name, xml_schema_errors,
     };
 
     #[test]
@@ -308,6 +356,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reports_violations_with_json_pointers() {
+        let doc = include_bytes!("../tests/fixtures/commonmeta/journal_article.json");
+        assert!(json_schema_violations(doc, None).unwrap().is_empty());
+
+        let mut value: serde_json::Value = serde_json::from_slice(doc).unwrap();
+        value["type"] = serde_json::json!(42);
+        let violations =
+            json_schema_violations(&serde_json::to_vec(&value).unwrap(), None).unwrap();
+        assert!(
+            violations.iter().any(|v| v.pointer == "/type"),
+            "unexpected violations: {violations:?}"
+        );
+    }
+
     #[test]
     fn rejects_unknown_schema_name() {
         let result = json_schema_errors(br#"{}"#, Some("does-not-exist"));
@@ -1,45 +1,72 @@
 use lazy_static::lazy_static;
-use regex::Regex;
+use regex::{Regex, RegexSet};
+use std::ops::Range;
 use unicode_normalization::UnicodeNormalization;
 use url::Url;
 
+use crate::checksums::{compute_mod10_weighted, compute_mod11_2};
 use crate::crockford::decode;
-use crate::doi_utils::{normalize_doi, validate_doi};
+use crate::doi_utils::{clean_doi, normalize_doi, strip_invisible, trim_doi, validate_doi};
 
 /// Validates the checksum of a string using the ISO 7064 Mod 11-2 algorithm.
 fn validate_mod11_2(input: &str) -> Result<(), String> {
-    if !input.chars().all(|c| c.is_ascii_digit() || c == 'X') {
-        return Err("Invalid characters in input".to_string());
-    }
-
     // the last character is the checksum
-    let checksum_char = input.chars().last().unwrap();
+    let Some(checksum_char) = input.chars().last() else {
+        return Err("Empty input".to_string());
+    };
     let body = &input[..input.len() - 1];
-
-    let mut m = 0;
-
-    for c in body.chars() {
-        let d = c.to_digit(10).unwrap() as i32;
-
-        m = ((m + d) * 2) % 11;
+    if !body.chars().all(|c| c.is_ascii_digit())
+        || !(checksum_char.is_ascii_digit() || checksum_char == 'X')
+    {
+        return Err("Invalid characters in input".to_string());
     }
 
-    let check_value = (12 - m) % 11;
-    let expected_char = if check_value == 10 {
-        'X'
-    } else {
-        char::from_digit(check_value as u32, 10).unwrap()
-    };
-
     // compare with expected checksum
-    if checksum_char == expected_char {
+    if checksum_char == compute_mod11_2(body) {
         Ok(())
     } else {
         Err("Invalid checksum".to_string())
     }
 }
 
-pub fn decode_id(id: &str) -> Result<i64, String> {
+/// Generates the ISO 7064 Mod 11-2 check character (0-9 or X) of the digits
+/// of an ORCID or ISNI, e.g. the first 15 digits of a new ORCID. Hyphens,
+/// spaces and other non-digits are skipped.
+pub fn generate_orcid_checksum(digits: &str) -> char {
+    compute_mod11_2(digits)
+}
+
+/// Formats an ORCID as `0000-0002-1825-0097`: strips an orcid.org resolver,
+/// spaces and misplaced hyphens, upper-cases the check character, and
+/// appends the check character if only the 15 digits before it are given.
+/// Returns an empty string if there aren't 15 digits plus optional check
+/// character. Doesn't verify the check character; see [`validate_orcid`].
+pub fn format_orcid(raw: &str) -> String {
+    lazy_static! {
+        static ref RESOLVER: Regex =
+            Regex::new(r"^(?i:(?:https?://)?(?:www\.|sandbox\.)?orcid\.org/)").unwrap();
+    }
+    let mut orcid: String = RESOLVER
+        .replace(raw.trim(), "")
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' ' | '\u{2010}'..='\u{2013}'))
+        .collect::<String>()
+        .to_uppercase();
+    if orcid.len() == 15 {
+        orcid.push(generate_orcid_checksum(&orcid));
+    }
+    let body_ok = orcid.len() == 16 && orcid[..15].chars().all(|c| c.is_ascii_digit());
+    if !body_ok || !orcid.ends_with(|c: char| c.is_ascii_digit() || c == 'X') {
+        return String::new();
+    }
+    format!("{}-{}-{}-{}", &orcid[..4], &orcid[4..8], &orcid[8..12], &orcid[12..])
+}
+
+/// Decode a DOI suffix, ROR ID, RID or ORCID to the number it encodes.
+/// With `checksum` false the trailing check characters are neither expected
+/// (DOI, ROR, RID) nor verified (ORCID, whose check character is still
+/// dropped), for identifiers minted without them.
+pub fn decode_id(id: &str, checksum: bool) -> Result<u128, String> {
     let (identifier, identifier_type) = validate_id(id);
 
     match identifier_type {
@@ -53,29 +80,29 @@ pub fn decode_id(id: &str) -> Result<i64, String> {
                 return Err(format!("Invalid DOI format: {}", id));
             }
             let suffix = parts[1];
-            decode(suffix, true).map_err(|e| e.to_string())
+            decode(suffix, checksum).map_err(|e| e.to_string())
         }
         "ROR" => {
             // ROR ID is a 9-character string that starts with 0
             // and is a base32-encoded number with a mod 97-1
-            decode(&identifier, true).map_err(|e| e.to_string())
+            decode(&identifier, checksum).map_err(|e| e.to_string())
         }
         "RID" => {
             // RID is a 10-character string with a hyphen after five digits.
             // It is a base32-encoded numbers with checksum.
-            decode(&identifier, true).map_err(|e| e.to_string())
+            decode(&identifier, checksum).map_err(|e| e.to_string())
         }
         "ORCID" => {
             let cleaned = identifier.replace("-", "");
 
             // Verify checksum using iso7064 mod 11-2
-            if let Err(e) = validate_mod11_2(&cleaned) {
+            if checksum && let Err(e) = validate_mod11_2(&cleaned) {
                 return Err(format!("Invalid checksum for ORCID {}: {}", identifier, e));
             }
 
             // Parse the identifier without the checksum
             let number_str = &cleaned[..cleaned.len() - 1];
-            match number_str.parse::<i64>() {
+            match number_str.parse::<u128>() {
                 Ok(n) => Ok(n),
                 Err(e) => Err(format!("Failed to parse ORCID: {}", e)),
             }
@@ -84,58 +111,488 @@ pub fn decode_id(id: &str) -> Result<i64, String> {
     }
 }
 
-/// Validates an identifier and returns the identifier and its type.
-/// Type can be: DOI, UUID, PMID, PMCID, OpenAlex, ORCID, ROR, GRID,
-/// RID, Wikidata, ISNI, ISSN, Crossref Funder ID, URL, or "".
-pub fn validate_id(id: &str) -> (String, &'static str) {
-    if let Some(fundref) = validate_crossref_funder_id(id) {
-        return (fundref, "Crossref Funder ID");
-    }
-    if let Some(doi) = validate_doi(id) {
-        return (doi, "DOI");
-    }
-    if let Some(uuid) = validate_uuid(id) {
-        return (uuid, "UUID");
+/// A persistent identifier, as recognized by [`Identifier::parse`]. Each
+/// variant holds the identifier in the form the matching `validate_*`
+/// function returns, e.g. a bare DOI or ORCID without resolver.
+///
+/// Displays as its canonical URL ([`Identifier::to_url`]), parses from any
+/// accepted spelling with [`FromStr`](std::str::FromStr), and serializes as
+/// `{"type": "DOI", "id": "10.5555/12345678"}`. Deserializes from that form
+/// or from a plain string such as `"https://doi.org/10.5555/12345678"`,
+/// validating the ID either way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "id", try_from = "IdentifierRepr")]
+pub enum Identifier {
+    #[serde(rename = "Crossref Funder ID")]
+    CrossrefFunderId(String),
+    #[serde(rename = "DOI")]
+    Doi(String),
+    #[serde(rename = "UUID")]
+    Uuid(String),
+    #[serde(rename = "PMID")]
+    Pmid(String),
+    #[serde(rename = "PMCID")]
+    Pmcid(String),
+    OpenAlex(String),
+    #[serde(rename = "ORCID")]
+    Orcid(String),
+    #[serde(rename = "ROR")]
+    Ror(String),
+    #[serde(rename = "GRID")]
+    Grid(String),
+    #[serde(rename = "RID")]
+    Rid(String),
+    Wikidata(String),
+    #[serde(rename = "ISNI")]
+    Isni(String),
+    #[serde(rename = "ISSN")]
+    Issn(String),
+    #[serde(rename = "ISBN")]
+    Isbn(String),
+    #[serde(rename = "LCCN")]
+    Lccn(String),
+    #[serde(rename = "VIAF")]
+    Viaf(String),
+    #[serde(rename = "GND")]
+    Gnd(String),
+    #[serde(rename = "Scopus")]
+    ScopusEid(String),
+    #[serde(rename = "ARK")]
+    Ark(String),
+    #[serde(rename = "IGSN")]
+    Igsn(String),
+    #[serde(rename = "RAID")]
+    Raid(String),
+    #[serde(rename = "SWHID")]
+    Swhid(String),
+    #[serde(rename = "URN")]
+    UrnNbn(String),
+    #[serde(rename = "arXiv")]
+    Arxiv(String),
+    #[serde(rename = "JSONFEEDID")]
+    JsonFeedId(String),
+    #[serde(rename = "URL")]
+    Url(String),
+}
+
+impl Identifier {
+    /// Recognize `id`. Types are tried in a fixed order, so e.g. a Crossref
+    /// Funder ID DOI is a `CrossrefFunderId` rather than a `Doi`, and a bare
+    /// number is a PMID rather than a PMCID.
+    pub fn parse(id: &str) -> Option<Identifier> {
+        if let Some(fundref) = validate_crossref_funder_id(id) {
+            return Some(Identifier::CrossrefFunderId(fundref));
+        }
+        // IGSNs that are DOIs stay DOIs, which is what writers and DOI
+        // lookups expect; `parse_as("IGSN", ..)` reads them as IGSNs.
+        if validate_doi(id).is_none()
+            && let Some(igsn) = validate_igsn(id)
+        {
+            return Some(Identifier::Igsn(igsn));
+        }
+        if let Some(raid) = validate_raid(id) {
+            return Some(Identifier::Raid(raid));
+        }
+        if let Some(doi) = validate_doi(id) {
+            return Some(Identifier::Doi(doi));
+        }
+        if let Some(uuid) = validate_uuid(id) {
+            return Some(Identifier::Uuid(uuid));
+        }
+        if let Some(pmid) = validate_pmid(id) {
+            return Some(Identifier::Pmid(pmid));
+        }
+        if let Some(pmcid) = validate_pmcid(id) {
+            return Some(Identifier::Pmcid(pmcid));
+        }
+        if let Some(openalex) = validate_openalex(id) {
+            return Some(Identifier::OpenAlex(openalex));
+        }
+        if let Some(orcid) = validate_orcid(id) {
+            return Some(Identifier::Orcid(orcid));
+        }
+        if let Some(ror) = validate_ror(id) {
+            return Some(Identifier::Ror(ror));
+        }
+        if let Some(grid) = validate_grid(id) {
+            return Some(Identifier::Grid(grid));
+        }
+        if let Some(rid) = validate_rid(id) {
+            return Some(Identifier::Rid(rid));
+        }
+        if let Some(wikidata) = validate_wikidata(id) {
+            return Some(Identifier::Wikidata(wikidata));
+        }
+        if let Some(isni) = validate_isni(id) {
+            return Some(Identifier::Isni(isni));
+        }
+        if let Some(issn) = validate_issn(id) {
+            return Some(Identifier::Issn(issn));
+        }
+        if let Some(isbn) = validate_isbn(id) {
+            return Some(Identifier::Isbn(isbn));
+        }
+        // One in eleven bare numbers passes the GND check, so a bare GND ID
+        // needs the hyphen of the former subject files; the others need the
+        // `gnd:` prefix or d-nb.info URL, or `parse_as("GND", ..)`.
+        if (id.contains('-') || id.to_lowercase().contains("gnd"))
+            && let Some(gnd) = validate_gnd(id)
+        {
+            return Some(Identifier::Gnd(gnd));
+        }
+        if let Some(eid) = validate_scopus_eid(id) {
+            return Some(Identifier::ScopusEid(eid));
+        }
+        if let Some(lccn) = validate_lccn(id) {
+            return Some(Identifier::Lccn(lccn));
+        }
+        // Any other number: VIAF IDs are bare numbers of any length.
+        // (GND IDs are numbers too, but have a check character.)
+        if let Some(viaf) = validate_viaf(id) {
+            return Some(Identifier::Viaf(viaf));
+        }
+        if let Some(ark) = validate_ark(id) {
+            return Some(Identifier::Ark(ark));
+        }
+        if let Some(swhid) = validate_swhid(id) {
+            return Some(Identifier::Swhid(swhid));
+        }
+        if let Some(urn) = validate_urn_nbn(id) {
+            return Some(Identifier::UrnNbn(urn));
+        }
+        if let Some(arxiv) = validate_arxiv(id) {
+            return Some(Identifier::Arxiv(arxiv));
+        }
+
+        match validate_url(id).as_str() {
+            "DOI" => Some(Identifier::Doi(id.to_string())),
+            "JSONFEEDID" => Some(Identifier::JsonFeedId(id.to_string())),
+            "URL" => Some(Identifier::Url(id.to_string())),
+            _ => None,
+        }
     }
-    if let Some(pmid) = validate_pmid(id) {
-        return (pmid, "PMID");
+
+    /// Recognize `id` as an identifier of the type named `type_name` (as
+    /// returned by [`Identifier::type_name`]), e.g. a bare number as a VIAF
+    /// ID where [`Identifier::parse`] would take it for a PMID.
+    pub fn parse_as(type_name: &str, id: &str) -> Option<Identifier> {
+        match type_name {
+            "Crossref Funder ID" => {
+                validate_crossref_funder_id(id).map(Identifier::CrossrefFunderId)
+            }
+            "DOI" => validate_doi(id).map(Identifier::Doi),
+            "UUID" => validate_uuid(id).map(Identifier::Uuid),
+            "PMID" => validate_pmid(id).map(Identifier::Pmid),
+            "PMCID" => validate_pmcid(id).map(Identifier::Pmcid),
+            "OpenAlex" => validate_openalex(id).map(Identifier::OpenAlex),
+            "ORCID" => validate_orcid(id).map(Identifier::Orcid),
+            "ROR" => validate_ror(id).map(Identifier::Ror),
+            "GRID" => validate_grid(id).map(Identifier::Grid),
+            "RID" => validate_rid(id).map(Identifier::Rid),
+            "Wikidata" => validate_wikidata(id).map(Identifier::Wikidata),
+            "ISNI" => validate_isni(id).map(Identifier::Isni),
+            "ISSN" => validate_issn(id).map(Identifier::Issn),
+            "ISBN" => validate_isbn(id).map(Identifier::Isbn),
+            "LCCN" => validate_lccn(id).map(Identifier::Lccn),
+            "VIAF" => validate_viaf(id).map(Identifier::Viaf),
+            "GND" => validate_gnd(id).map(Identifier::Gnd),
+            "Scopus" => validate_scopus_eid(id).map(Identifier::ScopusEid),
+            "ARK" => validate_ark(id).map(Identifier::Ark),
+            "IGSN" => validate_igsn(id).map(Identifier::Igsn),
+            "RAID" => validate_raid(id).map(Identifier::Raid),
+            "SWHID" => validate_swhid(id).map(Identifier::Swhid),
+            "URN" => validate_urn_nbn(id).map(Identifier::UrnNbn),
+            // The type makes a bare new-style arXiv ID unambiguous.
+            "arXiv" => validate_arxiv(id)
+                .or_else(|| validate_arxiv(&format!("arXiv:{}", id)))
+                .map(Identifier::Arxiv),
+            "JSONFEEDID" | "URL" => match validate_url(id).as_str() {
+                "JSONFEEDID" => Some(Identifier::JsonFeedId(id.to_string())),
+                "" => None,
+                _ => Some(Identifier::Url(id.to_string())),
+            },
+            _ => None,
+        }
     }
-    if let Some(pmcid) = validate_pmcid(id) {
-        return (pmcid, "PMCID");
+
+    /// Recognize `id` like [`Identifier::parse`], as strictly or leniently
+    /// as `options` ask.
+    pub fn parse_with(id: &str, options: &ValidationOptions) -> Option<Identifier> {
+        parse_with_options(id, options, Identifier::parse)
+    }
+
+    /// Recognize `id` like [`Identifier::parse_as`], as strictly or
+    /// leniently as `options` ask.
+    pub fn parse_as_with(
+        type_name: &str,
+        id: &str,
+        options: &ValidationOptions,
+    ) -> Option<Identifier> {
+        parse_with_options(id, options, |id| Identifier::parse_as(type_name, id))
+    }
+
+    /// Whether `id` is spelled the canonical way: as the bare value or as
+    /// the URL [`Identifier::to_url`] returns (in any case for DOIs).
+    fn is_canonical(&self, id: &str) -> bool {
+        let url = self.to_url();
+        id == self.value()
+            || id == url
+            || matches!(self, Identifier::Doi(_)) && id.to_lowercase() == url
+    }
+
+    /// The identifier without its type.
+    pub fn value(&self) -> &str {
+        match self {
+            Identifier::CrossrefFunderId(v)
+            | Identifier::Doi(v)
+            | Identifier::Uuid(v)
+            | Identifier::Pmid(v)
+            | Identifier::Pmcid(v)
+            | Identifier::OpenAlex(v)
+            | Identifier::Orcid(v)
+            | Identifier::Ror(v)
+            | Identifier::Grid(v)
+            | Identifier::Rid(v)
+            | Identifier::Wikidata(v)
+            | Identifier::Isni(v)
+            | Identifier::Issn(v)
+            | Identifier::Isbn(v)
+            | Identifier::Lccn(v)
+            | Identifier::Viaf(v)
+            | Identifier::Gnd(v)
+            | Identifier::ScopusEid(v)
+            | Identifier::Ark(v)
+            | Identifier::Igsn(v)
+            | Identifier::Raid(v)
+            | Identifier::Swhid(v)
+            | Identifier::UrnNbn(v)
+            | Identifier::Arxiv(v)
+            | Identifier::JsonFeedId(v)
+            | Identifier::Url(v) => v,
+        }
     }
-    if let Some(openalex) = validate_openalex(id) {
-        return (openalex, "OpenAlex");
+
+    /// The name of the type, as returned by [`validate_id`]: DOI, UUID, PMID,
+    /// PMCID, OpenAlex, ORCID, ROR, GRID, RID, Wikidata, ISNI, ISSN, ISBN, LCCN,
+    /// VIAF, GND, Scopus, ARK,
+    /// IGSN, RAID, SWHID, URN, arXiv, Crossref Funder ID, JSONFEEDID or URL.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Identifier::CrossrefFunderId(_) => "Crossref Funder ID",
+            Identifier::Doi(_) => "DOI",
+            Identifier::Uuid(_) => "UUID",
+            Identifier::Pmid(_) => "PMID",
+            Identifier::Pmcid(_) => "PMCID",
+            Identifier::OpenAlex(_) => "OpenAlex",
+            Identifier::Orcid(_) => "ORCID",
+            Identifier::Ror(_) => "ROR",
+            Identifier::Grid(_) => "GRID",
+            Identifier::Rid(_) => "RID",
+            Identifier::Wikidata(_) => "Wikidata",
+            Identifier::Isni(_) => "ISNI",
+            Identifier::Issn(_) => "ISSN",
+            Identifier::Isbn(_) => "ISBN",
+            Identifier::Lccn(_) => "LCCN",
+            Identifier::Viaf(_) => "VIAF",
+            Identifier::Gnd(_) => "GND",
+            Identifier::ScopusEid(_) => "Scopus",
+            Identifier::Ark(_) => "ARK",
+            Identifier::Igsn(_) => "IGSN",
+            Identifier::Raid(_) => "RAID",
+            Identifier::Swhid(_) => "SWHID",
+            Identifier::UrnNbn(_) => "URN",
+            Identifier::Arxiv(_) => "arXiv",
+            Identifier::JsonFeedId(_) => "JSONFEEDID",
+            Identifier::Url(_) => "URL",
+        }
     }
-    if let Some(orcid) = validate_orcid(id) {
-        return (orcid, "ORCID");
+
+    /// The canonical https URL of the identifier, e.g. `https://doi.org/...`,
+    /// `https://orcid.org/...` or `https://ror.org/...`. UUIDs, InvenioRDM
+    /// RIDs and JSON Feed IDs have no resolver and are returned unchanged,
+    /// ISBNs as ISBN-13.
+    pub fn to_url(&self) -> String {
+        match self {
+            Identifier::CrossrefFunderId(v) => format!("https://doi.org/10.13039/{}", v),
+            Identifier::Doi(v) => normalize_doi(v),
+            Identifier::Pmid(v) => format!("https://pubmed.ncbi.nlm.nih.gov/{}", v),
+            Identifier::Pmcid(v) => normalize_pmcid(v),
+            Identifier::OpenAlex(v) => format!("https://openalex.org/{}", v),
+            Identifier::Orcid(v) => format!("https://orcid.org/{}", v),
+            Identifier::Ror(v) => format!("https://ror.org/{}", v),
+            Identifier::Grid(v) => format!("https://grid.ac/institutes/{}", v),
+            Identifier::Wikidata(v) => format!("https://www.wikidata.org/wiki/{}", v),
+            Identifier::Isni(v) => format!("https://isni.org/isni/{}", v),
+            Identifier::Issn(v) => issn_as_url(v),
+            Identifier::Ark(v) => normalize_ark(v),
+            // Legacy IGSNs and DOI-form RAiDs are bare, which normalize_igsn
+            // and normalize_raid reject.
+            Identifier::Igsn(v) if v.starts_with("10.") => normalize_doi(v),
+            Identifier::Igsn(v) => format!("https://hdl.handle.net/10273/{}", v),
+            Identifier::Raid(v) if v.starts_with(RAID_LEGACY_PREFIX) => {
+                format!("https://hdl.handle.net/{}", v)
+            }
+            Identifier::Raid(v) => format!("https://raid.org/{}", v),
+            Identifier::Swhid(v) => normalize_swhid(v),
+            Identifier::UrnNbn(v) => normalize_urn_nbn(v),
+            Identifier::Arxiv(v) => format!("https://arxiv.org/abs/{}", v),
+            Identifier::Url(v) => normalize_url(v, true, false)
+                .map(|u| u.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| v.clone()),
+            Identifier::Isbn(v) => normalize_isbn(v),
+            Identifier::Lccn(v) => normalize_lccn(v),
+            Identifier::Viaf(v) => normalize_viaf(v),
+            Identifier::Gnd(v) => normalize_gnd(v),
+            Identifier::ScopusEid(v) => normalize_scopus_eid(v),
+            Identifier::Uuid(v) | Identifier::Rid(v) | Identifier::JsonFeedId(v) => v.clone(),
+        }
     }
-    if let Some(ror) = validate_ror(id) {
-        return (ror, "ROR");
+
+    /// Follow the redirects from the identifier's canonical resolver
+    /// (doi.org, ror.org, orcid.org, n2t.net, ...) to its landing page,
+    /// one request at a time so every hop is recorded. Landing pages that
+    /// refuse HEAD requests are asked again with GET. Fails for identifiers
+    /// without a resolver (UUIDs, RIDs, JSON Feed IDs), on a network error
+    /// and after [`MAX_REDIRECTS`] redirects.
+    pub async fn resolve(&self) -> Result<Resolution, String> {
+        let mut url = self.to_url();
+        if !url.starts_with("http") {
+            return Err(format!("{} {} has no resolver", self.type_name(), url));
+        }
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        let mut chain = Vec::new();
+        loop {
+            tracing::debug!("HEAD {}", url);
+            let mut resp = client.head(&url).send().await.map_err(|e| format!("{}: {}", url, e))?;
+            if matches!(resp.status().as_u16(), 403 | 405 | 501) {
+                tracing::debug!("GET {}", url);
+                resp = client.get(&url).send().await.map_err(|e| format!("{}: {}", url, e))?;
+            }
+            let status = resp.status().as_u16();
+            chain.push(ResolutionHop { url: url.clone(), status });
+
+            let location = resp
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|location| location.to_str().ok());
+            match location {
+                Some(location) if resp.status().is_redirection() => {
+                    if chain.len() > MAX_REDIRECTS {
+                        let start = &chain[0].url;
+                        return Err(format!("{}: more than {} redirects", start, MAX_REDIRECTS));
+                    }
+                    url = redirect_target(&url, location)
+                        .ok_or_else(|| format!("{}: invalid redirect to {}", url, location))?;
+                }
+                _ => return Ok(Resolution { url, chain }),
+            }
+        }
     }
-    if let Some(grid) = validate_grid(id) {
-        return (grid, "GRID");
+}
+
+/// How many redirects [`Identifier::resolve`] follows.
+pub const MAX_REDIRECTS: usize = 10;
+
+/// Where [`Identifier::resolve`] ended up: the last URL requested, normally
+/// the landing page, and the status of every request on the way.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Resolution {
+    pub url: String,
+    pub chain: Vec<ResolutionHop>,
+}
+
+impl Resolution {
+    /// The HTTP status of the last request, e.g. 200 for a working landing
+    /// page or 404 for a DOI that isn't registered.
+    pub fn status(&self) -> u16 {
+        self.chain.last().map_or(0, |hop| hop.status)
     }
-    if let Some(rid) = validate_rid(id) {
-        return (rid, "RID");
+
+    /// True if the chain ended at a page that answered with 2xx.
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.status())
     }
-    if let Some(wikidata) = validate_wikidata(id) {
-        return (wikidata, "Wikidata");
+}
+
+/// One request made by [`Identifier::resolve`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ResolutionHop {
+    pub url: String,
+    pub status: u16,
+}
+
+/// The absolute URL a `Location` header points to, which may be relative
+/// to the URL that was requested.
+fn redirect_target(url: &str, location: &str) -> Option<String> {
+    Url::parse(url).ok()?.join(location).ok().map(|u| u.to_string())
+}
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_url())
     }
-    if let Some(isni) = validate_isni(id) {
-        return (isni, "ISNI");
+}
+
+/// What an [`Identifier`] deserializes from: its serialized form, or any
+/// string [`Identifier::parse`] accepts.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum IdentifierRepr {
+    Tagged {
+        #[serde(rename = "type")]
+        type_: String,
+        id: String,
+    },
+    Plain(String),
+}
+
+impl TryFrom<IdentifierRepr> for Identifier {
+    type Error = ParseIdentifierError;
+
+    fn try_from(repr: IdentifierRepr) -> Result<Self, Self::Error> {
+        match repr {
+            IdentifierRepr::Tagged { type_, id } => Identifier::parse_as(&type_, &id)
+                .ok_or_else(|| ParseIdentifierError(format!("{} as {}", id, type_))),
+            IdentifierRepr::Plain(id) => id.parse(),
+        }
     }
-    if let Some(issn) = validate_issn(id) {
-        return (issn, "ISSN");
+}
+
+/// The error of parsing a string that isn't a supported identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdentifierError(pub String);
+
+impl std::fmt::Display for ParseIdentifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "identifier {} not recognized", self.0)
     }
+}
 
-    match validate_url(id).as_str() {
-        "DOI" => return (id.to_string(), "DOI"),
-        "JSONFEEDID" => return (id.to_string(), "JSONFEEDID"),
-        "URL" => return (id.to_string(), "URL"),
-        _ => {}
+impl std::error::Error for ParseIdentifierError {}
+
+impl std::str::FromStr for Identifier {
+    type Err = ParseIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Identifier::parse(s).ok_or_else(|| ParseIdentifierError(s.to_string()))
     }
+}
 
-    (String::new(), "")
+/// Validates an identifier and returns the identifier and its type.
+/// Type can be: DOI, UUID, PMID, PMCID, OpenAlex, ORCID, ROR, GRID,
+/// RID, Wikidata, ISNI, ISSN, ISBN, LCCN, VIAF, GND, Scopus, ARK, IGSN, RAID,
+/// SWHID, URN, arXiv, Crossref Funder ID, JSONFEEDID, URL, or "".
+/// A thin wrapper around [`Identifier::parse`].
+pub fn validate_id(id: &str) -> (String, &'static str) {
+    match Identifier::parse(id) {
+        Some(identifier) => (identifier.value().to_string(), identifier.type_name()),
+        None => (String::new(), ""),
+    }
 }
 
 /// Validates an identifier and additionally returns its category.
@@ -144,15 +601,406 @@ pub fn validate_id_category(id: &str) -> (String, &'static str, &'static str) {
     let (pid, type_) = validate_id(id);
     let category = match type_ {
         "ROR" | "Crossref Funder ID" | "GRID" => "Organization",
+        "RAID" => "Project",
         "ORCID" => "Person",
-        "ISNI" => "Contributor",
-        "DOI" | "PMID" | "PMCID" => "Work",
-        "Wikidata" | "OpenAlex" | "URL" | "UUID" => "All",
+        "ISNI" | "VIAF" => "Contributor",
+        "DOI" | "PMID" | "PMCID" | "ISBN" | "Scopus" | "ARK" | "IGSN" | "SWHID" | "URN"
+        | "arXiv" => "Work",
+        "OpenAlex" => match openalex_entity_type(&pid) {
+            Some("Work") => "Work",
+            Some("Author") => "Person",
+            Some("Institution" | "Publisher" | "Funder") => "Organization",
+            _ => "All",
+        },
+        "Wikidata" | "LCCN" | "GND" | "URL" | "UUID" => "All",
         _ => "",
     };
     (pid, type_, category)
 }
 
+/// How strictly identifiers are read by [`Identifier::parse_with`],
+/// [`validate_id_with_options`] and
+/// [`validate_doi_with_options`](crate::doi_utils::validate_doi_with_options). The
+/// default reads them as the plain validators do: by regex alone, which
+/// already allows the usual resolver URLs and prefixes but no stray
+/// characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// Clean up what users paste before validating, see [`clean_id`], and
+    /// fall back to [`clean_doi`].
+    pub cleanup: bool,
+    /// Accept only the canonical spellings: the bare identifier, or its URL
+    /// as [`Identifier::to_url`] returns it. Rejects e.g. `doi:` prefixes,
+    /// `http://dx.doi.org/` and `orcid.org/` without scheme.
+    pub canonical_only: bool,
+}
+
+impl ValidationOptions {
+    /// Accept only canonical forms, for pipelines that write identifiers.
+    pub fn strict() -> Self {
+        ValidationOptions { cleanup: false, canonical_only: true }
+    }
+
+    /// Accept what users typed or pasted, for pipelines that read them.
+    pub fn lenient() -> Self {
+        ValidationOptions { cleanup: true, canonical_only: false }
+    }
+}
+
+/// Removes the noise around an identifier copy-pasted from a PDF, web page
+/// or spreadsheet: surrounding whitespace, quotes, brackets and angle
+/// brackets, trailing punctuation, invisible characters and typographic
+/// hyphens. Whitespace inside the identifier is kept, so `ISBN 978-...`
+/// or an ISNI in blocks of four still validate.
+pub fn clean_id(id: &str) -> String {
+    let mut id = strip_invisible(id);
+    loop {
+        let before = id.len();
+        let trimmed = id
+            .trim()
+            .trim_start_matches(['(', '[', '{', '<', '"', '\'', '\u{201C}', '\u{2018}', '\u{AB}'])
+            .trim_end_matches(['>', '"', '\'', '\u{201D}', '\u{2019}', '\u{BB}']);
+        id = trim_doi(trimmed).to_string();
+        if id.len() == before {
+            return id;
+        }
+    }
+}
+
+fn parse_with_options(
+    id: &str,
+    options: &ValidationOptions,
+    parse: impl Fn(&str) -> Option<Identifier>,
+) -> Option<Identifier> {
+    let mut id = id.to_string();
+    if options.cleanup {
+        id = clean_id(&id);
+        if parse(&id).is_none()
+            && let Some(doi) = clean_doi(&id)
+        {
+            id = doi;
+        }
+    }
+    let identifier = parse(&id)?;
+    if options.canonical_only && !identifier.is_canonical(&id) {
+        return None;
+    }
+    Some(identifier)
+}
+
+/// Validates an identifier like [`validate_id`], as strictly or leniently
+/// as `options` ask.
+pub fn validate_id_with_options(id: &str, options: &ValidationOptions) -> (String, &'static str) {
+    match Identifier::parse_with(id, options) {
+        Some(identifier) => (identifier.value().to_string(), identifier.type_name()),
+        None => (String::new(), ""),
+    }
+}
+
+/// How sure [`classify_id`] is that a string is an identifier of some type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    /// A bare number or short string that many identifier types share,
+    /// e.g. a PMID, VIAF ID or LCCN.
+    Low,
+    /// The shape (or check digit) of the type, but nothing naming it.
+    Medium,
+    /// The string names the type, e.g. with its resolver URL or a
+    /// `PMID:` / `ISBN` / `arXiv:` label.
+    High,
+}
+
+/// A type [`classify_id`] found a string to match.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct IdCandidate {
+    pub identifier: Identifier,
+    pub confidence: Confidence,
+}
+
+/// The identifier types tried by [`classify_id`], in the priority order of
+/// [`Identifier::parse`].
+const CLASSIFY_TYPES: [&str; 25] = [
+    "Crossref Funder ID", "IGSN", "RAID", "DOI", "UUID", "PMID", "PMCID", "OpenAlex", "ORCID",
+    "ROR", "GRID", "RID", "Wikidata", "ISNI", "ISSN", "ISBN", "GND", "Scopus", "LCCN", "VIAF",
+    "ARK", "SWHID", "URN", "arXiv", "URL",
+];
+
+/// Every identifier type `id` is valid as, most confident first, where
+/// [`validate_id`] only reports the first in a fixed priority order. A bare
+/// `100000001` is a Crossref Funder ID with medium and a VIAF ID with low
+/// confidence, while `https://doi.org/10.13039/100000001` is a Crossref
+/// Funder ID with high confidence (and a DOI). Types of equal confidence
+/// keep the priority order.
+pub fn classify_id(id: &str) -> Vec<IdCandidate> {
+    let id = id.trim();
+    let lowercase = id.to_lowercase();
+    let mut candidates: Vec<IdCandidate> = Vec::new();
+    for type_name in CLASSIFY_TYPES {
+        let Some(identifier) = Identifier::parse_as(type_name, id) else {
+            continue;
+        };
+        if candidates.iter().any(|c| c.identifier == identifier) {
+            continue;
+        }
+        // A resolver URL of another type (https://orcid.org/...) is no
+        // candidate for a plain URL.
+        if type_name == "URL" && !candidates.is_empty() {
+            continue;
+        }
+        let confidence = if type_markers(identifier.type_name())
+            .iter()
+            .any(|marker| lowercase.contains(marker))
+        {
+            Confidence::High
+        } else if matches!(identifier.type_name(), "PMID" | "VIAF" | "LCCN" | "GND") {
+            Confidence::Low
+        } else {
+            Confidence::Medium
+        };
+        candidates.push(IdCandidate { identifier, confidence });
+    }
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.confidence));
+    candidates
+}
+
+/// The strings that name an identifier type when they occur in a spelling
+/// of the identifier: resolver hosts, labels and fixed prefixes.
+fn type_markers(type_name: &str) -> &'static [&'static str] {
+    match type_name {
+        "Crossref Funder ID" => &["10.13039", "fundref"],
+        "DOI" => &["doi"],
+        "PMID" => &["pmid", "pubmed"],
+        "PMCID" => &["pmc"],
+        "OpenAlex" => &["openalex"],
+        "ORCID" => &["orcid"],
+        "ROR" => &["ror.org", "ror:"],
+        "GRID" => &["grid"],
+        "Wikidata" => &["wikidata"],
+        "ISNI" => &["isni"],
+        "ISSN" => &["issn"],
+        "ISBN" => &["isbn"],
+        "LCCN" => &["lccn"],
+        "VIAF" => &["viaf"],
+        "GND" => &["gnd", "d-nb.info"],
+        "Scopus" => &["scopus", "2-s2.0-"],
+        "ARK" => &["ark:"],
+        "IGSN" => &["igsn", "10.58052", "10.60471", "10273/"],
+        "RAID" => &["raid", "10378.1/"],
+        "SWHID" => &["swh:"],
+        "URN" => &["urn:nbn"],
+        "arXiv" => &["arxiv"],
+        _ => &[],
+    }
+}
+
+/// The result of [`validate_batch`]: how many entries of each type were
+/// valid, which entries were not and why, and which identifiers occurred
+/// more than once.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ValidationReport {
+    pub total: usize,
+    pub valid: usize,
+    /// Valid entries per type, as named by [`Identifier::type_name`].
+    pub counts: std::collections::BTreeMap<&'static str, usize>,
+    pub invalid: Vec<InvalidEntry>,
+    pub duplicates: Vec<DuplicateEntry>,
+}
+
+/// An entry of a batch that isn't a valid identifier.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct InvalidEntry {
+    /// 1-based position of the entry in the batch.
+    pub position: usize,
+    pub entry: String,
+    pub reason: String,
+}
+
+/// An identifier that occurs more than once in a batch, in any spelling.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DuplicateEntry {
+    /// The canonical URL shared by the entries, see [`Identifier::to_url`].
+    pub identifier: String,
+    /// 1-based positions of all its entries.
+    pub positions: Vec<usize>,
+}
+
+/// Validates a batch of identifiers, e.g. one column of a spreadsheet, in
+/// one pass. Each entry is recognized as by [`Identifier::parse`], except
+/// that an entry shaped like an identifier with a check digit whose check
+/// fails is invalid even when a looser type (URL, LCCN, VIAF) would accept
+/// it: `https://ror.org/02nr0ka48` is a typo, not a web page. Entries are
+/// duplicates when they resolve to the same URL, so `10.5555/ABC` and
+/// `https://doi.org/10.5555/abc` count as one identifier.
+pub fn validate_batch(ids: impl Iterator<Item = String>) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let mut seen: std::collections::HashMap<String, Vec<usize>> = std::collections::HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+
+    for (i, entry) in ids.enumerate() {
+        let position = i + 1;
+        report.total += 1;
+        let identifier = Identifier::parse(entry.trim()).filter(|identifier| {
+            !matches!(identifier, Identifier::Url(_) | Identifier::Lccn(_) | Identifier::Viaf(_))
+                || check_digit_failure(&entry).is_none()
+        });
+        match identifier {
+            Some(identifier) => {
+                report.valid += 1;
+                *report.counts.entry(identifier.type_name()).or_default() += 1;
+                let key = identifier.to_url();
+                let positions = seen.entry(key.clone()).or_default();
+                if positions.is_empty() {
+                    order.push(key);
+                }
+                positions.push(position);
+            }
+            None => {
+                let reason = invalid_reason(&entry);
+                report.invalid.push(InvalidEntry { position, entry, reason });
+            }
+        }
+    }
+
+    report.duplicates = order
+        .into_iter()
+        .filter_map(|identifier| {
+            let positions = seen.remove(&identifier)?;
+            (positions.len() > 1).then_some(DuplicateEntry { identifier, positions })
+        })
+        .collect();
+    report
+}
+
+/// An identifier from [`dedupe_identifiers`] and the input spellings that
+/// collapsed to it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DedupedIdentifier {
+    pub identifier: Identifier,
+    /// The distinct spellings of the identifier, in input order.
+    pub spellings: Vec<String>,
+    /// How often the identifier occurred, in any spelling.
+    pub count: usize,
+}
+
+/// The result of [`dedupe_identifiers`].
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct Deduplication {
+    /// One entry per distinct identifier, in order of first occurrence.
+    pub identifiers: Vec<DedupedIdentifier>,
+    /// Entries that aren't identifiers, without exact repeats.
+    pub unrecognized: Vec<String>,
+}
+
+impl Deduplication {
+    /// The identifiers that occurred in more than one spelling.
+    pub fn collapsed(&self) -> impl Iterator<Item = &DedupedIdentifier> {
+        self.identifiers.iter().filter(|d| d.spellings.len() > 1)
+    }
+}
+
+/// Deduplicates a list of mixed identifiers, e.g. merged from the exports of
+/// several systems. Entries are read leniently (see
+/// [`ValidationOptions::lenient`]) and are the same identifier when their
+/// canonical URLs match ignoring case, so `10.5555/ABC`,
+/// `doi:10.5555/abc` and `https://doi.org/10.5555/abc` collapse into one.
+/// Case is kept apart for case-sensitive identifiers: URLs, ARKs and JSON
+/// Feed IDs.
+pub fn dedupe_identifiers<S: AsRef<str>>(ids: impl IntoIterator<Item = S>) -> Deduplication {
+    let mut deduplication = Deduplication::default();
+    let mut index: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+
+    for entry in ids {
+        let entry = entry.as_ref().trim();
+        let Some(identifier) = Identifier::parse_with(entry, &ValidationOptions::lenient()) else {
+            if !entry.is_empty() && !deduplication.unrecognized.iter().any(|u| u == entry) {
+                deduplication.unrecognized.push(entry.to_string());
+            }
+            continue;
+        };
+        let key = match identifier {
+            Identifier::Url(_) | Identifier::Ark(_) | Identifier::JsonFeedId(_) => {
+                identifier.to_url()
+            }
+            _ => identifier.to_url().to_lowercase(),
+        };
+        let i = *index.entry(key).or_insert_with(|| {
+            deduplication.identifiers.push(DedupedIdentifier {
+                identifier,
+                spellings: Vec::new(),
+                count: 0,
+            });
+            deduplication.identifiers.len() - 1
+        });
+        let deduped = &mut deduplication.identifiers[i];
+        deduped.count += 1;
+        if !deduped.spellings.iter().any(|s| s == entry) {
+            deduped.spellings.push(entry.to_string());
+        }
+    }
+    deduplication
+}
+
+/// Why `entry` isn't recognized as an identifier: a wrong check digit when it
+/// is shaped like an identifier with one, otherwise a generic reason.
+fn invalid_reason(entry: &str) -> String {
+    let entry = entry.trim();
+    if entry.is_empty() {
+        return "empty entry".to_string();
+    }
+    if let Some(reason) = check_digit_failure(entry) {
+        return reason;
+    }
+    if let Some(doi) = clean_doi(entry) {
+        return format!("DOI with stray characters, cleaned: {}", doi);
+    }
+    if entry.starts_with("10.") && !entry.contains('/') {
+        return "DOI without suffix".to_string();
+    }
+    "identifier not recognized".to_string()
+}
+
+/// The failed check if `entry` is shaped like a ROR ID, ORCID, ISNI, ISSN
+/// or ISBN but isn't a valid one.
+fn check_digit_failure(entry: &str) -> Option<String> {
+    lazy_static! {
+        static ref ORCID: Regex = Regex::new(
+            r"^(?:https?://(?:www\.)?orcid\.org/)?\d{4}-\d{4}-\d{4}-\d{3}[0-9X]$"
+        )
+        .unwrap();
+        static ref ISNI: Regex = Regex::new(r"^\d{4} \d{4} \d{4} \d{3}[0-9X]$").unwrap();
+        static ref ISSN: Regex =
+            Regex::new(r"^(?:(?i:issn(?:-l)?:?\s*)\d{4}-?|\d{4}-)\d{3}[0-9Xx]$").unwrap();
+        static ref ISBN: Regex = Regex::new(
+            r"^(?i:urn:isbn:|isbn(?:-1[03])?:?\s*)?[0-9][0-9\- ]{8,15}[0-9Xx]$"
+        )
+        .unwrap();
+    }
+    let entry = entry.trim();
+    if let Err(e @ RorIdError::InvalidChecksum { .. }) = parse_ror(entry) {
+        return Some(e.to_string());
+    }
+    if ORCID.is_match(entry) && validate_orcid(entry).is_none() {
+        return Some("ORCID outside the assigned ranges".to_string());
+    }
+    if ISNI.is_match(entry) && validate_isni(entry).is_none() {
+        return Some("wrong ISNI check character".to_string());
+    }
+    if ISSN.is_match(entry) && validate_issn(entry).is_none() {
+        return Some("wrong ISSN check digit".to_string());
+    }
+    // A bare number is as likely a VIAF ID as an ISBN with a typo.
+    let bare = entry.chars().all(|c| c.is_ascii_digit());
+    if ISBN.is_match(entry) && !bare && validate_isbn(entry).is_none() {
+        let digits = entry.chars().filter(|c| c.is_ascii_digit() || *c == 'X' || *c == 'x');
+        return Some(match digits.count() {
+            10 | 13 => "wrong ISBN check digit".to_string(),
+            _ => "ISBN must have 10 or 13 digits".to_string(),
+        });
+    }
+    None
+}
+
 /// Validates a Crossref Funder ID
 pub fn validate_crossref_funder_id(fundref: &str) -> Option<String> {
     lazy_static! {
@@ -182,7 +1030,8 @@ pub fn validate_grid(grid: &str) -> Option<String> {
 /// optionally separated by hyphens or spaces and NOT
 /// between 0000-0001-5000-0007 and 0000-0003-5000-0001,
 /// or between 0009-0000-0000-0000 and 0009-0010-0000-0000
-/// (the ranged reserved for ORCID).
+/// (the ranged reserved for ORCID), whose last character is the
+/// ISO 7064 mod 11-2 check character.
 pub fn validate_isni(isni: &str) -> Option<String> {
     lazy_static! {
       static ref RE: Regex = Regex::new(r"^(?:(?:http|https)://(?:(?:www)?\.)?isni\.org/)?(?:isni/)?(0000[ -]?00\d{2}[ -]?\d{4}[ -]?\d{3}[0-9X]+)$").unwrap();
@@ -192,6 +1041,9 @@ pub fn validate_isni(isni: &str) -> Option<String> {
         .and_then(|captures| captures.get(1))
         .and_then(|m| {
             let clean_match = m.as_str().replace(" ", "").replace("-", "");
+            if clean_match.len() != 16 || validate_mod11_2(&clean_match).is_err() {
+                return None;
+            }
 
             // Return None if it's in the ORCID range
             if !check_orcid_number_range(&clean_match) {
@@ -202,17 +1054,32 @@ pub fn validate_isni(isni: &str) -> Option<String> {
         })
 }
 
-/// Validates an ISSN
+/// Validates an ISSN, with or without hyphen, `ISSN` prefix or
+/// portal.issn.org URL, and verifies its mod 11 check digit. Returns the
+/// ISSN hyphenated, with an uppercase `X` check digit.
 pub fn validate_issn(issn: &str) -> Option<String> {
     lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^(?:https://portal\.issn\.org/resource/ISSN/)?(\d{4}\-\d{3}(\d|x|X))$")
-                .unwrap();
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://portal\.issn\.org/resource/ISSN(?:-L)?/|(?i:issn(?:-l)?):?\s*)?(\d{4})-?(\d{3}[0-9Xx])$"
+        )
+        .unwrap();
     }
 
-    RE.captures(issn)
-        .and_then(|captures| captures.get(1))
-        .map(|m| m.as_str().to_string())
+    let captures = RE.captures(issn.trim())?;
+    let issn = format!("{}{}", &captures[1], captures[2].to_uppercase());
+    let sum: u32 = issn[..7]
+        .chars()
+        .zip((2..=8).rev())
+        .map(|(c, weight)| c.to_digit(10).unwrap_or_default() * weight)
+        .sum();
+    let check = match (11 - sum % 11) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10)?,
+    };
+    if !issn.ends_with(check) {
+        return None;
+    }
+    Some(format!("{}-{}", &issn[..4], &issn[4..]))
 }
 
 /// Validates an ORCID
@@ -269,15 +1136,57 @@ pub fn validate_rid(rid: &str) -> Option<String> {
 /// Validates a ROR ID
 /// The ROR ID starts with 0 followed by a 6-character
 /// alphanumeric string which is base32-encoded and a 2-digit checksum.
+/// See [`parse_ror`] for why an ID is rejected.
 pub fn validate_ror(ror: &str) -> Option<String> {
+    parse_ror(ror).ok()
+}
+
+/// Why a string is not a ROR ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RorIdError {
+    /// Not shaped like a ROR ID (URL).
+    Malformed(String),
+    /// Shaped like a ROR ID, but the two check digits don't match the
+    /// mod 97-10 checksum of the base32 body, e.g. because of a typo.
+    InvalidChecksum { ror: String, expected: u8 },
+}
+
+impl std::fmt::Display for RorIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RorIdError::Malformed(ror) => write!(f, "{} is not a ROR ID", ror),
+            RorIdError::InvalidChecksum { ror, expected } => write!(
+                f,
+                "wrong checksum for ROR ID {}: expected check digits {:02}",
+                ror, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RorIdError {}
+
+/// Parses a ROR ID or ROR URL and verifies the checksum of the ID: its last
+/// two digits are the ISO 7064 mod 97-10 checksum of the number the six
+/// base32 characters after the leading 0 encode. Returns the ID without
+/// resolver.
+pub fn parse_ror(ror: &str) -> Result<String, RorIdError> {
     lazy_static! {
         static ref RE: Regex =
-            Regex::new(r"^(?:(?:http|https)://ror\.org/)?(0[0-9a-z]{6}\d{2})$").unwrap();
+            Regex::new(r"^(?:(?:http|https)://ror\.org/)?(0[0-9a-hjkmnp-tv-z]{6}\d{2})$").unwrap();
     }
 
-    RE.captures(ror)
+    let id = RE
+        .captures(ror)
         .and_then(|captures| captures.get(1))
         .map(|m| m.as_str().to_string())
+        .ok_or_else(|| RorIdError::Malformed(ror.to_string()))?;
+    let number = decode(&id[..7], false).map_err(|_| RorIdError::Malformed(ror.to_string()))?;
+    let expected = crate::crockford::generate_checksum(number);
+    if id[7..].parse::<u8>().ok() != Some(expected) {
+        return Err(RorIdError::InvalidChecksum { ror: id, expected });
+    }
+    Ok(id)
 }
 
 /// Validates a URL and checks if it is a DOI
@@ -363,57 +1272,481 @@ pub fn validate_uuid(uuid: &str) -> Option<String> {
     }
 }
 
-/// Validates a Wikidata item ID
-/// Wikidata item ID is a string prefixed with Q followed by a number
-pub fn validate_wikidata(wikidata: &str) -> Option<String> {
+/// DOI prefixes under which IGSN sample identifiers are registered with
+/// DataCite.
+pub const IGSN_PREFIXES: [&str; 2] = ["10.58052", "10.60471"];
+
+/// Validates an IGSN (International Generic Sample Number): a DOI under one
+/// of the [`IGSN_PREFIXES`], or a legacy IGSN such as `IGSN:IEXXX0001`,
+/// `http://igsn.org/IEXXX0001` or `https://hdl.handle.net/10273/IEXXX0001`.
+///
+/// Returns the bare DOI for the former and the uppercase IGSN for the latter.
+pub fn validate_igsn(igsn: &str) -> Option<String> {
     lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^(?:(?:http|https)://(?:(?:www)?\.)?wikidata\.org/wiki/)?(Q\d+)$")
-                .unwrap();
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?i:igsn):\s*|(?:http|https)://(?:igsn\.org/|hdl\.handle\.net/10273/))([0-9A-Za-z]{4,}(?:[.\-][0-9A-Za-z]+)*)$"
+        )
+        .unwrap();
+    }
+    if let Some(doi) = validate_doi(igsn) {
+        return IGSN_PREFIXES
+            .iter()
+            .any(|prefix| doi.starts_with(&format!("{}/", prefix)))
+            .then_some(doi);
     }
+    RE.captures(igsn.trim())
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_uppercase())
+}
 
-    RE.captures(wikidata)
-        .and_then(|captures| captures.get(1))
+/// Handle prefix of the RAiDs minted before RAiDs became DOIs.
+const RAID_LEGACY_PREFIX: &str = "10378.1";
+
+/// Validates a RAiD (Research Activity Identifier). RAiDs are DOIs, but are
+/// only distinguishable from the DOIs of works by their resolver, so only
+/// `https://raid.org/10.xxxxx/suffix` URLs and legacy RAiD handles
+/// (`10378.1/1234567`, optionally as `https://hdl.handle.net/` URL) are
+/// recognized. Returns the DOI or handle without resolver.
+pub fn validate_raid(raid: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://(?:app\.)?raid\.org/(10\.\d{4,5}/[^\s/]+)|(?:(?:http|https)://hdl\.handle\.net/)?(10378\.1/\d+))/?$"
+        )
+        .unwrap();
+    }
+    RE.captures(raid.trim())
+        .and_then(|c| c.get(1).or_else(|| c.get(2)))
         .map(|m| m.as_str().to_string())
 }
 
-/// Validates an OpenAlex ID.
-/// First letter indicates resource type (A author, F funder, I institution,
-/// P publisher, S source, W work), followed by 8-10 digits.
-pub fn validate_openalex(openalex: &str) -> Option<String> {
+/// Validates an ARK (Archival Resource Key): `ark:/12345/x6np1wh8k` or
+/// `ark:12345/x6np1wh8k`, optionally followed by qualifiers such as
+/// `/c2/s4.pdf`, or any resolver URL ending in one. The name-assigning
+/// authority number (NAAN) is five or more betanumeric characters.
+///
+/// Returns the ARK in its normalized form: `ark:` in lowercase, without the
+/// slash after it and without the hyphens, which are identity-inert in ARKs.
+pub fn validate_ark(ark: &str) -> Option<String> {
     lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^(?:(?:http|https)://openalex\.org/)?([AFIPSW]\d{8,10})$").unwrap();
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://[^/\s]+/)?(?i:ark):/?([0-9bcdfghjkmnpqrstvwxz]{5,})/([0-9A-Za-z=~*+@_$.\-][0-9A-Za-z=~*+@_$./\-]*)$"
+        )
+        .unwrap();
+    }
+    let captures = RE.captures(ark.trim())?;
+    let name = captures[2].replace('-', "");
+    let name = name.trim_end_matches(['/', '.']);
+    if name.is_empty() {
+        return None;
+    }
+    Some(format!("ark:{}/{}", &captures[1], name))
+}
+
+/// Look up where an ARK resolves to via the n2t.net resolver (blocking),
+/// without following the redirect. Returns the target URL, or `None` if the
+/// ARK is invalid, not known to n2t.net or the request fails.
+pub fn resolve_ark_sync(ark: &str) -> Option<String> {
+    let url = normalize_ark(ark);
+    if url.is_empty() {
+        return None;
+    }
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .ok()?;
+
+    tracing::debug!("HEAD {}", url);
+    let resp = client.head(&url).send().ok()?;
+    if !resp.status().is_redirection() {
+        return None;
+    }
+    resp.headers()
+        .get(reqwest::header::LOCATION)?
+        .to_str()
+        .ok()
+        .map(|location| location.to_string())
+}
+
+/// Validates a SoftWare Heritage persistent IDentifier (SWHID): the core
+/// identifier `swh:1:<type>:<40 hex digits>`, with type one of cnt, dir, rev,
+/// rel, snp or ori, optionally followed by `;key=value` qualifiers (origin,
+/// visit, anchor, path, lines), or an archive.softwareheritage.org URL of one.
+/// Returns the SWHID without resolver.
+pub fn validate_swhid(swhid: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://archive\.softwareheritage\.org/)?(swh:1:(?:cnt|dir|rev|rel|snp|ori):[0-9a-f]{40}(?:;(?:origin|visit|anchor|path|lines)=[^;\s]+)*)/?$"
+        )
+        .unwrap();
     }
-    RE.captures(openalex)
+    RE.captures(swhid.trim())
         .and_then(|c| c.get(1))
         .map(|m| m.as_str().to_string())
 }
 
-/// Validates a PubMed ID (PMID).
-pub fn validate_pmid(pmid: &str) -> Option<String> {
+/// Validates an arXiv identifier, new-style (`2101.00001`, `2101.00001v2`)
+/// or old-style (`hep-th/9901001`), with an `arXiv:` prefix or as an
+/// arxiv.org abs or pdf URL. Bare new-style IDs look like any decimal
+/// number and aren't accepted. Returns the ID without prefix, keeping
+/// its version.
+pub fn validate_arxiv(arxiv: &str) -> Option<String> {
     lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^(?:(?:http|https)://pubmed\.ncbi\.nlm\.nih\.gov/)?(\d{4,8})$").unwrap();
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?i:arxiv):\s*|(?:http|https)://(?:www\.)?arxiv\.org/(?:abs|pdf)/)(\d{4}\.\d{4,5}(?:v\d+)?|[a-z\-]+(?:\.[A-Z]{2})?/\d{7}(?:v\d+)?)(?:\.pdf)?$"
+        )
+        .unwrap();
     }
-    RE.captures(pmid)
+    RE.captures(arxiv.trim())
         .and_then(|c| c.get(1))
         .map(|m| m.as_str().to_string())
 }
 
-/// Validates a PubMed Central ID (PMCID).
-pub fn validate_pmcid(pmcid: &str) -> Option<String> {
+/// Validates an ISBN-10 or ISBN-13, with or without hyphens or spaces and
+/// an `ISBN`, `ISBN-13:` or `urn:isbn:` prefix, and verifies its check digit
+/// (mod 11 for ISBN-10, mod 10 for ISBN-13). Returns the digits without
+/// separators, with an uppercase `X` check digit.
+pub fn validate_isbn(isbn: &str) -> Option<String> {
     lazy_static! {
         static ref RE: Regex =
-            Regex::new(r"^(?:(?:http|https)://www\.ncbi\.nlm\.nih\.gov/pmc/articles/)?(\d{4,8})$")
+            Regex::new(r"^(?i:urn:isbn:|isbn(?:-1[03])?:?\s*)?([0-9][0-9\- ]{8,15}[0-9Xx])$")
                 .unwrap();
     }
-    RE.captures(pmcid)
-        .and_then(|c| c.get(1))
-        .map(|m| m.as_str().to_string())
+    let captures = RE.captures(isbn.trim())?;
+    let isbn: String = captures[1]
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .collect::<String>()
+        .to_uppercase();
+    let valid = match isbn.len() {
+        10 => isbn10_check_digit(&isbn[..9]) == isbn[9..].chars().next(),
+        13 => {
+            (isbn.starts_with("978") || isbn.starts_with("979"))
+                && isbn13_check_digit(&isbn[..12]) == isbn[12..].chars().next()
+        }
+        _ => false,
+    };
+    valid.then_some(isbn)
 }
 
-// ── Normalizers ───────────────────────────────────────────────────────────────
+/// The mod 11 check digit (0-9 or X) of the first nine digits of an ISBN-10.
+fn isbn10_check_digit(digits: &str) -> Option<char> {
+    let sum = digits
+        .chars()
+        .zip((2..=10).rev())
+        .map(|(c, weight)| c.to_digit(10).map(|d| d * weight))
+        .sum::<Option<u32>>()?;
+    match (11 - sum % 11) % 11 {
+        10 => Some('X'),
+        check => char::from_digit(check, 10),
+    }
+}
+
+/// The mod 10 check digit of the first twelve digits of an ISBN-13.
+fn isbn13_check_digit(digits: &str) -> Option<char> {
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(compute_mod10_weighted(digits, &[3, 1]))
+}
+
+/// Converts an ISBN-10 to the ISBN-13 with the 978 prefix. Returns `None` for
+/// an invalid ISBN; an ISBN-13 is returned without separators.
+pub fn isbn10_to_isbn13(isbn: &str) -> Option<String> {
+    let isbn = validate_isbn(isbn)?;
+    if isbn.len() == 13 {
+        return Some(isbn);
+    }
+    let digits = format!("978{}", &isbn[..9]);
+    let check = isbn13_check_digit(&digits)?;
+    Some(format!("{}{}", digits, check))
+}
+
+/// Converts an ISBN-13 to an ISBN-10. Returns `None` for an invalid ISBN and
+/// for ISBN-13s with the 979 prefix, which have no ISBN-10; an ISBN-10 is
+/// returned without separators.
+pub fn isbn13_to_isbn10(isbn: &str) -> Option<String> {
+    let isbn = validate_isbn(isbn)?;
+    if isbn.len() == 10 {
+        return Some(isbn);
+    }
+    let digits = isbn.strip_prefix("978")?[..9].to_string();
+    let check = isbn10_check_digit(&digits)?;
+    Some(format!("{}{}", digits, check))
+}
+
+/// Validates a VIAF (Virtual International Authority File) cluster ID: a
+/// number of up to 22 digits, bare, with a `viaf:` prefix, or as viaf.org
+/// URL. Since bare numbers are ambiguous, [`Identifier::parse`] only
+/// classifies them as VIAF if they are no PMID, ISBN, GND or LCCN.
+pub fn validate_viaf(viaf: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://(?:www\.)?viaf\.org/viaf/|(?i:viaf):\s*)?([1-9]\d{0,21})/?$"
+        )
+        .unwrap();
+    }
+    RE.captures(viaf.trim())
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Validates a GND (Gemeinsame Normdatei) ID, bare, with a `gnd:` prefix or
+/// as d-nb.info/gnd URL, and verifies its mod 11 check character: IDs
+/// from the former person name authority file (`118540238`, `1012345678`)
+/// end with it, IDs from the former subject heading and corporate body files
+/// (`4011882-4`) have it after a hyphen. Returns the ID with uppercase `X`.
+pub fn validate_gnd(gnd: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://d-nb\.info/gnd/|(?i:gnd):\s*)?(?:(1\d{7,8})([0-9Xx])|(\d{1,8})-([0-9Xx]))/?$"
+        )
+        .unwrap();
+    }
+    let captures = RE.captures(gnd.trim())?;
+    let (digits, check, hyphen) = match (captures.get(1), captures.get(3)) {
+        (Some(digits), _) => (digits.as_str(), &captures[2], false),
+        (None, Some(digits)) => (digits.as_str(), &captures[4], true),
+        _ => return None,
+    };
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .zip(2..)
+        .map(|(c, weight)| c.to_digit(10).unwrap_or_default() * weight)
+        .sum();
+    // The two former authority files computed the check character differently.
+    let expected = if hyphen { sum % 11 } else { (11 - sum % 11) % 11 };
+    let expected = if expected == 10 { 'X' } else { char::from_digit(expected, 10)? };
+    if !check.eq_ignore_ascii_case(&expected.to_string()) {
+        return None;
+    }
+    let separator = if hyphen { "-" } else { "" };
+    Some(format!("{}{}{}", digits, separator, expected))
+}
+
+/// Validates a Scopus EID (`2-s2.0-85012345678`), bare, with an `eid:`
+/// prefix or as the `eid` parameter of a scopus.com record URL. Returns the
+/// EID.
+pub fn validate_scopus_eid(eid: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://(?:www\.)?scopus\.com/\S*[?&]eid=|(?i:eid):\s*)?(2-s2\.0-\d{6,12})(?:&\S*)?$"
+        )
+        .unwrap();
+    }
+    RE.captures(eid.trim())
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Validates a Library of Congress Control Number (LCCN), as written in MARC
+/// field 010 (`   85000002 `), hyphenated (`n78-890351`), with an `lccn:`
+/// prefix or as lccn.loc.gov permalink, and returns it in the normalized form
+/// used by the permalinks: an optional lowercase alphabetic prefix, the year
+/// and the serial number zero-padded to six digits (`n78890351`).
+pub fn validate_lccn(lccn: &str) -> Option<String> {
+    lazy_static! {
+        static ref PREFIX: Regex =
+            Regex::new(r"^(?:(?:http|https)://lccn\.loc\.gov/|(?i:lccn):\s*)").unwrap();
+        static ref RE: Regex = Regex::new(r"^([a-z]{0,3})(\d{2}|\d{4})(\d{6})$").unwrap();
+    }
+    let lccn = PREFIX.replace(lccn.trim(), "");
+    // Drop revision information after a slash (`75-425165//r75`) and blanks.
+    let lccn: String = lccn
+        .split('/')
+        .next()?
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    let lccn = match lccn.split_once('-') {
+        Some((year, serial)) if !serial.is_empty() && serial.len() <= 6 => {
+            format!("{}{:0>6}", year, serial)
+        }
+        Some(_) => return None,
+        None => lccn,
+    };
+    let captures = RE.captures(&lccn)?;
+    // Only LCCNs with two-digit years have three-letter prefixes.
+    if captures[2].len() == 4 && captures[1].len() > 2 {
+        return None;
+    }
+    Some(lccn)
+}
+
+/// Validates a URN:NBN (National Bibliography Number), e.g.
+/// `urn:nbn:de:101:1-2019012108` or `urn:nbn:fi-fe2019052316789`, bare or as
+/// URL of a national resolver. Returns the URN with `urn:nbn:` and the
+/// country code in lowercase.
+pub fn validate_urn_nbn(urn: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://(?:nbn-resolving\.(?:org|de)/|urn\.fi/|urn\.nb\.no/|persistent-identifier\.nl/|urn\.kb\.se/resolve\?urn=))?(?i:urn:nbn):([A-Za-z]{2})([:\-][0-9A-Za-z:._\-/]*[0-9A-Za-z])$"
+        )
+        .unwrap();
+    }
+    RE.captures(urn.trim())
+        .map(|c| format!("urn:nbn:{}{}", c[1].to_lowercase(), &c[2]))
+}
+
+/// Validates a Wikidata item ID
+/// Wikidata item ID is a string prefixed with Q followed by a number
+pub fn validate_wikidata(wikidata: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"^(?:(?:http|https)://(?:(?:www)?\.)?wikidata\.org/wiki/)?(Q\d+)$")
+                .unwrap();
+    }
+
+    RE.captures(wikidata)
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Validates an OpenAlex ID.
+/// First letter indicates resource type (A author, F funder, I institution,
+/// P publisher, S source, W work), followed by 8-10 digits. Accepts the bare
+/// ID and openalex.org or api.openalex.org URLs, with or without the entity
+/// path (`/works/W2741809807`). Returns the bare ID with uppercase letter.
+pub fn validate_openalex(openalex: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:http|https)://(?:api\.)?openalex\.org/(?:(?:works|authors|sources|institutions|publishers|funders)/)?)?([AFIPSWafipsw]\d{8,10})/?$"
+        )
+        .unwrap();
+    }
+    RE.captures(openalex.trim())
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_uppercase())
+}
+
+/// The entity type of an OpenAlex ID: Work, Author, Source, Institution,
+/// Publisher or Funder.
+pub fn openalex_entity_type(openalex: &str) -> Option<&'static str> {
+    match validate_openalex(openalex)?.chars().next()? {
+        'W' => Some("Work"),
+        'A' => Some("Author"),
+        'S' => Some("Source"),
+        'I' => Some("Institution"),
+        'P' => Some("Publisher"),
+        'F' => Some("Funder"),
+        _ => None,
+    }
+}
+
+/// Validates a PubMed ID (PMID): a bare number, a number with a `pmid:`
+/// prefix, or a PubMed URL. Bare numbers need at least 4 digits, so that
+/// they aren't mistaken for e.g. a page or volume number.
+pub fn validate_pmid(pmid: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?:(?:(?i:pmid):\s*|(?:http|https)://(?:pubmed\.ncbi\.nlm\.nih\.gov|(?:www\.)?ncbi\.nlm\.nih\.gov/pubmed)/)(\d{1,8})/?|(\d{4,8}))$"
+        )
+        .unwrap();
+    }
+    RE.captures(pmid.trim())
+        .and_then(|c| c.get(1).or_else(|| c.get(2)))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Validates a PubMed Central ID (PMCID): `PMC1234567`, optionally with a
+/// `pmcid:` prefix, or a PubMed Central or Europe PMC article URL. Returns
+/// the ID with its `PMC` prefix.
+pub fn validate_pmcid(pmcid: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"^(?i:pmcid:\s*|(?:http|https)://(?:(?:www\.)?ncbi\.nlm\.nih\.gov/pmc/|pmc\.ncbi\.nlm\.nih\.gov/)articles/|(?:http|https)://(?:www\.)?europepmc\.org/(?:article/PMC/|articles/))?(?i:PMC)(\d{1,8})/?$"
+        )
+        .unwrap();
+    }
+    RE.captures(pmcid.trim())
+        .and_then(|c| c.get(1))
+        .map(|m| format!("PMC{}", m.as_str()))
+}
+
+/// The PMID, PMCID and DOI of an article, as mapped by the NCBI ID Converter
+/// API. Empty strings for IDs the article doesn't have.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PubmedIds {
+    pub pmid: String,
+    pub pmcid: String,
+    pub doi: String,
+}
+
+/// Look up the PMID, PMCID and DOI of an article given any one of them, with
+/// the NCBI ID Converter API (blocking).
+///
+/// Calls `https://www.ncbi.nlm.nih.gov/pmc/utils/idconv/v1.0/` and returns
+/// the IDs in normalized form (PMID and PMCID without resolver, DOI as URL),
+/// or `None` if the ID is invalid or unknown, or the request fails. The API
+/// only knows articles that are also in PubMed Central.
+pub fn get_pubmed_ids_sync(id: &str) -> Option<PubmedIds> {
+    let (id, idtype) = if let Some(pmcid) = validate_pmcid(id) {
+        (pmcid, "pmcid")
+    } else if let Some(pmid) = validate_pmid(id) {
+        (pmid, "pmid")
+    } else {
+        (validate_doi(id)?, "doi")
+    };
+    let url = format!(
+        "https://www.ncbi.nlm.nih.gov/pmc/utils/idconv/v1.0/?ids={}&idtype={}&format=json\
+        &tool=commonmeta&email=info@front-matter.de",
+        id, idtype
+    );
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .ok()?;
+
+    tracing::debug!("GET {}", url);
+    let json = client.get(&url).send().ok()?.text().ok()?;
+    read_idconv(&json)
+}
+
+/// Look up the DOI of a PubMed ID with the NCBI ID Converter API (blocking).
+/// Returns the DOI as URL, or `None` if the PMID has none or the lookup
+/// fails; see [`get_pubmed_ids_sync`].
+pub fn get_pmid_doi_sync(pmid: &str) -> Option<String> {
+    let pmid = validate_pmid(pmid)?;
+    get_pubmed_ids_sync(&pmid)
+        .map(|ids| ids.doi)
+        .filter(|doi| !doi.is_empty())
+}
+
+/// The IDs of the first record of an NCBI ID Converter API response, or
+/// `None` if the record reports an error.
+fn read_idconv(json: &str) -> Option<PubmedIds> {
+    #[derive(serde::Deserialize)]
+    struct Response {
+        #[serde(default)]
+        records: Vec<Record>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Record {
+        #[serde(default)]
+        pmid: Option<String>,
+        #[serde(default)]
+        pmcid: Option<String>,
+        #[serde(default)]
+        doi: Option<String>,
+        #[serde(default)]
+        status: Option<String>,
+    }
+
+    let record = serde_json::from_str::<Response>(json).ok()?.records.into_iter().next()?;
+    if record.status.as_deref() == Some("error") {
+        return None;
+    }
+    Some(PubmedIds {
+        pmid: record.pmid.as_deref().and_then(validate_pmid).unwrap_or_default(),
+        pmcid: record.pmcid.as_deref().and_then(validate_pmcid).unwrap_or_default(),
+        doi: record.doi.as_deref().map(normalize_doi).unwrap_or_default(),
+    })
+}
+
+// ── Normalizers ───────────────────────────────────────────────────────────────
 
 /// Normalizes any PID: DOI → canonical URL, UUID, Wikidata, or plain URL.
 pub fn normalize_id(pid: &str) -> String {
@@ -499,6 +1832,120 @@ pub fn normalize_orcid(orcid: &str) -> String {
     }
 }
 
+/// Returns a normalized PubMed URL.
+pub fn normalize_pmid(pmid: &str) -> String {
+    match validate_pmid(pmid) {
+        Some(id) => format!("https://pubmed.ncbi.nlm.nih.gov/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a normalized PubMed Central URL.
+pub fn normalize_pmcid(pmcid: &str) -> String {
+    match validate_pmcid(pmcid) {
+        Some(id) => format!("https://www.ncbi.nlm.nih.gov/pmc/articles/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns an IGSN as resolver URL: doi.org for IGSN DOIs, the legacy IGSN
+/// handle otherwise.
+pub fn normalize_igsn(igsn: &str) -> String {
+    match validate_igsn(igsn) {
+        Some(id) if id.starts_with("10.") => normalize_doi(&id),
+        Some(id) => format!("https://hdl.handle.net/10273/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a RAiD as raid.org URL, or legacy RAiD as handle URL.
+pub fn normalize_raid(raid: &str) -> String {
+    match validate_raid(raid) {
+        Some(id) if id.starts_with(RAID_LEGACY_PREFIX) => {
+            format!("https://hdl.handle.net/{}", id)
+        }
+        Some(id) => format!("https://raid.org/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a SWHID as archive.softwareheritage.org resolver URL.
+pub fn normalize_swhid(swhid: &str) -> String {
+    match validate_swhid(swhid) {
+        Some(id) => format!("https://archive.softwareheritage.org/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns an arXiv identifier as arxiv.org abs URL.
+pub fn normalize_arxiv(arxiv: &str) -> String {
+    match validate_arxiv(arxiv) {
+        Some(id) => format!("https://arxiv.org/abs/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a URN:NBN as URL of the resolver of its country: urn.fi,
+/// urn.nb.no, urn.kb.se or persistent-identifier.nl for Finnish, Norwegian,
+/// Swedish and Dutch URNs, nbn-resolving.org for all others.
+pub fn normalize_urn_nbn(urn: &str) -> String {
+    let Some(urn) = validate_urn_nbn(urn) else {
+        return String::new();
+    };
+    match &urn[8..10] {
+        "fi" => format!("https://urn.fi/{}", urn),
+        "no" => format!("https://urn.nb.no/{}", urn),
+        "se" => format!("https://urn.kb.se/resolve?urn={}", urn),
+        "nl" => format!("https://persistent-identifier.nl/{}", urn),
+        _ => format!("https://nbn-resolving.org/{}", urn),
+    }
+}
+
+/// Returns an ARK as n2t.net resolver URL.
+pub fn normalize_ark(ark: &str) -> String {
+    match validate_ark(ark) {
+        Some(id) => format!("https://n2t.net/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns an ISBN as ISBN-13 without separators.
+pub fn normalize_isbn(isbn: &str) -> String {
+    isbn10_to_isbn13(isbn).unwrap_or_default()
+}
+
+/// Returns a Scopus EID as scopus.com record URL.
+pub fn normalize_scopus_eid(eid: &str) -> String {
+    match validate_scopus_eid(eid) {
+        Some(id) => format!("https://www.scopus.com/inward/record.uri?eid={}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a GND ID as d-nb.info URL.
+pub fn normalize_gnd(gnd: &str) -> String {
+    match validate_gnd(gnd) {
+        Some(id) => format!("https://d-nb.info/gnd/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns a VIAF cluster ID as viaf.org URL.
+pub fn normalize_viaf(viaf: &str) -> String {
+    match validate_viaf(viaf) {
+        Some(id) => format!("https://viaf.org/viaf/{}", id),
+        None => String::new(),
+    }
+}
+
+/// Returns an LCCN as lccn.loc.gov permalink.
+pub fn normalize_lccn(lccn: &str) -> String {
+    match validate_lccn(lccn) {
+        Some(id) => format!("https://lccn.loc.gov/{}", id),
+        None => String::new(),
+    }
+}
+
 /// Returns a normalized ROR URL.
 pub fn normalize_ror(ror: &str) -> String {
     match validate_ror(ror) {
@@ -507,6 +1954,15 @@ pub fn normalize_ror(ror: &str) -> String {
     }
 }
 
+/// Returns the canonical https URL of any identifier [`Identifier::parse`]
+/// recognizes, in any accepted spelling: a bare ORCID, an `http://` ROR URL,
+/// a `doi:` DOI, etc. Returns an empty string for unrecognized input.
+pub fn normalize_identifier(id: &str) -> String {
+    Identifier::parse(id.trim())
+        .map(|identifier| identifier.to_url())
+        .unwrap_or_default()
+}
+
 /// Normalizes a URL: upgrades http→https when `secure`, lowercases when `lower`.
 pub fn normalize_url(s: &str, secure: bool, lower: bool) -> Option<String> {
     let mut u = Url::parse(s).ok()?;
@@ -726,6 +2182,80 @@ pub fn issn_as_url(issn: &str) -> String {
     format!("https://portal.issn.org/resource/ISSN/{}", issn)
 }
 
+/// The ISSN-L (linking ISSN) of each ISSN, which groups the print, online
+/// and other editions of a serial, as published by the ISSN International
+/// Centre in its "ISSN-to-ISSN-L" table.
+#[derive(Debug, Clone, Default)]
+pub struct IssnLTable {
+    issn_l: std::collections::HashMap<String, String>,
+}
+
+impl IssnLTable {
+    /// Parse the tab-separated "ISSN-to-ISSN-L" table (`ISSN<TAB>ISSN-L`
+    /// lines below a header line). Lines without two valid ISSNs are skipped.
+    pub fn parse(table: &str) -> IssnLTable {
+        let issn_l = table
+            .lines()
+            .filter_map(|line| {
+                let (issn, issn_l) = line.split_once('\t')?;
+                Some((validate_issn(issn)?, validate_issn(issn_l)?))
+            })
+            .collect();
+        IssnLTable { issn_l }
+    }
+
+    /// The ISSN-L of `issn`, in any form [`validate_issn`] accepts.
+    pub fn get(&self, issn: &str) -> Option<&str> {
+        self.issn_l.get(&validate_issn(issn)?).map(String::as_str)
+    }
+
+    /// The ISSNs linked by the ISSN-L of `issn`, including itself.
+    pub fn linked(&self, issn: &str) -> Vec<&str> {
+        let Some(issn_l) = self.get(issn) else {
+            return Vec::new();
+        };
+        let mut linked: Vec<&str> = self
+            .issn_l
+            .iter()
+            .filter(|(_, l)| l.as_str() == issn_l)
+            .map(|(issn, _)| issn.as_str())
+            .collect();
+        linked.sort_unstable();
+        linked
+    }
+
+    pub fn len(&self) -> usize {
+        self.issn_l.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issn_l.is_empty()
+    }
+}
+
+/// Look up the ISSN-L of an ISSN with the OpenAlex sources API (blocking),
+/// for when the ISSN-to-ISSN-L table isn't at hand. Returns `None` if the
+/// ISSN is invalid, unknown to OpenAlex or the request fails.
+pub fn get_issn_l_sync(issn: &str) -> Option<String> {
+    let issn = validate_issn(issn)?;
+    let url = format!("https://api.openalex.org/sources/issn:{}?select=issn_l", issn);
+
+    #[derive(serde::Deserialize)]
+    struct Source {
+        #[serde(default)]
+        issn_l: Option<String>,
+    }
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .ok()?;
+
+    tracing::debug!("GET {}", url);
+    let source: Source = client.get(&url).send().ok()?.error_for_status().ok()?.json().ok()?;
+    validate_issn(&source.issn_l?)
+}
+
 /// Returns a community slug as a Rogue Scholar API URL.
 pub fn community_slug_as_url(slug: &str, host: &str) -> String {
     if slug.is_empty() {
@@ -946,6 +2476,8 @@ pub fn find_from_format_by_ext(ext: &str) -> &'static str {
     match ext {
         ".bib" => "bibtex",
         ".ris" => "ris",
+        ".mrc" | ".marc" => "marc21",
+        ".csv" | ".tsv" => "csv",
         _ => "",
     }
 }
@@ -997,6 +2529,149 @@ pub fn find_from_format_by_filename(filename: &str) -> &'static str {
     ""
 }
 
+/// An identifier found in free text by [`extract_identifiers`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct IdentifierMatch {
+    /// The identifier in its normalized form, e.g. a DOI or ORCID URL.
+    pub identifier: String,
+    /// DOI, ORCID, ROR, arXiv, PMID, PMCID, ISSN, Wikidata or OpenAlex.
+    pub identifier_type: &'static str,
+    /// Byte offsets of the match in the text.
+    pub start: usize,
+    pub end: usize,
+    /// The text as written, e.g. `doi:10.5555/12345678`.
+    pub text: String,
+}
+
+/// Finds the identifiers in free text, such as the reference list of a
+/// manuscript, in one pass over the text. DOIs, ORCIDs and arXiv IDs are
+/// recognized with or without a resolver URL or prefix; PMIDs and ISSNs
+/// need their label (`PMID: 12345678`, `ISSN 2749-9952`), and ROR,
+/// Wikidata and OpenAlex IDs their URL, since bare numbers would be
+/// ambiguous. Matches that fail validation (e.g. an ORCID outside the
+/// assigned ranges) are left out.
+pub fn extract_identifiers(text: &str) -> Vec<IdentifierMatch> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r#"(?xi)
+            (?P<doi>(?:https?://(?:dx\.)?doi\.org/|\bdoi:\s*)?\b10\.\d{4,9}/[^\s"'<>]+)
+            | (?P<orcid>(?:https?://(?:www\.)?orcid\.org/)?\b000[09]-000[123]-\d{4}-\d{3}[0-9X]\b)
+            | (?P<ror>https?://ror\.org/0[0-9a-z]{6}\d{2}\b)
+            | (?P<arxiv>(?:\barxiv:\s*|https?://arxiv\.org/abs/)
+                (?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?)
+            | (?P<pmcid>\bPMC\d{4,8}\b)
+            | (?P<pmid>\bPMID:?\s*\d{4,8}\b|https?://pubmed\.ncbi\.nlm\.nih\.gov/\d{4,8}\b)
+            | (?P<issn>\bISSN:?\s*\d{4}-\d{3}[0-9X]\b)
+            | (?P<wikidata>https?://(?:www\.)?wikidata\.org/wiki/Q\d+)
+            | (?P<openalex>https?://openalex\.org/[AFIPSW]\d{8,10}\b)
+            "#
+        )
+        .unwrap();
+        static ref DIGITS: Regex = Regex::new(r"\d[\d\-Xx]*$").unwrap();
+    }
+
+    let mut matches = Vec::new();
+    for captures in RE.captures_iter(text) {
+        let Some((name, m)) = RE
+            .capture_names()
+            .flatten()
+            .find_map(|name| captures.name(name).map(|m| (name, m)))
+        else {
+            continue;
+        };
+        let mut found = m.as_str();
+        let (identifier, identifier_type) = match name {
+            "doi" => {
+                found = trim_doi(found);
+                let doi = &found[found.find("10.").unwrap_or(0)..];
+                (normalize_doi(doi), "DOI")
+            }
+            "orcid" => (normalize_orcid(found), "ORCID"),
+            "ror" => (normalize_ror(found), "ROR"),
+            "arxiv" => {
+                let id = found.rsplit_once("/abs/").map_or(found, |(_, id)| id);
+                let id = id.rsplit_once(':').map_or(id, |(_, id)| id).trim();
+                (format!("https://arxiv.org/abs/{}", id), "arXiv")
+            }
+            "pmcid" => (found.to_uppercase(), "PMCID"),
+            "pmid" => (
+                DIGITS.find(found).map(|d| d.as_str().to_string()).unwrap_or_default(),
+                "PMID",
+            ),
+            "issn" => {
+                let issn = DIGITS.find(found).map_or("", |d| d.as_str());
+                (validate_issn(issn).unwrap_or_default().to_uppercase(), "ISSN")
+            }
+            "wikidata" => (found.to_string(), "Wikidata"),
+            _ => (found.to_string(), "OpenAlex"),
+        };
+        if identifier.is_empty() {
+            continue;
+        }
+        matches.push(IdentifierMatch {
+            identifier,
+            identifier_type,
+            start: m.start(),
+            end: m.start() + found.len(),
+            text: found.to_string(),
+        });
+    }
+    matches
+}
+
+/// Finds every DOI, ORCID, ROR, arXiv ID and ISBN in free text, with the
+/// byte range of each in `text`, in order of appearance. Unlike
+/// [`extract_identifiers`] the matches are typed: each is an [`Identifier`]
+/// that passed its `validate_*` check (so ROR IDs and ISBNs have a valid
+/// checksum, and a DOI under an IGSN prefix is an IGSN). A combined
+/// [`RegexSet`] decides in one pass which patterns occur at all.
+///
+/// ROR IDs need their ror.org URL, arXiv IDs their prefix or URL, and ISBNs
+/// an `ISBN` label unless they are ISBN-13s. Where matches overlap, e.g. an
+/// ISBN inside a DOI for a book chapter, the one that starts first wins.
+pub fn scan_identifiers(text: &str) -> Vec<(Identifier, Range<usize>)> {
+    const DOI: usize = 0;
+    const PATTERNS: [&str; 5] = [
+        r#"(?i:https?://(?:dx\.)?doi\.org/|\bdoi:\s*)?\b10\.\d{4,9}/[^\s"'<>]+"#,
+        r"(?:https?://(?:www\.)?orcid\.org/)?\b\d{4}-\d{4}-\d{4}-\d{3}[0-9X]\b",
+        r"(?:https?://)?(?:www\.)?ror\.org/0[0-9a-hjkmnp-tv-z]{6}[0-9]{2}\b",
+        r"(?i:\barxiv:\s*|https?://(?:www\.)?arxiv\.org/(?:abs|pdf)/)(?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[A-Z]{2})?/\d{7})(?:v\d+)?",
+        r"(?i:\bisbn(?:-1[03])?:?\s*)[0-9][0-9\-]{8,15}[0-9Xx]\b|\b97[89](?:-?[0-9]){10}\b",
+    ];
+    lazy_static! {
+        static ref SET: RegexSet = RegexSet::new(PATTERNS).unwrap();
+        static ref RES: Vec<Regex> = PATTERNS.iter().map(|p| Regex::new(p).unwrap()).collect();
+    }
+
+    let mut found: Vec<(Identifier, Range<usize>)> = Vec::new();
+    for i in SET.matches(text).iter() {
+        for m in RES[i].find_iter(text) {
+            let matched = if i == DOI { trim_doi(m.as_str()) } else { m.as_str() };
+            let identifier = match i {
+                DOI => Identifier::parse(&matched[matched.find("10.").unwrap_or(0)..]),
+                1 => validate_orcid(matched).map(Identifier::Orcid),
+                2 => validate_ror(matched).map(Identifier::Ror),
+                3 => validate_arxiv(matched).map(Identifier::Arxiv),
+                _ => validate_isbn(matched).map(Identifier::Isbn),
+            };
+            if let Some(identifier) = identifier {
+                found.push((identifier, m.start()..m.start() + matched.len()));
+            }
+        }
+    }
+
+    found.sort_by_key(|(_, range)| (range.start, std::cmp::Reverse(range.end)));
+    let mut end = 0;
+    found.retain(|(_, range)| {
+        let keep = range.start >= end;
+        if keep {
+            end = range.end;
+        }
+        keep
+    });
+    found
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1009,6 +2684,30 @@ mod tests {
             Some("W1234567890".into())
         );
         assert_eq!(validate_openalex("X123"), None);
+        assert_eq!(
+            validate_openalex("https://api.openalex.org/works/w2741809807"),
+            Some("W2741809807".into())
+        );
+        assert_eq!(
+            validate_openalex("https://openalex.org/institutions/I4210095817/"),
+            Some("I4210095817".into())
+        );
+        assert_eq!(validate_openalex("https://openalex.org/topics/T13607"), None);
+    }
+
+    #[test]
+    fn test_openalex_entity_type() {
+        assert_eq!(openalex_entity_type("W2741809807"), Some("Work"));
+        assert_eq!(openalex_entity_type("https://openalex.org/A5023888391"), Some("Author"));
+        assert_eq!(openalex_entity_type("S1983995261"), Some("Source"));
+        assert_eq!(openalex_entity_type("F4320332161"), Some("Funder"));
+        assert_eq!(openalex_entity_type("T13607"), None);
+        assert_eq!(
+            validate_id_category("https://api.openalex.org/works/W2741809807"),
+            ("W2741809807".to_string(), "OpenAlex", "Work")
+        );
+        assert_eq!(validate_id_category("I4210095817").2, "Organization");
+        assert_eq!(validate_id_category("S1983995261").2, "All");
     }
 
     #[test]
@@ -1019,6 +2718,60 @@ mod tests {
             Some("12345678".into())
         );
         assert_eq!(validate_pmid("123"), None); // too short
+        assert_eq!(validate_pmid("PMID: 123"), Some("123".into()));
+        assert_eq!(validate_pmid("pmid:29456894"), Some("29456894".into()));
+        assert_eq!(
+            validate_pmid("http://www.ncbi.nlm.nih.gov/pubmed/29456894/"),
+            Some("29456894".into())
+        );
+        assert_eq!(validate_pmid("pmid:"), None);
+        assert_eq!(validate_pmid("123456789"), None); // too long
+    }
+
+    #[test]
+    fn test_normalize_pmid() {
+        assert_eq!(normalize_pmid("pmid:29456894"), "https://pubmed.ncbi.nlm.nih.gov/29456894");
+        assert_eq!(normalize_pmid("10.5555/12345678"), "");
+    }
+
+    #[test]
+    fn test_validate_pmcid() {
+        assert_eq!(validate_pmcid("PMC5815332"), Some("PMC5815332".into()));
+        assert_eq!(validate_pmcid("pmcid: pmc5815332"), Some("PMC5815332".into()));
+        for url in [
+            "https://www.ncbi.nlm.nih.gov/pmc/articles/PMC5815332/",
+            "https://pmc.ncbi.nlm.nih.gov/articles/PMC5815332",
+            "https://europepmc.org/article/PMC/PMC5815332",
+            "http://europepmc.org/articles/PMC5815332",
+        ] {
+            assert_eq!(validate_pmcid(url), Some("PMC5815332".into()), "{}", url);
+        }
+        assert_eq!(validate_pmcid("5815332"), None); // that's a PMID
+        assert_eq!(
+            validate_id("PMC5815332"),
+            ("PMC5815332".to_string(), "PMCID")
+        );
+        assert_eq!(
+            normalize_pmcid("https://europepmc.org/article/PMC/PMC5815332"),
+            "https://www.ncbi.nlm.nih.gov/pmc/articles/PMC5815332"
+        );
+    }
+
+    #[test]
+    fn test_read_idconv() {
+        let json = r#"{"status": "ok", "records": [
+            {"pmcid": "PMC5815332", "pmid": "29456894", "doi": "10.7717/peerj.4375"}
+        ]}"#;
+        assert_eq!(
+            read_idconv(json),
+            Some(PubmedIds {
+                pmid: "29456894".to_string(),
+                pmcid: "PMC5815332".to_string(),
+                doi: "https://doi.org/10.7717/peerj.4375".to_string(),
+            })
+        );
+        let json = r#"{"status": "ok", "records": [{"pmid": "12345678", "status": "error"}]}"#;
+        assert_eq!(read_idconv(json), None);
     }
 
     #[test]
@@ -1033,11 +2786,40 @@ mod tests {
     #[test]
     fn test_normalize_ror() {
         assert_eq!(
-            normalize_ror("https://ror.org/0521rfr06"),
-            "https://ror.org/0521rfr06"
+            normalize_ror("https://ror.org/0521rfr81"),
+            "https://ror.org/0521rfr81"
         );
     }
 
+    #[test]
+    fn test_validate_issn_check_digit() {
+        let cases = [
+            ("2049-3630", Some("2049-3630")),
+            ("20493630", Some("2049-3630")),
+            ("ISSN 1050-124X", Some("1050-124X")),
+            ("1050-124x", Some("1050-124X")),
+            ("https://portal.issn.org/resource/ISSN/2167-8359", Some("2167-8359")),
+            ("2049-3631", None),
+            ("1234-5678", None),
+            ("2049-363", None),
+        ];
+        for (issn, expected) in cases {
+            assert_eq!(validate_issn(issn).as_deref(), expected, "{}", issn);
+        }
+    }
+
+    #[test]
+    fn test_issn_l_table() {
+        let table = IssnLTable::parse(
+            "ISSN\tISSN-L\n0028-0836\t0028-0836\n1476-4687\t0028-0836\n2049-3630\t2049-3630\n",
+        );
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.get("14764687"), Some("0028-0836"));
+        assert_eq!(table.get("2167-8359"), None);
+        assert_eq!(table.linked("0028-0836"), vec!["0028-0836", "1476-4687"]);
+        assert!(table.linked("2167-8359").is_empty());
+    }
+
     #[test]
     fn test_issn_as_url() {
         assert_eq!(
@@ -1125,10 +2907,10 @@ mod tests {
 
     #[test]
     fn test_validate_id_category() {
-        let (id, type_, cat) = validate_id_category("https://ror.org/0521rfr06");
+        let (id, type_, cat) = validate_id_category("https://ror.org/0521rfr81");
         assert_eq!(type_, "ROR");
         assert_eq!(cat, "Organization");
-        assert_eq!(id, "0521rfr06");
+        assert_eq!(id, "0521rfr81");
 
         let (_, type_, cat) = validate_id_category("https://orcid.org/0000-0001-5000-0007");
         assert_eq!(type_, "ORCID");
@@ -1172,6 +2954,9 @@ mod tests {
             ),
             ("0000-0001-2112-2291", Some("0000000121122291")),
             ("https://isni.org/isni/000000021825009", None),
+            ("0000 0001 2112 2292", None), // wrong check character
+            ("0000 0001 2112 22910", None),
+            ("0000 0001 2146 438X", Some("000000012146438X")),
         ];
 
         for (input, expected) in cases {
@@ -1201,6 +2986,8 @@ mod tests {
     fn test_validate_ror_parity_cases() {
         let cases = [
             ("https://ror.org/0342dzm54", Some("0342dzm54")),
+            ("https://ror.org/0342dzm55", None), // wrong checksum
+            ("0342dzu54", None),                 // u is not base32
             ("0342dzm54", Some("0342dzm54")),
             ("invalid", None),
         ];
@@ -1210,6 +2997,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_ror_errors() {
+        assert_eq!(
+            parse_ror("https://ror.org/0342dzm55"),
+            Err(RorIdError::InvalidChecksum {
+                ror: "0342dzm55".to_string(),
+                expected: 54
+            })
+        );
+        assert_eq!(
+            parse_ror("0342dzm55").unwrap_err().to_string(),
+            "wrong checksum for ROR ID 0342dzm55: expected check digits 54"
+        );
+        assert_eq!(
+            parse_ror("Leibniz University").unwrap_err().to_string(),
+            "Leibniz University is not a ROR ID"
+        );
+    }
+
     #[test]
     fn test_validate_crossref_funder_id_parity_cases() {
         let cases = [
@@ -1257,9 +3063,664 @@ mod tests {
     fn test_find_from_format_helpers_parity_cases() {
         assert_eq!(find_from_format_by_ext(".bib"), "bibtex");
         assert_eq!(find_from_format_by_ext(".ris"), "ris");
+        assert_eq!(find_from_format_by_ext(".mrc"), "marc21");
+        assert_eq!(find_from_format_by_ext(".tsv"), "csv");
         assert_eq!(find_from_format_by_ext(".json"), "");
 
         assert_eq!(find_from_format_by_filename("CITATION.cff"), "cff");
         assert_eq!(find_from_format_by_filename("citation.cff"), "");
     }
+
+    #[test]
+    fn test_extract_identifiers() {
+        let text = "Doe J (2024). A title. doi:10.5555/12345678. See also \
+            https://doi.org/10.1016/S0140-6736(20)30183-5 (Lancet), arXiv: 2101.00001v2,\n\
+            PMID: 12345678; PMC1234567. Jane Doe, https://orcid.org/0000-0002-1825-0097,\n\
+            https://ror.org/02nr0ka47. ISSN 2749-9952, not an ORCID: 0000-0000-0000-0000.";
+        let found: Vec<(&str, String)> = extract_identifiers(text)
+            .into_iter()
+            .map(|m| (m.identifier_type, m.identifier))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("DOI", "https://doi.org/10.5555/12345678".to_string()),
+                ("DOI", "https://doi.org/10.1016/s0140-6736(20)30183-5".to_string()),
+                ("arXiv", "https://arxiv.org/abs/2101.00001v2".to_string()),
+                ("PMID", "12345678".to_string()),
+                ("PMCID", "PMC1234567".to_string()),
+                ("ORCID", "https://orcid.org/0000-0002-1825-0097".to_string()),
+                ("ROR", "https://ror.org/02nr0ka47".to_string()),
+                ("ISSN", "2749-9952".to_string()),
+            ]
+        );
+
+        let first = &extract_identifiers(text)[0];
+        assert_eq!(first.text, "doi:10.5555/12345678");
+        assert_eq!(&text[first.start..first.end], first.text);
+    }
+
+    #[test]
+    fn test_scan_identifiers() {
+        let text = "Doe J (2024). A title. doi:10.5555/12345678. arXiv: 2101.00001v2, \
+            Jane Doe https://orcid.org/0000-0002-1825-0097 (https://ror.org/02nr0ka47). \
+            In: Proceedings, ISBN 978-3-16-148410-0, ch. 5, \
+            https://doi.org/10.1007/978-3-16-148410-0_5. \
+            Not an ORCID: 0000-0000-0000-0000, not a ROR: https://ror.org/02nr0ka48.";
+        let found = scan_identifiers(text);
+        let identifiers: Vec<&Identifier> = found.iter().map(|(id, _)| id).collect();
+        assert_eq!(
+            identifiers,
+            vec![
+                &Identifier::Doi("10.5555/12345678".to_string()),
+                &Identifier::Arxiv("2101.00001v2".to_string()),
+                &Identifier::Orcid("0000-0002-1825-0097".to_string()),
+                &Identifier::Ror("02nr0ka47".to_string()),
+                &Identifier::Isbn("9783161484100".to_string()),
+                &Identifier::Doi("10.1007/978-3-16-148410-0_5".to_string()),
+            ]
+        );
+        let spans: Vec<&str> = found.iter().map(|(_, range)| &text[range.clone()]).collect();
+        assert_eq!(spans[0], "doi:10.5555/12345678");
+        assert_eq!(spans[1], "arXiv: 2101.00001v2");
+        assert_eq!(spans[4], "ISBN 978-3-16-148410-0");
+        assert_eq!(spans[5], "https://doi.org/10.1007/978-3-16-148410-0_5");
+        assert!(scan_identifiers("no identifiers here").is_empty());
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        let ids = [
+            "10.5555/12345678",
+            "https://orcid.org/0000-0002-1825-0097",
+            "https://doi.org/10.5555/12345678",
+            "",
+            "https://ror.org/02nr0ka48",
+            "2049-3631",
+            "ISBN 978-3-16-148410-1",
+            "0000-0000-0000-0000",
+            "not an identifier",
+            "02nr0ka47",
+            "(doi: 10.5555/87654321).",
+        ];
+        let report = validate_batch(ids.iter().map(|id| id.to_string()));
+        assert_eq!(report.total, 11);
+        assert_eq!(report.valid, 4);
+        assert_eq!(report.counts.get("DOI"), Some(&2));
+        assert_eq!(report.counts.get("ORCID"), Some(&1));
+        assert_eq!(report.counts.get("ROR"), Some(&1));
+        let reasons: Vec<(usize, &str)> = report
+            .invalid
+            .iter()
+            .map(|e| (e.position, e.reason.as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                (4, "empty entry"),
+                (5, "wrong checksum for ROR ID 02nr0ka48: expected check digits 47"),
+                (6, "wrong ISSN check digit"),
+                (7, "wrong ISBN check digit"),
+                (8, "ORCID outside the assigned ranges"),
+                (9, "identifier not recognized"),
+                (11, "DOI with stray characters, cleaned: 10.5555/87654321"),
+            ]
+        );
+        assert_eq!(
+            report.duplicates,
+            vec![DuplicateEntry {
+                identifier: "https://doi.org/10.5555/12345678".to_string(),
+                positions: vec![1, 3],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dedupe_identifiers() {
+        let deduplication = dedupe_identifiers([
+            "10.5555/ABC",
+            "https://orcid.org/0000-0002-1825-0097",
+            "doi:10.5555/abc",
+            "0000-0002-1825-0097",
+            "https://doi.org/10.5555/abc",
+            "10.5555/ABC",
+            "https://example.org/Page",
+            "https://example.org/page",
+            "not an id",
+            "not an id",
+            "ISBN 978-3-16-148410-0",
+            "9783161484100",
+        ]);
+        let spellings: Vec<(&str, Vec<&str>, usize)> = deduplication
+            .identifiers
+            .iter()
+            .map(|d| {
+                let spellings = d.spellings.iter().map(String::as_str).collect();
+                (d.identifier.type_name(), spellings, d.count)
+            })
+            .collect();
+        assert_eq!(
+            spellings,
+            vec![
+                ("DOI", vec!["10.5555/ABC", "doi:10.5555/abc", "https://doi.org/10.5555/abc"], 4),
+                (
+                    "ORCID",
+                    vec!["https://orcid.org/0000-0002-1825-0097", "0000-0002-1825-0097"],
+                    2
+                ),
+                ("URL", vec!["https://example.org/Page"], 1),
+                ("URL", vec!["https://example.org/page"], 1),
+                ("ISBN", vec!["ISBN 978-3-16-148410-0", "9783161484100"], 2),
+            ]
+        );
+        assert_eq!(deduplication.identifiers[0].identifier, Identifier::Doi("10.5555/ABC".into()));
+        assert_eq!(deduplication.collapsed().count(), 3);
+        assert_eq!(deduplication.unrecognized, vec!["not an id"]);
+    }
+
+    #[test]
+    fn test_classify_id() {
+        let types = |id: &str| -> Vec<(&'static str, Confidence)> {
+            classify_id(id)
+                .into_iter()
+                .map(|c| (c.identifier.type_name(), c.confidence))
+                .collect()
+        };
+        assert_eq!(
+            types("100000001"),
+            vec![("Crossref Funder ID", Confidence::Medium), ("VIAF", Confidence::Low)]
+        );
+        assert_eq!(types("12345678")[0], ("PMID", Confidence::Low));
+        assert_eq!(
+            types("https://doi.org/10.13039/100000001"),
+            vec![("Crossref Funder ID", Confidence::High), ("DOI", Confidence::High)]
+        );
+        assert_eq!(types("PMID: 12345678"), vec![("PMID", Confidence::High)]);
+        assert_eq!(
+            types("https://orcid.org/0000-0002-1825-0097"),
+            vec![("ORCID", Confidence::High)]
+        );
+        assert_eq!(types("2049-3630")[0], ("ISSN", Confidence::Medium));
+        assert_eq!(types("https://example.org/page"), vec![("URL", Confidence::Medium)]);
+        assert!(classify_id("not an identifier").is_empty());
+    }
+
+    #[test]
+    fn test_validate_id_with_options() {
+        let strict = ValidationOptions::strict();
+        let lenient = ValidationOptions::lenient();
+        let orcid = ("0000-0002-1825-0097".to_string(), "ORCID");
+        for id in ["0000-0002-1825-0097", "https://orcid.org/0000-0002-1825-0097"] {
+            assert_eq!(validate_id_with_options(id, &strict), orcid);
+        }
+        assert_eq!(validate_id_with_options("orcid.org/0000-0002-1825-0097", &strict).1, "");
+        assert_eq!(validate_id_with_options("https://doi.org/10.5555/ABC", &strict).1, "DOI");
+        assert_eq!(validate_id_with_options("doi:10.5555/abc", &strict).1, "");
+        assert_eq!(
+            validate_id_with_options("doi:10.5555/abc", &ValidationOptions::default()).1,
+            "DOI"
+        );
+
+        let pasted = " <https://orcid.org/0000\u{2010}0002-1825-0097>. ";
+        assert_eq!(validate_id_with_options(pasted, &ValidationOptions::default()).1, "");
+        assert_eq!(validate_id_with_options(pasted, &lenient), orcid);
+        assert_eq!(
+            validate_id_with_options("DOI: 10.5555/12\n345678,", &lenient),
+            ("10.5555/12345678".to_string(), "DOI")
+        );
+        assert_eq!(
+            Identifier::parse_as_with("VIAF", "(113230702)", &lenient),
+            Some(Identifier::Viaf("113230702".to_string()))
+        );
+        assert_eq!(clean_id("\u{201C}ISBN 978-3-16-148410-0\u{201D};"), "ISBN 978-3-16-148410-0");
+    }
+
+    #[test]
+    fn test_validate_arxiv() {
+        assert_eq!(validate_arxiv("arXiv:2101.00001").as_deref(), Some("2101.00001"));
+        assert_eq!(validate_arxiv("arxiv: 2101.00001v2").as_deref(), Some("2101.00001v2"));
+        assert_eq!(
+            validate_arxiv("https://arxiv.org/pdf/2101.00001v2.pdf").as_deref(),
+            Some("2101.00001v2")
+        );
+        assert_eq!(validate_arxiv("arXiv:hep-th/9901001").as_deref(), Some("hep-th/9901001"));
+        assert_eq!(validate_arxiv("arXiv:math.GT/0309136").as_deref(), Some("math.GT/0309136"));
+        assert_eq!(validate_arxiv("2101.00001"), None);
+        assert_eq!(normalize_arxiv("arXiv:2101.00001"), "https://arxiv.org/abs/2101.00001");
+        assert_eq!(
+            validate_id_category("https://arxiv.org/abs/2101.00001"),
+            ("2101.00001".to_string(), "arXiv", "Work")
+        );
+    }
+
+    #[test]
+    fn test_identifier_parse_and_display() {
+        let doi: Identifier = "https://doi.org/10.5555/12345678".parse().unwrap();
+        assert_eq!(doi, Identifier::Doi("10.5555/12345678".to_string()));
+        assert_eq!(doi.value(), "10.5555/12345678");
+        assert_eq!(doi.to_string(), "https://doi.org/10.5555/12345678");
+        assert_eq!(doi.type_name(), "DOI");
+
+        let orcid = Identifier::parse("https://orcid.org/0000-0002-1825-0097").unwrap();
+        assert_eq!(orcid, Identifier::Orcid("0000-0002-1825-0097".to_string()));
+        assert_eq!(Identifier::parse("https://ror.org/02nr0ka47").unwrap().type_name(), "ROR");
+
+        let err = "not an identifier".parse::<Identifier>().unwrap_err();
+        assert_eq!(err.to_string(), "identifier not an identifier not recognized");
+        assert_eq!(validate_id("not an identifier"), (String::new(), ""));
+    }
+
+    #[test]
+    fn test_identifier_serde() {
+        let pmcid = Identifier::Pmcid("PMC1234567".to_string());
+        let json = serde_json::to_string(&pmcid).unwrap();
+        assert_eq!(json, r#"{"type":"PMCID","id":"PMC1234567"}"#);
+        assert_eq!(serde_json::from_str::<Identifier>(&json).unwrap(), pmcid);
+        let funder: Identifier =
+            serde_json::from_str(r#"{"type":"Crossref Funder ID","id":"501100000780"}"#).unwrap();
+        assert_eq!(funder, Identifier::CrossrefFunderId("501100000780".to_string()));
+
+        // Plain strings are parsed, tagged IDs validated as their type.
+        let doi: Identifier =
+            serde_json::from_str(r#""https://doi.org/10.5555/12345678""#).unwrap();
+        assert_eq!(doi, Identifier::Doi("10.5555/12345678".to_string()));
+        let viaf: Identifier = serde_json::from_str(r#"{"type":"VIAF","id":"12345678"}"#).unwrap();
+        assert_eq!(viaf, Identifier::Viaf("12345678".to_string()));
+        let err = serde_json::from_str::<Identifier>(r#"{"type":"ROR","id":"02nr0ka48"}"#);
+        assert!(err.unwrap_err().to_string().contains("02nr0ka48 as ROR not recognized"));
+        assert!(serde_json::from_str::<Identifier>(r#""not an identifier""#).is_err());
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Work {
+            identifiers: Vec<Identifier>,
+        }
+        let json = r#"{"identifiers":["10.5555/12345678","https://ror.org/02nr0ka47"]}"#;
+        let work: Work = serde_json::from_str(json).unwrap();
+        assert_eq!(work.identifiers[1], Identifier::Ror("02nr0ka47".to_string()));
+    }
+
+    #[test]
+    fn test_redirect_target() {
+        let doi = "https://doi.org/10.5555/12345678";
+        assert_eq!(
+            redirect_target(doi, "https://example.org/works/1").as_deref(),
+            Some("https://example.org/works/1")
+        );
+        assert_eq!(
+            redirect_target("https://example.org/works/1", "/articles/1?lang=en").as_deref(),
+            Some("https://example.org/articles/1?lang=en")
+        );
+        assert_eq!(redirect_target("not a url", "/articles/1"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_without_resolver() {
+        let uuid = Identifier::Uuid("e5d5e4a8-ac9f-4c5e-9c4e-7a8a6a0a0a0a".to_string());
+        assert_eq!(
+            uuid.resolve().await,
+            Err("UUID e5d5e4a8-ac9f-4c5e-9c4e-7a8a6a0a0a0a has no resolver".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolution_status() {
+        let resolution = Resolution {
+            url: "https://example.org/works/1".to_string(),
+            chain: vec![
+                ResolutionHop { url: "https://doi.org/10.5555/12345678".to_string(), status: 302 },
+                ResolutionHop { url: "https://example.org/works/1".to_string(), status: 200 },
+            ],
+        };
+        assert_eq!(resolution.status(), 200);
+        assert!(resolution.is_ok());
+    }
+
+    #[test]
+    fn test_identifier_display_round_trip() {
+        let ids = [
+            "10.5555/12345678",
+            "501100000780",
+            "https://pubmed.ncbi.nlm.nih.gov/12345678",
+            "PMC1234567",
+            "W2741809807",
+            "0000-0002-1825-0097",
+            "02nr0ka47",
+            "grid.4709.a",
+            "Q42",
+            "0000 0001 2103 2683",
+            "2049-3630",
+            "978-3-16-148410-0",
+            "https://lccn.loc.gov/2016022992",
+            "https://viaf.org/viaf/313230702",
+            "https://d-nb.info/gnd/118540238",
+            "ark:/12148/btv1b8449691v",
+            "swh:1:dir:d198bc9d7a6bcf6db04f476d29314f157507d505",
+            "urn:nbn:de:101:1-2016022996",
+            "arXiv:2101.00001",
+            "https://example.org/page",
+        ];
+        for id in ids {
+            let identifier = Identifier::parse(id).unwrap_or_else(|| panic!("{} not parsed", id));
+            // DOI-based IDs come back lowercased, so compare the URLs.
+            let url = identifier.to_string();
+            let reparsed: Identifier = url.parse().unwrap();
+            assert_eq!(reparsed.type_name(), identifier.type_name(), "{}", url);
+            assert_eq!(reparsed.to_string(), url);
+            assert_eq!(
+                Identifier::parse_as(identifier.type_name(), identifier.value()),
+                Some(identifier),
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_identifier() {
+        let cases = [
+            ("10.5555/12345678", "https://doi.org/10.5555/12345678"),
+            ("doi:10.5555/12345678", "https://doi.org/10.5555/12345678"),
+            ("0000-0002-1825-0097", "https://orcid.org/0000-0002-1825-0097"),
+            ("http://orcid.org/0000-0002-1825-0097", "https://orcid.org/0000-0002-1825-0097"),
+            ("02nr0ka47", "https://ror.org/02nr0ka47"),
+            ("http://ror.org/02nr0ka47", "https://ror.org/02nr0ka47"),
+            ("501100000780", "https://doi.org/10.13039/501100000780"),
+            ("Q42", "https://www.wikidata.org/wiki/Q42"),
+            ("W2741809807", "https://openalex.org/W2741809807"),
+            ("http://example.org/page/", "https://example.org/page"),
+            ("not an identifier", ""),
+        ];
+        for (id, expected) in cases {
+            assert_eq!(normalize_identifier(id), expected, "{}", id);
+        }
+    }
+
+    #[test]
+    fn test_validate_ark() {
+        let cases = [
+            ("ark:/13030/tf5p30086k", Some("ark:13030/tf5p30086k")),
+            ("ark:13030/tf5p30086k", Some("ark:13030/tf5p30086k")),
+            ("ARK:/13030/tf5p3-0086k", Some("ark:13030/tf5p30086k")),
+            ("ark:/12345/x6np1wh8k/c2/s4.pdf", Some("ark:12345/x6np1wh8k/c2/s4.pdf")),
+            ("ark:12345/x6np1wh8k.v2", Some("ark:12345/x6np1wh8k.v2")),
+            ("https://n2t.net/ark:/13030/tf5p30086k", Some("ark:13030/tf5p30086k")),
+            ("https://example.org/ark:12345/x6np1wh8k/", Some("ark:12345/x6np1wh8k")),
+            ("ark:/1234/x6np1wh8k", None), // NAAN too short
+            ("ark:/12345/", None),
+            ("ark:/12345/x6np 1wh8k", None),
+        ];
+        for (ark, expected) in cases {
+            assert_eq!(validate_ark(ark).as_deref(), expected, "{}", ark);
+        }
+        assert_eq!(
+            normalize_ark("ark:/13030/tf5p30086k"),
+            "https://n2t.net/ark:13030/tf5p30086k"
+        );
+        assert_eq!(
+            validate_id("https://n2t.net/ark:/13030/tf5p30086k"),
+            ("ark:13030/tf5p30086k".to_string(), "ARK")
+        );
+    }
+
+    #[test]
+    fn test_validate_igsn() {
+        let cases = [
+            ("IGSN:IEXXX0001", Some("IEXXX0001")),
+            ("igsn: au1101", Some("AU1101")),
+            ("http://igsn.org/IEXXX0001", Some("IEXXX0001")),
+            ("https://hdl.handle.net/10273/CSRWA27B", Some("CSRWA27B")),
+            ("10.58052/IEXXX0001", Some("10.58052/IEXXX0001")),
+            ("https://doi.org/10.60471/ABC123", Some("10.60471/ABC123")),
+            ("10.5555/IEXXX0001", None),
+            ("IEXXX0001", None),
+        ];
+        for (igsn, expected) in cases {
+            assert_eq!(validate_igsn(igsn).as_deref(), expected, "{}", igsn);
+        }
+        assert_eq!(normalize_igsn("IGSN:IEXXX0001"), "https://hdl.handle.net/10273/IEXXX0001");
+        assert_eq!(normalize_igsn("10.58052/IEXXX0001"), "https://doi.org/10.58052/iexxx0001");
+        assert_eq!(validate_id("IGSN:IEXXX0001").1, "IGSN");
+        let legacy = Identifier::parse("IGSN:IEXXX0001").unwrap();
+        assert_eq!(legacy.to_url(), "https://hdl.handle.net/10273/IEXXX0001");
+        assert_eq!(validate_id("10.58052/IEXXX0001").1, "DOI");
+        assert_eq!(validate_id("https://doi.org/10.60471/ABC123").1, "DOI");
+        assert_eq!(
+            Identifier::parse_as("IGSN", "10.58052/IEXXX0001"),
+            Some(Identifier::Igsn("10.58052/IEXXX0001".to_string()))
+        );
+        assert_eq!(validate_id("10.5555/IEXXX0001").1, "DOI");
+    }
+
+    #[test]
+    fn test_validate_raid() {
+        let cases = [
+            ("https://raid.org/10.80368/b1adfb3a", Some("10.80368/b1adfb3a")),
+            ("http://raid.org/10.80368/b1adfb3a/", Some("10.80368/b1adfb3a")),
+            ("https://hdl.handle.net/10378.1/1587071", Some("10378.1/1587071")),
+            ("10378.1/1587071", Some("10378.1/1587071")),
+            ("https://doi.org/10.80368/b1adfb3a", None),
+            ("https://raid.org/about", None),
+        ];
+        for (raid, expected) in cases {
+            assert_eq!(validate_raid(raid).as_deref(), expected, "{}", raid);
+        }
+        assert_eq!(
+            normalize_raid("http://raid.org/10.80368/b1adfb3a"),
+            "https://raid.org/10.80368/b1adfb3a"
+        );
+        assert_eq!(normalize_raid("10378.1/1587071"), "https://hdl.handle.net/10378.1/1587071");
+        assert_eq!(
+            validate_id_category("https://raid.org/10.80368/b1adfb3a"),
+            ("10.80368/b1adfb3a".to_string(), "RAID", "Project")
+        );
+        assert_eq!(validate_id("https://doi.org/10.80368/b1adfb3a").1, "DOI");
+        let urls = ["https://raid.org/10.80368/b1adfb3a", "https://hdl.handle.net/10378.1/1587071"];
+        for raid in urls {
+            assert_eq!(Identifier::parse(raid).unwrap().to_url(), raid);
+        }
+    }
+
+    #[test]
+    fn test_validate_swhid() {
+        let dir = "swh:1:dir:d198bc9d7a6bcf6db04f476d29314f157507d505";
+        let qualified = format!(
+            "{};origin=https://github.com/front-matter/commonmeta;visit=swh:1:snp:{};path=/src/",
+            dir,
+            "c7c108084bc0bf3d81436bf980b46e98bd338453"
+        );
+        assert_eq!(validate_swhid(dir).as_deref(), Some(dir));
+        assert_eq!(validate_swhid(&qualified), Some(qualified.clone()));
+        assert_eq!(
+            validate_swhid(&format!("https://archive.softwareheritage.org/{}/", dir)).as_deref(),
+            Some(dir)
+        );
+        assert_eq!(validate_swhid("swh:1:xyz:d198bc9d7a6bcf6db04f476d29314f157507d505"), None);
+        assert_eq!(validate_swhid("swh:1:dir:d198bc9d"), None);
+        assert_eq!(validate_swhid(&format!("{};color=red", dir)), None);
+        assert_eq!(normalize_swhid(dir), format!("https://archive.softwareheritage.org/{}", dir));
+        assert_eq!(validate_id(&qualified), (qualified.clone(), "SWHID"));
+    }
+
+    #[test]
+    fn test_validate_urn_nbn() {
+        let cases = [
+            ("urn:nbn:de:101:1-2019012108", Some("urn:nbn:de:101:1-2019012108")),
+            ("URN:NBN:DE:101:1-2019012108", Some("urn:nbn:de:101:1-2019012108")),
+            ("urn:nbn:fi-fe2019052316789", Some("urn:nbn:fi-fe2019052316789")),
+            ("urn:nbn:se:uu:diva-123456", Some("urn:nbn:se:uu:diva-123456")),
+            (
+                "https://nbn-resolving.org/urn:nbn:de:bsz:14-qucosa2-123456",
+                Some("urn:nbn:de:bsz:14-qucosa2-123456"),
+            ),
+            ("https://urn.fi/urn:nbn:fi-fe2019052316789", Some("urn:nbn:fi-fe2019052316789")),
+            ("urn:nbn:de:", None),
+            ("urn:isbn:9780262033848", None),
+        ];
+        for (urn, expected) in cases {
+            assert_eq!(validate_urn_nbn(urn).as_deref(), expected, "{}", urn);
+        }
+        assert_eq!(
+            normalize_urn_nbn("urn:nbn:de:101:1-2019012108"),
+            "https://nbn-resolving.org/urn:nbn:de:101:1-2019012108"
+        );
+        assert_eq!(
+            normalize_urn_nbn("urn:nbn:fi-fe2019052316789"),
+            "https://urn.fi/urn:nbn:fi-fe2019052316789"
+        );
+        assert_eq!(
+            normalize_urn_nbn("urn:nbn:se:uu:diva-123456"),
+            "https://urn.kb.se/resolve?urn=urn:nbn:se:uu:diva-123456"
+        );
+        assert_eq!(validate_id("urn:nbn:de:101:1-2019012108").1, "URN");
+    }
+
+    #[test]
+    fn test_validate_isbn() {
+        let cases = [
+            ("978-3-16-148410-0", Some("9783161484100")),
+            ("ISBN 978 0 262 03384 8", Some("9780262033848")),
+            ("ISBN-10: 0-262-03384-4", Some("0262033844")),
+            ("urn:isbn:0-8044-2957-x", Some("080442957X")),
+            ("9791032305560", Some("9791032305560")),
+            ("978-3-16-148410-1", None), // wrong check digit
+            ("0-262-03384-5", None),
+            ("1234-5678", None),
+        ];
+        for (isbn, expected) in cases {
+            assert_eq!(validate_isbn(isbn).as_deref(), expected, "{}", isbn);
+        }
+        assert_eq!(validate_id("978-3-16-148410-0"), ("9783161484100".to_string(), "ISBN"));
+    }
+
+    #[test]
+    fn test_isbn_conversion() {
+        assert_eq!(isbn10_to_isbn13("0-262-03384-4").as_deref(), Some("9780262033848"));
+        assert_eq!(isbn10_to_isbn13("080442957X").as_deref(), Some("9780804429573"));
+        assert_eq!(isbn13_to_isbn10("978-0-8044-2957-3").as_deref(), Some("080442957X"));
+        assert_eq!(isbn13_to_isbn10("9780262033848").as_deref(), Some("0262033844"));
+        assert_eq!(isbn13_to_isbn10("9791032305560"), None);
+        assert_eq!(normalize_isbn("0-262-03384-4"), "9780262033848");
+        assert_eq!(normalize_isbn("0-262-03384-5"), "");
+    }
+
+    #[test]
+    fn test_validate_lccn() {
+        let cases = [
+            ("n78-890351", Some("n78890351")),
+            ("n  78890351 ", Some("n78890351")),
+            ("   85000002 ", Some("85000002")),
+            ("85-2", Some("85000002")),
+            ("75-425165//r75", Some("75425165")),
+            ("2001-000002", Some("2001000002")),
+            ("https://lccn.loc.gov/2001000002", Some("2001000002")),
+            ("lccn: sh85-26642", Some("sh85026642")),
+            ("85-1234567", None),
+            ("abc2001000002", None),
+            ("12345", None),
+        ];
+        for (lccn, expected) in cases {
+            assert_eq!(validate_lccn(lccn).as_deref(), expected, "{}", lccn);
+        }
+        assert_eq!(normalize_lccn("n78-890351"), "https://lccn.loc.gov/n78890351");
+        assert_eq!(validate_id("n78-890351"), ("n78890351".to_string(), "LCCN"));
+    }
+
+    #[test]
+    fn test_validate_viaf() {
+        assert_eq!(validate_viaf("113230702"), Some("113230702".into()));
+        assert_eq!(validate_viaf("http://viaf.org/viaf/113230702/"), Some("113230702".into()));
+        assert_eq!(validate_viaf("VIAF: 113230702"), Some("113230702".into()));
+        assert_eq!(validate_viaf("0113230702"), None);
+        assert_eq!(validate_viaf("https://viaf.org/viaf/abc"), None);
+        assert_eq!(normalize_viaf("113230702"), "https://viaf.org/viaf/113230702");
+        assert_eq!(
+            validate_id_category("https://viaf.org/viaf/12345678"),
+            ("12345678".to_string(), "VIAF", "Contributor")
+        );
+        assert_eq!(validate_id("313230702").1, "VIAF");
+        assert_eq!(validate_id("12345678").1, "PMID");
+    }
+
+    #[test]
+    fn test_validate_gnd() {
+        let cases = [
+            ("118540238", Some("118540238")),
+            ("https://d-nb.info/gnd/118529579/", Some("118529579")),
+            ("gnd:4011882-4", Some("4011882-4")),
+            ("http://d-nb.info/gnd/2004272-3", Some("2004272-3")),
+            ("118540237", None), // wrong check character
+            ("4011882-7", None),
+            ("d-nb.info/gnd/abc", None),
+        ];
+        for (gnd, expected) in cases {
+            assert_eq!(validate_gnd(gnd).as_deref(), expected, "{}", gnd);
+        }
+        assert_eq!(normalize_gnd("gnd:4011882-4"), "https://d-nb.info/gnd/4011882-4");
+        assert_eq!(
+            validate_id("https://d-nb.info/gnd/118540238"),
+            ("118540238".to_string(), "GND")
+        );
+        assert_eq!(validate_id("118540237").1, "VIAF");
+        assert_eq!(validate_id("118540238").1, "VIAF");
+        assert_ne!(validate_id("123456789").1, "GND");
+        assert_eq!(validate_id("gnd:118540238").1, "GND");
+        assert_eq!(validate_id("4011882-4").1, "GND");
+        assert_eq!(
+            Identifier::parse_as("GND", "118540238"),
+            Some(Identifier::Gnd("118540238".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_scopus_eid() {
+        let cases = [
+            ("2-s2.0-85012345678", Some("2-s2.0-85012345678")),
+            ("EID: 2-s2.0-0034567890", Some("2-s2.0-0034567890")),
+            (
+                "https://www.scopus.com/record/display.uri?eid=2-s2.0-85012345678&origin=resultslist",
+                Some("2-s2.0-85012345678"),
+            ),
+            (
+                "https://www.scopus.com/inward/record.uri?eid=2-s2.0-85012345678",
+                Some("2-s2.0-85012345678"),
+            ),
+            ("2-s2.0-", None),
+            ("85012345678", None),
+        ];
+        for (eid, expected) in cases {
+            assert_eq!(validate_scopus_eid(eid).as_deref(), expected, "{}", eid);
+        }
+        assert_eq!(
+            normalize_scopus_eid("eid:2-s2.0-85012345678"),
+            "https://www.scopus.com/inward/record.uri?eid=2-s2.0-85012345678"
+        );
+        assert_eq!(
+            validate_id_category("2-s2.0-85012345678"),
+            ("2-s2.0-85012345678".to_string(), "Scopus", "Work")
+        );
+    }
+
+    #[test]
+    fn test_generate_orcid_checksum() {
+        assert_eq!(generate_orcid_checksum("000000021825009"), '7');
+        assert_eq!(generate_orcid_checksum("0000-0002-1694-233"), 'X');
+        assert_eq!(generate_orcid_checksum("0000 0001 2146 438"), 'X');
+    }
+
+    #[test]
+    fn test_format_orcid() {
+        let cases = [
+            ("0000000218250097", "0000-0002-1825-0097"),
+            ("000000021825009", "0000-0002-1825-0097"),
+            ("https://orcid.org/0000 0002 1694 233x", "0000-0002-1694-233X"),
+            ("ORCID.org/0000-00021694-233X", "0000-0002-1694-233X"),
+            ("0000–0002–1825–0097", "0000-0002-1825-0097"),
+            ("0000-0002-1825-009", "0000-0002-1825-0097"),
+            ("0000-0002-1825", ""),
+            ("0000-0002-1825-00X7", ""),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(format_orcid(raw), expected, "{}", raw);
+        }
+    }
 }
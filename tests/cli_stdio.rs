@@ -0,0 +1,62 @@
+//! The command-line tool reads `-` as stdin and writes `-` to stdout.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/commonmeta")
+        .join(name)
+}
+
+/// Run the commonmeta binary with `args`, piping `stdin` into it.
+fn run(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_commonmeta"))
+        .args(args)
+        .env("COMMONMETA_DB", "/nonexistent/commonmeta.sqlite3")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start commonmeta");
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn convert_reads_stdin() {
+    let input = std::fs::read(fixture("journal_article.json")).unwrap();
+    let output = run(&["--quiet", "convert", "-", "--from", "commonmeta", "--to", "csl"], &input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let csl: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(csl["type"], "article-journal");
+}
+
+#[test]
+fn convert_writes_stdout_for_dash_file() {
+    let path = fixture("journal_article.json");
+    let output = run(
+        &["--quiet", "convert", path.to_str().unwrap(), "--to", "csl", "--file", "-"],
+        b"",
+    );
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let csl: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(csl["type"], "article-journal");
+}
+
+#[test]
+fn list_reads_commonmeta_batch_from_stdin() {
+    let record: Value =
+        serde_json::from_slice(&std::fs::read(fixture("journal_article.json")).unwrap()).unwrap();
+    let input = format!("{}\n{}\n", record, record);
+    let output = run(&["--quiet", "list", "-", "--to", "commonmeta"], input.as_bytes());
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let list: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(list.as_array().map(Vec::len), Some(2));
+}
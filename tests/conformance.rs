@@ -332,7 +332,7 @@ fn assert_golden_ext_reader(format: &str, input_dir: &std::path::Path, ext: &str
     );
 }
 
-/// Golden test: Crossref XML API response → commonmeta reader.
+/// Golden test: Crossref XML (API response or deposit) → commonmeta reader.
 /// Convention:
 ///   tests/fixtures/crossref_xml/<name>.xml          -> input
 ///   tests/fixtures/crossref_xml_commonmeta/<name>.json -> expected commonmeta output
@@ -360,6 +360,20 @@ fn cff_to_commonmeta_golden() {
     );
 }
 
+/// Golden test: JATS → commonmeta reader.
+/// Convention:
+///   tests/fixtures/jats/<name>.xml             -> input
+///   tests/fixtures/jats_commonmeta/<name>.json -> expected commonmeta output
+#[test]
+fn jats_to_commonmeta_golden() {
+    assert_golden_ext_reader(
+        "jats",
+        &fixtures_dir().join("jats"),
+        "xml",
+        &fixtures_dir().join("jats_commonmeta"),
+    );
+}
+
 /// Golden test: RIS → commonmeta reader.
 /// Convention:
 ///   tests/fixtures/ris/<name>.ris             -> input